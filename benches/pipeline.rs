@@ -0,0 +1,149 @@
+//! Per-phase pipeline benchmarks
+//!
+//! Times each phase of analysis on its own over the checked-in fixtures in
+//! `tests/fixtures`, so a regression points at the phase that caused it and
+//! the numbers are repeatable without the external test repos. The `bench_*`
+//! functions are the entry points; each criterion group wraps one of them.
+//!
+//! Run with: cargo bench --bench pipeline
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tree_sitter::Tree;
+
+use semfora_engine::cache::CacheDir;
+use semfora_engine::extract::extract;
+use semfora_engine::socket_server::{index_directory, IndexOptions, IndexResult};
+use semfora_engine::{encode_toon, Lang, SemanticSummary};
+
+/// Directory of source files the benchmarks run over
+fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+/// A fixture file with its detected language
+struct Fixture {
+    name: String,
+    path: PathBuf,
+    source: String,
+    lang: Lang,
+}
+
+/// Every fixture with a supported language, sorted by name
+fn load_fixtures() -> Vec<Fixture> {
+    let mut fixtures: Vec<Fixture> = fs::read_dir(fixtures_dir())
+        .expect("tests/fixtures not found")
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let lang = Lang::from_path(&path).ok()?;
+            let source = fs::read_to_string(&path).ok()?;
+            let name = path.file_name()?.to_string_lossy().into_owned();
+            Some(Fixture {
+                name,
+                path,
+                source,
+                lang,
+            })
+        })
+        .collect();
+    fixtures.sort_by(|a, b| a.name.cmp(&b.name));
+    fixtures
+}
+
+/// Parse `source` into a syntax tree
+pub fn bench_parse(source: &str, lang: Lang) -> Tree {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&lang.tree_sitter_language())
+        .expect("failed to set language");
+    parser.parse(source, None).expect("failed to parse")
+}
+
+/// Extract a semantic summary from an already parsed tree
+pub fn bench_extract(path: &Path, source: &str, tree: &Tree, lang: Lang) -> SemanticSummary {
+    extract(path, source, tree, lang).expect("failed to extract")
+}
+
+/// Encode a summary as TOON
+pub fn bench_encode_toon(summary: &SemanticSummary) -> String {
+    encode_toon(summary)
+}
+
+/// Index every source file under `dir` into a throwaway cache
+pub fn bench_index_dir(dir: &Path) -> IndexResult {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let cache = CacheDir {
+        root: temp_dir.path().to_path_buf(),
+        repo_root: dir.to_path_buf(),
+        repo_hash: "bench_pipeline".to_string(),
+    };
+    cache.init().unwrap();
+
+    let options = IndexOptions {
+        // The fixtures live under tests/, which is skipped by default
+        include_tests: true,
+        ..Default::default()
+    };
+    index_directory(dir, cache, &options).expect("failed to index")
+}
+
+fn bench_parse_phase(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    for fixture in load_fixtures() {
+        group.throughput(Throughput::Bytes(fixture.source.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(&fixture.name),
+            &fixture,
+            |b, f| b.iter(|| bench_parse(black_box(&f.source), f.lang)),
+        );
+    }
+    group.finish();
+}
+
+fn bench_extract_phase(c: &mut Criterion) {
+    let mut group = c.benchmark_group("extract");
+    for fixture in load_fixtures() {
+        let tree = bench_parse(&fixture.source, fixture.lang);
+        group.throughput(Throughput::Bytes(fixture.source.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(&fixture.name),
+            &fixture,
+            |b, f| b.iter(|| bench_extract(&f.path, black_box(&f.source), &tree, f.lang)),
+        );
+    }
+    group.finish();
+}
+
+fn bench_encode_toon_phase(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encode_toon");
+    for fixture in load_fixtures() {
+        let tree = bench_parse(&fixture.source, fixture.lang);
+        let summary = bench_extract(&fixture.path, &fixture.source, &tree, fixture.lang);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(&fixture.name),
+            &summary,
+            |b, s| b.iter(|| bench_encode_toon(black_box(s))),
+        );
+    }
+    group.finish();
+}
+
+fn bench_index_dir_phase(c: &mut Criterion) {
+    let dir = fixtures_dir();
+    let mut group = c.benchmark_group("index_dir");
+    group.sample_size(20);
+    group.throughput(Throughput::Elements(load_fixtures().len() as u64));
+    group.bench_function("fixtures", |b| b.iter(|| bench_index_dir(black_box(&dir))));
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_parse_phase,
+    bench_extract_phase,
+    bench_encode_toon_phase,
+    bench_index_dir_phase
+);
+criterion_main!(benches);
@@ -3,12 +3,16 @@
 //! Provides complexity metrics, call graph analysis, and code health reports
 //! built on top of the semantic index.
 
-use crate::cache::CacheDir;
-use crate::schema::{RiskLevel, SemanticSummary, SymbolKind};
+use crate::cache::{normalize_kind, CacheDir, SymbolIndexEntry};
+use crate::detectors::javascript::frameworks::nextjs::extract_route_path;
+use crate::duplicate::boilerplate::matches_glob;
+use crate::schema::{FrameworkEntryPoint, RiskLevel, SemanticSummary, SymbolInfo, SymbolKind};
+use crate::search::is_test_file;
 use crate::utils::truncate_to_char_boundary;
 use crate::Result;
 use rayon::prelude::*;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::path::Path;
 
 /// Complexity metrics for a single symbol
@@ -80,6 +84,20 @@ pub fn calculate_cognitive_complexity(control_flow: &[crate::schema::ControlFlow
     complexity
 }
 
+/// Cognitive complexity increments that don't come from control flow
+///
+/// SonarSource adds +1 for recursion. Nested functions (closures, lambdas, inner
+/// functions) add nothing here: they raise the `nesting_depth` of the control
+/// flow inside them instead.
+pub fn structural_cognitive_increments(symbol: &SymbolInfo) -> usize {
+    usize::from(symbol.is_recursive)
+}
+
+/// Calculate cognitive complexity for a symbol, including recursion
+pub fn calculate_symbol_cognitive_complexity(symbol: &SymbolInfo) -> usize {
+    calculate_cognitive_complexity(&symbol.control_flow) + structural_cognitive_increments(symbol)
+}
+
 /// Get the maximum nesting depth from control flow changes
 pub fn max_nesting_depth(control_flow: &[crate::schema::ControlFlowChange]) -> usize {
     control_flow
@@ -174,6 +192,32 @@ impl ModuleMetrics {
     }
 }
 
+/// Maximum average cognitive complexity per module, read from
+/// `.semfora-budgets.json`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ComplexityBudgets {
+    /// Checked in order; a module uses the first budget whose glob matches
+    #[serde(default)]
+    pub budgets: Vec<ModuleBudget>,
+}
+
+/// Complexity cap for the modules matching a glob
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModuleBudget {
+    /// Module name glob, e.g. `src.api` or `src.legacy.**`
+    pub module: String,
+    /// Largest allowed [`ModuleMetrics::avg_complexity`]
+    pub max_avg_complexity: f64,
+}
+
+/// Module whose average complexity is over its budget
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetViolation {
+    pub module: String,
+    pub actual: f64,
+    pub budget: f64,
+}
+
 /// Call graph analysis results
 #[derive(Debug, Clone, Default)]
 pub struct CallGraphAnalysis {
@@ -189,6 +233,8 @@ pub struct CallGraphAnalysis {
     pub entry_points: Vec<String>,
     /// Leaf functions (called but don't call others)
     pub leaf_functions: Vec<String>,
+    /// Symbols ranked by PageRank over the call graph, most central first
+    pub centrality: Vec<(String, f64)>,
 }
 
 /// Repository-wide analysis summary
@@ -205,6 +251,70 @@ pub struct RepoAnalysis {
     pub total_lines: usize,
     pub avg_complexity: f64,
     pub high_risk_percentage: f64,
+    /// Per-file aggregate complexity, worst first
+    pub heatmap: Vec<FileHeat>,
+}
+
+/// Aggregate complexity of one file, a row of the complexity heatmap
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileHeat {
+    pub file: String,
+    /// Sum of cognitive complexity over the file's symbols
+    pub cognitive: usize,
+    /// Cognitive complexity of the file's worst symbol
+    pub max_cognitive: usize,
+    pub symbols: usize,
+    /// Highest risk of any symbol in the file
+    pub risk: RiskLevel,
+}
+
+impl RepoAnalysis {
+    /// Modules ranked by afferent coupling (most imported first)
+    ///
+    /// Modules nothing else imports are left out. Ties are broken by name.
+    pub fn most_depended_upon(&self, limit: usize) -> Vec<&ModuleMetrics> {
+        let mut ranked: Vec<&ModuleMetrics> = self
+            .modules
+            .iter()
+            .filter(|m| m.afferent_coupling > 0)
+            .collect();
+        ranked.sort_by(|a, b| {
+            b.afferent_coupling
+                .cmp(&a.afferent_coupling)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        ranked.truncate(limit);
+        ranked
+    }
+}
+
+/// Fill afferent/efferent coupling on each module from the module import graph
+///
+/// `module_graph` maps a module to the modules it imports (see
+/// [`crate::cache::build_module_graph`]). Self-imports and duplicate edges
+/// are ignored.
+pub fn compute_module_coupling(
+    modules: &mut [ModuleMetrics],
+    module_graph: &HashMap<String, Vec<String>>,
+) {
+    let mut afferent: HashMap<&str, usize> = HashMap::new();
+    let mut efferent: HashMap<&str, usize> = HashMap::new();
+    for (module, deps) in module_graph {
+        let unique: BTreeSet<&str> = deps
+            .iter()
+            .map(String::as_str)
+            .filter(|dep| *dep != module.as_str())
+            .collect();
+        efferent.insert(module.as_str(), unique.len());
+        for dep in unique {
+            *afferent.entry(dep).or_insert(0) += 1;
+        }
+    }
+
+    for module in modules.iter_mut() {
+        module.afferent_coupling = afferent.get(module.name.as_str()).copied().unwrap_or(0);
+        module.efferent_coupling = efferent.get(module.name.as_str()).copied().unwrap_or(0);
+    }
 }
 
 /// Analyze complexity from a call graph
@@ -279,9 +389,83 @@ pub fn analyze_call_graph(
     // Cycle detection using DFS
     analysis.cycles = detect_cycles(call_graph);
 
+    // Transitive importance (PageRank)
+    let mut centrality: Vec<_> = pagerank(call_graph)
+        .into_iter()
+        .map(|(hash, score)| {
+            let name = symbol_names
+                .get(&hash)
+                .cloned()
+                .unwrap_or_else(|| hash.clone());
+            (name, score)
+        })
+        .collect();
+    centrality.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    analysis.centrality = centrality.into_iter().take(10).collect();
+
     analysis
 }
 
+/// Damping factor for call graph PageRank
+const PAGERANK_DAMPING: f64 = 0.85;
+
+/// Fixed iteration count, so scores are identical across runs
+const PAGERANK_ITERATIONS: usize = 50;
+
+/// PageRank over the call graph, with rank flowing from callers to callees
+///
+/// A symbol called by central symbols is itself central. Symbols that call
+/// nothing spread their rank evenly over the whole graph.
+fn pagerank(graph: &HashMap<String, Vec<String>>) -> Vec<(String, f64)> {
+    // Sorted so floating-point sums happen in the same order every run
+    let nodes: Vec<&str> = graph
+        .iter()
+        .flat_map(|(caller, callees)| {
+            std::iter::once(caller.as_str()).chain(callees.iter().map(String::as_str))
+        })
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    if nodes.is_empty() {
+        return Vec::new();
+    }
+    let index: HashMap<&str, usize> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let edges: Vec<Vec<usize>> = nodes
+        .iter()
+        .map(|node| {
+            graph
+                .get(*node)
+                .map(|callees| callees.iter().map(|c| index[c.as_str()]).collect())
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let n = nodes.len() as f64;
+    let mut ranks = vec![1.0 / n; nodes.len()];
+    for _ in 0..PAGERANK_ITERATIONS {
+        let dangling: f64 = edges
+            .iter()
+            .zip(&ranks)
+            .filter(|(out, _)| out.is_empty())
+            .map(|(_, rank)| rank)
+            .sum();
+        let base = (1.0 - PAGERANK_DAMPING) / n + PAGERANK_DAMPING * dangling / n;
+        let mut next = vec![base; nodes.len()];
+        for (out, rank) in edges.iter().zip(&ranks) {
+            if out.is_empty() {
+                continue;
+            }
+            let share = PAGERANK_DAMPING * rank / out.len() as f64;
+            for &callee in out {
+                next[callee] += share;
+            }
+        }
+        ranks = next;
+    }
+
+    nodes.into_iter().map(str::to_string).zip(ranks).collect()
+}
+
 /// Detect cycles in call graph using DFS
 fn detect_cycles(graph: &HashMap<String, Vec<String>>) -> Vec<Vec<String>> {
     let mut cycles = Vec::new();
@@ -417,6 +601,28 @@ pub fn format_analysis_report(analysis: &RepoAnalysis) -> String {
     }
     output.push('\n');
 
+    let depended_upon = analysis.most_depended_upon(10);
+    if !depended_upon.is_empty() {
+        output.push_str("── MOST DEPENDED-UPON MODULES ───────────────────────────────────────\n");
+        output.push_str("  Module              Imported By  Imports  Instability\n");
+        output.push_str("  ─────────────────────────────────────────────────────────────────\n");
+        for m in depended_upon {
+            let name = if m.name.len() > 18 {
+                format!("{}...", truncate_to_char_boundary(&m.name, 15))
+            } else {
+                m.name.clone()
+            };
+            output.push_str(&format!(
+                "  {:<18} {:>12}  {:>7}  {:>11.2}\n",
+                name,
+                m.afferent_coupling,
+                m.efferent_coupling,
+                m.instability()
+            ));
+        }
+        output.push('\n');
+    }
+
     // Call Graph Analysis
     output.push_str("── CALL GRAPH ANALYSIS ──────────────────────────────────────────────\n");
 
@@ -429,6 +635,15 @@ pub fn format_analysis_report(analysis: &RepoAnalysis) -> String {
         output.push('\n');
     }
 
+    if !analysis.call_graph.centrality.is_empty() {
+        output.push_str("  Central Symbols (PageRank):\n");
+        for (name, score) in analysis.call_graph.centrality.iter().take(5) {
+            let display = truncate_to_char_boundary(name, 40);
+            output.push_str(&format!("    {:<40} ({:.3})\n", display, score));
+        }
+        output.push('\n');
+    }
+
     if !analysis.call_graph.high_coupling.is_empty() {
         output.push_str("  High Coupling (many outgoing calls):\n");
         for (name, count) in analysis.call_graph.high_coupling.iter().take(5) {
@@ -525,6 +740,550 @@ pub fn symbol_complexity_from_summary(
     }
 }
 
+/// Column names of [`metrics_to_csv`]
+const METRICS_CSV_HEADER: &str = "path,language,symbol,symbol_count,cognitive_complexity,\
+cyclomatic_complexity,loc,risk,io_operations,dependencies";
+
+/// Per-file metrics as CSV, one row per summary
+///
+/// Complexity and LOC describe the file's primary symbol, as in
+/// [`symbol_complexity_from_summary`]. Rows end in `\n`; fields containing
+/// commas, quotes, or line breaks are quoted (RFC 4180).
+pub fn metrics_to_csv(summaries: &[SemanticSummary]) -> String {
+    let mut csv = String::from(METRICS_CSV_HEADER);
+    csv.push('\n');
+
+    for summary in summaries {
+        let metrics = symbol_complexity_from_summary(summary, 0);
+        let fields = [
+            csv_field(&summary.file),
+            csv_field(&summary.language),
+            csv_field(summary.symbol.as_deref().unwrap_or("")),
+            summary.symbols.len().to_string(),
+            metrics.cognitive.to_string(),
+            metrics.cyclomatic.to_string(),
+            metrics.loc.to_string(),
+            metrics.risk.as_str().to_string(),
+            metrics.io_operations.to_string(),
+            metrics.dependencies.to_string(),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+/// Quote a CSV field if it contains a delimiter, quote, or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Minimum number of symbols sharing a parameter group before it is reported
+pub const PRIMITIVE_GROUP_MIN_OCCURRENCES: usize = 3;
+
+/// Type names treated as primitives (compared case-insensitively, `&` stripped)
+const PRIMITIVE_TYPES: &[&str] = &[
+    "number", "string", "boolean", "bool", "str", "char", "int", "integer", "long", "short",
+    "byte", "float", "double", "decimal", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16",
+    "u32", "u64", "u128", "usize", "f32", "f64", "int32", "int64", "uint", "uint32", "uint64",
+    "float32", "float64", "rune",
+];
+
+/// A cluster of primitive parameters that recurs across functions
+///
+/// Recurring groups such as `(lat: f64, lng: f64)` usually point at a missing
+/// type ("primitive obsession").
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrimitiveParamGroup {
+    /// Parameters in the group, rendered as `name: type` and sorted
+    pub params: Vec<String>,
+    /// Symbols taking every parameter of the group, as `file:name`
+    pub symbols: Vec<String>,
+}
+
+fn is_primitive_type(ty: &str) -> bool {
+    let ty = ty.trim().trim_start_matches('&').trim();
+    PRIMITIVE_TYPES.iter().any(|p| p.eq_ignore_ascii_case(ty))
+}
+
+/// Find primitive parameter groups shared by at least `min_occurrences` functions
+///
+/// Parameters match on both name and declared type; untyped parameters are
+/// ignored. Co-occurring pairs are found first, then pairs seen in exactly the
+/// same set of functions are merged into larger groups. Results are sorted by
+/// number of functions, most widespread first.
+pub fn find_primitive_param_groups(
+    summaries: &[SemanticSummary],
+    min_occurrences: usize,
+) -> Vec<PrimitiveParamGroup> {
+    let mut pair_symbols: HashMap<(String, String), BTreeSet<String>> = HashMap::new();
+
+    for summary in summaries {
+        for symbol in &summary.symbols {
+            if !matches!(symbol.kind, SymbolKind::Function | SymbolKind::Method) {
+                continue;
+            }
+
+            let mut params: Vec<String> = symbol
+                .arguments
+                .iter()
+                .filter_map(|arg| {
+                    let ty = arg.arg_type.as_deref()?;
+                    is_primitive_type(ty).then(|| format!("{}: {}", arg.name, ty.trim()))
+                })
+                .collect();
+            params.sort();
+            params.dedup();
+
+            let id = format!("{}:{}", summary.file, symbol.name);
+            for (i, first) in params.iter().enumerate() {
+                for second in &params[i + 1..] {
+                    pair_symbols
+                        .entry((first.clone(), second.clone()))
+                        .or_default()
+                        .insert(id.clone());
+                }
+            }
+        }
+    }
+
+    let mut merged: BTreeMap<BTreeSet<String>, BTreeSet<String>> = BTreeMap::new();
+    for ((first, second), symbols) in pair_symbols {
+        if symbols.len() < min_occurrences.max(2) {
+            continue;
+        }
+        let params = merged.entry(symbols).or_default();
+        params.insert(first);
+        params.insert(second);
+    }
+
+    let mut groups: Vec<PrimitiveParamGroup> = merged
+        .into_iter()
+        .map(|(symbols, params)| PrimitiveParamGroup {
+            params: params.into_iter().collect(),
+            symbols: symbols.into_iter().collect(),
+        })
+        .collect();
+    groups.sort_by(|a, b| {
+        b.symbols
+            .len()
+            .cmp(&a.symbols.len())
+            .then_with(|| a.params.cmp(&b.params))
+    });
+    groups
+}
+
+/// Call to a repository function that passes many arguments by position
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PositionalArgCall {
+    /// File containing the call
+    pub file: String,
+    pub line: usize,
+    pub callee: String,
+    /// Number of positional arguments
+    pub args: usize,
+    /// File defining the callee
+    pub defined_in: String,
+}
+
+/// Find calls that pass `min_args` or more arguments to a repository function
+/// without naming any of them
+///
+/// Builds on each summary's [`positional_calls`](SemanticSummary::positional_calls):
+/// a call is kept when its callee is a function or method defined in
+/// `summaries`, so calls into libraries and builtins (`print`, `max`) are
+/// ignored. Callees taking variadic parameters (`*args`, `...rest`) are
+/// skipped since they can only be called positionally.
+pub fn find_positional_arg_calls(
+    summaries: &[SemanticSummary],
+    min_args: usize,
+) -> Vec<PositionalArgCall> {
+    // Short name -> defining file, for non-variadic functions
+    let mut definitions: HashMap<&str, &str> = HashMap::new();
+    let mut variadic: HashSet<&str> = HashSet::new();
+    for summary in summaries {
+        for symbol in &summary.symbols {
+            if !matches!(symbol.kind, SymbolKind::Function | SymbolKind::Method)
+                || symbol.is_escape_local
+            {
+                continue;
+            }
+            let name = short_name(&symbol.name);
+            let is_variadic = symbol
+                .arguments
+                .iter()
+                .any(|a| a.name.starts_with('*') || a.name.starts_with("..."));
+            if is_variadic {
+                variadic.insert(name);
+            } else {
+                definitions.entry(name).or_insert(&summary.file);
+            }
+        }
+    }
+
+    let mut found = Vec::new();
+    for summary in summaries {
+        for call in &summary.positional_calls {
+            if call.args < min_args || variadic.contains(call.callee.as_str()) {
+                continue;
+            }
+            if let Some(defined_in) = definitions.get(call.callee.as_str()) {
+                found.push(PositionalArgCall {
+                    file: summary.file.clone(),
+                    line: call.location.line,
+                    callee: call.callee.clone(),
+                    args: call.args,
+                    defined_in: defined_in.to_string(),
+                });
+            }
+        }
+    }
+    found
+}
+
+/// String literal repeated across the repository
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DuplicateLiteral {
+    pub value: String,
+    /// (file, line) of every occurrence, in `summaries` order
+    pub locations: Vec<(String, usize)>,
+}
+
+/// Find string literals that appear `min_occurrences` times or more
+///
+/// Builds on each summary's [`string_literals`](SemanticSummary::string_literals),
+/// which already leaves out short strings, import paths, and docstrings.
+/// Literals in test files are ignored since fixtures repeat by design. The
+/// most repeated literals come first; ties are ordered by value.
+///
+/// # Library only
+///
+/// No CLI command or MCP tool calls this. `string_literals` is not
+/// serialized, so the index holds none: pass summaries straight from
+/// [`parse_and_extract`](crate::parsing::parse_and_extract) or
+/// [`extract`](crate::extract::extract). Summaries read back from JSON or
+/// the cache report no duplicates.
+pub fn duplicate_literals(
+    summaries: &[SemanticSummary],
+    min_occurrences: usize,
+) -> Vec<DuplicateLiteral> {
+    let mut occurrences: HashMap<&str, Vec<(String, usize)>> = HashMap::new();
+    for summary in summaries {
+        if is_test_file(&summary.file) {
+            continue;
+        }
+        for literal in &summary.string_literals {
+            occurrences
+                .entry(literal.value.as_str())
+                .or_default()
+                .push((summary.file.clone(), literal.location.line));
+        }
+    }
+
+    let mut found: Vec<DuplicateLiteral> = occurrences
+        .into_iter()
+        .filter(|(_, locations)| locations.len() >= min_occurrences.max(2))
+        .map(|(value, locations)| DuplicateLiteral {
+            value: value.to_string(),
+            locations,
+        })
+        .collect();
+    found.sort_by(|a, b| {
+        b.locations
+            .len()
+            .cmp(&a.locations.len())
+            .then_with(|| a.value.cmp(&b.value))
+    });
+    found
+}
+
+/// Modules of `analysis` whose average complexity exceeds their budget
+///
+/// Modules that match no budget are unconstrained. Violations come in
+/// `analysis.modules` order.
+pub fn check_budgets(analysis: &RepoAnalysis, config: &ComplexityBudgets) -> Vec<BudgetViolation> {
+    analysis
+        .modules
+        .iter()
+        .filter_map(|module| {
+            let budget = config
+                .budgets
+                .iter()
+                .find(|b| matches_glob(&b.module, &module.name))?;
+            (module.avg_complexity > budget.max_avg_complexity).then(|| BudgetViolation {
+                module: module.name.clone(),
+                actual: module.avg_complexity,
+                budget: budget.max_avg_complexity,
+            })
+        })
+        .collect()
+}
+
+/// Last segment of a qualified name (`Cart.total` -> `total`)
+fn short_name(name: &str) -> &str {
+    name.rsplit(['.', ':']).next().unwrap_or(name)
+}
+
+/// Whether an index entry is a test: defined in a test file or named `test_*`
+fn is_test_entry(entry: &SymbolIndexEntry) -> bool {
+    is_test_file(&entry.file) || entry.symbol.starts_with("test_")
+}
+
+/// Whether an index entry looks like a one-line accessor (`getX`, `set_x`)
+fn is_trivial_accessor(entry: &SymbolIndexEntry) -> bool {
+    let name = short_name(&entry.symbol);
+    let is_accessor = ["get", "set"].iter().any(|prefix| {
+        name.strip_prefix(prefix).is_some_and(|rest| {
+            rest.starts_with('_') || rest.starts_with(|c: char| c.is_ascii_uppercase())
+        })
+    });
+    let (start, end) = parse_lines(&entry.lines);
+    is_accessor && end.saturating_sub(start) <= 2
+}
+
+/// Public functions that no test appears to call
+///
+/// Name-based and heuristic: every call made by a test symbol (one in a
+/// test file, or named `test_*`) counts as covering any symbol with that
+/// name. Trivial getters and setters are left out. Results are sorted by
+/// file and line.
+pub fn untested_symbols(cache: &CacheDir) -> Result<Vec<SymbolIndexEntry>> {
+    let entries = cache.load_all_symbol_entries()?;
+    let call_graph = cache.load_call_graph()?;
+
+    let hash_to_name: HashMap<&str, &str> = entries
+        .iter()
+        .map(|e| (e.hash.as_str(), e.symbol.as_str()))
+        .collect();
+
+    let mut tested: HashSet<String> = HashSet::new();
+    for entry in entries.iter().filter(|e| is_test_entry(e)) {
+        for callee in call_graph.get(&entry.hash).into_iter().flatten() {
+            let callee = callee.trim_matches('"');
+            let name = match hash_to_name.get(callee) {
+                Some(&name) => name,
+                None => {
+                    let call = callee.strip_prefix("ext:").unwrap_or(callee);
+                    let call = call.split('(').next().unwrap_or(call);
+                    short_name(call)
+                }
+            };
+            tested.insert(name.to_string());
+        }
+    }
+
+    let mut untested: Vec<SymbolIndexEntry> = entries
+        .into_iter()
+        .filter(|e| {
+            e.is_exported
+                && !e.is_escape_local
+                && matches!(normalize_kind(&e.kind), "function" | "component")
+                && !is_test_entry(e)
+                && !is_trivial_accessor(e)
+                && !tested.contains(short_name(&e.symbol))
+        })
+        .collect();
+    untested.sort_by(|a, b| {
+        a.file
+            .cmp(&b.file)
+            .then_with(|| parse_lines(&a.lines).0.cmp(&parse_lines(&b.lines).0))
+    });
+    Ok(untested)
+}
+
+/// Shortest call chain from one symbol to another
+///
+/// Breadth-first over the cached call graph, so the first chain found has
+/// the fewest hops. The path is returned as symbol names, from `from_hash`
+/// to `to_hash` inclusive; callees missing from the symbol index keep their
+/// raw graph key. `None` when `to_hash` can't be reached; an error when the
+/// call graph or symbol index can't be loaded.
+pub fn trace_call_path(
+    cache: &CacheDir,
+    from_hash: &str,
+    to_hash: &str,
+) -> Result<Option<Vec<String>>> {
+    let call_graph = cache.load_call_graph()?;
+    let entries = cache.load_all_symbol_entries()?;
+    let hash_to_name: HashMap<&str, &str> = entries
+        .iter()
+        .map(|e| (e.hash.as_str(), e.symbol.as_str()))
+        .collect();
+
+    // Each reached node remembers the caller it was reached from
+    let mut came_from: HashMap<&str, &str> = HashMap::new();
+    let mut queue: VecDeque<&str> = VecDeque::from([from_hash]);
+    came_from.insert(from_hash, from_hash);
+    while let Some(current) = queue.pop_front() {
+        if current == to_hash {
+            let mut path = vec![current];
+            let mut node = current;
+            while node != from_hash {
+                node = came_from[node];
+                path.push(node);
+            }
+            path.reverse();
+            return Ok(Some(
+                path.into_iter()
+                    .map(|hash| hash_to_name.get(hash).unwrap_or(&hash).to_string())
+                    .collect(),
+            ));
+        }
+        for callee in call_graph.get(current).into_iter().flatten() {
+            let callee = callee.trim_matches('"');
+            if !came_from.contains_key(callee) {
+                came_from.insert(callee, current);
+                queue.push_back(callee);
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Where execution can start, as classified by [`entry_points`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EntryPointKind {
+    /// A program's `main` function, or a framework bootstrap
+    Main,
+    /// An HTTP handler; `method` is `None` when it accepts any method, and
+    /// `path` when the route isn't derivable from the file path
+    HttpRoute {
+        method: Option<String>,
+        path: Option<String>,
+    },
+    /// A CLI subcommand (`@click.command`, `@app.command`)
+    CliCommand,
+    /// A Go `TestMain`, which wraps a package's tests
+    TestMain,
+    /// A public symbol of a package root (`lib.rs`, `index.ts`, `__init__.py`)
+    LibraryRoot,
+}
+
+/// A symbol where execution can start
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntryPoint {
+    pub kind: EntryPointKind,
+    pub symbol: String,
+    pub hash: String,
+    pub file: String,
+    pub lines: String,
+}
+
+/// HTTP methods recognized in handler names and route decorators
+const HTTP_METHODS: &[&str] = &["GET", "POST", "PUT", "DELETE", "PATCH", "HEAD", "OPTIONS"];
+
+/// File names whose exports form a package's public API
+const LIBRARY_ROOT_FILES: &[&str] = &[
+    "lib.rs",
+    "index.ts",
+    "index.tsx",
+    "index.js",
+    "index.mjs",
+    "__init__.py",
+];
+
+/// Every entry point in the index, typed by kind
+///
+/// Unifies the framework tags set during extraction (Next.js routes and
+/// pages, Express routes, NestJS bootstrap, CLI mains) with name and
+/// decorator heuristics: `main`, Go `TestMain`, route decorators such as
+/// `@app.get` or `@PostMapping`, `*.command` decorators, and exports of a
+/// package root file. Each symbol gets at most one kind. Results are sorted
+/// by file and line.
+pub fn entry_points(cache: &CacheDir) -> Result<Vec<EntryPoint>> {
+    let mut found: Vec<EntryPoint> = cache
+        .load_all_symbol_entries()?
+        .into_iter()
+        .filter_map(|entry| {
+            let kind = classify_entry_point(&entry)?;
+            Some(EntryPoint {
+                kind,
+                symbol: entry.symbol,
+                hash: entry.hash,
+                file: entry.file,
+                lines: entry.lines,
+            })
+        })
+        .collect();
+    found.sort_by(|a, b| {
+        a.file
+            .cmp(&b.file)
+            .then_with(|| parse_lines(&a.lines).0.cmp(&parse_lines(&b.lines).0))
+    });
+    Ok(found)
+}
+
+/// The kind of entry point an index entry is, if any
+fn classify_entry_point(entry: &SymbolIndexEntry) -> Option<EntryPointKind> {
+    let route_path = || extract_route_path(&entry.file);
+    match entry.framework_entry_point {
+        FrameworkEntryPoint::CliMain | FrameworkEntryPoint::NestBootstrap => {
+            return Some(EntryPointKind::Main)
+        }
+        FrameworkEntryPoint::NextApiRoute => {
+            let method = entry.symbol.to_uppercase();
+            return Some(EntryPointKind::HttpRoute {
+                method: HTTP_METHODS.contains(&method.as_str()).then_some(method),
+                path: route_path(),
+            });
+        }
+        FrameworkEntryPoint::NextPage => {
+            return Some(EntryPointKind::HttpRoute {
+                method: Some("GET".to_string()),
+                path: route_path(),
+            });
+        }
+        FrameworkEntryPoint::ExpressRoute => {
+            return Some(EntryPointKind::HttpRoute {
+                method: None,
+                path: None,
+            });
+        }
+        _ => {}
+    }
+
+    let is_function = matches!(normalize_kind(&entry.kind), "function" | "method");
+    if is_function && entry.symbol == "main" {
+        return Some(EntryPointKind::Main);
+    }
+    if is_function && entry.symbol == "TestMain" && entry.file.ends_with("_test.go") {
+        return Some(EntryPointKind::TestMain);
+    }
+
+    for decorator in entry.decorators.split(',').filter(|d| !d.is_empty()) {
+        let name = decorator.trim_start_matches('@');
+        let name = name.rsplit('.').next().unwrap_or(name);
+        if name == "command" {
+            return Some(EntryPointKind::CliCommand);
+        }
+        // `@app.get`, `@Get`, `@GetMapping`; `@app.route` takes any method
+        let verb = name.strip_suffix("Mapping").unwrap_or(name).to_uppercase();
+        if HTTP_METHODS.contains(&verb.as_str()) {
+            return Some(EntryPointKind::HttpRoute {
+                method: Some(verb),
+                path: None,
+            });
+        }
+        if matches!(verb.as_str(), "ROUTE" | "API_ROUTE" | "REQUEST") {
+            return Some(EntryPointKind::HttpRoute {
+                method: None,
+                path: None,
+            });
+        }
+    }
+
+    let file_name = entry.file.rsplit(['/', '\\']).next().unwrap_or(&entry.file);
+    let is_library_root = entry.framework_entry_point == FrameworkEntryPoint::PackageExport
+        || (entry.is_exported && LIBRARY_ROOT_FILES.contains(&file_name));
+    is_library_root.then_some(EntryPointKind::LibraryRoot)
+}
+
 /// Analyze a repository from its cached index
 ///
 /// This is the main entry point for static analysis. It reads from the
@@ -561,8 +1320,9 @@ pub fn analyze_repo(repo_path: &Path) -> Result<RepoAnalysis> {
     // Build symbol name map for call graph analysis
     let mut symbol_names: HashMap<String, String> = HashMap::new();
 
-    // Load all symbol entries from the index
-    let symbol_entries = cache.load_all_symbol_entries().unwrap_or_default();
+    // Load all symbol entries from the index, minus files indexed as logic-free
+    let mut symbol_entries = cache.load_all_symbol_entries().unwrap_or_default();
+    symbol_entries.retain(|e| !e.logic_free);
 
     // Build map of hash -> (file, start, end) for aggregating fan_out in impl blocks
     let mut hash_to_location: HashMap<String, (&str, usize, usize)> = HashMap::new();
@@ -758,12 +1518,207 @@ pub fn analyze_repo(repo_path: &Path) -> Result<RepoAnalysis> {
     });
     analysis.complex_symbols.truncate(20);
 
+    // Module coupling from the import graph
+    let module_graph = crate::cache::load_module_graph(&cache).unwrap_or_default();
+    compute_module_coupling(&mut analysis.modules, &module_graph);
+
     // Analyze call graph
     analysis.call_graph = analyze_call_graph(&call_graph, &symbol_names);
 
+    analysis.heatmap = file_heatmap(&symbol_entries);
+
     Ok(analysis)
 }
 
+/// Aggregate symbol complexity per file, worst first
+///
+/// Files are ranked by total cognitive complexity, then by their worst
+/// symbol, then by path. Escaping locals are left out.
+fn file_heatmap(entries: &[SymbolIndexEntry]) -> Vec<FileHeat> {
+    let mut files: BTreeMap<&str, FileHeat> = BTreeMap::new();
+    for entry in entries.iter().filter(|e| !e.is_escape_local) {
+        let heat = files
+            .entry(entry.file.as_str())
+            .or_insert_with(|| FileHeat {
+                file: entry.file.clone(),
+                ..Default::default()
+            });
+        heat.cognitive += entry.cognitive_complexity;
+        heat.max_cognitive = heat.max_cognitive.max(entry.cognitive_complexity);
+        heat.symbols += 1;
+        let risk = RiskLevel::from_str(&entry.risk);
+        heat.risk = heat.risk.max(risk);
+    }
+
+    let mut heatmap: Vec<FileHeat> = files.into_values().collect();
+    heatmap.sort_by(|a, b| {
+        b.cognitive
+            .cmp(&a.cognitive)
+            .then(b.max_cognitive.cmp(&a.max_cognitive))
+            .then_with(|| a.file.cmp(&b.file))
+    });
+    heatmap
+}
+
+/// Legend line of [`format_heatmap_toon`]
+const HEATMAP_LEGEND: &str =
+    "cc=total cognitive complexity, max=worst symbol, n=symbols, risk=highest symbol risk";
+
+/// Compact "where's the pain" view of [`RepoAnalysis::heatmap`]
+///
+/// One row per file, worst first, capped at `limit` rows; `omitted` counts
+/// the files left out.
+pub fn format_heatmap_toon(analysis: &RepoAnalysis, limit: usize) -> String {
+    let mut output = crate::commands::toon_header("complexity_heatmap");
+    output.push_str(&format!("legend: \"{}\"\n", HEATMAP_LEGEND));
+
+    let shown = &analysis.heatmap[..analysis.heatmap.len().min(limit)];
+    output.push_str(&format!("files[{}]{{file,cc,max,n,risk}}:\n", shown.len()));
+    for heat in shown {
+        output.push_str(&format!(
+            "  {},{},{},{},{}\n",
+            heat.file,
+            heat.cognitive,
+            heat.max_cognitive,
+            heat.symbols,
+            heat.risk.as_str()
+        ));
+    }
+    if analysis.heatmap.len() > shown.len() {
+        output.push_str(&format!(
+            "omitted: {}\n",
+            analysis.heatmap.len() - shown.len()
+        ));
+    }
+    output
+}
+
+/// SARIF rule for symbols over the cognitive complexity threshold
+pub const SARIF_COMPLEXITY_RULE: &str = "semfora/cognitive-complexity";
+
+/// SARIF rule for circular call chains
+pub const SARIF_CYCLE_RULE: &str = "semfora/circular-dependency";
+
+/// Cognitive complexity above which a symbol becomes a SARIF result
+const SARIF_COGNITIVE_THRESHOLD: usize = 10;
+
+/// Render an analysis as a SARIF 2.1.0 log for code-scanning tools
+///
+/// Each complex symbol over a cognitive complexity of 10 is a result at its
+/// file and lines: a warning when `complex`, an error when `very complex`.
+/// Call graph cycles are warnings under their own rule, located at the first
+/// member that is also a complex symbol (cycle members are symbol hashes, and
+/// only those symbols carry a location).
+pub fn format_analysis_sarif(analysis: &RepoAnalysis) -> String {
+    let by_hash: HashMap<&str, &SymbolComplexity> = analysis
+        .complex_symbols
+        .iter()
+        .map(|s| (s.hash.as_str(), s))
+        .collect();
+
+    let location = |sym: &SymbolComplexity| {
+        let (start, end) = parse_lines(&sym.lines);
+        let mut physical = serde_json::json!({
+            "artifactLocation": { "uri": sym.file }
+        });
+        if start > 0 {
+            physical["region"] = serde_json::json!({
+                "startLine": start,
+                "endLine": end.max(start)
+            });
+        }
+        serde_json::json!({ "physicalLocation": physical })
+    };
+
+    let mut results = Vec::new();
+    for sym in &analysis.complex_symbols {
+        if sym.cognitive <= SARIF_COGNITIVE_THRESHOLD {
+            continue;
+        }
+        let level = if sym.rating() == "very complex" {
+            "error"
+        } else {
+            "warning"
+        };
+        results.push(serde_json::json!({
+            "ruleId": SARIF_COMPLEXITY_RULE,
+            "ruleIndex": 0,
+            "level": level,
+            "message": {
+                "text": format!(
+                    "`{}` has cognitive complexity {} ({})",
+                    sym.name,
+                    sym.cognitive,
+                    sym.rating()
+                )
+            },
+            "locations": [location(sym)],
+            "partialFingerprints": { "symbolHash/v1": sym.hash }
+        }));
+    }
+
+    for cycle in &analysis.call_graph.cycles {
+        let names: Vec<&str> = cycle
+            .iter()
+            .map(|hash| {
+                by_hash
+                    .get(hash.as_str())
+                    .map_or(hash.as_str(), |s| s.name.as_str())
+            })
+            .collect();
+        let mut result = serde_json::json!({
+            "ruleId": SARIF_CYCLE_RULE,
+            "ruleIndex": 1,
+            "level": "warning",
+            "message": {
+                "text": format!(
+                    "Circular call chain: {} -> {}",
+                    names.join(" -> "),
+                    names.first().unwrap_or(&"")
+                )
+            }
+        });
+        if let Some(sym) = cycle
+            .iter()
+            .find_map(|hash| by_hash.get(hash.as_str()).copied())
+        {
+            result["locations"] = serde_json::json!([location(sym)]);
+        }
+        results.push(result);
+    }
+
+    let log = serde_json::json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "semfora",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": [
+                        {
+                            "id": SARIF_COMPLEXITY_RULE,
+                            "shortDescription": {
+                                "text": "Cognitive complexity above 10"
+                            },
+                            "defaultConfiguration": { "level": "warning" }
+                        },
+                        {
+                            "id": SARIF_CYCLE_RULE,
+                            "shortDescription": {
+                                "text": "Functions that call each other in a cycle"
+                            },
+                            "defaultConfiguration": { "level": "warning" }
+                        }
+                    ]
+                }
+            },
+            "results": results
+        }]
+    });
+    serde_json::to_string_pretty(&log).unwrap_or_default()
+}
+
 /// Quick complexity check for a single module
 pub fn analyze_module(repo_path: &Path, module_name: &str) -> Result<ModuleMetrics> {
     let cache = CacheDir::for_repo(repo_path)?;
@@ -824,6 +1779,67 @@ pub fn analyze_module(repo_path: &Path, module_name: &str) -> Result<ModuleMetri
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_metrics_to_csv() {
+        let summary = SemanticSummary {
+            file: "src/report, \"final\".ts".to_string(),
+            language: "typescript".to_string(),
+            symbol: Some("render".to_string()),
+            start_line: Some(3),
+            end_line: Some(12),
+            behavioral_risk: RiskLevel::Medium,
+            added_dependencies: vec!["fs".to_string(), "path".to_string()],
+            symbols: vec![SymbolInfo::default(), SymbolInfo::default()],
+            ..Default::default()
+        };
+
+        let csv = metrics_to_csv(&[summary]);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some(
+                "path,language,symbol,symbol_count,cognitive_complexity,\
+                 cyclomatic_complexity,loc,risk,io_operations,dependencies"
+            )
+        );
+        assert_eq!(
+            lines.next(),
+            Some("\"src/report, \"\"final\"\".ts\",typescript,render,2,0,1,10,medium,0,2")
+        );
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn test_call_graph_centrality() {
+        let edges = [
+            ("h_main", vec!["h_parse", "h_render"]),
+            ("h_parse", vec!["h_store"]),
+            ("h_render", vec!["h_store", "h_format"]),
+            ("h_cli", vec!["h_store"]),
+        ];
+        let call_graph: HashMap<String, Vec<String>> = edges
+            .iter()
+            .map(|(caller, callees)| {
+                let callees = callees.iter().map(|c| c.to_string()).collect();
+                (caller.to_string(), callees)
+            })
+            .collect();
+        let symbol_names: HashMap<String, String> =
+            ["main", "parse", "render", "cli", "store", "format"]
+                .iter()
+                .map(|name| (format!("h_{}", name), name.to_string()))
+                .collect();
+
+        let analysis = analyze_call_graph(&call_graph, &symbol_names);
+        assert_eq!(analysis.centrality.len(), 6);
+        assert_eq!(analysis.centrality[0].0, "store");
+        assert!(analysis.centrality[0].1 > analysis.centrality[1].1);
+
+        // Fixed iteration count keeps scores identical across runs
+        let again = analyze_call_graph(&call_graph, &symbol_names);
+        assert_eq!(analysis.centrality, again.centrality);
+    }
+
     #[test]
     fn test_complexity_score() {
         let mut sym = SymbolComplexity::default();
@@ -846,6 +1862,465 @@ mod tests {
         assert_eq!(sym.rating(), "very complex");
     }
 
+    #[test]
+    fn test_symbol_cognitive_complexity_counts_recursion_and_nesting() {
+        use crate::lang::Lang;
+        use crate::parsing::parse_and_extract;
+
+        let source = r#"
+export function walk(node, depth) {
+    const visit = (n) => {
+        if (n.hidden) {
+            return 0;
+        }
+        return n.weight;
+    };
+    if (depth > 10) {
+        return 0;
+    }
+    return visit(node) + walk(node.next, depth + 1);
+}
+"#;
+        let summary = parse_and_extract(Path::new("walk.js"), source, Lang::JavaScript).unwrap();
+
+        let walk = summary.symbols.iter().find(|s| s.name == "walk").unwrap();
+        assert!(walk.is_recursive);
+        assert_eq!(walk.nested_functions, 1);
+
+        // The `if` inside the closure sits one level deeper than the outer one
+        let mut depths: Vec<usize> = walk
+            .control_flow
+            .iter()
+            .map(|cf| cf.nesting_depth)
+            .collect();
+        depths.sort_unstable();
+        assert_eq!(depths, vec![0, 1]);
+
+        // 1 (outer if) + 2 (closure if, nested) + 1 (recursion)
+        let control_flow_only = calculate_cognitive_complexity(&walk.control_flow);
+        assert_eq!(control_flow_only, 3);
+        assert_eq!(calculate_symbol_cognitive_complexity(walk), 4);
+    }
+
+    #[test]
+    fn test_primitive_param_groups() {
+        use crate::schema::Argument;
+
+        let arg = |name: &str, ty: &str| Argument {
+            name: name.to_string(),
+            arg_type: Some(ty.to_string()),
+            default_value: None,
+            is_optional: false,
+            description: None,
+        };
+        let func = |name: &str, arguments: Vec<Argument>| SymbolInfo {
+            name: name.to_string(),
+            kind: SymbolKind::Function,
+            arguments,
+            ..Default::default()
+        };
+
+        let summary = SemanticSummary {
+            file: "src/geometry.rs".to_string(),
+            symbols: vec![
+                func(
+                    "translate",
+                    vec![arg("x", "f64"), arg("y", "f64"), arg("dx", "f64")],
+                ),
+                func("distance", vec![arg("x", "f64"), arg("y", "f64")]),
+                func(
+                    "plot",
+                    vec![
+                        arg("canvas", "&mut Canvas"),
+                        arg("y", "f64"),
+                        arg("x", "f64"),
+                    ],
+                ),
+                func("scale", vec![arg("x", "f64"), arg("factor", "f64")]),
+            ],
+            ..Default::default()
+        };
+
+        let groups = find_primitive_param_groups(&[summary], PRIMITIVE_GROUP_MIN_OCCURRENCES);
+        assert_eq!(
+            groups,
+            vec![PrimitiveParamGroup {
+                params: vec!["x: f64".to_string(), "y: f64".to_string()],
+                symbols: vec![
+                    "src/geometry.rs:distance".to_string(),
+                    "src/geometry.rs:plot".to_string(),
+                    "src/geometry.rs:translate".to_string(),
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_check_budgets() {
+        let module = |name: &str, avg_complexity: f64| ModuleMetrics {
+            name: name.to_string(),
+            avg_complexity,
+            ..Default::default()
+        };
+        let analysis = RepoAnalysis {
+            modules: vec![module("src.legacy.billing", 14.5), module("src.api", 3.0)],
+            ..Default::default()
+        };
+        let config: ComplexityBudgets = serde_json::from_str(
+            r#"{"budgets": [
+                {"module": "src.legacy.**", "max_avg_complexity": 10.0},
+                {"module": "src.*", "max_avg_complexity": 5.0}
+            ]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            check_budgets(&analysis, &config),
+            vec![BudgetViolation {
+                module: "src.legacy.billing".to_string(),
+                actual: 14.5,
+                budget: 10.0,
+            }]
+        );
+
+        let within = RepoAnalysis {
+            modules: vec![module("src.legacy.billing", 9.0), module("src.api", 5.0)],
+            ..Default::default()
+        };
+        assert!(check_budgets(&within, &config).is_empty());
+    }
+
+    #[test]
+    fn test_positional_arg_calls() {
+        use crate::lang::Lang;
+        use crate::parsing::parse_and_extract;
+
+        let source = r#"
+def create_user(name, email, age, role, active):
+    return {"name": name, "email": email, "age": age, "role": role, "active": active}
+
+
+def register():
+    first = create_user("ada", "ada@example.com", 36, "admin", True)
+    second = create_user(name="bob", email="bob@example.com", age=41, role="user", active=False)
+    print("registered", first, second, "users")
+    return [first, second]
+"#;
+        let summary = parse_and_extract(Path::new("users.py"), source, Lang::Python).unwrap();
+        let found = find_positional_arg_calls(&[summary], 4);
+
+        assert_eq!(
+            found,
+            vec![PositionalArgCall {
+                file: "users.py".to_string(),
+                line: 7,
+                callee: "create_user".to_string(),
+                args: 5,
+                defined_in: "users.py".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_literals() {
+        use crate::lang::Lang;
+        use crate::parsing::parse_and_extract;
+
+        let api = r#"
+export function getUser(id) {
+    return fetch(`/users/${id}`, { headers: { "Content-Type": "application/json" } });
+}
+"#;
+        let client = r#"
+def post(url, body):
+    return requests.post(url, body, headers={"Content-Type": "text/plain"})
+"#;
+        let server = r#"
+fn respond(body: String) -> Response {
+    Response::new(body).header("Content-Type", "text/plain").header("X", "1")
+}
+"#;
+        let summaries = vec![
+            parse_and_extract(Path::new("src/api.js"), api, Lang::JavaScript).unwrap(),
+            parse_and_extract(Path::new("src/client.py"), client, Lang::Python).unwrap(),
+            parse_and_extract(Path::new("src/server.rs"), server, Lang::Rust).unwrap(),
+        ];
+
+        let found = duplicate_literals(&summaries, 3);
+        assert_eq!(
+            found,
+            vec![DuplicateLiteral {
+                value: "Content-Type".to_string(),
+                locations: vec![
+                    ("src/api.js".to_string(), 3),
+                    ("src/client.py".to_string(), 3),
+                    ("src/server.rs".to_string(), 3),
+                ],
+            }]
+        );
+
+        // "text/plain" repeats too, but only twice; "1" is too short
+        let values: Vec<String> = duplicate_literals(&summaries, 2)
+            .into_iter()
+            .map(|d| d.value)
+            .collect();
+        assert_eq!(values, vec!["Content-Type", "text/plain"]);
+
+        // Literals don't survive serialization, so cached summaries find none
+        let round_tripped: Vec<SemanticSummary> = summaries
+            .iter()
+            .map(|s| serde_json::from_str(&serde_json::to_string(s).unwrap()).unwrap())
+            .collect();
+        assert!(duplicate_literals(&round_tripped, 2).is_empty());
+    }
+
+    #[test]
+    fn test_untested_symbols() {
+        use crate::lang::Lang;
+        use crate::parsing::parse_and_extract;
+        use crate::ShardWriter;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheDir {
+            root: temp_dir.path().join("cache"),
+            repo_root: temp_dir.path().to_path_buf(),
+            repo_hash: "untested".to_string(),
+        };
+
+        let lib = parse_and_extract(
+            Path::new("src/math.ts"),
+            "export function add(a: number, b: number): number {\n  return a + b;\n}\n\n\
+             export function scale(value: number, factor: number): number {\n  return value * factor;\n}\n",
+            Lang::TypeScript,
+        )
+        .unwrap();
+        let tests = parse_and_extract(
+            Path::new("tests/math.test.ts"),
+            "import { add } from '../src/math';\n\n\
+             export function testAdd() {\n  const sum = add(1, 2);\n  expect(sum).toBe(3);\n}\n",
+            Lang::TypeScript,
+        )
+        .unwrap();
+
+        let mut writer = ShardWriter::with_cache(cache.clone()).unwrap();
+        writer.add_summaries(vec![lib, tests]);
+        writer
+            .write_all(&temp_dir.path().display().to_string())
+            .unwrap();
+
+        let untested = untested_symbols(&cache).unwrap();
+        let names: Vec<&str> = untested.iter().map(|e| e.symbol.as_str()).collect();
+        assert_eq!(names, vec!["scale"]);
+    }
+
+    #[test]
+    fn test_format_analysis_sarif() {
+        let symbol = |name: &str, hash: &str, cognitive: usize| SymbolComplexity {
+            name: name.to_string(),
+            hash: hash.to_string(),
+            file: "src/billing.ts".to_string(),
+            lines: "10-40".to_string(),
+            cognitive,
+            ..Default::default()
+        };
+        let analysis = RepoAnalysis {
+            complex_symbols: vec![
+                symbol("reconcile", "h1", 25),
+                symbol("applyDiscount", "h2", 12),
+                symbol("formatTotal", "h3", 4),
+            ],
+            call_graph: CallGraphAnalysis {
+                cycles: vec![vec!["h2".to_string(), "h9".to_string()]],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let sarif: serde_json::Value =
+            serde_json::from_str(&format_analysis_sarif(&analysis)).unwrap();
+        assert_eq!(sarif["version"], "2.1.0");
+        let results = sarif["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+
+        assert_eq!(results[0]["ruleId"], SARIF_COMPLEXITY_RULE);
+        assert_eq!(results[0]["level"], "error");
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/billing.ts");
+        assert_eq!(location["region"]["startLine"], 10);
+        assert_eq!(results[1]["level"], "warning");
+
+        // The cycle is named and located through its known member
+        assert_eq!(results[2]["ruleId"], SARIF_CYCLE_RULE);
+        assert_eq!(
+            results[2]["message"]["text"],
+            "Circular call chain: applyDiscount -> h9 -> applyDiscount"
+        );
+        assert!(results[2]["locations"].is_array());
+    }
+
+    #[test]
+    fn test_trace_call_path() {
+        use crate::lang::Lang;
+        use crate::parsing::parse_and_extract;
+        use crate::ShardWriter;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheDir::for_repo(temp_dir.path()).unwrap();
+
+        let summary = parse_and_extract(
+            Path::new("src/chain.ts"),
+            "export function a() {\n  return b();\n}\n\n\
+             export function b() {\n  return c();\n}\n\n\
+             export function c() {\n  return 1;\n}\n\n\
+             export function d() {\n  return 2;\n}\n",
+            Lang::TypeScript,
+        )
+        .unwrap();
+        let mut writer = ShardWriter::with_cache(cache.clone()).unwrap();
+        writer.add_summaries(vec![summary]);
+        writer
+            .write_all(&temp_dir.path().display().to_string())
+            .unwrap();
+
+        let entries = cache.load_all_symbol_entries().unwrap();
+        let hash = |name: &str| {
+            entries
+                .iter()
+                .find(|e| e.symbol == name)
+                .map(|e| e.hash.clone())
+                .unwrap()
+        };
+
+        assert_eq!(
+            trace_call_path(&cache, &hash("a"), &hash("c")).unwrap(),
+            Some(vec!["a".to_string(), "b".to_string(), "c".to_string()])
+        );
+        assert_eq!(
+            trace_call_path(&cache, &hash("a"), &hash("d")).unwrap(),
+            None
+        );
+
+        // A missing index is an error, not an unreachable target
+        let empty_dir = TempDir::new().unwrap();
+        let empty = CacheDir::for_repo(empty_dir.path()).unwrap();
+        assert!(trace_call_path(&empty, &hash("a"), &hash("c")).is_err());
+    }
+
+    #[test]
+    fn test_entry_points() {
+        use crate::lang::Lang;
+        use crate::parsing::parse_and_extract;
+        use crate::ShardWriter;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheDir::for_repo(temp_dir.path()).unwrap();
+
+        let main = parse_and_extract(
+            Path::new("src/main.rs"),
+            "fn main() {\n    run();\n}\n\nfn run() {}\n",
+            Lang::Rust,
+        )
+        .unwrap();
+        let route = parse_and_extract(
+            Path::new("web/app/api/users/route.ts"),
+            "export async function GET(request: Request) {\n  return Response.json([]);\n}\n",
+            Lang::TypeScript,
+        )
+        .unwrap();
+
+        let mut writer = ShardWriter::with_cache(cache.clone()).unwrap();
+        writer.add_summaries(vec![main, route]);
+        writer
+            .write_all(&temp_dir.path().display().to_string())
+            .unwrap();
+
+        let found: Vec<(String, EntryPointKind)> = entry_points(&cache)
+            .unwrap()
+            .into_iter()
+            .map(|e| (e.symbol, e.kind))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("main".to_string(), EntryPointKind::Main),
+                (
+                    "GET".to_string(),
+                    EntryPointKind::HttpRoute {
+                        method: Some("GET".to_string()),
+                        path: Some("/api/users".to_string()),
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_complexity_heatmap() {
+        use crate::lang::Lang;
+        use crate::parsing::parse_and_extract;
+        use crate::ShardWriter;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let cache = CacheDir::for_repo(temp_dir.path()).unwrap();
+
+        let sources = [
+            (
+                "src/flat.ts",
+                "export function id(x: number): number {\n  return x;\n}\n",
+            ),
+            (
+                "src/nested.ts",
+                "export function walk(items: number[]): number {\n  let n = 0;\n  \
+                 for (const i of items) {\n    if (i > 0) {\n      if (i % 2 === 0) {\n        \
+                 n += i;\n      }\n    }\n  }\n  return n;\n}\n",
+            ),
+            (
+                "src/branchy.ts",
+                "export function sign(x: number): number {\n  if (x > 0) {\n    return 1;\n  }\n  \
+                 return 0;\n}\n",
+            ),
+        ];
+        let summaries = sources
+            .iter()
+            .map(|(path, source)| parse_and_extract(Path::new(path), source, Lang::TypeScript))
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        let mut writer = ShardWriter::with_cache(cache).unwrap();
+        writer.add_summaries(summaries);
+        writer
+            .write_all(&temp_dir.path().display().to_string())
+            .unwrap();
+
+        let analysis = analyze_repo(temp_dir.path()).unwrap();
+        let files: Vec<&str> = analysis.heatmap.iter().map(|h| h.file.as_str()).collect();
+        assert_eq!(
+            files,
+            vec!["src/nested.ts", "src/branchy.ts", "src/flat.ts"]
+        );
+        let scores: Vec<usize> = analysis.heatmap.iter().map(|h| h.cognitive).collect();
+        assert!(
+            scores[0] > scores[1] && scores[1] > scores[2],
+            "{:?}",
+            scores
+        );
+        assert_eq!(scores[2], 0);
+
+        let toon = format_heatmap_toon(&analysis, 2);
+        assert!(toon.contains("legend: "), "{}", toon);
+        let rows: Vec<&str> = toon.lines().filter(|l| l.starts_with("  ")).collect();
+        for (row, heat) in rows.iter().zip(&analysis.heatmap) {
+            let prefix = format!("  {},{},{},", heat.file, heat.cognitive, heat.max_cognitive);
+            assert!(row.starts_with(&prefix), "{}", toon);
+        }
+        assert_eq!(rows.len(), 2);
+        assert!(toon.contains("omitted: 1"));
+    }
+
     #[test]
     fn test_cycle_detection() {
         let mut graph = HashMap::new();
@@ -868,6 +2343,37 @@ mod tests {
         metrics.efferent_coupling = 10;
         assert!((metrics.instability() - 1.0).abs() < 0.01);
     }
+
+    #[test]
+    fn test_module_coupling() {
+        let mut graph = HashMap::new();
+        graph.insert("api".to_string(), vec!["core".to_string()]);
+        graph.insert(
+            "cli".to_string(),
+            vec!["core".to_string(), "core".to_string()],
+        );
+        graph.insert(
+            "web".to_string(),
+            vec!["core".to_string(), "api".to_string()],
+        );
+
+        let mut analysis = RepoAnalysis::default();
+        for name in ["api", "cli", "core", "web"] {
+            analysis.modules.push(ModuleMetrics {
+                name: name.to_string(),
+                ..Default::default()
+            });
+        }
+        compute_module_coupling(&mut analysis.modules, &graph);
+
+        let ranked = analysis.most_depended_upon(10);
+        assert_eq!(ranked[0].name, "core");
+        assert_eq!(ranked[0].afferent_coupling, 3);
+        assert_eq!(ranked[0].efferent_coupling, 0);
+        assert!((ranked[0].instability() - 0.0).abs() < 0.01);
+        assert_eq!(ranked[1].name, "api");
+        assert_eq!(ranked.len(), 2);
+    }
 }
 
 #[test]
@@ -4,7 +4,7 @@
 //! call graphs, complexity metrics, and timing information.
 
 use super::types::*;
-use crate::analysis::{calculate_cognitive_complexity, max_nesting_depth};
+use crate::analysis::{calculate_symbol_cognitive_complexity, max_nesting_depth};
 use crate::extract::extract;
 use crate::lang::Lang;
 use crate::overlay::compute_symbol_hash;
@@ -200,7 +200,7 @@ fn analyze_project(
 
         for sym in &summary.symbols {
             let hash = compute_symbol_hash(sym, &relative_path);
-            let cognitive = calculate_cognitive_complexity(&sym.control_flow);
+            let cognitive = calculate_symbol_cognitive_complexity(sym);
             let max_nest = max_nesting_depth(&sym.control_flow);
 
             symbols.push(SymbolSnapshot {
@@ -11,6 +11,8 @@
 //! - Terms are extracted from symbol names, file paths, and TOON summaries
 //! - An inverted index maps terms to documents (symbols)
 //! - At query time, BM25 scoring ranks results by relevance
+//! - A Bloom filter over all indexed terms lets SQLite searches skip the
+//!   posting-list query for terms that cannot appear
 //!
 //! # BM25 Parameters
 //!
@@ -22,6 +24,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::Path;
 
+use crate::schema::fnv1a_hash;
+
 /// BM25 parameters
 const K1: f64 = 1.2;
 const B: f64 = 0.75;
@@ -71,6 +75,87 @@ pub struct Bm25Index {
     pub avg_doc_length: f64,
 }
 
+/// Bloom filter over index terms
+///
+/// `might_contain` never returns false for an inserted term, so skipping terms
+/// it rejects leaves search results unchanged. Hashing uses FNV-1a so filters
+/// persisted to SQLite stay valid across runs and platforms.
+///
+/// Only the SQLite search consults it: there a rejected term saves a query,
+/// while the in-memory index's hash lookup is already as cheap as the filter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TermBloomFilter {
+    bits: Vec<u64>,
+    num_hashes: u32,
+}
+
+impl TermBloomFilter {
+    /// Bits allocated per expected term (~1% false positives with 7 hashes)
+    const BITS_PER_TERM: usize = 10;
+    const NUM_HASHES: u32 = 7;
+
+    /// Create an empty filter sized for `expected_terms`
+    pub fn with_capacity(expected_terms: usize) -> Self {
+        let num_bits = (expected_terms.max(1) * Self::BITS_PER_TERM).next_multiple_of(64);
+        Self {
+            bits: vec![0; num_bits / 64],
+            num_hashes: Self::NUM_HASHES,
+        }
+    }
+
+    /// Build a filter containing every given term
+    pub fn from_terms<'a>(terms: impl ExactSizeIterator<Item = &'a str>) -> Self {
+        let mut filter = Self::with_capacity(terms.len());
+        for term in terms {
+            filter.insert(term);
+        }
+        filter
+    }
+
+    /// Add a term to the filter
+    pub fn insert(&mut self, term: &str) {
+        for bit in self.bit_positions(term) {
+            self.bits[bit / 64] |= 1 << (bit % 64);
+        }
+    }
+
+    /// Whether the term may be present (false means definitely absent)
+    pub fn might_contain(&self, term: &str) -> bool {
+        self.bit_positions(term)
+            .all(|bit| self.bits[bit / 64] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Serialize the bit array for storage
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.bits
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect()
+    }
+
+    /// Restore a filter from `to_bytes` output
+    pub fn from_bytes(bytes: &[u8], num_hashes: u32) -> Option<Self> {
+        if bytes.is_empty() || bytes.len() % 8 != 0 || num_hashes == 0 {
+            return None;
+        }
+        let bits = bytes
+            .chunks_exact(8)
+            .map(|chunk| u64::from_le_bytes(chunk.try_into().expect("8-byte chunk")))
+            .collect();
+        Some(Self { bits, num_hashes })
+    }
+
+    /// Double hashing: position_i = h1 + i * h2 (mod m)
+    fn bit_positions(&self, term: &str) -> impl Iterator<Item = usize> {
+        let hash = fnv1a_hash(term);
+        let h1 = hash & 0xffff_ffff;
+        let h2 = (hash >> 32) | 1;
+        let num_bits = (self.bits.len() * 64) as u64;
+        (0..self.num_hashes as u64)
+            .map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % num_bits) as usize)
+    }
+}
+
 /// Search result with BM25 score
 #[derive(Debug, Clone)]
 pub struct Bm25SearchResult {
@@ -317,6 +402,10 @@ pub fn init_bm25_sqlite(conn: &Connection) -> rusqlite::Result<()> {
             total_docs INTEGER,
             avg_doc_length REAL
         );
+        CREATE TABLE IF NOT EXISTS bm25_term_filter (
+            bits BLOB,
+            num_hashes INTEGER
+        );
         CREATE INDEX IF NOT EXISTS idx_bm25_terms_term ON bm25_terms(term);
         CREATE INDEX IF NOT EXISTS idx_bm25_terms_doc ON bm25_terms(doc_id);
         CREATE UNIQUE INDEX IF NOT EXISTS idx_bm25_terms_unique ON bm25_terms(term, doc_id);
@@ -331,6 +420,7 @@ pub fn clear_bm25_sqlite(conn: &Connection) -> rusqlite::Result<()> {
         DELETE FROM bm25_terms;
         DELETE FROM bm25_documents;
         DELETE FROM bm25_meta;
+        DELETE FROM bm25_term_filter;
         "#,
     )?;
     Ok(())
@@ -348,6 +438,33 @@ pub fn write_bm25_meta(
     Ok(())
 }
 
+/// Build the term prefilter from all terms currently in `bm25_terms`
+pub fn write_bm25_term_filter(conn: &Connection) -> rusqlite::Result<()> {
+    let mut stmt = conn.prepare("SELECT DISTINCT term FROM bm25_terms")?;
+    let terms = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<rusqlite::Result<Vec<String>>>()?;
+    let filter = TermBloomFilter::from_terms(terms.iter().map(String::as_str));
+
+    conn.execute("DELETE FROM bm25_term_filter", [])?;
+    conn.execute(
+        "INSERT INTO bm25_term_filter (bits, num_hashes) VALUES (?, ?)",
+        params![filter.to_bytes(), filter.num_hashes as i64],
+    )?;
+    Ok(())
+}
+
+/// Load the term prefilter, if the index has one (older indexes don't)
+fn read_bm25_term_filter(conn: &Connection) -> Option<TermBloomFilter> {
+    conn.query_row(
+        "SELECT bits, num_hashes FROM bm25_term_filter LIMIT 1",
+        [],
+        |row| Ok((row.get::<_, Vec<u8>>(0)?, row.get::<_, i64>(1)?)),
+    )
+    .ok()
+    .and_then(|(bits, num_hashes)| TermBloomFilter::from_bytes(&bits, num_hashes as u32))
+}
+
 pub fn search_sqlite(
     path: &Path,
     query: &str,
@@ -368,9 +485,17 @@ pub fn search_sqlite(
         return Ok(Vec::new());
     }
 
+    let term_filter = read_bm25_term_filter(&conn);
     let mut scores: HashMap<String, (f64, Vec<String>, Bm25Document)> = HashMap::new();
 
     for term in &query_terms {
+        // Definitely absent: skip the posting-list query entirely
+        if let Some(filter) = &term_filter {
+            if !filter.might_contain(term) {
+                continue;
+            }
+        }
+
         let mut stmt = conn
             .prepare(
                 r#"
@@ -684,4 +809,118 @@ mod tests {
         assert!(!results.is_empty());
         assert_eq!(results[0].symbol, "authenticate_user");
     }
+
+    fn sample_docs() -> Vec<(Bm25Document, Vec<String>)> {
+        [
+            ("hash1", "authenticate_user", "src/auth.rs"),
+            ("hash2", "format_output", "src/format.rs"),
+            ("hash3", "validate_token", "src/auth.rs"),
+            ("hash4", "render_user_profile", "src/ui.rs"),
+        ]
+        .into_iter()
+        .map(|(hash, symbol, file)| {
+            let doc = Bm25Document {
+                hash: hash.to_string(),
+                symbol: symbol.to_string(),
+                file: file.to_string(),
+                lines: "1-10".to_string(),
+                kind: "fn".to_string(),
+                module: "app".to_string(),
+                risk: "low".to_string(),
+                doc_length: 0,
+            };
+            let mut terms = tokenize(symbol);
+            terms.extend(extract_terms_from_file_path(file));
+            (doc, terms)
+        })
+        .collect()
+    }
+
+    /// Results as (hash, score) sorted by hash, so tie order doesn't matter
+    fn normalized(results: Vec<Bm25SearchResult>) -> Vec<(String, String)> {
+        let mut pairs: Vec<(String, String)> = results
+            .into_iter()
+            .map(|r| (r.hash, format!("{:.9}", r.score)))
+            .collect();
+        pairs.sort();
+        pairs
+    }
+
+    #[test]
+    fn test_term_filter_has_no_false_negatives() {
+        let terms = ["auth", "user", "token", "render"];
+        let filter = TermBloomFilter::from_terms(terms.iter().copied());
+        for term in terms {
+            assert!(filter.might_contain(term));
+        }
+
+        let restored = TermBloomFilter::from_bytes(&filter.to_bytes(), filter.num_hashes);
+        assert_eq!(restored, Some(filter));
+    }
+
+    #[test]
+    fn test_search_identical_with_and_without_term_filter() {
+        let queries = [
+            "user authentication",
+            "token",
+            "missing nonexistent words",
+            "render profile output",
+        ];
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("index.sqlite");
+        let conn = Connection::open(&path).unwrap();
+        init_bm25_sqlite(&conn).unwrap();
+        let mut total_length = 0;
+        let docs = sample_docs();
+        for (doc, terms) in &docs {
+            conn.execute(
+                "INSERT INTO bm25_documents (doc_id, symbol, file, lines, kind, module, risk, doc_length) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+                params![
+                    doc.hash,
+                    doc.symbol,
+                    doc.file,
+                    doc.lines,
+                    doc.kind,
+                    doc.module,
+                    doc.risk,
+                    terms.len() as i64
+                ],
+            )
+            .unwrap();
+            for term in terms {
+                conn.execute(
+                    "INSERT OR IGNORE INTO bm25_terms (term, doc_id, tf) VALUES (?, ?, 1)",
+                    params![term, doc.hash],
+                )
+                .unwrap();
+            }
+            total_length += terms.len();
+        }
+        write_bm25_meta(
+            &conn,
+            docs.len() as u32,
+            total_length as f64 / docs.len() as f64,
+        )
+        .unwrap();
+        write_bm25_term_filter(&conn).unwrap();
+        assert!(read_bm25_term_filter(&conn).is_some());
+
+        let filtered: Vec<_> = queries
+            .iter()
+            .map(|q| normalized(search_sqlite(&path, q, 10).unwrap()))
+            .collect();
+
+        conn.execute("DELETE FROM bm25_term_filter", []).unwrap();
+        assert!(read_bm25_term_filter(&conn).is_none());
+
+        for (query, expected) in queries.iter().zip(filtered) {
+            assert_eq!(
+                normalized(search_sqlite(&path, query, 10).unwrap()),
+                expected,
+                "sqlite results differ for {:?}",
+                query
+            );
+        }
+    }
 }
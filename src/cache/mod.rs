@@ -3,9 +3,15 @@
 //! Provides XDG-compliant cache directory management and repo hashing
 //! for storing sharded semantic IR that can be queried by AI agents.
 
+pub mod module_graph;
 pub mod signatures;
+pub mod strings;
+pub mod warm;
 
+pub use module_graph::{build_module_graph, load_module_graph, module_topo_order};
 pub use signatures::load_function_signatures;
+pub use strings::{SeenInitializers, StringTable};
+pub use warm::{WarmIndex, WarmStatus};
 
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -176,6 +182,110 @@ impl SourceFileInfo {
     }
 }
 
+/// One line of `summaries.jsonl`: enough of a file's last summary to rebuild
+/// its shards in an incremental reindex without analyzing it again
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedSummary {
+    /// The file's mtime and size when it was analyzed
+    pub source: SourceFileInfo,
+
+    /// The summary, reduced to the fields the shard writer reads
+    pub summary: crate::schema::SemanticSummary,
+}
+
+impl CachedSummary {
+    /// Reduce `summary` to what its shards are built from
+    ///
+    /// Per-file findings, string literals and the raw source fallback only
+    /// appear in live analysis output, so they are left out. Returns `None`
+    /// for legacy single-symbol summaries, whose shard renders the whole
+    /// summary; those files are re-analyzed instead.
+    pub fn new(source: SourceFileInfo, summary: &crate::schema::SemanticSummary) -> Option<Self> {
+        if summary.symbols.is_empty() && summary.symbol_id.is_some() {
+            return None;
+        }
+
+        Some(Self {
+            source,
+            summary: crate::schema::SemanticSummary {
+                file: summary.file.clone(),
+                language: summary.language.clone(),
+                symbol_id: summary.symbol_id.clone(),
+                symbol: summary.symbol.clone(),
+                symbol_kind: summary.symbol_kind,
+                symbols: summary.symbols.clone(),
+                start_line: summary.start_line,
+                end_line: summary.end_line,
+                props: summary.props.clone(),
+                arguments: summary.arguments.clone(),
+                return_type: summary.return_type.clone(),
+                insertions: summary.insertions.clone(),
+                added_dependencies: summary.added_dependencies.clone(),
+                local_imports: summary.local_imports.clone(),
+                import_sources: summary.import_sources.clone(),
+                imports: summary.imports.clone(),
+                state_changes: summary.state_changes.clone(),
+                control_flow_changes: summary.control_flow_changes.clone(),
+                calls: summary.calls.clone(),
+                public_surface_changed: summary.public_surface_changed,
+                behavioral_risk: summary.behavioral_risk,
+                framework_entry_point: summary.framework_entry_point,
+                ..Default::default()
+            },
+        })
+    }
+}
+
+/// Writes `summaries.jsonl` a batch at a time, see [`CacheDir::summary_log`]
+pub struct SummaryLog {
+    writer: std::io::BufWriter<fs::File>,
+    temp_path: PathBuf,
+    path: PathBuf,
+    repo_root: PathBuf,
+}
+
+impl SummaryLog {
+    /// Append a [`CachedSummary`] line for each of `summaries`
+    ///
+    /// Paths are recorded relative to the repository root, as in
+    /// `source_files.json`. Files that can no longer be read, or whose
+    /// summary can't be cached, are left out and will be analyzed again.
+    pub fn append(&mut self, summaries: &[crate::schema::SemanticSummary]) -> Result<()> {
+        use std::io::Write;
+
+        for summary in summaries {
+            let file = self.repo_root.join(&summary.file);
+            let Some(record) = SourceFileInfo::from_path(&file, &self.repo_root)
+                .and_then(|source| CachedSummary::new(source, summary))
+            else {
+                continue;
+            };
+            let line = serde_json::to_string(&record).map_err(|e| {
+                crate::McpDiffError::ExtractionFailure {
+                    message: format!("Failed to serialize summary for {}: {}", summary.file, e),
+                }
+            })?;
+            self.writer.write_all(line.as_bytes())?;
+            self.writer.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+
+    /// Replace `summaries.jsonl` with everything appended
+    ///
+    /// Until then the previous log stays in place, so a run that fails
+    /// partway leaves the last complete one for the next incremental run.
+    pub fn finish(self) -> Result<()> {
+        use std::io::Write;
+
+        let mut writer = self.writer;
+        writer.flush()?;
+        drop(writer);
+        fs_utils::atomic_rename(&self.temp_path, &self.path)?;
+        Ok(())
+    }
+}
+
 /// Progress status for ongoing indexing
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndexingStatus {
@@ -384,6 +494,17 @@ impl CacheDir {
         self.symbols_dir().join(format!("{}.toon", symbol_hash))
     }
 
+    /// Path to the string table shared by symbol shards
+    pub fn string_table_path(&self) -> PathBuf {
+        self.root.join("strings.json")
+    }
+
+    /// Read a symbol shard, resolving string table references
+    pub fn read_symbol_shard(&self, symbol_hash: &str) -> Result<String> {
+        let content = fs::read_to_string(self.symbol_path(symbol_hash))?;
+        Ok(StringTable::load_shared(self)?.resolve(&content))
+    }
+
     /// Path to graphs directory
     pub fn graphs_dir(&self) -> PathBuf {
         self.root.join("graphs")
@@ -472,6 +593,76 @@ impl CacheDir {
         Ok(())
     }
 
+    /// Path to source_files.json (mtime and size of each file when last indexed)
+    pub fn source_meta_path(&self) -> PathBuf {
+        self.root.join("source_files.json")
+    }
+
+    /// Get the source file metadata recorded by the last index run
+    pub fn load_source_meta(&self) -> Option<CacheMeta> {
+        let content = fs::read_to_string(self.source_meta_path()).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Save source file metadata so the next run can skip unchanged files
+    pub fn save_source_meta(&self, meta: &CacheMeta) -> Result<()> {
+        let json =
+            serde_json::to_string(meta).map_err(|e| crate::McpDiffError::ExtractionFailure {
+                message: format!("Failed to serialize source file metadata: {}", e),
+            })?;
+        fs::write(self.source_meta_path(), json)?;
+        Ok(())
+    }
+
+    /// Path to summaries.jsonl (each file's reduced summary from the last index run)
+    pub fn summaries_path(&self) -> PathBuf {
+        self.root.join("summaries.jsonl")
+    }
+
+    /// Load the summaries saved by the last index run
+    ///
+    /// Lines that no longer deserialize (e.g. after a schema change) are
+    /// skipped, so their files are treated as never indexed.
+    pub fn load_summaries(&self) -> Vec<CachedSummary> {
+        fs::read_to_string(self.summaries_path())
+            .map(|content| {
+                content
+                    .lines()
+                    .filter_map(|line| serde_json::from_str(line).ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Save each summary as a [`CachedSummary`] line for incremental reindexing
+    ///
+    /// See [`SummaryLog::append`] for which files are recorded.
+    pub fn save_summaries(
+        &self,
+        summaries: &[crate::schema::SemanticSummary],
+        repo_root: &Path,
+    ) -> Result<()> {
+        let mut log = self.summary_log(repo_root)?;
+        log.append(summaries)?;
+        log.finish()
+    }
+
+    /// Start a new `summaries.jsonl` that summaries can be appended to as
+    /// they're written, instead of collected first for [`save_summaries`]
+    ///
+    /// [`save_summaries`]: Self::save_summaries
+    pub fn summary_log(&self, repo_root: &Path) -> Result<SummaryLog> {
+        let path = self.summaries_path();
+        let temp_path = path.with_extension("jsonl.tmp");
+        let file = fs::File::create(&temp_path)?;
+        Ok(SummaryLog {
+            writer: std::io::BufWriter::new(file),
+            temp_path,
+            path,
+            repo_root: repo_root.to_path_buf(),
+        })
+    }
+
     /// Path to status_hash file (hash of git status when last indexed)
     pub fn status_hash_path(&self) -> PathBuf {
         self.root.join("status_hash")
@@ -676,6 +867,10 @@ impl CacheDir {
     /// Returns a HashMap where keys are symbol hashes and values are lists of
     /// called symbol names/hashes.
     pub fn load_call_graph(&self) -> Result<std::collections::HashMap<String, Vec<String>>> {
+        if let Some(warm) = self.warm_index() {
+            return Ok(warm.call_graph.clone());
+        }
+
         let path = self.call_graph_path();
         if !path.exists() {
             return Ok(std::collections::HashMap::new());
@@ -991,6 +1186,22 @@ impl CacheDir {
     ) -> Result<Vec<SymbolIndexEntry>> {
         use std::io::BufRead;
 
+        let query_lower = query.to_lowercase();
+        let matches = |entry: &SymbolIndexEntry| {
+            symbol_matches_query(entry, &query_lower)
+                && symbol_matches_filters(entry, module_filter, kind_filter, risk_filter)
+        };
+
+        if let Some(warm) = self.warm_index() {
+            return Ok(warm
+                .symbols
+                .iter()
+                .filter(|e| matches(e))
+                .take(limit)
+                .cloned()
+                .collect());
+        }
+
         let index_path = self.symbol_index_path();
         if !index_path.exists() {
             return Err(crate::McpDiffError::FileNotFound {
@@ -1000,7 +1211,6 @@ impl CacheDir {
 
         let file = fs::File::open(&index_path)?;
         let reader = std::io::BufReader::new(file);
-        let query_lower = query.to_lowercase();
         let mut results = Vec::new();
 
         for line in reader.lines() {
@@ -1014,42 +1224,10 @@ impl CacheDir {
                 Err(_) => continue, // Skip malformed lines
             };
 
-            // Match query against symbol name (case-insensitive)
-            // Supports: empty/"*" = match all, *foo* = glob pattern, foo = substring
-            let symbol_lower = entry.symbol.to_lowercase();
-            let matches = if query_lower.is_empty() || query_lower == "*" {
-                // Empty or "*" matches all symbols
-                true
-            } else if query_lower.contains('*') || query_lower.contains('?') {
-                // Glob pattern matching
-                match_glob_pattern(&symbol_lower, &query_lower)
-            } else {
-                // Default: substring matching (original behavior)
-                symbol_lower.contains(&query_lower)
-            };
-
-            if !matches {
+            if !matches(&entry) {
                 continue;
             }
 
-            // Apply optional filters
-            if let Some(m) = module_filter {
-                if entry.module != m {
-                    continue;
-                }
-            }
-            if let Some(k) = kind_filter {
-                let normalized_kind = normalize_kind(k);
-                if entry.kind != normalized_kind {
-                    continue;
-                }
-            }
-            if let Some(r) = risk_filter {
-                if entry.risk != r {
-                    continue;
-                }
-            }
-
             results.push(entry);
 
             if results.len() >= limit {
@@ -1070,6 +1248,16 @@ impl CacheDir {
     ) -> Result<Vec<SymbolIndexEntry>> {
         use std::io::BufRead;
 
+        if let Some(warm) = self.warm_index() {
+            return Ok(warm
+                .symbols
+                .iter()
+                .filter(|e| symbol_matches_filters(e, Some(module), kind_filter, risk_filter))
+                .take(limit)
+                .cloned()
+                .collect());
+        }
+
         let index_path = self.symbol_index_path();
         if !index_path.exists() {
             return Err(crate::McpDiffError::FileNotFound {
@@ -1092,24 +1280,10 @@ impl CacheDir {
                 Err(_) => continue,
             };
 
-            // Must match module
-            if entry.module != module {
+            if !symbol_matches_filters(&entry, Some(module), kind_filter, risk_filter) {
                 continue;
             }
 
-            // Apply optional filters
-            if let Some(k) = kind_filter {
-                let normalized_kind = normalize_kind(k);
-                if entry.kind != normalized_kind {
-                    continue;
-                }
-            }
-            if let Some(r) = risk_filter {
-                if entry.risk != r {
-                    continue;
-                }
-            }
-
             results.push(entry);
 
             if results.len() >= limit {
@@ -1127,6 +1301,10 @@ impl CacheDir {
     pub fn load_all_symbol_entries(&self) -> Result<Vec<SymbolIndexEntry>> {
         use std::io::BufRead;
 
+        if let Some(warm) = self.warm_index() {
+            return Ok(warm.symbols.clone());
+        }
+
         let index_path = self.symbol_index_path();
         if !index_path.exists() {
             return Err(crate::McpDiffError::FileNotFound {
@@ -1914,7 +2092,7 @@ impl CacheDir {
         let import_graph_entries = import_graph.len();
 
         // Build module graph
-        let module_graph = self.build_module_graph_from_summaries(summaries);
+        let module_graph = build_module_graph(summaries);
         let module_graph_entries = module_graph.len();
 
         // Write call graph (streaming to avoid huge in-memory buffers)
@@ -2367,43 +2545,6 @@ impl CacheDir {
         graph
     }
 
-    /// Build module dependency graph from summaries
-    fn build_module_graph_from_summaries(
-        &self,
-        summaries: &[crate::schema::SemanticSummary],
-    ) -> std::collections::HashMap<String, Vec<String>> {
-        use std::collections::HashMap;
-
-        // First, group summaries by module
-        let mut modules: HashMap<String, Vec<&crate::schema::SemanticSummary>> = HashMap::new();
-        for summary in summaries {
-            let module_name = crate::shard::extract_module_name(&summary.file);
-            modules.entry(module_name).or_default().push(summary);
-        }
-
-        // Build module dependency graph
-        let mut graph: HashMap<String, Vec<String>> = HashMap::new();
-
-        for (module_name, module_summaries) in &modules {
-            let mut deps: Vec<String> = Vec::new();
-
-            for summary in module_summaries {
-                for import in &summary.local_imports {
-                    let import_module = crate::shard::extract_module_name(import);
-                    if import_module != *module_name && !deps.contains(&import_module) {
-                        deps.push(import_module);
-                    }
-                }
-            }
-
-            if !deps.is_empty() {
-                graph.insert(module_name.clone(), deps);
-            }
-        }
-
-        graph
-    }
-
     /// Encode call graph to TOON format
     #[allow(dead_code)]
     fn encode_call_graph(graph: &std::collections::HashMap<String, Vec<String>>) -> String {
@@ -2627,12 +2768,44 @@ pub struct SymbolIndexEntry {
     #[serde(rename = "pkg", default, skip_serializing_if = "String::is_empty")]
     pub ext_package: String,
 
+    /// Whether the symbol's file has no behavioral content and was left out
+    /// of repository metrics (only set when indexing with `--skip-logic-free`)
+    #[serde(rename = "lf", default, skip_serializing_if = "std::ops::Not::not")]
+    pub logic_free: bool,
+
     /// Base classes / parent interfaces (comma-separated), e.g. "BaseModel,Mixin"
     /// Only populated for class/interface/struct nodes
     #[serde(rename = "bc", default, skip_serializing_if = "String::is_empty")]
     pub base_classes: String,
 }
 
+/// Match a symbol name against a search query (lowercased)
+///
+/// Empty or `*` matches everything, `*`/`?` make it a glob, and anything
+/// else is a case-insensitive substring match.
+fn symbol_matches_query(entry: &SymbolIndexEntry, query_lower: &str) -> bool {
+    let symbol_lower = entry.symbol.to_lowercase();
+    if query_lower.is_empty() || query_lower == "*" {
+        true
+    } else if query_lower.contains('*') || query_lower.contains('?') {
+        match_glob_pattern(&symbol_lower, query_lower)
+    } else {
+        symbol_lower.contains(query_lower)
+    }
+}
+
+/// Apply the optional module, kind, and risk filters shared by index queries
+fn symbol_matches_filters(
+    entry: &SymbolIndexEntry,
+    module_filter: Option<&str>,
+    kind_filter: Option<&str>,
+    risk_filter: Option<&str>,
+) -> bool {
+    module_filter.map_or(true, |m| entry.module == m)
+        && kind_filter.map_or(true, |k| entry.kind == normalize_kind(k))
+        && risk_filter.map_or(true, |r| entry.risk == r)
+}
+
 fn is_zero_usize(v: &usize) -> bool {
     *v == 0
 }
@@ -2895,6 +3068,82 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_source_meta_round_trip() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let cache = CacheDir {
+            root: temp_dir.path().to_path_buf(),
+            repo_root: temp_dir.path().to_path_buf(),
+            repo_hash: "test_hash".to_string(),
+        };
+        assert!(cache.load_source_meta().is_none());
+
+        let source = temp_dir.path().join("lib.rs");
+        fs::write(&source, "pub fn a() {}\n").unwrap();
+        let meta = CacheMeta::for_file(&source, temp_dir.path());
+        cache.save_source_meta(&meta).unwrap();
+
+        let loaded = cache.load_source_meta().expect("meta should load");
+        assert!(loaded.is_compatible());
+        assert_eq!(loaded.source_files.len(), 1);
+        assert_eq!(loaded.source_files[0].path, "lib.rs");
+        assert!(!loaded.is_stale(temp_dir.path()));
+
+        fs::write(&source, "pub fn a() {}\npub fn b() {}\n").unwrap();
+        assert!(loaded.is_stale(temp_dir.path()));
+    }
+
+    #[test]
+    fn test_summaries_round_trip() {
+        use crate::schema::SemanticSummary;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let cache = CacheDir {
+            root: temp_dir.path().to_path_buf(),
+            repo_root: temp_dir.path().to_path_buf(),
+            repo_hash: "test_hash".to_string(),
+        };
+        assert!(cache.load_summaries().is_empty());
+
+        fs::create_dir_all(temp_dir.path().join("src")).unwrap();
+        fs::write(temp_dir.path().join("src/a.rs"), "fn a() {}").unwrap();
+        fs::write(temp_dir.path().join("src/b.rs"), "").unwrap();
+
+        let summaries = vec![
+            SemanticSummary {
+                file: "src/a.rs".to_string(),
+                language: "rust".to_string(),
+                symbol: Some("a".to_string()),
+                raw_fallback: Some("fn a() {}".to_string()),
+                ..Default::default()
+            },
+            SemanticSummary {
+                file: "src/b.rs".to_string(),
+                language: "rust".to_string(),
+                ..Default::default()
+            },
+            // Never written to disk, so there's nothing to reuse it against
+            SemanticSummary {
+                file: "src/gone.rs".to_string(),
+                language: "rust".to_string(),
+                ..Default::default()
+            },
+        ];
+        cache.save_summaries(&summaries, temp_dir.path()).unwrap();
+
+        let loaded = cache.load_summaries();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[0].source.path, "src/a.rs");
+        assert_eq!(loaded[0].source.size, 9);
+        assert_eq!(loaded[0].summary.symbol.as_deref(), Some("a"));
+        assert!(loaded[0].summary.raw_fallback.is_none());
+        assert!(!loaded[0].source.is_stale(temp_dir.path()));
+        assert_eq!(loaded[1].summary.file, "src/b.rs");
+    }
+
     // ========================================================================
     // Layer Cache Tests (SEM-45)
     // ========================================================================
@@ -4787,9 +5036,12 @@ mod tests {
                 name: "arg1".to_string(),
                 arg_type: Some("string".to_string()),
                 default_value: None,
+                is_optional: false,
+                description: None,
             }],
             props: Vec::new(),
             return_type: Some("void".to_string()),
+            type_params: Vec::new(),
             calls: Vec::new(),
             control_flow: Vec::new(),
             state_changes: Vec::new(),
@@ -4799,6 +5051,17 @@ mod tests {
             framework_entry_point: crate::schema::FrameworkEntryPoint::None,
             is_async: false,
             base_classes: Vec::new(),
+            is_recursive: false,
+            nested_functions: 0,
+            assertion_count: None,
+            overloaded: false,
+            is_stub: false,
+            injected_deps: Vec::new(),
+            naming_violation: None,
+            feature_envy: None,
+            patterns: Vec::new(),
+            is_stub_impl: false,
+            returns_description: None,
         };
 
         let hash1 = compute_symbol_hash(&symbol, "/path/to/file.ts");
@@ -4989,6 +5252,7 @@ export { formatName, processData };
                 is_async: symbol.is_async,
                 return_type: symbol.return_type.clone().unwrap_or_default(),
                 ext_package: String::new(),
+                logic_free: false,
                 base_classes: symbol.base_classes.join(","),
             });
         }
@@ -0,0 +1,237 @@
+//! Module dependency graph utilities for the cache module.
+//!
+//! Builds the module-level dependency graph from `local_imports`, loads it
+//! back from `module_graph.toon`, and orders modules so that every module
+//! comes after the modules it depends on.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+
+use crate::error::{McpDiffError, Result};
+use crate::schema::SemanticSummary;
+
+use super::{split_respecting_quotes, CacheDir};
+
+/// Build the module dependency graph from summaries.
+///
+/// Keys are module names (see [`crate::shard::extract_module_name`]) and
+/// values are the modules they import. Modules without local imports are
+/// omitted.
+pub fn build_module_graph(summaries: &[SemanticSummary]) -> HashMap<String, Vec<String>> {
+    // First, group summaries by module
+    let mut modules: HashMap<String, Vec<&SemanticSummary>> = HashMap::new();
+    for summary in summaries {
+        let module_name = crate::shard::extract_module_name(&summary.file);
+        modules.entry(module_name).or_default().push(summary);
+    }
+
+    // Build module dependency graph
+    let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+
+    for (module_name, module_summaries) in &modules {
+        let mut deps: Vec<String> = Vec::new();
+
+        for summary in module_summaries {
+            for import in &summary.local_imports {
+                let import_module = crate::shard::extract_module_name(import);
+                if import_module != *module_name && !deps.contains(&import_module) {
+                    deps.push(import_module);
+                }
+            }
+        }
+
+        if !deps.is_empty() {
+            graph.insert(module_name.clone(), deps);
+        }
+    }
+
+    graph
+}
+
+/// Load the module dependency graph from the cache.
+///
+/// Returns an empty graph if `module_graph.toon` has not been generated.
+pub fn load_module_graph(cache: &CacheDir) -> Result<HashMap<String, Vec<String>>> {
+    let path = cache.module_graph_path();
+    if !path.exists() {
+        return Ok(HashMap::new());
+    }
+
+    let content = fs::read_to_string(&path)?;
+    let mut graph = HashMap::new();
+
+    // Format: "module": ["dep1","dep2"]
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty()
+            || line.starts_with("_type:")
+            || line.starts_with("schema_version:")
+            || line.starts_with("modules:")
+        {
+            continue;
+        }
+
+        if let Some(bracket_pos) = line.find(": [") {
+            let module = line[..bracket_pos].trim().trim_matches('"').to_string();
+            let rest = line[bracket_pos + 2..].trim();
+
+            if rest.starts_with('[') && rest.ends_with(']') {
+                let deps = split_respecting_quotes(&rest[1..rest.len() - 1]);
+                if !deps.is_empty() {
+                    graph.insert(module, deps);
+                }
+            }
+        }
+    }
+
+    Ok(graph)
+}
+
+/// Order modules so that each module appears after all of its dependencies.
+///
+/// Modules that only appear as dependencies are included. Ties are broken
+/// alphabetically so the order is deterministic.
+///
+/// # Errors
+///
+/// Returns an error naming one dependency cycle if the graph is not acyclic.
+pub fn topological_order(graph: &HashMap<String, Vec<String>>) -> Result<Vec<String>> {
+    // module -> unique deps, including leaf modules with no entry of their own
+    let mut deps: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for (module, module_deps) in graph {
+        deps.entry(module.as_str())
+            .or_default()
+            .extend(module_deps.iter().map(String::as_str));
+        for dep in module_deps {
+            deps.entry(dep.as_str()).or_default();
+        }
+    }
+
+    let mut dependents: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    let mut pending: BTreeMap<&str, usize> = BTreeMap::new();
+    for (&module, module_deps) in &deps {
+        pending.insert(module, module_deps.len());
+        for &dep in module_deps {
+            dependents.entry(dep).or_default().push(module);
+        }
+    }
+
+    // Kahn's algorithm over a sorted ready set
+    let mut ready: BTreeSet<&str> = pending
+        .iter()
+        .filter(|(_, &count)| count == 0)
+        .map(|(&module, _)| module)
+        .collect();
+    let mut order = Vec::with_capacity(deps.len());
+
+    while let Some(module) = ready.pop_first() {
+        order.push(module.to_string());
+        for &dependent in dependents.get(module).into_iter().flatten() {
+            let count = pending
+                .get_mut(dependent)
+                .expect("dependent is a known module");
+            *count -= 1;
+            if *count == 0 {
+                ready.insert(dependent);
+            }
+        }
+    }
+
+    if order.len() < deps.len() {
+        let cycle = find_cycle(&deps, &pending);
+        return Err(McpDiffError::Generic(format!(
+            "Module dependency cycle: {}",
+            cycle.join(" -> ")
+        )));
+    }
+
+    Ok(order)
+}
+
+/// Load the cached module graph and return modules in dependency order.
+pub fn module_topo_order(cache: &CacheDir) -> Result<Vec<String>> {
+    topological_order(&load_module_graph(cache)?)
+}
+
+/// Walk unresolved modules until one repeats.
+///
+/// Every module left over by Kahn's algorithm still has an unresolved
+/// dependency, so following those edges must eventually revisit a module.
+fn find_cycle<'a>(
+    deps: &BTreeMap<&'a str, BTreeSet<&'a str>>,
+    pending: &BTreeMap<&'a str, usize>,
+) -> Vec<&'a str> {
+    let unresolved = |module: &str| pending.get(module).is_some_and(|&count| count > 0);
+
+    let Some(mut current) = pending.keys().copied().find(|m| unresolved(m)) else {
+        return Vec::new();
+    };
+    let mut path: Vec<&str> = Vec::new();
+
+    while !path.contains(&current) {
+        path.push(current);
+        match deps[current].iter().copied().find(|d| unresolved(d)) {
+            Some(next) => current = next,
+            None => return path,
+        }
+    }
+
+    let start = path.iter().position(|&m| m == current).unwrap_or(0);
+    let mut cycle = path.split_off(start);
+    cycle.push(current);
+    cycle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(file: &str, imports: &[&str]) -> SemanticSummary {
+        SemanticSummary {
+            file: file.to_string(),
+            local_imports: imports.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn graph(edges: &[(&str, &[&str])]) -> HashMap<String, Vec<String>> {
+        edges
+            .iter()
+            .map(|(m, deps)| (m.to_string(), deps.iter().map(|d| d.to_string()).collect()))
+            .collect()
+    }
+
+    #[test]
+    fn test_topological_order_respects_dependencies() {
+        // api -> service -> storage, api -> storage
+        let g = graph(&[("api", &["service", "storage"]), ("service", &["storage"])]);
+        let order = topological_order(&g).unwrap();
+        assert_eq!(order, vec!["storage", "service", "api"]);
+    }
+
+    #[test]
+    fn test_topological_order_reports_cycle() {
+        let g = graph(&[("a", &["b"]), ("b", &["c"]), ("c", &["a"]), ("d", &["a"])]);
+        let err = topological_order(&g).unwrap_err().to_string();
+        assert!(
+            err.contains("a -> b -> c -> a"),
+            "unexpected error: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_build_module_graph_groups_by_module() {
+        let summaries = vec![
+            summary("/repo/src/api/routes.ts", &["/repo/src/service/users.ts"]),
+            summary("/repo/src/api/handlers.ts", &["/repo/src/api/routes.ts"]),
+            summary("/repo/src/service/users.ts", &[]),
+        ];
+        let g = build_module_graph(&summaries);
+        let api = crate::shard::extract_module_name("/repo/src/api/routes.ts");
+        let service = crate::shard::extract_module_name("/repo/src/service/users.ts");
+        assert_ne!(api, service);
+        assert_eq!(g.len(), 1);
+        assert_eq!(g[&api], vec![service]);
+    }
+}
@@ -0,0 +1,362 @@
+//! Shared string table for symbol shards.
+//!
+//! Long state-change initializers (config objects, query strings, JSX
+//! fragments) tend to repeat across many symbols. The shard writer interns
+//! each repeated value once into `strings.json` and writes a `"$sN"`
+//! reference in the `init` cell of the shard's `state_changes` rows instead;
+//! [`CacheDir::read_symbol_shard`] swaps those references back so readers
+//! always see the original text. Nothing outside those cells is rewritten.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
+
+use crate::error::{McpDiffError, Result};
+use crate::fs_utils;
+use crate::schema::SemanticSummary;
+
+use super::CacheDir;
+
+/// Minimum length of a repeated value worth replacing with a reference
+pub const STRING_TABLE_MIN_LEN: usize = 32;
+
+/// Prefix of an encoded reference, e.g. `$s12`
+const REF_PREFIX: &str = "$s";
+
+/// Interned string values, referenced from shards by index
+#[derive(Debug, Clone, Default)]
+pub struct StringTable {
+    strings: Vec<String>,
+    index: HashMap<String, usize>,
+}
+
+/// Long initializers seen exactly once so far, for building a table from
+/// summaries that arrive a batch at a time
+#[derive(Debug, Default)]
+pub struct SeenInitializers {
+    once: HashSet<String>,
+}
+
+impl StringTable {
+    /// Intern every long initializer that occurs more than once across `summaries`
+    ///
+    /// Existing entries keep their indices, so shards written earlier stay
+    /// valid. Values that would read as a reference themselves are always
+    /// interned, so a literal `"$s3"` can never be mistaken for one.
+    pub fn intern_repeated(&mut self, summaries: &[SemanticSummary]) {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for summary in summaries {
+            for symbol in &summary.symbols {
+                for state in &symbol.state_changes {
+                    *counts.entry(state.initializer.as_str()).or_insert(0) += 1;
+                }
+            }
+        }
+
+        let mut values: Vec<&str> = counts
+            .into_iter()
+            .filter(|(value, count)| {
+                parse_reference(value).is_some()
+                    || (*count > 1 && value.len() >= STRING_TABLE_MIN_LEN)
+            })
+            .map(|(value, _)| value)
+            .collect();
+        // Sorted so indices are stable across runs
+        values.sort_unstable();
+
+        for value in values {
+            self.intern(value);
+        }
+    }
+
+    /// Intern every long initializer in `summaries` that has now occurred
+    /// more than once, counting earlier batches through `seen`
+    ///
+    /// For shards written as their module finishes, before later modules are
+    /// analyzed: a value's first occurrence may already be written inline.
+    /// That shard reads the same, it just isn't shortened. Values stop being
+    /// tracked in `seen` once interned.
+    pub fn intern_repeated_so_far(
+        &mut self,
+        seen: &mut SeenInitializers,
+        summaries: &[SemanticSummary],
+    ) {
+        let mut values: HashSet<&str> = HashSet::new();
+        for summary in summaries {
+            for symbol in &summary.symbols {
+                for state in &symbol.state_changes {
+                    let value = state.initializer.as_str();
+                    if parse_reference(value).is_some() {
+                        values.insert(value);
+                    } else if value.len() < STRING_TABLE_MIN_LEN
+                        || self.index.contains_key(value)
+                        || values.contains(value)
+                    {
+                        continue;
+                    } else if seen.once.remove(value) {
+                        values.insert(value);
+                    } else {
+                        seen.once.insert(value.to_string());
+                    }
+                }
+            }
+        }
+
+        let mut values: Vec<&str> = values.into_iter().collect();
+        // Sorted so indices don't depend on hash order within a batch
+        values.sort_unstable();
+
+        for value in values {
+            self.intern(value);
+        }
+    }
+
+    fn intern(&mut self, value: &str) -> usize {
+        if let Some(&idx) = self.index.get(value) {
+            return idx;
+        }
+        let idx = self.strings.len();
+        self.strings.push(value.to_string());
+        self.index.insert(value.to_string(), idx);
+        idx
+    }
+
+    /// Number of interned strings
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Whether the table is empty
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Look up an interned string by index
+    pub fn get(&self, idx: usize) -> Option<&str> {
+        self.strings.get(idx).map(String::as_str)
+    }
+
+    /// Encode a value for a shard: a `"$sN"` reference if interned, else quoted inline
+    pub fn encode(&self, value: &str) -> String {
+        match self.index.get(value) {
+            Some(idx) => format!("\"{}{}\"", REF_PREFIX, idx),
+            None => quote(value),
+        }
+    }
+
+    /// Replace the `"$sN"` references in a shard's state-change `init` cells
+    /// with their quoted values
+    ///
+    /// Only the last cell of each row under a `state_changes[N]` header is
+    /// looked at, since that is the only place [`encode`](Self::encode) writes
+    /// references. Text such as `"$s3"` anywhere else in the shard is source
+    /// content and is left alone.
+    pub fn resolve(&self, text: &str) -> String {
+        if self.is_empty() || !text.contains(REF_PREFIX) {
+            return text.to_string();
+        }
+
+        let mut resolved = String::with_capacity(text.len());
+        let mut rows_left = 0;
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                resolved.push('\n');
+            }
+            if rows_left > 0 {
+                rows_left -= 1;
+                match self.resolve_row(line) {
+                    Some(row) => resolved.push_str(&row),
+                    None => resolved.push_str(line),
+                }
+                continue;
+            }
+            rows_left = state_change_rows(line).unwrap_or(0);
+            resolved.push_str(line);
+        }
+        resolved
+    }
+
+    /// A `name,type,"$sN"` row with its reference swapped for the value
+    fn resolve_row(&self, row: &str) -> Option<String> {
+        let (head, init) = row.rsplit_once(',')?;
+        let reference = init.strip_prefix('"')?.strip_suffix('"')?;
+        let value = self.get(parse_reference(reference)?)?;
+        Some(format!("{},{}", head, quote(value)))
+    }
+
+    /// Load the table from the cache (empty if none was written)
+    pub fn load(cache: &CacheDir) -> Result<Self> {
+        let path = cache.string_table_path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(&path)?;
+        let strings: Vec<String> = serde_json::from_str(&content)
+            .map_err(|e| McpDiffError::Serialization(format!("Invalid string table: {}", e)))?;
+
+        let mut table = Self::default();
+        for value in &strings {
+            table.intern(value);
+        }
+        Ok(table)
+    }
+
+    /// Load the table through a per-cache memo, so bulk shard reads parse
+    /// `strings.json` once instead of once per shard
+    ///
+    /// The memo is keyed by the table's path and re-read when the file's
+    /// modification time or size changes, e.g. after another process saves it.
+    pub fn load_shared(cache: &CacheDir) -> Result<Arc<Self>> {
+        let path = cache.string_table_path();
+        let stamp = fs::metadata(&path)
+            .ok()
+            .map(|m| (m.modified().ok(), m.len()));
+
+        let loaded = LOADED_TABLES.get_or_init(Default::default);
+        if let Some((cached_stamp, table)) = loaded.lock().unwrap().get(&path) {
+            if *cached_stamp == stamp {
+                return Ok(Arc::clone(table));
+            }
+        }
+
+        let table = Arc::new(Self::load(cache)?);
+        loaded
+            .lock()
+            .unwrap()
+            .insert(path, (stamp, Arc::clone(&table)));
+        Ok(table)
+    }
+
+    /// Write the table to the cache, returning the bytes written
+    ///
+    /// Written to a temp file and renamed into place, so a concurrent reader
+    /// sees either the old table or the new one, never a partial file.
+    pub fn save(&self, cache: &CacheDir) -> Result<usize> {
+        let json = serde_json::to_string(&self.strings)
+            .map_err(|e| McpDiffError::Serialization(e.to_string()))?;
+        let path = cache.string_table_path();
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, &json)?;
+        fs_utils::atomic_rename(&temp_path, &path)?;
+
+        if let Some(loaded) = LOADED_TABLES.get() {
+            loaded.lock().unwrap().remove(&path);
+        }
+        Ok(json.len())
+    }
+}
+
+/// Modification time and size of a loaded `strings.json` (`None` if missing)
+type TableStamp = Option<(Option<SystemTime>, u64)>;
+
+/// Tables parsed by [`StringTable::load_shared`], by path
+static LOADED_TABLES: OnceLock<Mutex<HashMap<PathBuf, (TableStamp, Arc<StringTable>)>>> =
+    OnceLock::new();
+
+/// Row count of a `state_changes[N]{name,type,init}:` header line
+fn state_change_rows(line: &str) -> Option<usize> {
+    let rest = line.trim_start().strip_prefix("state_changes[")?;
+    let (count, header) = rest.split_once(']')?;
+    if !header.starts_with("{name,type,init}") {
+        return None;
+    }
+    count.parse().ok()
+}
+
+/// Index of a `$sN` reference
+fn parse_reference(value: &str) -> Option<usize> {
+    let digits = value.strip_prefix(REF_PREFIX)?;
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+/// Quote a value as an escaped string literal
+fn quote(value: &str) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("\"{}\"", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_round_trip() {
+        let mut table = StringTable::default();
+        let long = "{ retries: 3, backoff: \"exponential\", timeout: 30000 }";
+        table.intern(long);
+        table.intern("$s7");
+
+        let header = "state_changes[3]{name,type,init}:";
+        let shard = format!(
+            "{}\n  a,const,{}\n  b,const,{}\n  c,const,{}",
+            header,
+            table.encode(long),
+            table.encode("short"),
+            table.encode("$s7")
+        );
+        assert!(!shard.contains("exponential"));
+
+        let resolved = table.resolve(&shard);
+        assert_eq!(
+            resolved,
+            format!(
+                "{}\n  a,const,{}\n  b,const,\"short\"\n  c,const,\"$s7\"",
+                header,
+                quote(long)
+            )
+        );
+    }
+
+    #[test]
+    fn test_resolve_leaves_other_fields_alone() {
+        let mut table = StringTable::default();
+        table.intern("{ retries: 3, backoff: \"exponential\", timeout: 30000 }");
+
+        // A real `"$s0"` in a name, literal or call is source text, not a reference
+        let shard = "symbol: \"$s0\"\nsignature: \"fn pick(key = \"$s0\")\"\n\
+                     calls[1]{name,obj,await,try,count}:\n  \"$s0\",_,_,_,1\n\
+                     state_changes[1]{name,type,init}:\n  key,string,\"$s0\"\n\
+                     literal: \"$s0\"";
+        let resolved = table.resolve(shard);
+
+        assert_eq!(resolved.matches("\"$s0\"").count(), 4);
+        assert!(resolved.contains("  key,string,\"{ retries: 3"));
+    }
+
+    #[test]
+    fn test_intern_repeated_so_far_counts_across_batches() {
+        use crate::schema::{StateChange, SymbolInfo};
+
+        let summary = |init: &str| SemanticSummary {
+            symbols: vec![SymbolInfo {
+                state_changes: vec![StateChange {
+                    name: "config".to_string(),
+                    state_type: "const".to_string(),
+                    initializer: init.to_string(),
+                }],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let long = "{ retries: 3, backoff: \"exponential\", timeout: 30000 }";
+        let other = "{ retries: 5, backoff: \"linear\", timeout: 10000 }";
+
+        let mut table = StringTable::default();
+        let mut seen = SeenInitializers::default();
+        table.intern_repeated_so_far(&mut seen, &[summary(long), summary(other)]);
+        assert!(table.is_empty());
+
+        table.intern_repeated_so_far(&mut seen, &[summary(long), summary("short")]);
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.get(0), Some(long));
+
+        // A later repeat keeps the earlier index
+        table.intern_repeated_so_far(&mut seen, &[summary(other), summary(long)]);
+        assert_eq!(table.get(0), Some(long));
+        assert_eq!(table.get(1), Some(other));
+    }
+}
@@ -0,0 +1,167 @@
+//! In-memory symbol index and call graph for long-lived servers.
+//!
+//! Every query otherwise reads `symbol_index.jsonl` and the call graph from
+//! disk, so the first one after an MCP server starts is slow and the rest
+//! repeat the work. [`CacheDir::preload`] loads both once into a
+//! process-wide table keyed by cache root; the `CacheDir` readers serve from
+//! it and only go back to disk when the repository was never preloaded or
+//! either file changed size or mtime since.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::SystemTime;
+
+use parking_lot::RwLock;
+
+use crate::error::Result;
+
+use super::{CacheDir, SymbolIndexEntry};
+
+/// Size and mtime of the symbol index and call graph files
+type Stamp = [Option<(u64, SystemTime)>; 2];
+
+/// Symbol index and call graph held in memory for one repository
+#[derive(Debug)]
+pub struct WarmIndex {
+    /// Every symbol index entry, in file order
+    pub symbols: Vec<SymbolIndexEntry>,
+    /// Caller hash -> callees
+    pub call_graph: HashMap<String, Vec<String>>,
+    stamp: Stamp,
+    hits: AtomicUsize,
+}
+
+/// Whether a repository's queries are served from memory
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WarmStatus {
+    pub warm: bool,
+    /// Symbols held in memory
+    pub symbols: usize,
+    /// Callers in the in-memory call graph
+    pub callers: usize,
+    /// Reads served from memory since the preload
+    pub hits: usize,
+}
+
+static WARM_INDEXES: OnceLock<RwLock<HashMap<PathBuf, Arc<WarmIndex>>>> = OnceLock::new();
+
+fn warm_indexes() -> &'static RwLock<HashMap<PathBuf, Arc<WarmIndex>>> {
+    WARM_INDEXES.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+impl CacheDir {
+    /// Load the symbol index and call graph into memory
+    ///
+    /// Later reads through any `CacheDir` for this repository are served
+    /// from memory until either file changes on disk. Replaces an earlier
+    /// preload of the same repository.
+    pub fn preload(&self) -> Result<WarmStatus> {
+        // Drop the old copy first so the loads below read from disk
+        self.evict_warm();
+
+        let stamp = self.warm_stamp();
+        let index = WarmIndex {
+            symbols: self.load_all_symbol_entries()?,
+            call_graph: self.load_call_graph()?,
+            stamp,
+            hits: AtomicUsize::new(0),
+        };
+        warm_indexes()
+            .write()
+            .insert(self.root.clone(), Arc::new(index));
+
+        Ok(self.warm_status())
+    }
+
+    /// Status of this repository's in-memory index
+    pub fn warm_status(&self) -> WarmStatus {
+        match warm_indexes().read().get(&self.root) {
+            Some(index) if index.stamp == self.warm_stamp() => WarmStatus {
+                warm: true,
+                symbols: index.symbols.len(),
+                callers: index.call_graph.len(),
+                hits: index.hits.load(Ordering::Relaxed),
+            },
+            _ => WarmStatus::default(),
+        }
+    }
+
+    /// Drop this repository's in-memory index
+    pub fn evict_warm(&self) {
+        warm_indexes().write().remove(&self.root);
+    }
+
+    /// In-memory index, if preloaded and still matching the files on disk
+    ///
+    /// A stale copy is dropped, so the caller falls back to reading from disk.
+    pub(crate) fn warm_index(&self) -> Option<Arc<WarmIndex>> {
+        let index = warm_indexes().read().get(&self.root).cloned()?;
+        if index.stamp != self.warm_stamp() {
+            self.evict_warm();
+            return None;
+        }
+        index.hits.fetch_add(1, Ordering::Relaxed);
+        Some(index)
+    }
+
+    fn warm_stamp(&self) -> Stamp {
+        let stamp = |path: PathBuf| {
+            let meta = fs::metadata(path).ok()?;
+            Some((meta.len(), meta.modified().ok()?))
+        };
+        [
+            stamp(self.symbol_index_path()),
+            stamp(self.call_graph_path()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_search_after_preload_is_served_from_memory() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let cache = CacheDir {
+            root: temp_dir.path().to_path_buf(),
+            repo_root: temp_dir.path().to_path_buf(),
+            repo_hash: "test_hash".to_string(),
+        };
+        fs::write(
+            cache.symbol_index_path(),
+            concat!(
+                r#"{"s":"fetchUser","h":"aaa","k":"function","m":"api","f":"src/api.ts","l":"1-5","r":"low"}"#,
+                "\n",
+                r#"{"s":"saveUser","h":"bbb","k":"function","m":"api","f":"src/api.ts","l":"7-9","r":"low"}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+
+        assert!(!cache.warm_status().warm);
+        let status = cache.preload().unwrap();
+        assert!(status.warm);
+        assert_eq!(status.symbols, 2);
+        assert_eq!(status.hits, 0);
+
+        let results = cache.search_symbols("fetch", None, None, None, 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].symbol, "fetchUser");
+        assert_eq!(cache.warm_status().hits, 1);
+
+        // Rewriting the index invalidates the in-memory copy
+        fs::write(
+            cache.symbol_index_path(),
+            r#"{"s":"fetchAccount","h":"ccc","k":"function","m":"api","f":"src/api.ts","l":"1-5","r":"low"}"#,
+        )
+        .unwrap();
+        let results = cache.search_symbols("fetch", None, None, None, 10).unwrap();
+        assert_eq!(results[0].symbol, "fetchAccount");
+        assert!(!cache.warm_status().warm);
+    }
+}
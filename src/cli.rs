@@ -6,6 +6,8 @@
 use clap::{Args, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
+use crate::extract::SymbolScoringProfile;
+
 /// Semantic code analyzer with TOON output
 #[derive(Parser, Debug)]
 #[command(name = "semfora")]
@@ -57,6 +59,9 @@ pub enum Commands {
     #[command(visible_alias = "v")]
     Validate(ValidateArgs),
 
+    /// Save or check a complexity baseline (fail CI only on regressions)
+    Baseline(BaselineArgs),
+
     /// Manage the semantic index
     Index(IndexArgs),
 
@@ -87,6 +92,9 @@ pub enum Commands {
     /// Run token efficiency benchmark
     Benchmark(BenchmarkArgs),
 
+    /// List supported languages, their families, and file extensions
+    Languages,
+
     /// Start the MCP server (for AI coding assistants)
     Serve(ServeArgs),
 }
@@ -118,6 +126,23 @@ pub struct AnalyzeArgs {
     #[arg(long)]
     pub all_commits: bool,
 
+    /// Report symbols touched by a unified diff file ("-" reads stdin)
+    #[arg(long, value_name = "FILE")]
+    pub patch: Option<PathBuf>,
+
+    /// Compare two files' summaries symbol by symbol (no repository needed)
+    #[arg(long, num_args = 2, value_names = ["OLD", "NEW"])]
+    pub compare: Option<Vec<PathBuf>>,
+
+    /// With --diff, report only public API changes and the suggested semver bump
+    #[arg(long, requires = "diff")]
+    pub semver: bool,
+
+    /// With --diff, report which modules got riskier or safer between the
+    /// two refs (compared directly, not from their merge base)
+    #[arg(long, requires = "diff", conflicts_with = "semver")]
+    pub overview: bool,
+
     /// Base branch for diff comparison
     #[arg(long, value_name = "BRANCH")]
     pub base: Option<String>,
@@ -146,7 +171,8 @@ pub struct AnalyzeArgs {
     #[arg(long)]
     pub allow_tests: bool,
 
-    /// Show summary statistics only (no per-file details)
+    /// Show summary statistics only (no per-file details); for a single
+    /// file, only each symbol's kind, signature and risk
     #[arg(long)]
     pub summary_only: bool,
 
@@ -158,6 +184,11 @@ pub struct AnalyzeArgs {
     #[arg(long, value_name = "LINE")]
     pub end_line: Option<usize>,
 
+    /// Emit only the symbol table: name, kind, lines and exported (file mode
+    /// only; same as --output-mode=symbols_only)
+    #[arg(long, conflicts_with = "summary_only")]
+    pub symbols_only: bool,
+
     /// Output mode: 'full' (default), 'symbols_only', or 'summary'
     #[arg(long, value_name = "MODE", default_value = "full")]
     pub output_mode: String,
@@ -181,6 +212,18 @@ pub struct AnalyzeArgs {
     /// Print the parsed AST (for debugging)
     #[arg(long)]
     pub print_ast: bool,
+
+    /// Never include raw source when extraction is incomplete
+    #[arg(long)]
+    pub no_fallback: bool,
+
+    /// Weights used to pick each file's primary symbol
+    #[arg(long, value_enum, default_value_t = ScoringProfileArg::Default)]
+    pub scoring_profile: ScoringProfileArg,
+
+    /// List the factors behind the file's behavioral risk level
+    #[arg(long)]
+    pub explain: bool,
 }
 
 // ============================================
@@ -345,6 +388,10 @@ pub enum QueryType {
         #[arg(long)]
         symbols: bool,
 
+        /// Show a compact summary (purpose, files, risk, top symbols with signatures)
+        #[arg(long, conflicts_with = "symbols")]
+        summary: bool,
+
         /// Filter by symbol kind
         #[arg(long)]
         kind: Option<String>,
@@ -622,6 +669,16 @@ pub enum IndexOperation {
         /// Filter by file extension
         #[arg(long = "ext")]
         extensions: Vec<String>,
+
+        /// Leave files without logic (barrels, type-only modules, data files)
+        /// out of the overview's risk stats and complexity reports
+        #[arg(long)]
+        skip_logic_free: bool,
+
+        /// Worker threads for parsing and writing the index (default: one per
+        /// CPU; 1 runs sequentially)
+        #[arg(long, value_name = "N")]
+        threads: Option<usize>,
     },
 
     /// Check if the index is fresh or stale
@@ -643,6 +700,84 @@ pub enum IndexOperation {
     },
 }
 
+// ============================================
+// Baseline Subcommand
+// ============================================
+
+/// Default baseline file name, relative to the repository root
+pub const DEFAULT_BASELINE_FILE: &str = ".semfora-baseline.json";
+
+/// Default complexity budget file name, relative to the repository root
+pub const DEFAULT_BUDGET_FILE: &str = ".semfora-budgets.json";
+
+/// Default findings report file name, relative to the repository root
+pub const DEFAULT_FINDINGS_FILE: &str = ".semfora-findings.jsonl";
+
+/// Arguments for the baseline command
+#[derive(Args, Debug)]
+pub struct BaselineArgs {
+    /// Baseline operation to perform
+    #[command(subcommand)]
+    pub operation: BaselineOperation,
+}
+
+/// Baseline operations
+#[derive(Subcommand, Debug)]
+pub enum BaselineOperation {
+    /// Record current per-symbol complexity and risk to a baseline file
+    Save {
+        /// Repository path (defaults to current directory)
+        #[arg(value_name = "PATH")]
+        path: Option<PathBuf>,
+
+        /// Baseline file (defaults to .semfora-baseline.json in the repository)
+        #[arg(long)]
+        file: Option<PathBuf>,
+    },
+
+    /// Compare current analysis to the baseline; exits non-zero on regressions
+    Check {
+        /// Repository path (defaults to current directory)
+        #[arg(value_name = "PATH")]
+        path: Option<PathBuf>,
+
+        /// Baseline file (defaults to .semfora-baseline.json in the repository)
+        #[arg(long)]
+        file: Option<PathBuf>,
+
+        /// Allowed cognitive complexity increase per symbol before failing
+        #[arg(long, default_value = "0")]
+        tolerance: usize,
+    },
+
+    /// Check per-module average complexity against budgets; exits non-zero
+    /// when a module is over
+    Budget {
+        /// Repository path (defaults to current directory)
+        #[arg(value_name = "PATH")]
+        path: Option<PathBuf>,
+
+        /// Budget file (defaults to .semfora-budgets.json in the repository)
+        #[arg(long)]
+        file: Option<PathBuf>,
+    },
+
+    /// Write current findings to a JSONL report
+    Findings {
+        /// Repository path (defaults to current directory)
+        #[arg(value_name = "PATH")]
+        path: Option<PathBuf>,
+
+        /// Report file (defaults to .semfora-findings.jsonl in the repository)
+        #[arg(long)]
+        report: Option<PathBuf>,
+
+        /// Emit only findings added or resolved since the existing report
+        #[arg(long)]
+        incremental_output: bool,
+    },
+}
+
 // ============================================
 // Cache Subcommand
 // ============================================
@@ -1030,6 +1165,25 @@ pub enum TokenAnalysisMode {
     Compact,
 }
 
+/// Symbol scoring profile for CLI
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
+pub enum ScoringProfileArg {
+    /// Each language's built-in ranking (favors exported components)
+    #[default]
+    Default,
+    /// Favor structs, traits, and enums over components
+    Library,
+}
+
+impl ScoringProfileArg {
+    pub fn profile(self) -> SymbolScoringProfile {
+        match self {
+            Self::Default => SymbolScoringProfile::default(),
+            Self::Library => SymbolScoringProfile::library(),
+        }
+    }
+}
+
 /// Output format options
 #[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
 pub enum OutputFormat {
@@ -1041,6 +1195,12 @@ pub enum OutputFormat {
     Toon,
     /// JSON - standard JSON output for machine parsing
     Json,
+    /// GitHub-flavored Markdown for PR descriptions and wikis (file and directory analysis)
+    #[value(alias = "md")]
+    Markdown,
+    /// SARIF 2.1.0 for code-scanning uploads (repository-wide `validate` only;
+    /// any other command rejects it)
+    Sarif,
 }
 
 // ============================================
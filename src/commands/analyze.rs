@@ -10,23 +10,35 @@ use rayon::prelude::*;
 
 use crate::cli::{AnalyzeArgs, OutputFormat, TokenAnalysisMode};
 use crate::error::{McpDiffError, Result};
+use crate::extract::ExtractOptions;
 use crate::git::{
-    detect_base_branch, get_changed_files, get_commit_changed_files, get_commits_since,
-    get_file_at_ref, get_merge_base, get_repo_root, get_staged_changes, get_uncommitted_changes,
-    get_unstaged_changes, ChangeType, ChangedFile,
+    analyze_patch, detect_base_branch, diff_overview, get_changed_files, get_commit_changed_files,
+    get_commits_since, get_file_at_ref, get_merge_base, get_repo_root, get_staged_changes,
+    get_uncommitted_changes, get_unstaged_changes, semver_impact, ChangeType, ChangedFile,
 };
 use crate::mcp_server::formatting::{format_diff_output_paginated, format_diff_summary};
 use crate::parsing::{parse_and_extract, parse_and_extract_with_options};
+use crate::schema::{SemanticDiff, SymbolChangeKind, SymbolDiff};
 use crate::tokens::{format_analysis_compact, format_analysis_report, TokenAnalyzer};
 use crate::{
-    encode_toon, encode_toon_directory, fs_utils, generate_repo_overview, is_test_file, CacheDir,
-    Lang, SemanticSummary, ShardWriter,
+    encode_markdown, encode_markdown_directory, encode_toon, encode_toon_directory,
+    encode_toon_summary_only, encode_toon_symbols_only, explain_risk, fs_utils,
+    generate_repo_overview, is_test_file, summary_outline, symbol_table, CacheDir, Lang,
+    SemanticSummary, ShardWriter,
 };
 
 use super::CommandContext;
 
 /// Run the analyze command
 pub fn run_analyze(ctx: &CommandContext, args: &AnalyzeArgs) -> Result<String> {
+    // A patch needs no repository at all
+    if let Some(ref patch) = args.patch {
+        return run_patch(ctx, patch);
+    }
+    if let Some([old, new]) = args.compare.as_deref() {
+        return run_compare(ctx, args, old, new);
+    }
+
     // Determine what kind of analysis to perform
     if args.uncommitted {
         let base_ref = args.base.clone().unwrap_or_else(|| "HEAD".to_string());
@@ -35,6 +47,12 @@ pub fn run_analyze(ctx: &CommandContext, args: &AnalyzeArgs) -> Result<String> {
 
     if let Some(ref diff_ref) = args.diff {
         let base_ref = resolve_base_ref(args, diff_ref)?;
+        if args.semver {
+            return run_semver(ctx, args, &base_ref);
+        }
+        if args.overview {
+            return run_overview_diff(ctx, args, &base_ref);
+        }
         if args.all_commits {
             return run_all_commits(ctx, args, &base_ref);
         }
@@ -72,6 +90,157 @@ pub fn run_analyze(ctx: &CommandContext, args: &AnalyzeArgs) -> Result<String> {
     }
 }
 
+/// Report symbols touched by a unified diff read from `patch` ("-" for stdin)
+fn run_patch(ctx: &CommandContext, patch: &Path) -> Result<String> {
+    let diff_text = if patch == Path::new("-") {
+        let mut text = String::new();
+        std::io::Read::read_to_string(&mut std::io::stdin(), &mut text)?;
+        text
+    } else {
+        fs::read_to_string(patch).map_err(|e| McpDiffError::IoError {
+            path: patch.to_path_buf(),
+            message: e.to_string(),
+        })?
+    };
+
+    let touched = analyze_patch(&diff_text)?;
+
+    if ctx.format == OutputFormat::Text {
+        let mut output = format!("Touched symbols: {}\n", touched.len());
+        for symbol in &touched {
+            output.push_str(&format!(
+                "  {}:{}-{}  {} {}  [{}, {} risk]\n",
+                symbol.file,
+                symbol.start_line,
+                symbol.end_line,
+                symbol.kind.as_str(),
+                symbol.name,
+                symbol.change_type.as_str(),
+                symbol.risk.as_str()
+            ));
+        }
+        return Ok(output);
+    }
+
+    let symbols: Vec<serde_json::Value> = touched
+        .iter()
+        .map(|symbol| {
+            serde_json::json!({
+                "file": symbol.file,
+                "name": symbol.name,
+                "kind": symbol.kind.as_str(),
+                "lines": format!("{}-{}", symbol.start_line, symbol.end_line),
+                "risk": symbol.risk.as_str(),
+                "change": symbol.change_type.as_str(),
+            })
+        })
+        .collect();
+    let json_value = serde_json::json!({
+        "_type": "patch_analysis",
+        "touched": symbols,
+    });
+
+    Ok(match ctx.format {
+        OutputFormat::Json | OutputFormat::Sarif => {
+            serde_json::to_string_pretty(&json_value).unwrap_or_default()
+        }
+        _ => super::encode_toon(&json_value),
+    })
+}
+
+/// Symbol-level diff of two files' summaries
+fn run_compare(ctx: &CommandContext, args: &AnalyzeArgs, old: &Path, new: &Path) -> Result<String> {
+    let summarize = |path: &Path| -> Result<SemanticSummary> {
+        let lang = Lang::from_path(path)?;
+        let source = fs::read_to_string(path).map_err(|e| McpDiffError::IoError {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        parse_and_extract_with_options(path, &source, lang, args.print_ast, extract_options(args))
+    };
+    let before = summarize(old)?;
+    let after = summarize(new)?;
+
+    let file_diff = SemanticDiff::from_summaries(Some(&before), &after);
+    let symbol_diffs = SymbolDiff::between(&before, &after);
+
+    if ctx.format == OutputFormat::Text {
+        let mut output = format!(
+            "Comparing {} -> {}\nrisk: {} -> {} ({:+})\n",
+            old.display(),
+            new.display(),
+            file_diff.risk_before.as_str(),
+            file_diff.risk_after.as_str(),
+            file_diff.risk_change
+        );
+        for diff in &symbol_diffs {
+            let marker = match diff.change {
+                SymbolChangeKind::Added => '+',
+                SymbolChangeKind::Removed => '-',
+                SymbolChangeKind::Modified => '~',
+            };
+            output.push_str(&format!(
+                "  {} {} {}\n",
+                marker,
+                diff.kind.as_str(),
+                diff.name
+            ));
+            match (&diff.signature_before, &diff.signature_after) {
+                (Some(before), Some(after)) => {
+                    output.push_str(&format!("      signature: {} -> {}\n", before, after));
+                }
+                (Some(signature), None) | (None, Some(signature)) => {
+                    output.push_str(&format!("      signature: {}\n", signature));
+                }
+                (None, None) => {}
+            }
+            if let Some(visibility) = diff.visibility_change {
+                output.push_str(&format!("      visibility: {}\n", visibility.as_str()));
+            }
+            if !diff.calls_added.is_empty() {
+                output.push_str(&format!(
+                    "      calls added: {}\n",
+                    diff.calls_added.join(", ")
+                ));
+            }
+            if !diff.calls_removed.is_empty() {
+                output.push_str(&format!(
+                    "      calls removed: {}\n",
+                    diff.calls_removed.join(", ")
+                ));
+            }
+            if let (Some(before), Some(after)) = (diff.risk_before, diff.risk_after) {
+                if before != after {
+                    output.push_str(&format!(
+                        "      risk: {} -> {}\n",
+                        before.as_str(),
+                        after.as_str()
+                    ));
+                }
+            }
+        }
+        return Ok(output);
+    }
+
+    let json_value = serde_json::json!({
+        "_type": "file_comparison",
+        "old": old.display().to_string(),
+        "new": new.display().to_string(),
+        "risk_before": file_diff.risk_before.as_str(),
+        "risk_after": file_diff.risk_after.as_str(),
+        "risk_change": file_diff.risk_change,
+        "deltas": file_diff.deltas,
+        "symbols": symbol_diffs,
+    });
+
+    Ok(match ctx.format {
+        OutputFormat::Json | OutputFormat::Sarif => {
+            serde_json::to_string_pretty(&json_value).unwrap_or_default()
+        }
+        _ => super::encode_toon(&json_value),
+    })
+}
+
 /// Resolve the base ref for diff operations
 fn resolve_base_ref(args: &AnalyzeArgs, diff_ref: &str) -> Result<String> {
     if let Some(ref base) = args.base {
@@ -182,29 +351,62 @@ fn run_single_file(ctx: &CommandContext, args: &AnalyzeArgs, file_path: &Path) -
         source.clone()
     };
 
-    let summary =
-        parse_and_extract_with_options(file_path, &source_to_analyze, lang, args.print_ast)?;
+    let summary = parse_and_extract_with_options(
+        file_path,
+        &source_to_analyze,
+        lang,
+        args.print_ast,
+        extract_options(args),
+    )?;
 
     // Handle output mode
     let output = match args.output_mode.as_str() {
-        "symbols_only" => {
-            // Just list symbols with line ranges
-            let mut out = format!(
-                "_type: symbols_only\nfile: {}\nsymbol_count: {}\n\n",
-                file_path.display(),
-                summary.symbols.len()
-            );
-            for sym in &summary.symbols {
-                out.push_str(&format!(
-                    "- {} ({}) L{}-{}\n",
-                    sym.name,
-                    sym.kind.as_str(),
-                    sym.start_line,
-                    sym.end_line
-                ));
+        _ if args.summary_only => match ctx.format {
+            OutputFormat::Toon => encode_toon_summary_only(&summary),
+            OutputFormat::Json | OutputFormat::Sarif => {
+                serde_json::to_string_pretty(&summary_outline(&summary)).unwrap_or_default()
             }
-            out
-        }
+            OutputFormat::Text | OutputFormat::Markdown => {
+                let mut out = format!(
+                    "_type: summary_only\nfile: {}\nsymbol_count: {}\n\n",
+                    file_path.display(),
+                    summary.symbols.len()
+                );
+                for sym in &summary.symbols {
+                    out.push_str(&format!(
+                        "- {} ({}) risk: {}\n",
+                        crate::toon::symbol_signature(sym),
+                        sym.kind.as_str(),
+                        sym.behavioral_risk.as_str()
+                    ));
+                }
+                out
+            }
+        },
+        mode if args.symbols_only || mode == "symbols_only" => match ctx.format {
+            OutputFormat::Toon => encode_toon_symbols_only(&summary),
+            OutputFormat::Json | OutputFormat::Sarif => {
+                serde_json::to_string_pretty(&symbol_table(&summary)).unwrap_or_default()
+            }
+            OutputFormat::Text | OutputFormat::Markdown => {
+                // Just list symbols with line ranges
+                let mut out = format!(
+                    "_type: symbols_only\nfile: {}\nsymbol_count: {}\n\n",
+                    file_path.display(),
+                    summary.symbols.len()
+                );
+                for sym in &summary.symbols {
+                    out.push_str(&format!(
+                        "- {} ({}) L{}-{}\n",
+                        sym.name,
+                        sym.kind.as_str(),
+                        sym.start_line,
+                        sym.end_line
+                    ));
+                }
+                out
+            }
+        },
         "summary" => {
             // Brief overview only
             format!(
@@ -240,7 +442,7 @@ fn run_single_file(ctx: &CommandContext, args: &AnalyzeArgs, file_path: &Path) -
 
             // Token analysis if requested
             if let Some(mode) = args.analyze_tokens {
-                let analyzer = TokenAnalyzer::new();
+                let analyzer = TokenAnalyzer::for_lang(lang);
                 let analysis = analyzer.analyze(&source, &json_pretty, &json_compact, &toon_output);
 
                 let report = match mode {
@@ -283,9 +485,19 @@ fn run_single_file(ctx: &CommandContext, args: &AnalyzeArgs, file_path: &Path) -
                     text
                 }
                 OutputFormat::Toon => toon_output,
-                OutputFormat::Json => json_pretty,
+                OutputFormat::Json | OutputFormat::Sarif if args.explain => {
+                    let mut value = serde_json::to_value(&summary).unwrap_or_default();
+                    value["risk_factors"] = serde_json::json!(explain_risk(&summary));
+                    serde_json::to_string_pretty(&value).unwrap_or_default()
+                }
+                OutputFormat::Json | OutputFormat::Sarif => json_pretty,
+                OutputFormat::Markdown => encode_markdown(&summary),
             };
 
+            if args.explain && !matches!(ctx.format, OutputFormat::Json | OutputFormat::Sarif) {
+                output.push_str(&format_risk_factors(&summary));
+            }
+
             // Add focus context if applicable
             if has_focus {
                 output = format!(
@@ -300,10 +512,11 @@ fn run_single_file(ctx: &CommandContext, args: &AnalyzeArgs, file_path: &Path) -
                 );
             }
 
-            // Symbol count warning for large files (only for non-JSON formats)
-            if summary.symbols.len() > LARGE_SYMBOL_COUNT && ctx.format != OutputFormat::Json {
+            // Symbol count warning for large files (only for text and TOON)
+            let plain = matches!(ctx.format, OutputFormat::Text | OutputFormat::Toon);
+            if summary.symbols.len() > LARGE_SYMBOL_COUNT && plain {
                 output = format!(
-                    "# Note: {} symbols found. Consider using --output-mode=symbols_only for overview first.\n\n{}",
+                    "# Note: {} symbols found. Consider using --symbols-only for overview first.\n\n{}",
                     summary.symbols.len(),
                     output
                 );
@@ -316,6 +529,19 @@ fn run_single_file(ctx: &CommandContext, args: &AnalyzeArgs, file_path: &Path) -
     Ok(format!("{}\n", output))
 }
 
+/// The factors behind a file's risk level, one per line, largest first
+fn format_risk_factors(summary: &SemanticSummary) -> String {
+    let factors = explain_risk(summary);
+    let mut out = format!("\nrisk: {}\n", summary.behavioral_risk.as_str());
+    if factors.is_empty() {
+        out.push_str("  no contributing factors\n");
+    }
+    for factor in &factors {
+        out.push_str(&format!("  {}\n", factor));
+    }
+    out
+}
+
 /// Analyze a directory
 fn run_directory(ctx: &CommandContext, args: &AnalyzeArgs, dir_path: &Path) -> Result<String> {
     if !dir_path.exists() {
@@ -373,7 +599,7 @@ fn run_directory(ctx: &CommandContext, args: &AnalyzeArgs, dir_path: &Path) -> R
             all_source_len_atomic.fetch_add(source.len(), Ordering::Relaxed);
             total_lines_atomic.fetch_add(source.lines().count(), Ordering::Relaxed);
 
-            match parse_and_extract_string(file_path, &source, lang) {
+            match parse_and_extract_string(file_path, &source, lang, args) {
                 Ok(s) => Some(s),
                 Err(e) => {
                     if verbose {
@@ -396,10 +622,11 @@ fn run_directory(ctx: &CommandContext, args: &AnalyzeArgs, dir_path: &Path) -> R
     let dir_str = dir_path.display().to_string();
     let overview = generate_repo_overview(&summaries, &dir_str);
 
-    let output = if args.summary_only {
-        encode_toon_directory(&overview, &[])
+    let shown: &[SemanticSummary] = if args.summary_only { &[] } else { &summaries };
+    let output = if ctx.format == OutputFormat::Markdown {
+        encode_markdown_directory(&overview, shown)
     } else {
-        encode_toon_directory(&overview, &summaries)
+        encode_toon_directory(&overview, shown)
     };
 
     if let Some(mode) = args.analyze_tokens {
@@ -513,7 +740,7 @@ fn run_shard(ctx: &CommandContext, args: &AnalyzeArgs, dir_path: &Path) -> Resul
                 }
             };
 
-            match parse_and_extract_string(file_path, &source, lang) {
+            match parse_and_extract_string(file_path, &source, lang, args) {
                 Ok(s) => Some(s),
                 Err(e) => {
                     if verbose {
@@ -548,7 +775,7 @@ fn run_shard(ctx: &CommandContext, args: &AnalyzeArgs, dir_path: &Path) -> Resul
 }
 
 /// Analyze uncommitted changes
-fn run_uncommitted(ctx: &CommandContext, _args: &AnalyzeArgs, _base_ref: &str) -> Result<String> {
+fn run_uncommitted(ctx: &CommandContext, args: &AnalyzeArgs, _base_ref: &str) -> Result<String> {
     let repo_root = PathBuf::from(get_repo_root(None)?);
 
     let staged = get_staged_changes(None)?;
@@ -591,7 +818,7 @@ fn run_uncommitted(ctx: &CommandContext, _args: &AnalyzeArgs, _base_ref: &str) -
                 }
             };
 
-            parse_and_extract_string(&file_path, &source, lang).ok()
+            parse_and_extract_string(&file_path, &source, lang, args).ok()
         })
         .collect();
 
@@ -663,8 +890,135 @@ fn run_diff_branch(ctx: &CommandContext, args: &AnalyzeArgs, base_ref: &str) ->
     Ok(output)
 }
 
+/// Report public API changes between refs and the semver bump they imply
+fn run_semver(ctx: &CommandContext, args: &AnalyzeArgs, base_ref: &str) -> Result<String> {
+    let repo_root = match &args.path {
+        Some(p) if p.is_dir() => p.clone(),
+        _ => PathBuf::from(get_repo_root(None)?),
+    };
+    let target_ref = args.target_ref.as_deref().unwrap_or("HEAD");
+    let report = semver_impact(base_ref, target_ref, &repo_root)?;
+
+    if ctx.format == OutputFormat::Text {
+        let mut output = format!(
+            "Suggested bump: {} ({} -> {})\n",
+            report.bump.as_str(),
+            base_ref,
+            target_ref
+        );
+        for change in &report.changes {
+            output.push_str(&format!(
+                "  [{}] {} {}  {}\n",
+                change.kind.bump().as_str(),
+                change.kind.as_str(),
+                change.file,
+                change.signature
+            ));
+        }
+        return Ok(output);
+    }
+
+    let changes: Vec<serde_json::Value> = report
+        .changes
+        .iter()
+        .map(|change| {
+            serde_json::json!({
+                "file": change.file,
+                "symbol": change.symbol,
+                "change": change.kind.as_str(),
+                "bump": change.kind.bump().as_str(),
+                "signature": change.signature,
+            })
+        })
+        .collect();
+    let json_value = serde_json::json!({
+        "_type": "semver_impact",
+        "base": base_ref,
+        "target": target_ref,
+        "bump": report.bump.as_str(),
+        "changes": changes,
+    });
+
+    Ok(match ctx.format {
+        OutputFormat::Json | OutputFormat::Sarif => {
+            serde_json::to_string_pretty(&json_value).unwrap_or_default()
+        }
+        _ => super::encode_toon(&json_value),
+    })
+}
+
+/// Report module risk movement between `base_ref` and the target ref
+fn run_overview_diff(ctx: &CommandContext, args: &AnalyzeArgs, base_ref: &str) -> Result<String> {
+    let repo_root = match &args.path {
+        Some(p) if p.is_dir() => p.clone(),
+        _ => PathBuf::from(get_repo_root(None)?),
+    };
+    let target_ref = args.target_ref.as_deref().unwrap_or("HEAD");
+    let diff = diff_overview(base_ref, target_ref, &repo_root)?;
+    let risk = |r: Option<crate::RiskLevel>| r.map_or("-", |r| r.as_str());
+    let direction = |m: &crate::git::ModuleRiskChange| {
+        if m.is_riskier() {
+            "riskier"
+        } else if m.is_safer() {
+            "safer"
+        } else {
+            "same"
+        }
+    };
+
+    if ctx.format == OutputFormat::Text {
+        let mut output = format!("Module risk ({} -> {})\n", base_ref, target_ref);
+        for module in &diff.modules {
+            output.push_str(&format!(
+                "  {} {}: {} -> {}, symbols {} -> {}\n",
+                direction(module),
+                module.module,
+                risk(module.risk_before),
+                risk(module.risk_after),
+                module.symbols_before,
+                module.symbols_after
+            ));
+        }
+        for entry in &diff.new_high_risk {
+            output.push_str(&format!(
+                "  new high risk: {} {}\n",
+                entry.file,
+                entry.symbol.as_deref().unwrap_or("")
+            ));
+        }
+        return Ok(output);
+    }
+
+    let modules: Vec<serde_json::Value> = diff
+        .modules
+        .iter()
+        .map(|m| {
+            serde_json::json!({
+                "module": m.module,
+                "change": direction(m),
+                "risk": format!("{}->{}", risk(m.risk_before), risk(m.risk_after)),
+                "symbols": format!("{}->{}", m.symbols_before, m.symbols_after),
+            })
+        })
+        .collect();
+    let json_value = serde_json::json!({
+        "_type": "overview_diff",
+        "base": base_ref,
+        "target": target_ref,
+        "modules": modules,
+        "new_high_risk": diff.new_high_risk,
+    });
+
+    Ok(match ctx.format {
+        OutputFormat::Json | OutputFormat::Sarif => {
+            serde_json::to_string_pretty(&json_value).unwrap_or_default()
+        }
+        _ => super::encode_toon(&json_value),
+    })
+}
+
 /// Analyze a single commit
-fn run_single_commit(ctx: &CommandContext, _args: &AnalyzeArgs, sha: &str) -> Result<String> {
+fn run_single_commit(ctx: &CommandContext, args: &AnalyzeArgs, sha: &str) -> Result<String> {
     let changed_files = get_commit_changed_files(sha, None)?;
 
     if changed_files.is_empty() {
@@ -704,7 +1058,7 @@ fn run_single_commit(ctx: &CommandContext, _args: &AnalyzeArgs, sha: &str) -> Re
                 Err(_) => return None,
             };
 
-            parse_and_extract_string(&file_path, &source, lang).ok()
+            parse_and_extract_string(&file_path, &source, lang, args).ok()
         })
         .collect();
 
@@ -744,8 +1098,22 @@ fn run_all_commits(_ctx: &CommandContext, _args: &AnalyzeArgs, base_ref: &str) -
 ///
 /// Uses the shared parsing module (DEDUP-103).
 #[inline]
-fn parse_and_extract_string(file_path: &Path, source: &str, lang: Lang) -> Result<SemanticSummary> {
-    parse_and_extract(file_path, source, lang)
+fn parse_and_extract_string(
+    file_path: &Path,
+    source: &str,
+    lang: Lang,
+    args: &AnalyzeArgs,
+) -> Result<SemanticSummary> {
+    parse_and_extract_with_options(file_path, source, lang, false, extract_options(args))
+}
+
+/// Extraction options selected on the command line
+fn extract_options(args: &AnalyzeArgs) -> ExtractOptions {
+    ExtractOptions {
+        no_fallback: args.no_fallback,
+        scoring: args.scoring_profile.profile(),
+        ..Default::default()
+    }
 }
 
 /// Collect files for analysis
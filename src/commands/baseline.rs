@@ -0,0 +1,505 @@
+//! Baseline command handler - Ratchet complexity against a stored baseline
+//!
+//! `baseline save` records per-symbol cognitive complexity and behavioral risk;
+//! `baseline check` re-analyzes the repository and fails only when a symbol
+//! got worse than its recorded entry. Symbols missing from the baseline are
+//! new code and are not compared.
+//!
+//! `baseline budget` checks each module's average cognitive complexity
+//! against the cap its glob is given in `.semfora-budgets.json`.
+//!
+//! `baseline findings` writes per-symbol findings to a JSONL report; with
+//! `--incremental-output` it emits only findings added or resolved since the
+//! existing report. Findings are matched by `content_id` (the symbol hash) and
+//! rule.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::{
+    analyze_repo, calculate_symbol_cognitive_complexity, check_budgets, ComplexityBudgets,
+};
+use crate::cli::{
+    BaselineArgs, BaselineOperation, OutputFormat, DEFAULT_BASELINE_FILE, DEFAULT_BUDGET_FILE,
+    DEFAULT_FINDINGS_FILE,
+};
+use crate::commands::index::collect_files;
+use crate::commands::CommandContext;
+use crate::error::{McpDiffError, Result};
+use crate::lang::Lang;
+use crate::parsing::parse_and_extract;
+use crate::schema::RiskLevel;
+
+/// Baseline file format version
+const BASELINE_VERSION: u32 = 1;
+
+/// Maximum directory depth scanned for source files
+const MAX_DEPTH: usize = 10;
+
+/// Cognitive complexity above which a symbol is reported as a finding
+const FINDING_COGNITIVE_THRESHOLD: usize = 15;
+
+/// Recorded metrics for one symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// Cognitive complexity
+    pub cognitive: usize,
+    /// Behavioral risk level
+    pub risk: RiskLevel,
+}
+
+/// Per-symbol metrics keyed by `relative/path::symbol`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub version: u32,
+    pub symbols: BTreeMap<String, BaselineEntry>,
+}
+
+/// A symbol that got worse than its baseline entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regression {
+    pub symbol: String,
+    pub baseline: BaselineEntry,
+    pub current: BaselineEntry,
+}
+
+/// One reported issue on a symbol, a line of the findings report
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Finding {
+    /// Stable symbol hash
+    pub content_id: String,
+    /// Rule that produced the finding
+    pub rule: String,
+    pub file: String,
+    pub symbol: String,
+    pub line: usize,
+    pub message: String,
+}
+
+impl Finding {
+    fn key(&self) -> (&str, &str) {
+        (&self.content_id, &self.rule)
+    }
+}
+
+/// Findings added and resolved between two reports
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FindingsDelta {
+    pub new: Vec<Finding>,
+    pub resolved: Vec<Finding>,
+}
+
+/// Run the baseline command
+pub fn run_baseline(args: &BaselineArgs, ctx: &CommandContext) -> Result<String> {
+    match &args.operation {
+        BaselineOperation::Save { path, file } => {
+            let repo = repo_dir(path.as_ref())?;
+            run_baseline_save(&repo, &baseline_file(&repo, file.as_ref()), ctx)
+        }
+        BaselineOperation::Check {
+            path,
+            file,
+            tolerance,
+        } => {
+            let repo = repo_dir(path.as_ref())?;
+            run_baseline_check(&repo, &baseline_file(&repo, file.as_ref()), *tolerance, ctx)
+        }
+        BaselineOperation::Budget { path, file } => {
+            let repo = repo_dir(path.as_ref())?;
+            let file = file
+                .clone()
+                .unwrap_or_else(|| repo.join(DEFAULT_BUDGET_FILE));
+            run_budget_check(&repo, &file, ctx)
+        }
+        BaselineOperation::Findings {
+            path,
+            report,
+            incremental_output,
+        } => {
+            let repo = repo_dir(path.as_ref())?;
+            let report = report
+                .clone()
+                .unwrap_or_else(|| repo.join(DEFAULT_FINDINGS_FILE));
+            run_findings(&repo, &report, *incremental_output, ctx)
+        }
+    }
+}
+
+fn repo_dir(path: Option<&PathBuf>) -> Result<PathBuf> {
+    match path {
+        Some(path) => Ok(path.clone()),
+        None => Ok(std::env::current_dir()?),
+    }
+}
+
+fn baseline_file(repo: &Path, file: Option<&PathBuf>) -> PathBuf {
+    file.cloned()
+        .unwrap_or_else(|| repo.join(DEFAULT_BASELINE_FILE))
+}
+
+/// Write the current metrics to the baseline file
+fn run_baseline_save(repo: &Path, file: &Path, ctx: &CommandContext) -> Result<String> {
+    let baseline = collect_baseline(repo)?;
+    let json = serde_json::to_string_pretty(&baseline)
+        .map_err(|e| McpDiffError::Serialization(e.to_string()))?;
+    fs::write(file, json).map_err(|e| McpDiffError::IoError {
+        path: file.to_path_buf(),
+        message: e.to_string(),
+    })?;
+
+    let json_value = serde_json::json!({
+        "_type": "baseline_save",
+        "file": file.display().to_string(),
+        "symbols": baseline.symbols.len(),
+    });
+
+    Ok(match ctx.format {
+        OutputFormat::Json | OutputFormat::Sarif => {
+            serde_json::to_string_pretty(&json_value).unwrap_or_default()
+        }
+        OutputFormat::Toon => super::encode_toon(&json_value),
+        OutputFormat::Text | OutputFormat::Markdown => format!(
+            "Saved baseline for {} symbols to {}\n",
+            baseline.symbols.len(),
+            file.display()
+        ),
+    })
+}
+
+/// Compare current metrics to the baseline file, failing on regressions
+fn run_baseline_check(
+    repo: &Path,
+    file: &Path,
+    tolerance: usize,
+    ctx: &CommandContext,
+) -> Result<String> {
+    let content = fs::read_to_string(file).map_err(|e| McpDiffError::IoError {
+        path: file.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    let baseline: Baseline = serde_json::from_str(&content)
+        .map_err(|e| McpDiffError::Serialization(format!("Invalid baseline file: {}", e)))?;
+
+    let current = collect_baseline(repo)?;
+    let regressions = find_regressions(&baseline, &current, tolerance);
+
+    if !regressions.is_empty() {
+        let report: Vec<String> = regressions
+            .iter()
+            .map(|r| {
+                format!(
+                    "  {}: cognitive {} -> {}, risk {} -> {}",
+                    r.symbol,
+                    r.baseline.cognitive,
+                    r.current.cognitive,
+                    r.baseline.risk.as_str(),
+                    r.current.risk.as_str()
+                )
+            })
+            .collect();
+        return Err(McpDiffError::BaselineRegression {
+            report: report.join("\n"),
+        });
+    }
+
+    let json_value = serde_json::json!({
+        "_type": "baseline_check",
+        "file": file.display().to_string(),
+        "symbols": current.symbols.len(),
+        "tolerance": tolerance,
+        "regressions": 0,
+    });
+
+    Ok(match ctx.format {
+        OutputFormat::Json | OutputFormat::Sarif => {
+            serde_json::to_string_pretty(&json_value).unwrap_or_default()
+        }
+        OutputFormat::Toon => super::encode_toon(&json_value),
+        OutputFormat::Text | OutputFormat::Markdown => format!(
+            "No regressions against {} ({} symbols checked)\n",
+            file.display(),
+            current.symbols.len()
+        ),
+    })
+}
+
+/// Check module complexity against the budget file, failing on violations
+///
+/// Module metrics come from the index, so it must have been generated.
+fn run_budget_check(repo: &Path, file: &Path, ctx: &CommandContext) -> Result<String> {
+    let content = fs::read_to_string(file).map_err(|e| McpDiffError::IoError {
+        path: file.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    let config: ComplexityBudgets = serde_json::from_str(&content)
+        .map_err(|e| McpDiffError::Serialization(format!("Invalid budget file: {}", e)))?;
+
+    let analysis = analyze_repo(repo)?;
+    let violations = check_budgets(&analysis, &config);
+
+    if !violations.is_empty() {
+        let report: Vec<String> = violations
+            .iter()
+            .map(|v| {
+                format!(
+                    "  {}: average complexity {:.1} over budget {:.1}",
+                    v.module, v.actual, v.budget
+                )
+            })
+            .collect();
+        return Err(McpDiffError::BudgetExceeded {
+            report: report.join("\n"),
+        });
+    }
+
+    let json_value = serde_json::json!({
+        "_type": "budget_check",
+        "file": file.display().to_string(),
+        "modules": analysis.modules.len(),
+        "violations": 0,
+    });
+
+    Ok(match ctx.format {
+        OutputFormat::Json | OutputFormat::Sarif => {
+            serde_json::to_string_pretty(&json_value).unwrap_or_default()
+        }
+        OutputFormat::Toon => super::encode_toon(&json_value),
+        OutputFormat::Text | OutputFormat::Markdown => format!(
+            "All {} modules within the budgets in {}\n",
+            analysis.modules.len(),
+            file.display()
+        ),
+    })
+}
+
+/// Write current findings to `report`, returning all of them or only the delta
+fn run_findings(
+    repo: &Path,
+    report: &Path,
+    incremental: bool,
+    ctx: &CommandContext,
+) -> Result<String> {
+    let current = collect_findings(repo)?;
+
+    let delta = if incremental {
+        let previous = if report.exists() {
+            read_findings_report(report)?
+        } else {
+            Vec::new()
+        };
+        Some(diff_findings(&previous, &current))
+    } else {
+        None
+    };
+    let lines: Vec<(&str, &Finding)> = match &delta {
+        Some(delta) => delta
+            .new
+            .iter()
+            .map(|f| ("new", f))
+            .chain(delta.resolved.iter().map(|f| ("resolved", f)))
+            .collect(),
+        None => current.iter().map(|f| ("open", f)).collect(),
+    };
+
+    write_findings_report(report, &current)?;
+
+    Ok(match ctx.format {
+        OutputFormat::Text | OutputFormat::Markdown => {
+            let mut output = String::new();
+            for (change, finding) in &lines {
+                output.push_str(&format!(
+                    "{:<8} {} {}:{} {}: {}\n",
+                    change,
+                    finding.rule,
+                    finding.file,
+                    finding.line,
+                    finding.symbol,
+                    finding.message
+                ));
+            }
+            output
+        }
+        // JSONL either way, so the delta can be appended to a CI log as-is
+        OutputFormat::Json | OutputFormat::Sarif | OutputFormat::Toon => lines
+            .iter()
+            .map(|(change, finding)| {
+                let mut value = serde_json::to_value(finding).unwrap_or_default();
+                value["change"] = serde_json::json!(change);
+                value.to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    })
+}
+
+/// Findings in `current` missing from `previous` (new) and vice versa (resolved)
+pub fn diff_findings(previous: &[Finding], current: &[Finding]) -> FindingsDelta {
+    let previous_keys: BTreeSet<(&str, &str)> = previous.iter().map(Finding::key).collect();
+    let current_keys: BTreeSet<(&str, &str)> = current.iter().map(Finding::key).collect();
+
+    FindingsDelta {
+        new: current
+            .iter()
+            .filter(|f| !previous_keys.contains(&f.key()))
+            .cloned()
+            .collect(),
+        resolved: previous
+            .iter()
+            .filter(|f| !current_keys.contains(&f.key()))
+            .cloned()
+            .collect(),
+    }
+}
+
+fn read_findings_report(report: &Path) -> Result<Vec<Finding>> {
+    let content = fs::read_to_string(report).map_err(|e| McpDiffError::IoError {
+        path: report.to_path_buf(),
+        message: e.to_string(),
+    })?;
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|e| {
+                McpDiffError::Serialization(format!("Invalid findings report line: {}", e))
+            })
+        })
+        .collect()
+}
+
+fn write_findings_report(report: &Path, findings: &[Finding]) -> Result<()> {
+    let mut content = String::new();
+    for finding in findings {
+        let line = serde_json::to_string(finding)
+            .map_err(|e| McpDiffError::Serialization(e.to_string()))?;
+        content.push_str(&line);
+        content.push('\n');
+    }
+    fs::write(report, content).map_err(|e| McpDiffError::IoError {
+        path: report.to_path_buf(),
+        message: e.to_string(),
+    })
+}
+
+/// Analyze every supported file under `repo` into per-symbol findings
+pub fn collect_findings(repo: &Path) -> Result<Vec<Finding>> {
+    let mut findings = Vec::new();
+
+    for path in collect_files(repo, MAX_DEPTH, &[])? {
+        let Ok(lang) = Lang::from_path(&path) else {
+            continue;
+        };
+        let Ok(source) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let Ok(summary) = parse_and_extract(&path, &source, lang) else {
+            continue;
+        };
+
+        let relative = path
+            .strip_prefix(repo)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let namespace = crate::shard::extract_module_name(&relative);
+        for symbol in summary.symbols.iter().filter(|s| !s.is_escape_local) {
+            let content_id = symbol.to_symbol_id(&namespace, &relative).hash;
+            let mut push = |rule: &str, message: String| {
+                findings.push(Finding {
+                    content_id: content_id.clone(),
+                    rule: rule.to_string(),
+                    file: relative.clone(),
+                    symbol: symbol.name.clone(),
+                    line: symbol.start_line,
+                    message,
+                });
+            };
+
+            let cognitive = calculate_symbol_cognitive_complexity(symbol);
+            if cognitive > FINDING_COGNITIVE_THRESHOLD {
+                push(
+                    "cognitive-complexity",
+                    format!(
+                        "cognitive complexity {} exceeds {}",
+                        cognitive, FINDING_COGNITIVE_THRESHOLD
+                    ),
+                );
+            }
+            if symbol.behavioral_risk == RiskLevel::High {
+                push("high-risk", "high behavioral risk".to_string());
+            }
+            if let Some(violation) = &symbol.naming_violation {
+                push("naming-convention", violation.clone());
+            }
+        }
+    }
+
+    Ok(findings)
+}
+
+/// Analyze every supported file under `repo` into per-symbol metrics
+pub fn collect_baseline(repo: &Path) -> Result<Baseline> {
+    let mut baseline = Baseline {
+        version: BASELINE_VERSION,
+        symbols: BTreeMap::new(),
+    };
+
+    for path in collect_files(repo, MAX_DEPTH, &[])? {
+        let Ok(lang) = Lang::from_path(&path) else {
+            continue;
+        };
+        let Ok(source) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let Ok(summary) = parse_and_extract(&path, &source, lang) else {
+            continue;
+        };
+
+        let relative = path
+            .strip_prefix(repo)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+        for symbol in summary.symbols.iter().filter(|s| !s.is_escape_local) {
+            let entry = BaselineEntry {
+                cognitive: calculate_symbol_cognitive_complexity(symbol),
+                risk: symbol.behavioral_risk,
+            };
+            // Same-name symbols in one file share a key; keep the worst
+            baseline
+                .symbols
+                .entry(format!("{}::{}", relative, symbol.name))
+                .and_modify(|existing| {
+                    existing.cognitive = existing.cognitive.max(entry.cognitive);
+                    existing.risk = existing.risk.max(entry.risk);
+                })
+                .or_insert(entry);
+        }
+    }
+
+    Ok(baseline)
+}
+
+/// Symbols whose complexity grew beyond `tolerance` or whose risk level rose
+pub fn find_regressions(
+    baseline: &Baseline,
+    current: &Baseline,
+    tolerance: usize,
+) -> Vec<Regression> {
+    current
+        .symbols
+        .iter()
+        .filter_map(|(symbol, now)| {
+            let before = baseline.symbols.get(symbol)?;
+            let worse = now.cognitive > before.cognitive + tolerance || now.risk > before.risk;
+            worse.then(|| Regression {
+                symbol: symbol.clone(),
+                baseline: *before,
+                current: *now,
+            })
+        })
+        .collect()
+}
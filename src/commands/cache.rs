@@ -44,13 +44,13 @@ fn run_cache_info(ctx: &CommandContext) -> Result<String> {
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("═══════════════════════════════════════════════════════\n");
             output.push_str("  SEMFORA CACHE INFO\n");
             output.push_str("═══════════════════════════════════════════════════════\n\n");
@@ -114,13 +114,13 @@ fn run_cache_clear(ctx: &CommandContext) -> Result<String> {
     };
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             if json_value
                 .get("cleared")
                 .and_then(|v| v.as_bool())
@@ -158,13 +158,13 @@ fn run_cache_prune(days: u32, ctx: &CommandContext) -> Result<String> {
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str(&format!("Pruning caches older than {} days...\n", days));
             if pruned_count == 0 {
                 output.push_str("No caches pruned.\n");
@@ -122,13 +122,13 @@ pub fn run_commit(args: &CommitArgs, ctx: &CommandContext) -> Result<String> {
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str(&super::toon_header("prep_commit"));
             output
                 .push_str("_note: Information for commit message. This tool DOES NOT commit.\n\n");
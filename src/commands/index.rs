@@ -10,7 +10,7 @@ use crate::cache::CacheDir;
 use crate::cli::{IndexArgs, IndexOperation, OutputFormat};
 use crate::commands::CommandContext;
 use crate::error::{McpDiffError, Result};
-use crate::indexing::{analyze_files_parallel, IndexingProgressCallback};
+use crate::indexing::{analyze_files_parallel, with_thread_pool, IndexingProgressCallback};
 use crate::shard::{ShardProgressCallback, ShardWriter};
 use crate::Lang;
 
@@ -239,12 +239,16 @@ pub fn run_index(args: &IndexArgs, ctx: &CommandContext) -> Result<String> {
             incremental,
             max_depth,
             extensions,
+            skip_logic_free,
+            threads,
         } => run_generate(
             path.clone(),
             *force,
             *incremental,
             *max_depth,
             extensions.clone(),
+            *skip_logic_free,
+            *threads,
             ctx,
         ),
         IndexOperation::Check {
@@ -262,6 +266,8 @@ fn run_generate(
     incremental: bool,
     max_depth: usize,
     extensions: Vec<String>,
+    skip_logic_free: bool,
+    threads: Option<usize>,
     ctx: &CommandContext,
 ) -> Result<String> {
     let repo_dir =
@@ -298,7 +304,17 @@ fn run_generate(
         return Ok("Incremental mode: Index exists. Use --force to regenerate.".to_string());
     }
 
-    run_full_index(&repo_dir, &cache, max_depth, &extensions, ctx, force)
+    with_thread_pool(threads, || {
+        run_full_index(
+            &repo_dir,
+            &cache,
+            max_depth,
+            &extensions,
+            skip_logic_free,
+            ctx,
+            force,
+        )
+    })?
 }
 
 /// Run full index generation
@@ -307,6 +323,7 @@ fn run_full_index(
     cache: &CacheDir,
     max_depth: usize,
     extensions: &[String],
+    skip_logic_free: bool,
     ctx: &CommandContext,
     force: bool,
 ) -> Result<String> {
@@ -345,6 +362,7 @@ fn run_full_index(
 
     // Create shard writer (takes repo path)
     let mut writer = ShardWriter::new(repo_dir)?;
+    writer.set_skip_logic_free(skip_logic_free);
 
     // Process files in parallel (DEDUP-102: fixes the parallelism bug)
     // Previously used sequential for loop, now uses Rayon par_iter()
@@ -394,13 +412,13 @@ fn run_full_index(
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("Index generation complete:\n");
             output.push_str(&format!("  path: {}\n", repo_dir.display()));
             output.push_str(&format!("  files_found: {}\n", files.len()));
@@ -424,7 +442,7 @@ fn run_check(auto_refresh: bool, max_age: u64, ctx: &CommandContext) -> Result<S
 
     if !cache.exists() {
         if auto_refresh {
-            return run_full_index(&repo_dir, &cache, 10, &[], ctx, false);
+            return run_full_index(&repo_dir, &cache, 10, &[], false, ctx, false);
         }
         return Ok("No index found. Run `semfora index generate` to create one.".to_string());
     }
@@ -433,7 +451,7 @@ fn run_check(auto_refresh: bool, max_age: u64, ctx: &CommandContext) -> Result<S
     let meta_path = cache.root.join("meta.json");
     if !meta_path.exists() {
         if auto_refresh {
-            return run_full_index(&repo_dir, &cache, 10, &[], ctx, false);
+            return run_full_index(&repo_dir, &cache, 10, &[], false, ctx, false);
         }
         return Ok(
             "Index metadata not found. Run `semfora index generate` to regenerate.".to_string(),
@@ -460,7 +478,7 @@ fn run_check(auto_refresh: bool, max_age: u64, ctx: &CommandContext) -> Result<S
 
     if is_stale && auto_refresh {
         eprintln!("Index is stale. Refreshing...");
-        return run_full_index(&repo_dir, &cache, 10, &[], ctx, false);
+        return run_full_index(&repo_dir, &cache, 10, &[], false, ctx, false);
     }
 
     let mut output = String::new();
@@ -474,13 +492,13 @@ fn run_check(auto_refresh: bool, max_age: u64, ctx: &CommandContext) -> Result<S
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str(&format!("path: {}\n", repo_dir.display()));
             output.push_str(&format!("indexed_at: {}\n", indexed_at));
             output.push_str(&format!(
@@ -533,13 +551,13 @@ fn run_export(path: Option<String>, ctx: &CommandContext) -> Result<String> {
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("Export complete:\n");
             output.push_str(&format!("  path: {}\n", output_path.display()));
             output.push_str(&format!("  nodes: {}\n", stats.nodes_inserted));
@@ -556,7 +574,7 @@ fn run_export(path: Option<String>, ctx: &CommandContext) -> Result<String> {
 // ============================================
 
 /// Collect files to index
-fn collect_files(
+pub(crate) fn collect_files(
     dir: &std::path::Path,
     max_depth: usize,
     extensions: &[String],
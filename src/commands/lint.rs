@@ -104,13 +104,13 @@ fn run_lint_scan(
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             let status = if results.success { "pass" } else { "fail" };
             output.push_str("═══════════════════════════════════════════\n");
             output.push_str(&format!("  LINT SCAN: {}\n", status.to_uppercase()));
@@ -213,13 +213,13 @@ fn run_lint_fix(
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("═══════════════════════════════════════════\n");
             output.push_str(&format!("  LINT FIX ({})\n", mode.to_uppercase()));
             output.push_str("═══════════════════════════════════════════\n\n");
@@ -264,13 +264,13 @@ fn run_typecheck(
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("Type checking not yet implemented.\n");
         }
     }
@@ -313,13 +313,13 @@ fn run_detect_linters(path: &Option<PathBuf>, ctx: &CommandContext) -> Result<St
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("═══════════════════════════════════════════\n");
             output.push_str("  DETECTED LINTERS\n");
             output.push_str("═══════════════════════════════════════════\n\n");
@@ -410,13 +410,13 @@ fn run_lint_recommend(path: &Option<PathBuf>, ctx: &CommandContext) -> Result<St
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("═══════════════════════════════════════════\n");
             output.push_str("  LINTER RECOMMENDATIONS\n");
             output.push_str("═══════════════════════════════════════════\n\n");
@@ -9,6 +9,7 @@
 //! - `search` - Hybrid symbol + semantic search (the "magic" search)
 //! - `query` - Query the semantic index (symbols, source, callers, callgraph)
 //! - `validate` - Quality audits (complexity, duplicates)
+//! - `baseline` - Save or check a complexity baseline for CI
 //! - `index` - Manage the semantic index
 //! - `cache` - Manage the cache
 //! - `security` - CVE scanning and pattern management (internal use only)
@@ -19,6 +20,7 @@
 //! and a shared `CommandContext` for output format and verbosity.
 
 pub mod analyze;
+pub mod baseline;
 pub mod cache;
 pub mod commit;
 pub mod index;
@@ -35,13 +37,14 @@ pub mod validate;
 
 // Re-export command handlers for easy access
 pub use analyze::run_analyze;
+pub use baseline::run_baseline;
 pub use cache::run_cache;
 pub use commit::run_commit;
 pub use index::run_index;
 pub use lint::run_lint;
 pub use query::{
     run_file_symbols, run_get_callers, run_get_callgraph, run_get_source, run_get_symbol,
-    run_overview, run_query,
+    run_list_languages, run_module_summary, run_overview, run_query,
 };
 pub use search::run_search;
 // Security not re-exported - internal use only
@@ -49,7 +52,7 @@ pub use search::run_search;
 pub use serve::run_serve;
 pub use test::run_test;
 pub use trace::run_trace;
-pub use validate::{run_duplicates, run_validate};
+pub use validate::{run_duplicates, run_validate, sarif_unsupported};
 
 use crate::cli::OutputFormat;
 
@@ -75,8 +78,14 @@ impl Default for CommandContext {
 }
 
 /// Encode a JSON value as proper TOON using the rtoon library
+///
+/// Encoding failures are logged and yield an empty string; use
+/// [`crate::toon::try_encode_toon`] where the error must be handled.
 pub fn encode_toon(value: &serde_json::Value) -> String {
-    rtoon::encode_default(value).unwrap_or_else(|e| format!("TOON encoding error: {}", e))
+    crate::toon::try_encode_toon(value).unwrap_or_else(|e| {
+        tracing::warn!("{}", e);
+        String::new()
+    })
 }
 
 /// Package version from Cargo.toml
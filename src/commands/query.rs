@@ -5,10 +5,12 @@ use std::path::PathBuf;
 
 use crate::cache::{CacheDir, SymbolIndexEntry};
 use crate::cli::{OutputFormat, QueryArgs, QueryType, SymbolScope};
-use crate::commands::toon_parser::read_cached_file;
+use crate::commands::toon_parser::{parse_cached_content, read_cached_file};
 use crate::commands::CommandContext;
 use crate::error::{McpDiffError, Result};
 use crate::git::{get_current_branch, get_last_commit};
+use crate::lang::Lang;
+use crate::schema::RiskLevel;
 
 /// Run the query command
 pub fn run_query(args: &QueryArgs, ctx: &CommandContext) -> Result<String> {
@@ -30,13 +32,16 @@ pub fn run_query(args: &QueryArgs, ctx: &CommandContext) -> Result<String> {
         QueryType::Module {
             name,
             symbols,
+            summary,
             kind,
             risk,
             limit,
             symbol_scope,
             include_escape_refs,
         } => {
-            if *symbols {
+            if *summary {
+                run_module_summary(None, name, *limit, ctx)
+            } else if *symbols {
                 run_list_module_symbols(
                     name,
                     kind.as_deref(),
@@ -180,7 +185,7 @@ pub fn run_overview(
         filter_overview_content(&content, include_modules, max_modules, exclude_test_dirs);
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             // Convert TOON to JSON structure
             let mut json = toon_to_json_overview(&filtered_content);
             if let (Some(ctx), Some(obj)) = (git_context.as_ref(), json.as_object_mut()) {
@@ -204,7 +209,7 @@ pub fn run_overview(
             output.push_str(&filtered_content);
             Ok(output)
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             // Human-readable text format with header
             let mut output = String::new();
             output.push_str("═══════════════════════════════════════════\n");
@@ -504,9 +509,9 @@ fn run_get_module(name: &str, ctx: &CommandContext) -> Result<String> {
     let cached = read_cached_file(&module_file_path)?;
 
     match ctx.format {
-        OutputFormat::Json => Ok(cached.as_json()),
+        OutputFormat::Json | OutputFormat::Sarif => Ok(cached.as_json()),
         OutputFormat::Toon => Ok(cached.as_toon()),
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             // Human-readable text format
             let mut output = String::new();
             output.push_str("═══════════════════════════════════════════\n");
@@ -614,13 +619,13 @@ fn run_list_module_symbols(
     let mut output = String::new();
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("═══════════════════════════════════════════\n");
             output.push_str(&format!("  MODULE SYMBOLS: {}\n", module_name));
             output.push_str("═══════════════════════════════════════════\n\n");
@@ -637,6 +642,166 @@ fn run_list_module_symbols(
     Ok(output)
 }
 
+/// Summarize a module: purpose, file count, risk, and its riskiest symbols with signatures
+///
+/// Symbols come from the symbol index; signatures are rebuilt from each
+/// symbol's shard, falling back to the indexed arity when a shard is missing.
+pub fn run_module_summary(
+    path: Option<&PathBuf>,
+    module_name: &str,
+    limit: usize,
+    ctx: &CommandContext,
+) -> Result<String> {
+    use std::collections::BTreeSet;
+
+    let repo_dir = match path {
+        Some(p) => p.clone(),
+        None => std::env::current_dir().map_err(|e| McpDiffError::FileNotFound {
+            path: format!("current directory: {}", e),
+        })?,
+    };
+    let cache = CacheDir::for_repo(&repo_dir)?;
+
+    let mut modules = cache.list_modules();
+    if !modules.iter().any(|m| m == module_name) {
+        modules.sort();
+        let available = if modules.is_empty() {
+            "none (run `semfora index generate` first)".to_string()
+        } else {
+            modules.join(", ")
+        };
+        return Err(McpDiffError::FileNotFound {
+            path: format!(
+                "Module '{}' not found. Available modules: {}",
+                module_name, available
+            ),
+        });
+    }
+
+    let mut symbols: Vec<SymbolIndexEntry> = cache
+        .list_module_symbols(module_name, None, None, usize::MAX)?
+        .into_iter()
+        .filter(|s| !s.is_escape_local)
+        .collect();
+
+    let files: BTreeSet<&str> = symbols.iter().map(|s| s.file.as_str()).collect();
+    let file_count = files.len();
+    let risk = symbols
+        .iter()
+        .map(|s| RiskLevel::from_str(&s.risk))
+        .max()
+        .unwrap_or_default()
+        .as_str()
+        .to_string();
+    let symbol_count = symbols.len();
+
+    // Riskiest and most complex first
+    symbols.sort_by(|a, b| {
+        RiskLevel::from_str(&b.risk)
+            .cmp(&RiskLevel::from_str(&a.risk))
+            .then(b.cognitive_complexity.cmp(&a.cognitive_complexity))
+            .then(a.symbol.cmp(&b.symbol))
+    });
+    symbols.truncate(limit);
+
+    let top_symbols: Vec<serde_json::Value> = symbols
+        .iter()
+        .map(|s| {
+            serde_json::json!({
+                "symbol": s.symbol,
+                "kind": s.kind,
+                "signature": indexed_signature(&cache, s),
+                "risk": s.risk,
+                "file": s.file,
+                "lines": s.lines,
+                "hash": s.hash,
+            })
+        })
+        .collect();
+
+    let json_value = serde_json::json!({
+        "_type": "module_summary",
+        "module": module_name,
+        "purpose": crate::toon::get_module_purpose(module_name),
+        "files": file_count,
+        "risk": risk,
+        "symbol_count": symbol_count,
+        "top_symbols": top_symbols
+    });
+
+    let mut output = String::new();
+
+    match ctx.format {
+        OutputFormat::Json | OutputFormat::Sarif => {
+            output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
+        }
+        OutputFormat::Toon => {
+            output = super::encode_toon(&json_value);
+        }
+        OutputFormat::Text | OutputFormat::Markdown => {
+            output.push_str("═══════════════════════════════════════════\n");
+            output.push_str(&format!("  MODULE: {}\n", module_name));
+            output.push_str("═══════════════════════════════════════════\n\n");
+            output.push_str(&format!(
+                "purpose: {}\n",
+                crate::toon::get_module_purpose(module_name)
+            ));
+            output.push_str(&format!("files: {}\n", file_count));
+            output.push_str(&format!("risk: {}\n", risk));
+            output.push_str(&format!("symbols: {}\n\n", symbol_count));
+            output.push_str(&format!("top_symbols[{}]:\n", top_symbols.len()));
+            for sym in &symbols {
+                output.push_str(&format!(
+                    "  {} [{}] {}:{}\n",
+                    indexed_signature(&cache, sym),
+                    sym.risk,
+                    sym.file,
+                    sym.lines
+                ));
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+/// Signature for an indexed symbol, e.g. `fetchUser(id: string) -> User`
+fn indexed_signature(cache: &CacheDir, entry: &SymbolIndexEntry) -> String {
+    let mut arguments: Option<String> = None;
+    let mut return_type = Some(entry.return_type.clone()).filter(|r| !r.is_empty());
+
+    if let Ok(shard) = cache.read_symbol_shard(&entry.hash) {
+        for line in shard.lines() {
+            if line.starts_with("arguments[") {
+                arguments = line.split_once(": ").map(|(_, args)| {
+                    args.split(',')
+                        .map(|a| a.replacen(':', ": ", 1))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                });
+            } else if let Some(ret) = line.strip_prefix("return_type: ") {
+                return_type = Some(ret.trim_matches('"').to_string());
+            }
+        }
+    }
+
+    let arguments = arguments.unwrap_or_else(|| match entry.arity {
+        0 => String::new(),
+        1 => "1 arg".to_string(),
+        n => format!("{} args", n),
+    });
+    let mut signature = String::new();
+    if entry.is_async {
+        signature.push_str("async ");
+    }
+    signature.push_str(&format!("{}({})", entry.symbol, arguments));
+    if let Some(ret) = return_type {
+        signature.push_str(" -> ");
+        signature.push_str(&ret);
+    }
+    signature
+}
+
 /// Get symbol(s) by hash or file+line location (DEDUP-306: unified CLI/MCP handler)
 /// 1. Hash mode: single hash or comma-separated hashes for batch queries
 /// 2. File+line mode: find symbol at specific file:line location
@@ -702,13 +867,13 @@ pub fn run_get_symbol(
     let mut output = String::new();
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("═══════════════════════════════════════════\n");
             output.push_str("  SYMBOL DETAILS\n");
             output.push_str("═══════════════════════════════════════════\n\n");
@@ -878,13 +1043,13 @@ fn run_batch_source(
     let mut output = String::new();
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str(&format!(
                 "Batch source: {} requested, {} found\n",
                 hashes.len(),
@@ -963,13 +1128,13 @@ fn format_file_source(
     let mut output = String::new();
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str(&format!("file: {}\n", file));
             output.push_str(&format!("range: {}-{}\n", actual_start, actual_end));
             output.push_str("---\n");
@@ -1121,7 +1286,7 @@ pub fn run_get_callers(
     let mut output = String::new();
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
@@ -1169,7 +1334,7 @@ pub fn run_get_callers(
                 }
             }
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("═══════════════════════════════════════════\n");
             output.push_str("  CALLERS\n");
             output.push_str("═══════════════════════════════════════════\n\n");
@@ -1344,13 +1509,13 @@ pub fn run_get_callgraph(
         let mut output = String::new();
 
         match ctx.format {
-            OutputFormat::Json => {
+            OutputFormat::Json | OutputFormat::Sarif => {
                 output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
             }
             OutputFormat::Toon => {
                 output = super::encode_toon(&json_value);
             }
-            OutputFormat::Text => {
+            OutputFormat::Text | OutputFormat::Markdown => {
                 output.push_str("═══════════════════════════════════════════\n");
                 output.push_str("  CALL GRAPH SUMMARY\n");
                 output.push_str("═══════════════════════════════════════════\n\n");
@@ -1441,13 +1606,13 @@ pub fn run_get_callgraph(
     let mut output = String::new();
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("═══════════════════════════════════════════\n");
             output.push_str("  CALL GRAPH\n");
             output.push_str("═══════════════════════════════════════════\n\n");
@@ -1618,14 +1783,14 @@ pub fn run_file_symbols(
     if symbols.is_empty() {
         // Respect output format even for empty results
         return match ctx.format {
-            OutputFormat::Json => Ok(serde_json::json!({
+            OutputFormat::Json | OutputFormat::Sarif => Ok(serde_json::json!({
                 "_type": "file_symbols",
                 "file": file_path,
                 "count": 0,
                 "symbols": [],
                 "hint": "File may not be indexed or path doesn't match."
             }).to_string()),
-            OutputFormat::Toon | OutputFormat::Text => Ok(format!(
+            OutputFormat::Toon | OutputFormat::Text | OutputFormat::Markdown => Ok(format!(
                 "{}file: \"{}\"\nshowing: 0\nsymbols: (none)\nhint: File may not be indexed or path doesn't match.\n",
                 super::toon_header("file_symbols"),
                 file_path
@@ -1660,7 +1825,7 @@ pub fn run_file_symbols(
     let mut output = String::new();
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
@@ -1693,7 +1858,7 @@ pub fn run_file_symbols(
                 }
             }
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("═══════════════════════════════════════════\n");
             output.push_str(&format!("  FILE: {}\n", file_path));
             output.push_str("═══════════════════════════════════════════\n\n");
@@ -1723,45 +1888,16 @@ pub fn run_file_symbols(
     Ok(output)
 }
 
-/// List supported languages
-fn run_list_languages(ctx: &CommandContext) -> Result<String> {
-    // All supported languages with their extensions
-    let languages = vec![
-        ("TypeScript", vec!["ts", "mts", "cts"]),
-        ("Tsx", vec!["tsx"]),
-        ("JavaScript", vec!["js", "mjs", "cjs"]),
-        ("Jsx", vec!["jsx"]),
-        ("Rust", vec!["rs"]),
-        ("Python", vec!["py", "pyi"]),
-        ("Go", vec!["go"]),
-        ("Java", vec!["java"]),
-        ("C", vec!["c", "h"]),
-        ("Cpp", vec!["cpp", "cc", "cxx", "hpp", "hxx", "hh"]),
-        ("CSharp", vec!["cs"]),
-        ("Kotlin", vec!["kt", "kts"]),
-        ("Html", vec!["html", "htm"]),
-        ("Css", vec!["css"]),
-        ("Scss", vec!["scss", "sass"]),
-        ("Json", vec!["json"]),
-        ("Yaml", vec!["yaml", "yml"]),
-        ("Toml", vec!["toml"]),
-        (
-            "Xml",
-            vec!["xml", "xsd", "xsl", "xslt", "svg", "plist", "pom"],
-        ),
-        ("Hcl", vec!["tf", "hcl", "tfvars"]),
-        ("Markdown", vec!["md", "markdown"]),
-        ("Vue", vec!["vue"]),
-        ("Bash", vec!["sh", "bash", "zsh", "fish"]),
-        ("Gradle", vec!["gradle"]),
-        ("Dockerfile", vec!["dockerfile"]),
-    ];
+/// List supported languages with their families and file extensions
+pub fn run_list_languages(ctx: &CommandContext) -> Result<String> {
+    let languages = Lang::all();
 
     let json_value = serde_json::json!({
         "_type": "languages",
-        "languages": languages.iter().map(|(name, exts)| serde_json::json!({
-            "name": name,
-            "extensions": exts
+        "languages": languages.iter().map(|lang| serde_json::json!({
+            "name": lang.name(),
+            "family": lang.family().name(),
+            "extensions": lang.extensions()
         })).collect::<Vec<_>>(),
         "count": languages.len()
     });
@@ -1769,19 +1905,24 @@ fn run_list_languages(ctx: &CommandContext) -> Result<String> {
     let mut output = String::new();
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("═══════════════════════════════════════════\n");
             output.push_str("  SUPPORTED LANGUAGES\n");
             output.push_str("═══════════════════════════════════════════\n\n");
             output.push_str(&format!("languages[{}]:\n", languages.len()));
-            for (name, exts) in &languages {
-                output.push_str(&format!("  {}: {}\n", name, exts.join(", ")));
+            for lang in &languages {
+                output.push_str(&format!(
+                    "  {:<12} {:<12} {}\n",
+                    lang.name(),
+                    lang.family().name(),
+                    lang.extensions().join(", ")
+                ));
             }
         }
     }
@@ -1882,6 +2023,7 @@ fn symbol_from_json(sym: &serde_json::Value, module_name: &str) -> SymbolIndexEn
             .and_then(|v| v.as_str())
             .unwrap_or("")
             .to_string(),
+        logic_free: false,
         base_classes: sym
             .get("base_classes")
             .or_else(|| sym.get("bc"))
@@ -1897,7 +2039,7 @@ fn load_symbol_from_cache(cache: &CacheDir, hash: &str) -> Result<Option<SymbolI
     let symbol_path = cache.symbol_path(hash);
     if symbol_path.exists() {
         // Symbol shards are also in TOON format
-        let cached = read_cached_file(&symbol_path)?;
+        let cached = parse_cached_content(cache.read_symbol_shard(hash)?)?;
         let entry = symbol_from_json(&cached.json, "");
         return Ok(Some(entry));
     }
@@ -106,13 +106,13 @@ fn run_hybrid_search(args: &SearchArgs, ctx: &CommandContext) -> Result<String>
     };
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str(&format!("query: \"{}\"\n\n", args.query));
 
             // Symbol matches section
@@ -241,13 +241,13 @@ fn run_symbol_search(args: &SearchArgs, ctx: &CommandContext) -> Result<String>
         });
 
         match ctx.format {
-            OutputFormat::Json => {
+            OutputFormat::Json | OutputFormat::Sarif => {
                 output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
             }
             OutputFormat::Toon => {
                 output = super::encode_toon(&json_value);
             }
-            OutputFormat::Text => {
+            OutputFormat::Text | OutputFormat::Markdown => {
                 output.push_str("_note: Using ripgrep fallback (no semantic index)\n");
                 output.push_str(&format!("query: \"{}\"\n", args.query));
                 output.push_str(&format!("results[{}]:\n", ripgrep_results.len()));
@@ -284,13 +284,13 @@ fn run_symbol_search(args: &SearchArgs, ctx: &CommandContext) -> Result<String>
         });
 
         match ctx.format {
-            OutputFormat::Json => {
+            OutputFormat::Json | OutputFormat::Sarif => {
                 output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
             }
             OutputFormat::Toon => {
                 output = super::encode_toon(&json_value);
             }
-            OutputFormat::Text => {
+            OutputFormat::Text | OutputFormat::Markdown => {
                 output.push_str(&format!("query: \"{}\"\n", args.query));
                 output.push_str(&format!("results[{}]:\n", results.len()));
                 for entry in &results {
@@ -369,13 +369,13 @@ fn run_semantic_search(args: &SearchArgs, ctx: &CommandContext) -> Result<String
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str(&format!("query: \"{}\"\n", args.query));
             output.push_str(&format!("results[{}]:\n", results.len()));
 
@@ -455,13 +455,13 @@ fn run_raw_search(args: &SearchArgs, ctx: &CommandContext) -> Result<String> {
                 });
 
                 match ctx.format {
-                    OutputFormat::Json => {
+                    OutputFormat::Json | OutputFormat::Sarif => {
                         output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
                     }
                     OutputFormat::Toon => {
                         output = super::encode_toon(&json_value);
                     }
-                    OutputFormat::Text => {
+                    OutputFormat::Text | OutputFormat::Markdown => {
                         output.push_str(&format!("pattern: \"{}\"\n", args.query));
                         output.push_str(&format!("blocks[{}]:\n", blocks.len()));
                         for block in &blocks {
@@ -507,13 +507,13 @@ fn run_raw_search(args: &SearchArgs, ctx: &CommandContext) -> Result<String> {
                 });
 
                 match ctx.format {
-                    OutputFormat::Json => {
+                    OutputFormat::Json | OutputFormat::Sarif => {
                         output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
                     }
                     OutputFormat::Toon => {
                         output = super::encode_toon(&json_value);
                     }
-                    OutputFormat::Text => {
+                    OutputFormat::Text | OutputFormat::Markdown => {
                         output.push_str(&format!("pattern: \"{}\"\n", args.query));
                         output.push_str(&format!("matches[{}]:\n", matches.len()));
                         for m in &matches {
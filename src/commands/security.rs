@@ -161,13 +161,13 @@ fn run_cve_scan(
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("═══════════════════════════════════════════\n");
             output.push_str("  CVE VULNERABILITY SCAN\n");
             output.push_str("═══════════════════════════════════════════\n\n");
@@ -237,13 +237,13 @@ fn run_update_patterns(
                 });
 
                 match ctx.format {
-                    OutputFormat::Json => {
+                    OutputFormat::Json | OutputFormat::Sarif => {
                         output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
                     }
                     OutputFormat::Toon => {
                         output = super::encode_toon(&json_value);
                     }
-                    OutputFormat::Text => {
+                    OutputFormat::Text | OutputFormat::Markdown => {
                         output.push_str("Security patterns updated successfully.\n\n");
                         output.push_str(&format!("version: {}\n", result.current_version));
                         output.push_str(&format!("patterns: {}\n", result.pattern_count));
@@ -259,13 +259,13 @@ fn run_update_patterns(
                 });
 
                 match ctx.format {
-                    OutputFormat::Json => {
+                    OutputFormat::Json | OutputFormat::Sarif => {
                         output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
                     }
                     OutputFormat::Toon => {
                         output = super::encode_toon(&json_value);
                     }
-                    OutputFormat::Text => {
+                    OutputFormat::Text | OutputFormat::Markdown => {
                         output.push_str(&format!("Failed to update patterns: {}\n", e));
                     }
                 }
@@ -299,13 +299,13 @@ fn run_update_patterns(
                 });
 
                 match ctx.format {
-                    OutputFormat::Json => {
+                    OutputFormat::Json | OutputFormat::Sarif => {
                         output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
                     }
                     OutputFormat::Toon => {
                         output = super::encode_toon(&json_value);
                     }
-                    OutputFormat::Text => {
+                    OutputFormat::Text | OutputFormat::Markdown => {
                         output.push_str("Security patterns updated successfully.\n\n");
                         output.push_str(&format!("version: {}\n", result.current_version));
                         output.push_str(&format!("patterns: {}\n", result.pattern_count));
@@ -321,13 +321,13 @@ fn run_update_patterns(
                 });
 
                 match ctx.format {
-                    OutputFormat::Json => {
+                    OutputFormat::Json | OutputFormat::Sarif => {
                         output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
                     }
                     OutputFormat::Toon => {
                         output = super::encode_toon(&json_value);
                     }
-                    OutputFormat::Text => {
+                    OutputFormat::Text | OutputFormat::Markdown => {
                         output.push_str(&format!("Failed to update patterns: {}\n", e));
                     }
                 }
@@ -356,13 +356,13 @@ fn run_pattern_stats(ctx: &CommandContext) -> Result<String> {
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("═══════════════════════════════════════════\n");
             output.push_str("  SECURITY PATTERN STATISTICS\n");
             output.push_str("═══════════════════════════════════════════\n\n");
@@ -105,7 +105,17 @@ async fn run_serve_async(args: &ServeArgs) -> crate::Result<()> {
     };
 
     // Create MCP server with persistent state
-    let server = McpDiffServer::with_server_state(repo_path, server_state);
+    let server = McpDiffServer::with_server_state(repo_path.clone(), server_state);
+
+    // Load the index into memory so the first query doesn't pay for it
+    match server.preload(&repo_path).await {
+        Ok(status) => tracing::info!(
+            "Preloaded {} symbols and {} call graph entries",
+            status.symbols,
+            status.callers
+        ),
+        Err(e) => tracing::info!("Index not preloaded: {}", e),
+    }
 
     let service = server
         .serve(stdio())
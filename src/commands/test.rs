@@ -40,13 +40,13 @@ fn run_detect_tests(project_dir: &PathBuf, ctx: &CommandContext) -> Result<Strin
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str(&format!("path: {}\n", project_dir.display()));
             output.push_str(&format!("frameworks_detected: {}\n\n", frameworks.len()));
 
@@ -123,13 +123,13 @@ fn run_execute_tests(
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             let status = if results.failed == 0 {
                 "✓ PASSED"
             } else {
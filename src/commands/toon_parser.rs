@@ -260,7 +260,11 @@ pub fn read_cached_file(path: &Path) -> Result<CachedContent> {
         });
     }
 
-    let content = fs::read_to_string(path)?;
+    parse_cached_content(fs::read_to_string(path)?)
+}
+
+/// Parse already-loaded cached content, auto-detecting format (TOON or JSON)
+pub fn parse_cached_content(content: String) -> Result<CachedContent> {
     let is_json = content.trim_start().starts_with('{');
 
     let json = if is_json {
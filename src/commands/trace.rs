@@ -37,13 +37,13 @@ pub fn run_trace(options: TraceOptions, ctx: &CommandContext) -> Result<String>
     let mut output = String::new();
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str("═══════════════════════════════════════════\n");
             output.push_str("  TRACE\n");
             output.push_str("═══════════════════════════════════════════\n\n");
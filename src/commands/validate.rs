@@ -3,6 +3,7 @@
 use std::collections::BTreeMap;
 use std::path::Path;
 
+use crate::analysis::{analyze_repo, format_analysis_sarif};
 use crate::cache::{load_function_signatures, CacheDir};
 use crate::cli::{OutputFormat, SymbolScope, ValidateArgs};
 use crate::commands::CommandContext;
@@ -17,8 +18,18 @@ use crate::{DuplicateDetector, FunctionSignature};
 
 /// Run the validate command - unified validation with auto scope detection
 ///
-/// Scope priority: symbol_hash > file_path+line > file_path > module > duplicates
+/// Scope priority: symbol_hash > file_path+line > file_path > module > SARIF
+/// complexity report > duplicates
 pub fn run_validate(args: &ValidateArgs, ctx: &CommandContext) -> Result<String> {
+    let scoped = args.symbol_hash.is_some()
+        || args.file_path.is_some()
+        || args.module.is_some()
+        || args.target.is_some()
+        || args.duplicates;
+    if ctx.format == OutputFormat::Sarif && scoped {
+        return Err(sarif_unsupported("a scoped or duplicate `validate`"));
+    }
+
     // Use provided path or current directory
     let repo_dir = match &args.path {
         Some(p) => p.clone(),
@@ -54,7 +65,12 @@ pub fn run_validate(args: &ValidateArgs, ctx: &CommandContext) -> Result<String>
         return run_validate_module(args, &cache, module_name, ctx);
     }
 
-    // 5. duplicates flag or legacy target-based routing
+    // 5. SARIF → repository-wide complexity and cycle findings for code scanning
+    if ctx.format == OutputFormat::Sarif {
+        return Ok(format_analysis_sarif(&analyze_repo(&repo_dir)?));
+    }
+
+    // 6. duplicates flag or legacy target-based routing
     if args.duplicates {
         if let Some(ref target) = args.target {
             // Check if target looks like a hash (for single symbol duplicate check)
@@ -68,6 +84,20 @@ pub fn run_validate(args: &ValidateArgs, ctx: &CommandContext) -> Result<String>
     run_find_duplicates(args, &cache, ctx)
 }
 
+/// Error for `--format sarif` outside repository-wide `validate`
+///
+/// SARIF is only produced for the repository-wide complexity report; anything
+/// else would be JSON labelled as SARIF, which code-scanning uploads reject.
+pub fn sarif_unsupported(command: &str) -> McpDiffError {
+    McpDiffError::ConfigError {
+        message: format!(
+            "--format sarif is not supported by {}; run `validate` without a \
+             target or --duplicates",
+            command
+        ),
+    }
+}
+
 /// Validate a single symbol by hash (DEDUP-304)
 fn run_validate_symbol_by_hash(
     args: &ValidateArgs,
@@ -257,13 +287,13 @@ fn run_find_duplicates(
     if signatures.is_empty() {
         // Respect output format for empty results
         return match ctx.format {
-            OutputFormat::Json => Ok(serde_json::json!({
+            OutputFormat::Json | OutputFormat::Sarif => Ok(serde_json::json!({
                 "_type": "duplicate_analysis",
                 "clusters": 0,
                 "message": "No function signatures found in index."
             })
             .to_string()),
-            OutputFormat::Toon | OutputFormat::Text => {
+            OutputFormat::Toon | OutputFormat::Text | OutputFormat::Markdown => {
                 Ok("No function signatures found in index.".to_string())
             }
         };
@@ -322,7 +352,7 @@ fn run_find_duplicates(
     let mut output = String::new();
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             // JSON keeps full paths for programmatic access (DEDUP-207 enhanced)
             let json_value = serde_json::json!({
                 "_type": "duplicate_analysis",
@@ -491,7 +521,7 @@ fn run_find_duplicates(
                 output.push_str("\n");
             }
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             // Human-readable format for terminal (DEDUP-207 enhanced)
             output.push_str("═══════════════════════════════════════════\n");
             if let Some(ref target) = args.target {
@@ -642,13 +672,13 @@ fn run_check_duplicates(
     });
 
     match ctx.format {
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             output = serde_json::to_string_pretty(&json_value).unwrap_or_default();
         }
         OutputFormat::Toon => {
             output = super::encode_toon(&json_value);
         }
-        OutputFormat::Text => {
+        OutputFormat::Text | OutputFormat::Markdown => {
             output.push_str(&format!("symbol: {}\n", target_sig.name));
             output.push_str(&format!("hash: {}\n", target_sig.symbol_hash));
             output.push_str(&format!("file: {}\n", target_sig.file));
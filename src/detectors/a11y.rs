@@ -0,0 +1,363 @@
+//! Accessibility heuristics for JSX and HTML elements
+//!
+//! Flags common review-time a11y problems on individual elements:
+//! - `<img>` without `alt`
+//! - `<button>` with no text content and no `aria-label`
+//! - click handlers on non-interactive elements without a `role`
+//! - form controls with no associated `<label>`
+//!
+//! These are heuristics: elements with spread props (`{...props}`) are skipped,
+//! since the missing attribute may be supplied at runtime.
+
+use std::collections::HashSet;
+use tree_sitter::Node;
+
+use crate::detectors::common::{get_node_text, visit_all};
+use crate::schema::{A11yIssue, A11yIssueKind, Location};
+
+/// Elements that are interactive without a `role`
+const INTERACTIVE_ELEMENTS: &[&str] = &[
+    "a", "button", "input", "select", "textarea", "option", "summary", "details", "label",
+];
+
+/// Form controls that need an associated label
+const FORM_CONTROLS: &[&str] = &["input", "select", "textarea"];
+
+/// `<input type=...>` values that don't need a label
+const UNLABELED_INPUT_TYPES: &[&str] = &["hidden", "submit", "button", "reset", "image"];
+
+/// Attributes that give an element an accessible name
+const ACCESSIBLE_NAME_ATTRS: &[&str] = &["aria-label", "aria-labelledby", "title"];
+
+/// A JSX or HTML element reduced to what the checks need
+struct Element {
+    tag: String,
+    attrs: Vec<(String, Option<String>)>,
+    has_spread: bool,
+    has_content: bool,
+    inside_label: bool,
+    location: Location,
+}
+
+impl Element {
+    fn attr(&self, name: &str) -> Option<&(String, Option<String>)> {
+        self.attrs
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+    }
+
+    fn has_attr(&self, name: &str) -> bool {
+        self.attr(name).is_some()
+    }
+
+    fn has_accessible_name(&self) -> bool {
+        ACCESSIBLE_NAME_ATTRS.iter().any(|a| self.has_attr(a))
+    }
+}
+
+/// Find accessibility issues in a JSX or HTML syntax tree
+pub fn find_a11y_issues(root: &Node, source: &str) -> Vec<A11yIssue> {
+    let mut elements = Vec::new();
+    visit_all(root, |node| {
+        let element = match node.kind() {
+            "jsx_element" | "jsx_self_closing_element" => jsx_element(node, source),
+            "element" => html_element(node, source),
+            _ => None,
+        };
+        elements.extend(element);
+    });
+
+    // Ids referenced by <label for="..."> / <label htmlFor={...}>
+    let labelled_ids: HashSet<&str> = elements
+        .iter()
+        .filter(|e| e.tag == "label")
+        .filter_map(|e| e.attr("htmlFor").or_else(|| e.attr("for")))
+        .filter_map(|(_, value)| value.as_deref())
+        .collect();
+
+    let mut issues = Vec::new();
+    for element in &elements {
+        if element.has_spread {
+            continue;
+        }
+        for kind in check_element(element, &labelled_ids) {
+            issues.push(A11yIssue {
+                kind,
+                element: element.tag.clone(),
+                location: element.location.clone(),
+            });
+        }
+    }
+    issues
+}
+
+fn check_element(element: &Element, labelled_ids: &HashSet<&str>) -> Vec<A11yIssueKind> {
+    let mut kinds = Vec::new();
+    let tag = element.tag.as_str();
+
+    if tag == "img" && !element.has_attr("alt") {
+        kinds.push(A11yIssueKind::MissingAlt);
+    }
+
+    if tag == "button" && !element.has_content && !element.has_accessible_name() {
+        kinds.push(A11yIssueKind::ButtonWithoutText);
+    }
+
+    if element.has_attr("onClick")
+        && !INTERACTIVE_ELEMENTS.contains(&tag)
+        && !element.has_attr("role")
+    {
+        kinds.push(A11yIssueKind::ClickOnNonInteractive);
+    }
+
+    if FORM_CONTROLS.contains(&tag) {
+        let input_type = element
+            .attr("type")
+            .and_then(|(_, v)| v.as_deref())
+            .unwrap_or("text");
+        let is_labelled = element.inside_label
+            || element.has_accessible_name()
+            || element
+                .attr("id")
+                .and_then(|(_, v)| v.as_deref())
+                .is_some_and(|id| labelled_ids.contains(id));
+        if !is_labelled && !UNLABELED_INPUT_TYPES.contains(&input_type) {
+            kinds.push(A11yIssueKind::MissingFormLabel);
+        }
+    }
+
+    kinds
+}
+
+/// Whether any ancestor element is a `<label>`
+fn has_label_ancestor(
+    node: &Node,
+    source: &str,
+    tag_of: fn(&Node, &str) -> Option<String>,
+) -> bool {
+    let mut parent = node.parent();
+    while let Some(p) = parent {
+        if tag_of(&p, source).as_deref() == Some("label") {
+            return true;
+        }
+        parent = p.parent();
+    }
+    false
+}
+
+fn location_of(node: &Node) -> Location {
+    Location::new(node.start_position().row + 1, node.start_position().column)
+}
+
+// ============================================================================
+// JSX
+// ============================================================================
+
+/// Opening tag of a JSX element (the element itself when self-closing)
+fn jsx_open_tag<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    match node.kind() {
+        "jsx_element" => node.child_by_field_name("open_tag"),
+        "jsx_self_closing_element" => Some(*node),
+        _ => None,
+    }
+}
+
+fn jsx_tag_name(node: &Node, source: &str) -> Option<String> {
+    let open = jsx_open_tag(node)?;
+    Some(get_node_text(&open.child_by_field_name("name")?, source))
+}
+
+fn jsx_element(node: &Node, source: &str) -> Option<Element> {
+    let open = jsx_open_tag(node)?;
+    let tag = get_node_text(&open.child_by_field_name("name")?, source);
+
+    // Only intrinsic (lowercase) elements; components may render anything
+    if !tag.starts_with(|c: char| c.is_ascii_lowercase()) {
+        return None;
+    }
+
+    let mut attrs = Vec::new();
+    let mut has_spread = false;
+    let mut cursor = open.walk();
+    for attr in open.children_by_field_name("attribute", &mut cursor) {
+        match attr.kind() {
+            "jsx_attribute" => {
+                let Some(name) = attr.named_child(0) else {
+                    continue;
+                };
+                let value = attr.named_child(1).map(|v| {
+                    let text = get_node_text(&v, source);
+                    if v.kind() == "string" {
+                        text.trim_matches(|c| c == '"' || c == '\'').to_string()
+                    } else {
+                        text
+                    }
+                });
+                attrs.push((get_node_text(&name, source), value));
+            }
+            // `{...props}`
+            "jsx_expression" => has_spread = true,
+            _ => {}
+        }
+    }
+
+    let mut has_content = false;
+    if node.kind() == "jsx_element" {
+        let mut cursor = node.walk();
+        has_content = node
+            .named_children(&mut cursor)
+            .any(|child| match child.kind() {
+                "jsx_opening_element" | "jsx_closing_element" => false,
+                "jsx_text" => !get_node_text(&child, source).trim().is_empty(),
+                _ => true,
+            });
+    }
+
+    Some(Element {
+        tag,
+        attrs,
+        has_spread,
+        has_content,
+        inside_label: has_label_ancestor(node, source, jsx_tag_name),
+        location: location_of(node),
+    })
+}
+
+// ============================================================================
+// HTML
+// ============================================================================
+
+/// Start tag of an HTML element (`start_tag` or `self_closing_tag`)
+fn html_start_tag<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    let mut cursor = node.walk();
+    let tag = node
+        .children(&mut cursor)
+        .find(|c| matches!(c.kind(), "start_tag" | "self_closing_tag"));
+    tag
+}
+
+fn html_tag_name(node: &Node, source: &str) -> Option<String> {
+    if node.kind() != "element" {
+        return None;
+    }
+    let start = html_start_tag(node)?;
+    let mut cursor = start.walk();
+    let name = start
+        .children(&mut cursor)
+        .find(|c| c.kind() == "tag_name")
+        .map(|n| get_node_text(&n, source).to_lowercase());
+    name
+}
+
+fn html_element(node: &Node, source: &str) -> Option<Element> {
+    let tag = html_tag_name(node, source)?;
+    let start = html_start_tag(node)?;
+
+    let mut attrs = Vec::new();
+    let mut cursor = start.walk();
+    for attr in start.children(&mut cursor) {
+        if attr.kind() != "attribute" {
+            continue;
+        }
+        let Some(name) = attr.named_child(0) else {
+            continue;
+        };
+        let value = attr.named_child(1).map(|v| {
+            get_node_text(&v, source)
+                .trim_matches(|c| c == '"' || c == '\'')
+                .to_string()
+        });
+        // Normalise `onclick` so JSX and HTML share one check
+        let name = get_node_text(&name, source);
+        let name = if name.eq_ignore_ascii_case("onclick") {
+            "onClick".to_string()
+        } else {
+            name
+        };
+        attrs.push((name, value));
+    }
+
+    let mut cursor = node.walk();
+    let has_content = node
+        .named_children(&mut cursor)
+        .any(|child| match child.kind() {
+            "start_tag" | "end_tag" | "self_closing_tag" => false,
+            "text" => !get_node_text(&child, source).trim().is_empty(),
+            _ => true,
+        });
+
+    Some(Element {
+        tag,
+        attrs,
+        has_spread: false,
+        has_content,
+        inside_label: has_label_ancestor(node, source, html_tag_name),
+        location: location_of(node),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::Lang;
+    use tree_sitter::Parser;
+
+    fn issues(source: &str, lang: Lang) -> Vec<(A11yIssueKind, String)> {
+        let mut parser = Parser::new();
+        parser.set_language(&lang.tree_sitter_language()).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        find_a11y_issues(&tree.root_node(), source)
+            .into_iter()
+            .map(|i| (i.kind, i.element))
+            .collect()
+    }
+
+    #[test]
+    fn test_img_alt_in_jsx() {
+        let missing = r#"const Logo = () => <img src="logo.png" />;"#;
+        assert_eq!(
+            issues(missing, Lang::Jsx),
+            vec![(A11yIssueKind::MissingAlt, "img".to_string())]
+        );
+
+        let present = r#"const Logo = () => <img src="logo.png" alt="Company logo" />;"#;
+        assert!(issues(present, Lang::Jsx).is_empty());
+    }
+
+    #[test]
+    fn test_img_alt_in_html() {
+        assert_eq!(
+            issues(r#"<p><img src="logo.png"></p>"#, Lang::Html),
+            vec![(A11yIssueKind::MissingAlt, "img".to_string())]
+        );
+        assert!(issues(r#"<p><img src="logo.png" alt=""></p>"#, Lang::Html).is_empty());
+    }
+
+    #[test]
+    fn test_buttons_clicks_and_labels() {
+        let source = r#"
+const Form = ({ label }) => (
+    <form>
+        <button onClick={save} />
+        <button>{label}</button>
+        <div onClick={toggle}>Toggle</div>
+        <div role="button" onClick={toggle}>Toggle</div>
+        <input type="text" />
+        <label>Name <input type="text" /></label>
+        <label htmlFor="email">Email</label>
+        <input id="email" />
+        <input type="hidden" />
+        <Widget onClick={toggle} />
+    </form>
+);
+"#;
+        assert_eq!(
+            issues(source, Lang::Jsx),
+            vec![
+                (A11yIssueKind::ButtonWithoutText, "button".to_string()),
+                (A11yIssueKind::ClickOnNonInteractive, "div".to_string()),
+                (A11yIssueKind::MissingFormLabel, "input".to_string()),
+            ]
+        );
+    }
+}
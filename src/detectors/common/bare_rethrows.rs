@@ -0,0 +1,174 @@
+//! Bare rethrows
+
+use tree_sitter::Node;
+
+use crate::schema::BareRethrow;
+
+use super::{get_node_text, location_of, visit_all};
+
+/// Find error handlers that pass the error on unchanged
+///
+/// A catch block is flagged when its only statement rethrows what it caught
+/// (`catch (e) { throw e; }`, `except E as e: raise e`, or a bare `raise`);
+/// logging, cleanup, or wrapping in a new error clears it. A handler followed
+/// by another in the same `try` is skipped, since rethrowing there keeps the
+/// error away from the broader handler. In Rust, `.map_err(|e| e)` is flagged.
+pub fn find_bare_rethrows(root: &Node, source: &str) -> Vec<BareRethrow> {
+    let mut found = Vec::new();
+    visit_all(root, |node| {
+        let (error, body) = match node.kind() {
+            "catch_clause" => {
+                // JS/TS bind the error as `parameter`, Java in a `catch_formal_parameter`
+                let mut cursor = node.walk();
+                let error = node.child_by_field_name("parameter").or_else(|| {
+                    node.named_children(&mut cursor)
+                        .find(|c| c.kind() == "catch_formal_parameter")
+                        .and_then(|p| p.child_by_field_name("name"))
+                });
+                (error, node.child_by_field_name("body"))
+            }
+            "except_clause" => {
+                let mut cursor = node.walk();
+                let body = node
+                    .named_children(&mut cursor)
+                    .find(|c| c.kind() == "block");
+                (node.child_by_field_name("alias"), body)
+            }
+            "call_expression" => {
+                if let Some(error) = identity_map_err(node, source) {
+                    found.push(BareRethrow {
+                        error: Some(error),
+                        location: location_of(node),
+                    });
+                }
+                return;
+            }
+            _ => return,
+        };
+        let Some(body) = body else {
+            return;
+        };
+        if node.next_named_sibling().map(|s| s.kind()) == Some(node.kind()) {
+            return;
+        }
+
+        let error = error.map(|e| get_node_text(&e, source));
+        let statements = named_children_except_comments(&body);
+        let [statement] = statements.as_slice() else {
+            return;
+        };
+        if !matches!(statement.kind(), "throw_statement" | "raise_statement") {
+            return;
+        }
+        let rethrows = match named_children_except_comments(statement).as_slice() {
+            [] => statement.kind() == "raise_statement",
+            [thrown] => {
+                thrown.kind() == "identifier"
+                    && error.as_deref() == Some(get_node_text(thrown, source).as_str())
+            }
+            _ => false,
+        };
+        if rethrows {
+            found.push(BareRethrow {
+                error,
+                location: location_of(node),
+            });
+        }
+    });
+    found
+}
+
+/// The closure parameter of `.map_err(|e| e)`, if `node` is such a call
+fn identity_map_err(node: &Node, source: &str) -> Option<String> {
+    let function = node.child_by_field_name("function")?;
+    let method = function.child_by_field_name("field")?;
+    if function.kind() != "field_expression" || get_node_text(&method, source) != "map_err" {
+        return None;
+    }
+    let arguments = named_children_except_comments(&node.child_by_field_name("arguments")?);
+    let [closure] = arguments.as_slice() else {
+        return None;
+    };
+    if closure.kind() != "closure_expression" {
+        return None;
+    }
+    let params = named_children_except_comments(&closure.child_by_field_name("parameters")?);
+    let [param] = params.as_slice() else {
+        return None;
+    };
+    let body = closure.child_by_field_name("body")?;
+    let name = get_node_text(param, source);
+    (param.kind() == "identifier"
+        && body.kind() == "identifier"
+        && get_node_text(&body, source) == name)
+        .then_some(name)
+}
+
+fn named_children_except_comments<'tree>(node: &Node<'tree>) -> Vec<Node<'tree>> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor)
+        .filter(|c| !c.kind().contains("comment"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::common::tests::parse_source;
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_bare_rethrows() {
+        let rethrows = |source: &str, lang: Lang| -> Vec<(Option<String>, usize)> {
+            let tree = parse_source(source, lang);
+            find_bare_rethrows(&tree.root_node(), source)
+                .into_iter()
+                .map(|r| (r.error, r.location.line))
+                .collect()
+        };
+
+        let js = r#"
+async function loadUser(id) {
+    try {
+        return await db.find(id);
+    } catch (e) {
+        throw e;
+    }
+}
+
+async function loadOrder(id) {
+    try {
+        return await db.order(id);
+    } catch (err) {
+        throw new OrderError(`order ${id} failed`, { cause: err });
+    }
+}
+"#;
+        assert_eq!(
+            rethrows(js, Lang::JavaScript),
+            vec![(Some("e".to_string()), 5)]
+        );
+
+        let python = r#"
+try:
+    parse(data)
+except KeyError:
+    raise
+except ValueError as e:
+    raise e
+"#;
+        // Only the last handler; the first keeps KeyError from reaching it
+        assert_eq!(
+            rethrows(python, Lang::Python),
+            vec![(Some("e".to_string()), 6)]
+        );
+
+        let rust = r#"
+fn read(path: &str) -> io::Result<String> {
+    let text = fs::read_to_string(path).map_err(|e| e)?;
+    fs::read_to_string(path).map_err(|e| wrap(e, path))
+}
+"#;
+        assert_eq!(rethrows(rust, Lang::Rust), vec![(Some("e".to_string()), 3)]);
+    }
+}
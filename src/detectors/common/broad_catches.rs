@@ -0,0 +1,157 @@
+//! Broad exception catches
+
+use tree_sitter::Node;
+
+use crate::schema::BroadCatch;
+
+use super::{get_node_text, location_of, visit_all};
+
+/// Exception types at the root of their hierarchy
+const BROAD_EXCEPTION_TYPES: &[&str] = &["Exception", "BaseException", "Throwable"];
+
+/// Find handlers that catch every exception
+///
+/// Flags Python `except:` and `except Exception`, Java `catch (Exception e)`
+/// and `catch (Throwable t)`, C# `catch (Exception)` and a typeless `catch`,
+/// and a JS `catch {}` that doesn't even bind the error. A broad type inside
+/// a tuple or multi-catch counts; a C# `when` filter narrows the handler, so
+/// it is left alone.
+pub fn find_broad_catches(root: &Node, source: &str) -> Vec<BroadCatch> {
+    let mut found = Vec::new();
+    visit_all(root, |node| {
+        let caught: Vec<String> = match node.kind() {
+            "except_clause" => match node.child_by_field_name("value") {
+                Some(value) => get_node_text(&value, source)
+                    .trim_matches(|c| c == '(' || c == ')')
+                    .split(',')
+                    .map(|t| t.trim().to_string())
+                    .collect(),
+                None => Vec::new(),
+            },
+            "catch_clause" => {
+                let mut cursor = node.walk();
+                let children: Vec<Node> = node.named_children(&mut cursor).collect();
+                if let Some(param) = children
+                    .iter()
+                    .find(|c| c.kind() == "catch_formal_parameter")
+                {
+                    // Java: `catch (A | B e)` lists its types in a `catch_type`
+                    let mut cursor = param.walk();
+                    let types = param
+                        .named_children(&mut cursor)
+                        .find(|c| c.kind() == "catch_type");
+                    let mut cursor = node.walk();
+                    types
+                        .map(|t| {
+                            t.named_children(&mut cursor)
+                                .map(|c| get_node_text(&c, source))
+                                .collect()
+                        })
+                        .unwrap_or_default()
+                } else if children.iter().any(|c| c.kind() == "catch_filter_clause") {
+                    return;
+                } else if let Some(declaration) =
+                    children.iter().find(|c| c.kind() == "catch_declaration")
+                {
+                    declaration
+                        .child_by_field_name("type")
+                        .map(|t| vec![get_node_text(&t, source)])
+                        .unwrap_or_default()
+                } else if node.child_by_field_name("parameter").is_some() {
+                    // JS binds an untyped error; every JS catch is "broad"
+                    return;
+                } else {
+                    Vec::new()
+                }
+            }
+            _ => return,
+        };
+
+        let broad = if caught.is_empty() {
+            Some("*".to_string())
+        } else {
+            caught.into_iter().find(|t| {
+                let name = t.rsplit('.').next().unwrap_or(t.as_str());
+                BROAD_EXCEPTION_TYPES.contains(&name)
+            })
+        };
+        if let Some(caught) = broad {
+            found.push(BroadCatch {
+                caught,
+                location: location_of(node),
+            });
+        }
+    });
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::common::tests::parse_source;
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_broad_catches() {
+        let catches = |source: &str, lang: Lang| -> Vec<(String, usize)> {
+            let tree = parse_source(source, lang);
+            find_broad_catches(&tree.root_node(), source)
+                .into_iter()
+                .map(|c| (c.caught, c.location.line))
+                .collect()
+        };
+
+        let python = r#"
+try:
+    load()
+except:
+    pass
+
+try:
+    parse(text)
+except ValueError:
+    pass
+
+try:
+    save()
+except (OSError, Exception) as e:
+    log(e)
+"#;
+        assert_eq!(
+            catches(python, Lang::Python),
+            vec![("*".to_string(), 4), ("Exception".to_string(), 14)]
+        );
+
+        let java = r#"
+class Loader {
+    void load() {
+        try {
+            read();
+        } catch (IOException e) {
+            retry();
+        } catch (Throwable t) {
+            log(t);
+        }
+    }
+}
+"#;
+        assert_eq!(
+            catches(java, Lang::Java),
+            vec![("Throwable".to_string(), 8)]
+        );
+
+        let js = r#"
+try {
+    load();
+} catch (e) {
+    report(e);
+}
+try {
+    save();
+} catch {
+    retry();
+}
+"#;
+        assert_eq!(catches(js, Lang::JavaScript), vec![("*".to_string(), 9)]);
+    }
+}
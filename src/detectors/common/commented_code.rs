@@ -0,0 +1,135 @@
+//! Commented-out code detection
+
+use tree_sitter::Node;
+
+use crate::lang::Lang;
+use crate::schema::CommentedCodeBlock;
+
+use super::{get_node_text, visit_all};
+
+/// Minimum number of non-blank lines for a comment block to be reported
+pub const COMMENTED_CODE_MIN_LINES: usize = 2;
+
+/// Characters that prose rarely contains but nearly every line of code does
+const CODE_PUNCTUATION: &[char] = &[';', '{', '}', '=', '('];
+
+/// Find comment blocks that parse as code of the file's language
+///
+/// Adjacent line comments are merged into one block; block comments stand
+/// alone. A block counts as code when some line contains code punctuation
+/// and its uncommented text re-parses with `lang`'s grammar without errors,
+/// which rejects prose even when it happens to contain a `;` or `=`.
+/// Doc comments (`///`, `//!`, `/**`) and trailing comments after code are
+/// skipped.
+pub fn find_commented_code(root: &Node, source: &str, lang: Lang) -> Vec<CommentedCodeBlock> {
+    // (start_line, end_line, uncommented lines)
+    let mut blocks: Vec<(usize, usize, Vec<String>)> = Vec::new();
+    let mut previous_was_line_comment = false;
+
+    visit_all(root, |node| {
+        if !node.kind().contains("comment") {
+            return;
+        }
+        let text = get_node_text(node, source);
+        let line_start = source[..node.start_byte()].rfind('\n').map_or(0, |i| i + 1);
+        let is_trailing = !source[line_start..node.start_byte()].trim().is_empty();
+        if is_trailing || is_doc_comment(&text) {
+            previous_was_line_comment = false;
+            return;
+        }
+
+        let start = node.start_position().row + 1;
+        let end = node.end_position().row + 1;
+        let is_line_comment = !text.starts_with("/*");
+        let lines: Vec<String> = text.lines().map(strip_comment_markers).collect();
+
+        match blocks.last_mut() {
+            Some((_, block_end, block_lines))
+                if is_line_comment && previous_was_line_comment && *block_end + 1 == start =>
+            {
+                *block_end = end;
+                block_lines.extend(lines);
+            }
+            _ => blocks.push((start, end, lines)),
+        }
+        previous_was_line_comment = is_line_comment;
+    });
+
+    blocks
+        .into_iter()
+        .filter(|(_, _, lines)| looks_like_code(lines, lang))
+        .map(|(start_line, end_line, _)| CommentedCodeBlock {
+            start_line,
+            end_line,
+        })
+        .collect()
+}
+
+fn is_doc_comment(text: &str) -> bool {
+    ["///", "//!", "/**", "/*!", "#!"]
+        .iter()
+        .any(|prefix| text.starts_with(prefix))
+}
+
+/// Remove `//`, `#`, `/*`, `*/` and leading `*` from one comment line
+fn strip_comment_markers(line: &str) -> String {
+    let line = line.trim();
+    let line = line
+        .strip_prefix("//")
+        .or_else(|| line.strip_prefix("/*"))
+        .or_else(|| line.strip_prefix('#'))
+        .or_else(|| line.strip_prefix('*'))
+        .unwrap_or(line);
+    line.strip_suffix("*/").unwrap_or(line).to_string()
+}
+
+fn looks_like_code(lines: &[String], lang: Lang) -> bool {
+    let non_blank: Vec<&String> = lines.iter().filter(|l| !l.trim().is_empty()).collect();
+    if non_blank.len() < COMMENTED_CODE_MIN_LINES
+        || !non_blank.iter().any(|l| l.contains(CODE_PUNCTUATION))
+    {
+        return false;
+    }
+
+    let mut parser = tree_sitter::Parser::new();
+    if parser.set_language(&lang.tree_sitter_language()).is_err() {
+        return false;
+    }
+    let text = lines.join("\n");
+    parser
+        .parse(&text, None)
+        .is_some_and(|tree| !tree.root_node().has_error())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::common::tests::parse_source;
+
+    #[test]
+    fn test_commented_code() {
+        let source = r#"
+export function checkout(items) {
+    // const total = items.reduce((sum, item) => sum + item.price, 0);
+    // if (total > 100) {
+    //     applyDiscount(total);
+    // }
+    // return total;
+
+    // Prices are already discounted upstream, so we only sum them here;
+    // the old discount logic above is kept for reference.
+    return items.length; // trailing note = fine
+}
+"#;
+        let tree = parse_source(source, Lang::JavaScript);
+
+        let blocks = find_commented_code(&tree.root_node(), source, Lang::JavaScript);
+        assert_eq!(
+            blocks,
+            vec![CommentedCodeBlock {
+                start_line: 3,
+                end_line: 7
+            }]
+        );
+    }
+}
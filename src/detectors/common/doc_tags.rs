@@ -0,0 +1,204 @@
+//! Doc comment tags
+
+use crate::schema::{Argument, SemanticSummary};
+
+/// Parameter and return value descriptions from one doc comment
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocTags {
+    /// (parameter name, description), in comment order
+    pub params: Vec<(String, String)>,
+    pub returns: Option<String>,
+}
+
+/// Attach doc comment descriptions to each symbol's arguments and return
+///
+/// Reads the JSDoc block (`@param`, `@returns`) or rustdoc lines
+/// (`# Arguments`, `# Returns`) directly above a symbol, past any
+/// attributes or decorators. Arguments are matched by name; a documented
+/// parameter the signature doesn't have is ignored. The file-level
+/// arguments of the primary symbol are updated the same way.
+pub fn attach_doc_tags(summary: &mut SemanticSummary, source: &str) {
+    let lines: Vec<&str> = source.lines().collect();
+    for symbol in &mut summary.symbols {
+        let Some(tags) = doc_tags_before(&lines, symbol.start_line) else {
+            continue;
+        };
+        apply_param_descriptions(&tags, &mut symbol.arguments);
+        symbol.returns_description = tags.returns;
+    }
+    if let Some(tags) = summary
+        .start_line
+        .and_then(|line| doc_tags_before(&lines, line))
+    {
+        apply_param_descriptions(&tags, &mut summary.arguments);
+    }
+}
+
+fn apply_param_descriptions(tags: &DocTags, arguments: &mut [Argument]) {
+    for arg in arguments {
+        let name = arg
+            .name
+            .trim_start_matches("mut ")
+            .trim_start_matches(['.', '*', '&']);
+        if let Some((_, description)) = tags.params.iter().find(|(p, _)| p == name) {
+            arg.description = Some(description.clone());
+        }
+    }
+}
+
+/// Tags of the doc comment ending just above 1-based `line`
+fn doc_tags_before(lines: &[&str], line: usize) -> Option<DocTags> {
+    let mut end = line.checked_sub(1)?;
+    // Attributes and decorators sit between the doc comment and the item
+    while end > 0 {
+        let text = lines.get(end - 1)?.trim();
+        if text.starts_with("#[") || text.starts_with('@') {
+            end -= 1;
+        } else {
+            break;
+        }
+    }
+
+    let last = lines.get(end.checked_sub(1)?)?.trim();
+    if last.starts_with("///") {
+        let start = lines[..end]
+            .iter()
+            .rposition(|l| !l.trim().starts_with("///"))
+            .map_or(0, |i| i + 1);
+        return Some(parse_rustdoc(&lines[start..end]));
+    }
+    if last.ends_with("*/") {
+        let start = lines[..end].iter().rposition(|l| l.contains("/*"))?;
+        if !lines[start].contains("/**") {
+            return None;
+        }
+        return Some(parse_jsdoc(&lines[start..end]));
+    }
+    None
+}
+
+/// `@param {Type} name - description` and `@returns {Type} description`
+fn parse_jsdoc(lines: &[&str]) -> DocTags {
+    let mut tags = DocTags::default();
+    // Index of the param being continued, or None for `@returns`
+    let mut current: Option<Option<usize>> = None;
+
+    for line in lines {
+        let text = line
+            .trim()
+            .trim_start_matches("/**")
+            .trim_end_matches("*/")
+            .trim()
+            .trim_start_matches('*')
+            .trim();
+        if let Some(rest) = text.strip_prefix("@param") {
+            let rest = skip_jsdoc_type(rest);
+            let (name, description) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+            // `[name]` and `[name=default]` mark optional parameters
+            let name = name.trim_matches(['[', ']']);
+            let name = name.split('=').next().unwrap_or(name);
+            let description = description.trim().trim_start_matches('-').trim();
+            tags.params
+                .push((name.to_string(), description.to_string()));
+            current = Some(Some(tags.params.len() - 1));
+        } else if let Some(rest) = text
+            .strip_prefix("@returns")
+            .or_else(|| text.strip_prefix("@return"))
+        {
+            tags.returns = Some(skip_jsdoc_type(rest).to_string());
+            current = Some(None);
+        } else if text.starts_with('@') {
+            current = None;
+        } else if !text.is_empty() {
+            let target = match current {
+                Some(Some(i)) => &mut tags.params[i].1,
+                Some(None) => tags.returns.get_or_insert_with(String::new),
+                None => continue,
+            };
+            if !target.is_empty() {
+                target.push(' ');
+            }
+            target.push_str(text);
+        }
+    }
+
+    tags.params
+        .retain(|(_, description)| !description.is_empty());
+    tags.returns = tags.returns.filter(|r| !r.is_empty());
+    tags
+}
+
+/// Text after a leading `{Type}`, which may itself contain braces
+fn skip_jsdoc_type(text: &str) -> &str {
+    let text = text.trim();
+    if !text.starts_with('{') {
+        return text;
+    }
+    let mut depth = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return text[i + 1..].trim();
+                }
+            }
+            _ => {}
+        }
+    }
+    ""
+}
+
+/// `# Arguments` bullets (``* `name` - description``) and `# Returns` text
+fn parse_rustdoc(lines: &[&str]) -> DocTags {
+    enum Section {
+        Other,
+        Arguments,
+        Returns,
+    }
+
+    let mut tags = DocTags::default();
+    let mut section = Section::Other;
+    let mut returns: Vec<&str> = Vec::new();
+
+    for line in lines {
+        let text = line.trim().trim_start_matches("///").trim();
+        if let Some(heading) = text.strip_prefix('#') {
+            section = match heading.trim_start_matches('#').trim() {
+                "Arguments" | "Parameters" => Section::Arguments,
+                "Returns" => Section::Returns,
+                _ => Section::Other,
+            };
+            continue;
+        }
+        match section {
+            Section::Arguments => {
+                let bullet = text.strip_prefix("* ").or_else(|| text.strip_prefix("- "));
+                if let Some(item) = bullet.map(str::trim) {
+                    let (name, description) = match item.strip_prefix('`') {
+                        Some(rest) => rest.split_once('`').unwrap_or((rest, "")),
+                        None => item.split_once([' ', ':']).unwrap_or((item, "")),
+                    };
+                    let description = description.trim_start_matches([' ', '-', ':']).trim();
+                    tags.params
+                        .push((name.to_string(), description.to_string()));
+                } else if let Some((_, description)) = tags.params.last_mut() {
+                    if !text.is_empty() {
+                        description.push(' ');
+                        description.push_str(text);
+                    }
+                }
+            }
+            Section::Returns if !text.is_empty() => returns.push(text),
+            _ => {}
+        }
+    }
+
+    tags.params
+        .retain(|(_, description)| !description.is_empty());
+    if !returns.is_empty() {
+        tags.returns = Some(returns.join(" "));
+    }
+    tags
+}
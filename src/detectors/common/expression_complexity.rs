@@ -0,0 +1,80 @@
+//! Expression complexity from nested ternaries and optional chains
+
+use tree_sitter::Node;
+
+use super::member_chains::chain_object;
+use super::{has_child_kind, visit_all};
+
+/// Conditional (ternary) expression kinds across the supported grammars
+const TERNARY_KINDS: &[&str] = &["ternary_expression", "conditional_expression"];
+
+/// Score expression-level complexity: nested ternaries and optional chains
+///
+/// Each ternary adds 1 plus the number of ternaries it is nested in, so a
+/// three-level ternary scores 1 + 2 + 3. Each optional link (`?.`) after the
+/// first in a chain adds 1, so `a?.b?.c?.d` scores 2.
+pub fn expression_complexity(root: &Node) -> usize {
+    let mut score = 0;
+
+    visit_all(root, |node| {
+        if TERNARY_KINDS.contains(&node.kind()) {
+            let mut nesting = 0;
+            let mut parent = node.parent();
+            while let Some(p) = parent {
+                if TERNARY_KINDS.contains(&p.kind()) {
+                    nesting += 1;
+                }
+                parent = p.parent();
+            }
+            score += 1 + nesting;
+            return;
+        }
+
+        // Optional links, counted once per chain from its outermost link
+        if chain_object(node).is_none() {
+            return;
+        }
+        let continues_chain = node
+            .parent()
+            .and_then(|p| chain_object(&p))
+            .is_some_and(|object| object.id() == node.id());
+        if continues_chain {
+            return;
+        }
+        let mut optional_links = 0;
+        let mut current = Some(*node);
+        while let Some(link) = current {
+            if has_child_kind(&link, "optional_chain") {
+                optional_links += 1;
+            }
+            current = chain_object(&link);
+        }
+        score += optional_links.saturating_sub(1);
+    });
+
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::common::tests::parse_source;
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_expression_complexity() {
+        let score = |source: &str| -> usize {
+            let tree = parse_source(source, Lang::TypeScript);
+            expression_complexity(&tree.root_node())
+        };
+
+        // 1 + 2 + 3 for the nested ternary levels
+        assert_eq!(
+            score("const size = n < 10 ? \"s\" : n < 100 ? \"m\" : n < 1000 ? \"l\" : \"xl\";"),
+            6
+        );
+        // Three optional links past the first of four
+        assert_eq!(score("const city = user?.address?.city?.name?.trim();"), 3);
+        assert_eq!(score("const flat = a ? b : c;\nconst one = a?.b.c;"), 1);
+    }
+}
@@ -0,0 +1,61 @@
+//! Feature envy detection
+
+use std::collections::HashMap;
+
+use crate::schema::{SymbolInfo, SymbolKind};
+
+use super::recursion::SELF_RECEIVERS;
+
+/// Minimum calls on one foreign object before a method can be flagged
+pub const FEATURE_ENVY_MIN_CALLS: usize = 3;
+
+/// Flag methods that call into one other object more than twice as often as
+/// into their own (`self`/`this`)
+///
+/// A function counts as a method when its kind is `Method` or it lies inside
+/// a class-like symbol. Must run after calls have been attributed to symbols.
+pub fn detect_feature_envy(symbols: &mut [SymbolInfo]) {
+    let containers: Vec<(usize, usize)> = symbols
+        .iter()
+        .filter(|s| {
+            matches!(
+                s.kind,
+                SymbolKind::Class | SymbolKind::Struct | SymbolKind::Trait
+            )
+        })
+        .map(|s| (s.start_line, s.end_line))
+        .collect();
+
+    for symbol in symbols.iter_mut() {
+        let is_method = symbol.kind == SymbolKind::Method
+            || (symbol.kind == SymbolKind::Function
+                && containers
+                    .iter()
+                    .any(|&(start, end)| symbol.start_line > start && symbol.end_line <= end));
+        if !is_method {
+            continue;
+        }
+
+        let mut own_calls = 0usize;
+        let mut foreign_calls: HashMap<&str, usize> = HashMap::new();
+        for call in symbol
+            .calls
+            .iter()
+            .filter(|c| !c.ref_kind.is_variable_ref())
+        {
+            match call.object.as_deref() {
+                Some(obj) if SELF_RECEIVERS.contains(&obj) => own_calls += 1,
+                Some(obj) => *foreign_calls.entry(obj).or_insert(0) += 1,
+                None => {}
+            }
+        }
+
+        let envied = foreign_calls
+            .into_iter()
+            .filter(|&(_, count)| count >= FEATURE_ENVY_MIN_CALLS && count > own_calls * 2)
+            // Highest count wins; name breaks ties deterministically
+            .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(a.0)))
+            .map(|(obj, _)| obj.to_string());
+        symbol.feature_envy = envied;
+    }
+}
@@ -0,0 +1,137 @@
+//! Import-time side effects
+
+use tree_sitter::Node;
+
+use crate::schema::Location;
+
+use super::{first_named, get_node_text};
+
+/// Top-level expressions that run code when the module is loaded
+const SIDE_EFFECT_EXPRESSION_KINDS: &[&str] = &[
+    "call_expression",
+    "call",
+    "await_expression",
+    "await",
+    "new_expression",
+];
+
+/// Assignments that may write to state outside the module
+const ASSIGNMENT_KINDS: &[&str] = &[
+    "assignment_expression",
+    "augmented_assignment_expression",
+    "assignment",
+    "augmented_assignment",
+];
+
+/// Assignment targets that are properties of another object
+const MEMBER_TARGET_KINDS: &[&str] = &[
+    "member_expression",
+    "subscript_expression",
+    "attribute",
+    "subscript",
+];
+
+/// Find top-level statements that run when the module is imported
+///
+/// Only expression statements at module level count: calls (including
+/// IIFEs), `await`, `new`, and writes to another object's properties
+/// (`window.config = ...`, `os.environ["X"] = ...`). Imports, declarations
+/// (including `const app = createApp()`), docstrings, and CommonJS
+/// `module.exports`/`exports.x` assignments don't.
+pub fn find_import_side_effects(root: &Node, source: &str) -> Vec<Location> {
+    let mut found = Vec::new();
+    let mut cursor = root.walk();
+
+    for statement in root.named_children(&mut cursor) {
+        if statement.kind() != "expression_statement" {
+            continue;
+        }
+        let Some(expr) = first_named(statement) else {
+            continue;
+        };
+        let expr = if expr.kind() == "parenthesized_expression" {
+            first_named(expr).unwrap_or(expr)
+        } else {
+            expr
+        };
+
+        let has_side_effect = if SIDE_EFFECT_EXPRESSION_KINDS.contains(&expr.kind()) {
+            true
+        } else if ASSIGNMENT_KINDS.contains(&expr.kind()) {
+            expr.child_by_field_name("left").is_some_and(|target| {
+                MEMBER_TARGET_KINDS.contains(&target.kind()) && !is_commonjs_export(&target, source)
+            })
+        } else {
+            false
+        };
+
+        if has_side_effect {
+            found.push(Location::new(
+                statement.start_position().row + 1,
+                statement.start_position().column,
+            ));
+        }
+    }
+
+    found
+}
+
+/// Whether an assignment target is `module.exports` or `exports.x`
+fn is_commonjs_export(target: &Node, source: &str) -> bool {
+    let text = get_node_text(target, source);
+    text == "module.exports" || text.starts_with("module.exports.") || text.starts_with("exports.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::common::tests::parse_source;
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_import_side_effects() {
+        let side_effect_lines = |source: &str, lang: Lang| -> Vec<usize> {
+            let tree = parse_source(source, lang);
+            find_import_side_effects(&tree.root_node(), source)
+                .iter()
+                .map(|l| l.line)
+                .collect()
+        };
+
+        let app = r#"
+import { initApp } from "./app";
+import "./polyfills";
+
+const config = loadConfig();
+export function start() {
+    initApp(config);
+}
+
+initApp(config);
+window.appConfig = config;
+module.exports.start = start;
+"#;
+        assert_eq!(side_effect_lines(app, Lang::JavaScript), vec![10, 11]);
+
+        let pure = r#"
+import { helper } from "./helper";
+
+export const answer = helper(42);
+export class Service {}
+"#;
+        assert!(side_effect_lines(pure, Lang::JavaScript).is_empty());
+
+        let python = r#"
+"""Settings module."""
+import os
+
+DEBUG = os.getenv("DEBUG") == "1"
+os.environ["TZ"] = "UTC"
+logging.basicConfig(level=logging.INFO)
+
+if __name__ == "__main__":
+    main()
+"#;
+        assert_eq!(side_effect_lines(python, Lang::Python), vec![6, 7]);
+    }
+}
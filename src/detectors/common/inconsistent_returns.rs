@@ -0,0 +1,197 @@
+//! Inconsistent return detection
+
+use tree_sitter::Node;
+
+use crate::schema::{InconsistentReturn, Location};
+
+use super::stub_impls::{body_statements, STUB_BODY_KINDS};
+use super::{function_name, get_node_text, visit_all};
+
+/// Function node kinds in the dynamically-typed grammars (Python, JavaScript)
+pub(super) const RETURN_FUNCTION_KINDS: &[&str] = &[
+    "function_definition",
+    "function_declaration",
+    "function_expression",
+    "function",
+    "arrow_function",
+    "method_definition",
+];
+
+/// Nested scopes whose returns belong to someone else
+pub(super) const RETURN_SCOPE_KINDS: &[&str] = &[
+    "function_definition",
+    "function_declaration",
+    "function_expression",
+    "function",
+    "arrow_function",
+    "method_definition",
+    "generator_function",
+    "generator_function_declaration",
+    "lambda",
+    "class_definition",
+    "class_declaration",
+];
+
+/// Statements that leave the function
+const EXIT_STATEMENT_KINDS: &[&str] = &["return_statement", "raise_statement", "throw_statement"];
+
+/// Statements that may or may not exit on every path
+///
+/// Control is only assumed to fall off the end of a body after a simple
+/// statement, so `if x: return a` / `else: return b` is never flagged.
+const BRANCHING_STATEMENT_KINDS: &[&str] = &[
+    "if_statement",
+    "try_statement",
+    "with_statement",
+    "match_statement",
+    "switch_statement",
+    "for_statement",
+    "for_in_statement",
+    "while_statement",
+    "do_statement",
+    "labeled_statement",
+    "statement_block",
+    "block",
+];
+
+/// Find functions that return a value on some paths but not on others
+///
+/// Each return is classified as `value`, `none` (`None`, `null`,
+/// `undefined`), or `bare` (no expression); a body that ends in a simple
+/// statement also falls through (`implicit`). A function is reported when
+/// `value` is mixed with any of the others. Generators are skipped since
+/// a bare `return` is how they finish.
+pub fn find_inconsistent_returns(root: &Node, source: &str) -> Vec<InconsistentReturn> {
+    let mut found = Vec::new();
+
+    visit_all(root, |node| {
+        if !RETURN_FUNCTION_KINDS.contains(&node.kind()) {
+            return;
+        }
+        let Some(body) = node.child_by_field_name("body") else {
+            return;
+        };
+        if !STUB_BODY_KINDS.contains(&body.kind()) {
+            return;
+        }
+
+        let mut returns = Vec::new();
+        let mut is_generator = false;
+        collect_returns(&body, &mut returns, &mut is_generator);
+        if is_generator {
+            return;
+        }
+
+        let mut shapes: Vec<&str> = Vec::new();
+        for ret in &returns {
+            let shape = return_shape(ret, source);
+            if !shapes.contains(&shape) {
+                shapes.push(shape);
+            }
+        }
+        let last = body_statements(&body).pop();
+        let falls_through = last.is_some_and(|s| {
+            !EXIT_STATEMENT_KINDS.contains(&s.kind())
+                && !BRANCHING_STATEMENT_KINDS.contains(&s.kind())
+        });
+        if falls_through {
+            shapes.push("implicit");
+        }
+
+        if shapes.contains(&"value") && shapes.len() > 1 {
+            found.push(InconsistentReturn {
+                function: function_name(node, source),
+                shapes: shapes.into_iter().map(String::from).collect(),
+                location: Location::new(
+                    node.start_position().row + 1,
+                    node.start_position().column,
+                ),
+            });
+        }
+    });
+
+    found
+}
+
+/// Return statements of one function body, stopping at nested scopes
+fn collect_returns<'a>(node: &Node<'a>, returns: &mut Vec<Node<'a>>, is_generator: &mut bool) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            kind if RETURN_SCOPE_KINDS.contains(&kind) => {}
+            "return_statement" => returns.push(child),
+            "yield" | "yield_expression" => *is_generator = true,
+            _ => collect_returns(&child, returns, is_generator),
+        }
+    }
+}
+
+/// Shape of a single return statement
+fn return_shape(node: &Node, source: &str) -> &'static str {
+    let mut cursor = node.walk();
+    let value = node
+        .named_children(&mut cursor)
+        .find(|c| !c.kind().contains("comment"));
+    match value {
+        None => "bare",
+        Some(v) if matches!(v.kind(), "none" | "null" | "undefined") => "none",
+        Some(v) if get_node_text(&v, source) == "undefined" => "none",
+        Some(_) => "value",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::common::tests::parse_source;
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_inconsistent_returns() {
+        let source = r#"
+def find_user(users, name):
+    for user in users:
+        if user["name"] == name:
+            return {"id": user["id"], "name": name}
+    return None
+
+def lookup(table, key):
+    if key in table:
+        return table[key]
+    log("missing", key)
+
+def label(flag):
+    if flag:
+        return "on"
+    else:
+        return "off"
+
+def items(rows):
+    for row in rows:
+        yield row
+    return
+"#;
+        let tree = parse_source(source, Lang::Python);
+
+        let found: Vec<(String, Vec<String>, usize)> =
+            find_inconsistent_returns(&tree.root_node(), source)
+                .into_iter()
+                .map(|r| (r.function, r.shapes, r.location.line))
+                .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "find_user".to_string(),
+                    vec!["value".to_string(), "none".to_string()],
+                    2
+                ),
+                (
+                    "lookup".to_string(),
+                    vec!["value".to_string(), "implicit".to_string()],
+                    8
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! Framework lifecycle methods
+
+use tree_sitter::Node;
+
+use crate::schema::SemanticSummary;
+
+use super::{get_node_text, visit_all};
+
+/// Lifecycle methods a framework calls on subclasses of its base classes
+pub struct LifecycleFramework {
+    /// Base class names, matched against each identifier after the class name
+    pub base_classes: &'static [&'static str],
+    /// Method names with what the framework calls them for
+    pub methods: &'static [(&'static str, &'static str)],
+}
+
+/// Android `Activity`/`Fragment` callbacks (Java and Kotlin)
+pub const ANDROID_LIFECYCLE: LifecycleFramework = LifecycleFramework {
+    base_classes: &[
+        "Activity",
+        "AppCompatActivity",
+        "ComponentActivity",
+        "FragmentActivity",
+        "Fragment",
+    ],
+    methods: &[
+        ("onCreate", "creation"),
+        ("onCreateView", "view creation"),
+        ("onStart", "becoming visible"),
+        ("onResume", "gaining focus"),
+        ("onPause", "losing focus"),
+        ("onStop", "hidden"),
+        ("onDestroy", "teardown"),
+    ],
+};
+
+/// Class kinds whose bodies hold lifecycle methods
+const LIFECYCLE_CLASS_KINDS: &[&str] = &["class_declaration", "class"];
+
+/// Method kinds across JS, Java and Kotlin
+const LIFECYCLE_METHOD_KINDS: &[&str] = &[
+    "method_definition",
+    "method_declaration",
+    "function_declaration",
+];
+
+/// Note each lifecycle method of `framework` in `summary.insertions`
+///
+/// A class qualifies when one of `framework.base_classes` appears in its
+/// header after the name (`extends React.Component`, `: AppCompatActivity()`).
+/// Each insertion names the method and its class, e.g.
+/// `lifecycle: UserList.componentDidMount (after first render)`. Methods of
+/// nested classes belong to those classes.
+pub fn note_lifecycle_methods(
+    summary: &mut SemanticSummary,
+    root: &Node,
+    source: &str,
+    framework: &LifecycleFramework,
+) {
+    let mut notes: Vec<String> = Vec::new();
+
+    visit_all(root, |node| {
+        if !LIFECYCLE_CLASS_KINDS.contains(&node.kind()) {
+            return;
+        }
+        let Some(name) = node.child_by_field_name("name") else {
+            return;
+        };
+        let mut cursor = node.walk();
+        let Some(body) = node.child_by_field_name("body").or_else(|| {
+            node.named_children(&mut cursor)
+                .find(|c| c.kind() == "class_body")
+        }) else {
+            return;
+        };
+
+        let header = &source[name.end_byte()..body.start_byte()];
+        let extends_base = header
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .any(|word| framework.base_classes.contains(&word));
+        if !extends_base {
+            return;
+        }
+
+        let class_name = get_node_text(&name, source);
+        visit_all(&body, |member| {
+            if !LIFECYCLE_METHOD_KINDS.contains(&member.kind()) || !is_member_of(member, node) {
+                return;
+            }
+            let Some(method) = member.child_by_field_name("name") else {
+                return;
+            };
+            let method = get_node_text(&method, source);
+            if let Some((_, description)) = framework.methods.iter().find(|(m, _)| *m == method) {
+                notes.push(format!(
+                    "lifecycle: {}.{} ({})",
+                    class_name, method, description
+                ));
+            }
+        });
+    });
+
+    for note in notes {
+        if !summary.insertions.contains(&note) {
+            summary.insertions.push(note);
+        }
+    }
+}
+
+/// Whether the nearest enclosing class of `member` is `class`
+fn is_member_of(member: &Node, class: &Node) -> bool {
+    let mut current = member.parent();
+    while let Some(parent) = current {
+        if LIFECYCLE_CLASS_KINDS.contains(&parent.kind()) {
+            return parent.id() == class.id();
+        }
+        current = parent.parent();
+    }
+    false
+}
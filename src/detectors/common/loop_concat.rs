@@ -0,0 +1,181 @@
+//! String concatenation in loops
+
+use std::collections::HashSet;
+
+use tree_sitter::Node;
+
+use crate::schema::{Location, LoopStringConcat};
+
+use super::{first_named, get_node_text, has_child_kind, is_string_literal, visit_all};
+
+/// Loop node kinds across the supported grammars
+const LOOP_KINDS: &[&str] = &[
+    "for_statement",
+    "for_in_statement",
+    "enhanced_for_statement",
+    "foreach_statement",
+    "while_statement",
+    "do_statement",
+];
+
+/// Declared types that make a variable a string
+const STRING_TYPES: &[&str] = &["string", "String", "str"];
+
+/// Find strings built with `+=` (or `s = s + x`) inside a loop
+///
+/// A target counts as a string when the file assigns it a string literal or
+/// declares it with a string type, or when the appended value is itself a
+/// literal. Variables are tracked by name across the file, so this is a
+/// heuristic, not a type check.
+pub fn find_loop_string_concats(root: &Node, source: &str) -> Vec<LoopStringConcat> {
+    let mut string_vars: HashSet<String> = HashSet::new();
+    visit_all(root, |node| {
+        if let Some(name) = string_assignment_target(node, source) {
+            string_vars.insert(name);
+        }
+    });
+
+    let mut concats = Vec::new();
+    visit_all(root, |node| {
+        let Some((target, value)) = concat_assignment(node, source) else {
+            return;
+        };
+        let is_string = string_vars.contains(&target) || is_string_literal(&value);
+        if !is_string || !has_loop_ancestor(node) {
+            return;
+        }
+        concats.push(LoopStringConcat {
+            variable: target,
+            location: Location::new(node.start_position().row + 1, node.start_position().column),
+        });
+    });
+
+    concats
+}
+
+/// Name of a variable given a string value or a string type
+fn string_assignment_target(node: &Node, source: &str) -> Option<String> {
+    let (target, value) = match node.kind() {
+        "variable_declarator" => (
+            node.child_by_field_name("name")?,
+            node.child_by_field_name("value"),
+        ),
+        "assignment" | "assignment_expression" => (
+            node.child_by_field_name("left")?,
+            node.child_by_field_name("right"),
+        ),
+        "short_var_declaration" => (
+            first_named(node.child_by_field_name("left")?)?,
+            node.child_by_field_name("right").and_then(first_named),
+        ),
+        _ => return None,
+    };
+
+    // TS annotation or Python hint on the node, Java/C# type on the declaration
+    let declared_type = node.child_by_field_name("type").or_else(|| {
+        (node.kind() == "variable_declarator")
+            .then(|| node.parent()?.child_by_field_name("type"))
+            .flatten()
+    });
+    let has_string_type = declared_type.is_some_and(|ty| {
+        let text = get_node_text(&ty, source);
+        STRING_TYPES.contains(&text.trim_start_matches(':').trim())
+    });
+
+    (has_string_type || value.is_some_and(|v| is_string_literal(&v)))
+        .then(|| get_node_text(&target, source))
+}
+
+/// Target and appended value of `x += y` or `x = x + y`
+fn concat_assignment<'a>(node: &Node<'a>, source: &str) -> Option<(String, Node<'a>)> {
+    let (left, right) = match node.kind() {
+        "augmented_assignment_expression"
+        | "augmented_assignment"
+        | "assignment_expression"
+        | "assignment"
+        | "assignment_statement" => (
+            node.child_by_field_name("left")?,
+            node.child_by_field_name("right")?,
+        ),
+        _ => return None,
+    };
+    let (left, right) = if left.kind() == "expression_list" {
+        (first_named(left)?, first_named(right)?)
+    } else {
+        (left, right)
+    };
+    let target = get_node_text(&left, source);
+
+    if has_child_kind(node, "+=") {
+        return Some((target, right));
+    }
+    if has_child_kind(node, "=")
+        && matches!(right.kind(), "binary_expression" | "binary_operator")
+        && has_child_kind(&right, "+")
+    {
+        let operand = right.child_by_field_name("left")?;
+        if get_node_text(&operand, source) == target {
+            return Some((target, right.child_by_field_name("right")?));
+        }
+    }
+    None
+}
+
+fn has_loop_ancestor(node: &Node) -> bool {
+    let mut parent = node.parent();
+    while let Some(p) = parent {
+        if LOOP_KINDS.contains(&p.kind()) {
+            return true;
+        }
+        parent = p.parent();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::common::tests::parse_source;
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_loop_string_concats() {
+        let concats = |source: &str, lang: Lang| -> Vec<(String, usize)> {
+            let tree = parse_source(source, lang);
+            find_loop_string_concats(&tree.root_node(), source)
+                .into_iter()
+                .map(|c| (c.variable, c.location.line))
+                .collect()
+        };
+
+        let python = r#"
+def render(items):
+    result = ""
+    total = 0
+    for x in items:
+        result += x
+        total += x
+    return result, total
+"#;
+        assert_eq!(
+            concats(python, Lang::Python),
+            vec![("result".to_string(), 6)]
+        );
+
+        let js = r#"
+function render(items) {
+    let result = "";
+    let total = 0;
+    for (const x of items) {
+        result = result + x;
+        total += x;
+    }
+    return result + total;
+}
+"#;
+        assert_eq!(
+            concats(js, Lang::JavaScript),
+            vec![("result".to_string(), 6)]
+        );
+    }
+}
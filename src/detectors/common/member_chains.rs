@@ -0,0 +1,107 @@
+//! Long member chain detection
+
+use tree_sitter::Node;
+
+use crate::schema::{Location, MemberChain};
+
+use super::visit_all;
+
+/// Deepest member-access chain allowed before it is reported
+pub const MAX_MEMBER_CHAIN_DEPTH: usize = 4;
+
+/// Find member-access chains with more than `max_depth` segments
+///
+/// Covers JS/TS member expressions, Python attributes, and Java field
+/// accesses and method invocations. Calls inside a chain don't add a segment,
+/// so `a.b().c` and `a.b.c` are both depth 3. Each chain is reported once, at
+/// its outermost expression.
+pub fn find_long_member_chains(root: &Node, max_depth: usize) -> Vec<MemberChain> {
+    let mut chains = Vec::new();
+
+    visit_all(root, |node| {
+        if !is_member_access(node) && chain_object(node).is_none() {
+            return;
+        }
+        let continues_chain = node
+            .parent()
+            .and_then(|p| chain_object(&p))
+            .is_some_and(|object| object.id() == node.id());
+        if continues_chain {
+            return;
+        }
+
+        let depth = member_chain_depth(*node);
+        if depth > max_depth {
+            chains.push(MemberChain {
+                depth,
+                location: Location::new(
+                    node.start_position().row + 1,
+                    node.start_position().column,
+                ),
+            });
+        }
+    });
+
+    chains
+}
+
+/// Whether a node accesses a member of another expression
+fn is_member_access(node: &Node) -> bool {
+    match node.kind() {
+        "member_expression" | "attribute" | "field_access" => true,
+        "method_invocation" => node.child_by_field_name("object").is_some(),
+        _ => false,
+    }
+}
+
+/// The expression a chain link is applied to
+pub(super) fn chain_object<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    match node.kind() {
+        "member_expression" | "attribute" | "field_access" | "method_invocation" => {
+            node.child_by_field_name("object")
+        }
+        "call_expression" | "call" => node.child_by_field_name("function"),
+        _ => None,
+    }
+}
+
+/// Count the segments of the chain ending at `node`
+fn member_chain_depth(node: Node) -> usize {
+    let mut depth = 1;
+    let mut current = node;
+    while let Some(object) = chain_object(&current) {
+        if is_member_access(&current) {
+            depth += 1;
+        }
+        current = object;
+    }
+    depth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::common::tests::parse_source;
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_long_member_chains() {
+        let chains = |source: &str, lang: Lang| -> Vec<(usize, usize)> {
+            let tree = parse_source(source, lang);
+            find_long_member_chains(&tree.root_node(), MAX_MEMBER_CHAIN_DEPTH)
+                .iter()
+                .map(|c| (c.depth, c.location.line))
+                .collect()
+        };
+
+        assert_eq!(
+            chains("a.b.c.d.e();\na.b();\n", Lang::JavaScript),
+            vec![(5, 1)]
+        );
+        assert_eq!(chains("a.b.c.d.e()\na.b()\n", Lang::Python), vec![(5, 1)]);
+        assert_eq!(
+            chains("class A { void f() { a.b.c.d.e(); a.b(); } }\n", Lang::Java),
+            vec![(5, 1)]
+        );
+    }
+}
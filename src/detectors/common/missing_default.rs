@@ -0,0 +1,79 @@
+//! Missing default branch detection
+
+use tree_sitter::Node;
+
+use crate::schema::Location;
+
+use super::{get_node_text, has_child_kind, visit_all};
+
+/// Find `switch`/`match` constructs that have no default/catch-all arm
+///
+/// Covers JS/TS, Java and Go switches and Python `match` statements. Rust
+/// matches are exhaustiveness-checked by the compiler and are not inspected,
+/// nor are Java switch expressions used as values (also compiler-checked).
+pub fn find_missing_default_branches(root: &Node, source: &str) -> Vec<Location> {
+    let mut locations = Vec::new();
+
+    visit_all(root, |node| {
+        let has_default = match node.kind() {
+            // JavaScript/TypeScript
+            "switch_statement" => match node.child_by_field_name("body") {
+                Some(body) if body.kind() == "switch_body" => {
+                    has_child_kind(&body, "switch_default")
+                }
+                _ => return,
+            },
+            // Go
+            "expression_switch_statement" | "type_switch_statement" => {
+                has_child_kind(node, "default_case")
+            }
+            // Java (statement form only; switch expressions must be exhaustive)
+            "switch_expression" => {
+                let is_statement = node.parent().is_some_and(|p| {
+                    matches!(
+                        p.kind(),
+                        "block" | "expression_statement" | "switch_block_statement_group"
+                    )
+                });
+                let Some(body) = node.child_by_field_name("body").filter(|_| is_statement) else {
+                    return;
+                };
+                let mut cursor = body.walk();
+                let has_default = body.children(&mut cursor).any(|arm| {
+                    let mut arm_cursor = arm.walk();
+                    let is_default = arm.children(&mut arm_cursor).any(|label| {
+                        label.kind() == "switch_label"
+                            && get_node_text(&label, source).starts_with("default")
+                    });
+                    is_default
+                });
+                has_default
+            }
+            // Python
+            "match_statement" => {
+                let Some(body) = node.child_by_field_name("body") else {
+                    return;
+                };
+                let mut cursor = body.walk();
+                let has_wildcard = body.children(&mut cursor).any(|clause| {
+                    clause.kind() == "case_clause"
+                        && clause.child_by_field_name("guard").is_none()
+                        && clause
+                            .named_child(0)
+                            .is_some_and(|p| get_node_text(&p, source).trim() == "_")
+                });
+                has_wildcard
+            }
+            _ => return,
+        };
+
+        if !has_default {
+            locations.push(Location::new(
+                node.start_position().row + 1,
+                node.start_position().column,
+            ));
+        }
+    });
+
+    locations
+}
@@ -0,0 +1,100 @@
+//! Mixed async styles
+
+use tree_sitter::Node;
+
+use crate::schema::MixedAsyncStyle;
+
+use super::inconsistent_returns::{RETURN_FUNCTION_KINDS, RETURN_SCOPE_KINDS};
+use super::{call_callee, function_name, location_of, visit_all};
+
+/// Find functions that both `await` and chain `.then()` in their own body
+///
+/// Nested functions are their own scope, so an `async` callback passed to
+/// `.then()` does not count as mixing. Only the first `.then()` is reported.
+pub fn find_mixed_async_styles(root: &Node, source: &str) -> Vec<MixedAsyncStyle> {
+    let mut found = Vec::new();
+
+    visit_all(root, |node| {
+        if !RETURN_FUNCTION_KINDS.contains(&node.kind()) {
+            return;
+        }
+        let Some(body) = node.child_by_field_name("body") else {
+            return;
+        };
+
+        let mut awaits = false;
+        let mut then_line = None;
+        collect_async_styles(&body, source, &mut awaits, &mut then_line);
+        if let (true, Some(then_line)) = (awaits, then_line) {
+            found.push(MixedAsyncStyle {
+                function: function_name(node, source),
+                then_line,
+                location: location_of(node),
+            });
+        }
+    });
+
+    found
+}
+
+/// Whether a body awaits, and the line of its first `.then()` call
+fn collect_async_styles(
+    node: &Node,
+    source: &str,
+    awaits: &mut bool,
+    then_line: &mut Option<usize>,
+) {
+    match node.kind() {
+        kind if RETURN_SCOPE_KINDS.contains(&kind) => return,
+        "await_expression" => *awaits = true,
+        "call_expression" if then_line.is_none() => {
+            let is_then = call_callee(node, source)
+                .is_some_and(|(name, object)| name == "then" && object.is_some());
+            if is_then {
+                *then_line = Some(node.start_position().row + 1);
+            }
+        }
+        _ => {}
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_async_styles(&child, source, awaits, then_line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::common::tests::parse_source;
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_mixed_async_styles() {
+        let source = r#"
+async function loadProfile(id) {
+    const user = await fetchUser(id);
+    return fetchPosts(user.id).then((posts) => ({ user, posts }));
+}
+
+async function loadSettings(id) {
+    const user = await fetchUser(id);
+    const settings = await fetchSettings(user.id);
+    return settings;
+}
+
+function loadAll(ids) {
+    return Promise.all(ids.map(fetchUser)).then(async (users) => {
+        await cache(users);
+        return users;
+    });
+}
+"#;
+        let tree = parse_source(source, Lang::JavaScript);
+
+        let found: Vec<(String, usize, usize)> = find_mixed_async_styles(&tree.root_node(), source)
+            .into_iter()
+            .map(|m| (m.function, m.then_line, m.location.line))
+            .collect();
+        assert_eq!(found, vec![("loadProfile".to_string(), 4, 2)]);
+    }
+}
@@ -2,9 +2,74 @@
 //!
 //! This module provides helper functions for AST traversal, text extraction,
 //! and semantic information processing that are language-agnostic.
+//!
+//! Each cross-language detector lives in its own submodule next to its tests
+//! and is re-exported here, so callers keep importing from `common`.
+
+mod bare_rethrows;
+mod broad_catches;
+mod commented_code;
+mod doc_tags;
+mod expression_complexity;
+mod feature_envy;
+mod import_side_effects;
+mod inconsistent_returns;
+mod lifecycle;
+mod loop_concat;
+mod member_chains;
+mod missing_default;
+mod mixed_async;
+mod naming;
+mod nullable_access;
+mod overflow;
+mod overloads;
+mod platform_branches;
+mod positional_calls;
+mod recursion;
+mod repeated_blocks;
+mod resource_leaks;
+mod sensitive_logs;
+mod string_literals;
+mod stub_impls;
+mod test_assertions;
+mod type_params;
+mod unawaited_async;
+mod visitor_pattern;
 
 use tree_sitter::Node;
 
+use crate::schema::{Location, SymbolInfo};
+
+pub use bare_rethrows::find_bare_rethrows;
+pub use broad_catches::find_broad_catches;
+pub use commented_code::{find_commented_code, COMMENTED_CODE_MIN_LINES};
+pub use doc_tags::{attach_doc_tags, DocTags};
+pub use expression_complexity::expression_complexity;
+pub use feature_envy::{detect_feature_envy, FEATURE_ENVY_MIN_CALLS};
+pub use import_side_effects::find_import_side_effects;
+pub use inconsistent_returns::find_inconsistent_returns;
+pub use lifecycle::{note_lifecycle_methods, LifecycleFramework, ANDROID_LIFECYCLE};
+pub use loop_concat::find_loop_string_concats;
+pub use member_chains::{find_long_member_chains, MAX_MEMBER_CHAIN_DEPTH};
+pub use missing_default::find_missing_default_branches;
+pub use mixed_async::find_mixed_async_styles;
+pub use naming::check_naming_conventions;
+pub use nullable_access::find_unchecked_nullable_accesses;
+pub use overflow::find_overflow_risks;
+pub use overloads::mark_overloaded_symbols;
+pub use platform_branches::find_platform_branches;
+pub use positional_calls::{find_positional_calls, MIN_POSITIONAL_ARGS};
+pub use recursion::{count_nested_functions, mark_recursive_symbols};
+pub use repeated_blocks::{find_repeated_blocks, MIN_REPEATED_STATEMENTS};
+pub use resource_leaks::{find_resource_leaks, GO_RESOURCE_ACQUIRERS, PYTHON_RESOURCE_ACQUIRERS};
+pub use sensitive_logs::find_sensitive_logs;
+pub use string_literals::{find_string_literals, MIN_LITERAL_LEN};
+pub use stub_impls::mark_stub_implementations;
+pub use test_assertions::count_test_assertions;
+pub use type_params::extract_type_params;
+pub use unawaited_async::find_unawaited_async_calls;
+pub use visitor_pattern::detect_visitor_pattern;
+
 /// Maximum length for raw source fallback when extraction is incomplete
 pub const MAX_FALLBACK_LEN: usize = 1000;
 
@@ -34,21 +99,25 @@ pub use crate::utils::truncate_to_char_boundary;
 // AST Traversal
 // ============================================================================
 
-/// Visit all nodes in a tree with a visitor function (iterative to avoid stack overflow)
-pub fn visit_all<F>(node: &Node, mut visitor: F)
+/// Visit all nodes in pre-order, passing each node's depth below `node`
+///
+/// Iterative: the tree cursor serves as the explicit stack, so deeply nested
+/// trees (minified bundles, generated code) can't overflow the call stack.
+pub fn walk<F>(node: &Node, mut visitor: F)
 where
-    F: FnMut(&Node),
+    F: FnMut(&Node, usize),
 {
     let mut cursor = node.walk();
+    let mut depth = 0;
     let mut did_visit_children = false;
 
     loop {
         if !did_visit_children {
-            visitor(&cursor.node());
+            visitor(&cursor.node(), depth);
 
             // Try to go to first child
             if cursor.goto_first_child() {
-                did_visit_children = false;
+                depth += 1;
                 continue;
             }
         }
@@ -63,10 +132,19 @@ where
         if !cursor.goto_parent() {
             break; // Reached the root, we're done
         }
+        depth -= 1;
         did_visit_children = true;
     }
 }
 
+/// Visit all nodes in a tree with a visitor function (iterative to avoid stack overflow)
+pub fn visit_all<F>(node: &Node, mut visitor: F)
+where
+    F: FnMut(&Node),
+{
+    walk(node, |n, _| visitor(n));
+}
+
 /// Visit all nodes tracking control flow nesting depth (iterative)
 /// The visitor receives (node, nesting_depth) where nesting_depth increments
 /// inside control flow constructs (if, for, while, match, loop, try, switch)
@@ -267,8 +345,6 @@ pub fn infer_type_from_initializer(init: &str) -> String {
 // Symbol Line Range Utilities
 // ============================================================================
 
-use crate::schema::SymbolInfo;
-
 /// Find which symbol (by index) contains a given line number.
 /// Uses the symbol's start_line and end_line to determine containment.
 /// When multiple symbols contain the line (e.g., nested class/method), returns
@@ -300,9 +376,141 @@ pub fn find_containing_symbol_by_line(line: usize, symbols: &[SymbolInfo]) -> Op
     best_match.map(|(idx, _)| idx)
 }
 
+// ============================================================================
+// Node Helpers
+// ============================================================================
+
+/// Check whether a node has a direct child of the given kind
+fn has_child_kind(node: &Node, kind: &str) -> bool {
+    let mut cursor = node.walk();
+    let found = node.children(&mut cursor).any(|c| c.kind() == kind);
+    found
+}
+
+/// First named child of a node
+fn first_named(node: Node) -> Option<Node> {
+    node.named_child(0)
+}
+
+/// String literal node kinds across the supported grammars
+const STRING_LITERAL_KINDS: &[&str] = &[
+    "string",
+    "template_string",
+    "concatenated_string",
+    "string_literal",
+    "verbatim_string_literal",
+    "interpolated_string_expression",
+    "interpreted_string_literal",
+    "raw_string_literal",
+];
+
+/// Whether a node is a string literal in any supported grammar
+fn is_string_literal(node: &Node) -> bool {
+    STRING_LITERAL_KINDS.contains(&node.kind())
+}
+
+/// Declared name of a function, or the variable/property it is assigned to
+fn function_name(node: &Node, source: &str) -> String {
+    let name = node.child_by_field_name("name").or_else(|| {
+        let parent = node.parent()?;
+        match parent.kind() {
+            "variable_declarator" => parent.child_by_field_name("name"),
+            "assignment_expression" => parent.child_by_field_name("left"),
+            "pair" => parent.child_by_field_name("key"),
+            _ => None,
+        }
+    });
+    name.map(|n| get_node_text(&n, source))
+        .unwrap_or_else(|| "<anonymous>".to_string())
+}
+
+/// Location of a node's first character (1-indexed line)
+fn location_of(node: &Node) -> Location {
+    Location::new(node.start_position().row + 1, node.start_position().column)
+}
+
+/// Callee name and receiver of a call-like node, across grammars
+fn call_callee(node: &Node, source: &str) -> Option<(String, Option<String>)> {
+    let callee = match node.kind() {
+        "call_expression" | "call" => node.child_by_field_name("function")?,
+        "method_invocation" => {
+            let name = get_node_text(&node.child_by_field_name("name")?, source);
+            let object = node
+                .child_by_field_name("object")
+                .map(|o| get_node_text(&o, source));
+            return Some((name, object));
+        }
+        "macro_invocation" => {
+            let path = get_node_text(&node.child_by_field_name("macro")?, source);
+            let name = path.rsplit("::").next().unwrap_or(&path).to_string();
+            return Some((name, None));
+        }
+        _ => return None,
+    };
+
+    // Member access: obj.method / obj::method / obj.field
+    for (member_field, object_field) in [
+        ("property", "object"),
+        ("attribute", "object"),
+        ("field", "operand"),
+        ("field", "value"),
+        ("name", "path"),
+    ] {
+        if let Some(member) = callee.child_by_field_name(member_field) {
+            let object = callee
+                .child_by_field_name(object_field)
+                .map(|o| get_node_text(&o, source));
+            return Some((get_node_text(&member, source), object));
+        }
+    }
+
+    Some((get_node_text(&callee, source), None))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lang::Lang;
+
+    /// Parse `source` with `lang`'s grammar, shared by the detector tests
+    pub(super) fn parse_source(source: &str, lang: Lang) -> tree_sitter::Tree {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&lang.tree_sitter_language()).unwrap();
+        parser.parse(source, None).unwrap()
+    }
+
+    fn walk_recursive(node: &Node, depth: usize, out: &mut Vec<(String, usize)>) {
+        out.push((node.kind().to_string(), depth));
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            walk_recursive(&child, depth + 1, out);
+        }
+    }
+
+    #[test]
+    fn test_walk_matches_recursive_preorder() {
+        let source = "fn main() {\n    if x { for i in 0..3 { call(i); } } else { y(); }\n}\n";
+        let tree = parse_source(source, Lang::Rust);
+        let root = tree.root_node();
+
+        let mut expected = Vec::new();
+        walk_recursive(&root, 0, &mut expected);
+
+        let mut visited = Vec::new();
+        walk(&root, |node, depth| {
+            visited.push((node.kind().to_string(), depth))
+        });
+        assert_eq!(visited, expected);
+
+        // Walking a subtree stays within it and restarts depth at zero
+        let func = root.child(0).unwrap();
+        let mut sub = Vec::new();
+        walk(&func, |node, depth| {
+            sub.push((node.kind().to_string(), depth))
+        });
+        assert_eq!(sub[0], ("function_item".to_string(), 0));
+        assert_eq!(sub.len(), expected.len() - 1);
+    }
 
     #[test]
     fn test_truncate_to_char_boundary() {
@@ -0,0 +1,125 @@
+//! Naming convention checks
+
+use crate::lang::Lang;
+use crate::schema::{SymbolInfo, SymbolKind};
+
+/// Identifier casing styles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NamingStyle {
+    /// `parse_file`
+    Snake,
+    /// `parseFile`
+    Camel,
+    /// `ParseFile`
+    Pascal,
+    /// `MAX_RETRIES`
+    ScreamingSnake,
+}
+
+impl NamingStyle {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Snake => "snake_case",
+            Self::Camel => "camelCase",
+            Self::Pascal => "PascalCase",
+            Self::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+        }
+    }
+
+    fn matches(self, name: &str) -> bool {
+        let mut chars = name.chars();
+        let Some(first) = chars.next() else {
+            return true;
+        };
+        match self {
+            Self::Snake => {
+                first.is_ascii_lowercase()
+                    && name
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+            }
+            Self::Camel => first.is_ascii_lowercase() && chars.all(|c| c.is_ascii_alphanumeric()),
+            Self::Pascal => first.is_ascii_uppercase() && chars.all(|c| c.is_ascii_alphanumeric()),
+            Self::ScreamingSnake => {
+                first.is_ascii_uppercase()
+                    && name
+                        .chars()
+                        .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+            }
+        }
+    }
+}
+
+/// Accepted styles for a symbol kind in a language, or `None` if unchecked
+fn expected_naming_styles(lang: Lang, kind: SymbolKind) -> Option<&'static [NamingStyle]> {
+    use crate::lang::LangFamily;
+    use NamingStyle::*;
+
+    let styles: &'static [NamingStyle] = match (lang.family(), kind) {
+        (
+            LangFamily::Rust,
+            SymbolKind::Function | SymbolKind::Method | SymbolKind::Module | SymbolKind::Macro,
+        ) => &[Snake],
+        (LangFamily::Rust, SymbolKind::Variable) => &[ScreamingSnake],
+        (LangFamily::Python, SymbolKind::Function | SymbolKind::Method) => &[Snake],
+        // Constructor functions and factories are PascalCase by convention
+        (LangFamily::JavaScript, SymbolKind::Function) => &[Camel, Pascal],
+        (LangFamily::JavaScript | LangFamily::Java | LangFamily::Kotlin, SymbolKind::Method) => {
+            &[Camel]
+        }
+        (LangFamily::Java | LangFamily::Kotlin, SymbolKind::Function) => &[Camel],
+        // Go encodes visibility in the first letter
+        (LangFamily::Go, _) => &[Camel, Pascal],
+        (LangFamily::CSharp, _) => &[Pascal],
+        (
+            LangFamily::Rust
+            | LangFamily::Python
+            | LangFamily::JavaScript
+            | LangFamily::Java
+            | LangFamily::Kotlin,
+            SymbolKind::Class
+            | SymbolKind::Component
+            | SymbolKind::Interface
+            | SymbolKind::Trait
+            | SymbolKind::Struct
+            | SymbolKind::Enum
+            | SymbolKind::TypeAlias,
+        ) => &[Pascal],
+        _ => return None,
+    };
+    Some(styles)
+}
+
+/// Flag symbols whose names break the convention for their kind and language
+///
+/// Only the last path segment is checked (`Foo::bar`, `Foo.bar`), and
+/// leading/trailing underscores and `$` are ignored so private markers and
+/// Python dunders pass. Non-ASCII names and Go test functions are skipped.
+pub fn check_naming_conventions(symbols: &mut [SymbolInfo], lang: Lang) {
+    for symbol in symbols.iter_mut() {
+        let Some(styles) = expected_naming_styles(lang, symbol.kind) else {
+            continue;
+        };
+        let last = symbol
+            .name
+            .rsplit(|c| c == '.' || c == ':')
+            .next()
+            .unwrap_or(&symbol.name);
+        let name = last.trim_matches(|c| c == '_' || c == '$');
+        if name.is_empty() || !name.is_ascii() {
+            continue;
+        }
+        if lang.family() == crate::lang::LangFamily::Go
+            && ["Test", "Benchmark", "Example", "Fuzz"]
+                .iter()
+                .any(|prefix| name.starts_with(prefix))
+        {
+            continue;
+        }
+
+        if !styles.iter().any(|style| style.matches(name)) {
+            let expected: Vec<&str> = styles.iter().map(|style| style.label()).collect();
+            symbol.naming_violation = Some(format!("expected {}", expected.join(" or ")));
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Unchecked nullable access
+
+use std::collections::HashMap;
+
+use tree_sitter::Node;
+
+use crate::schema::UncheckedNullableAccess;
+
+use super::inconsistent_returns::RETURN_SCOPE_KINDS;
+use super::{call_callee, get_node_text, location_of};
+
+/// Methods that return null/undefined when nothing matches
+const NULLABLE_RETURNING_METHODS: &[&str] = &[
+    "find",
+    "findLast",
+    "get",
+    "querySelector",
+    "getElementById",
+    "closest",
+    "match",
+    "exec",
+];
+
+/// Binary operators whose operands are being checked rather than used
+const GUARD_OPERATORS: &[&str] = &["&&", "||", "??", "==", "!=", "===", "!=="];
+
+/// Find member accesses on variables assigned from a possibly-empty lookup
+///
+/// A variable is tracked from `const x = obj.find(...)` until the first
+/// access through `.` or `[]`, which is reported. Any guard-like use first
+/// (`if (x)`, `!x`, `x && ...`, `x === null`, `x ? ... : ...`) or a
+/// reassignment stops tracking, as does optional chaining or `x!` at the
+/// access itself. Each function body is scanned on its own.
+pub fn find_unchecked_nullable_accesses(root: &Node, source: &str) -> Vec<UncheckedNullableAccess> {
+    let mut found = Vec::new();
+    collect_nullable_accesses(root, source, &mut HashMap::new(), &mut found);
+    found
+}
+
+/// Walk one scope in source order; `tracked` maps variables to (call, line)
+fn collect_nullable_accesses(
+    node: &Node,
+    source: &str,
+    tracked: &mut HashMap<String, (String, usize)>,
+    found: &mut Vec<UncheckedNullableAccess>,
+) {
+    match node.kind() {
+        kind if RETURN_SCOPE_KINDS.contains(&kind) => {
+            let mut inner = HashMap::new();
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_nullable_accesses(&child, source, &mut inner, found);
+            }
+            return;
+        }
+        "variable_declarator" => {
+            let name = node.child_by_field_name("name");
+            let value = node.child_by_field_name("value");
+            if let (Some(name), Some(value)) = (name, value) {
+                // Scan the initializer first; it may use earlier variables
+                collect_nullable_accesses(&value, source, tracked, found);
+                let variable = get_node_text(&name, source);
+                tracked.remove(&variable);
+                if name.kind() == "identifier" {
+                    if let Some((call, Some(_))) = call_callee(&value, source) {
+                        if NULLABLE_RETURNING_METHODS.contains(&call.as_str()) {
+                            tracked.insert(variable, (call, value.start_position().row + 1));
+                        }
+                    }
+                }
+                return;
+            }
+        }
+        "member_expression" | "subscript_expression" => {
+            let object = node.child_by_field_name("object");
+            let optional = node.child_by_field_name("optional_chain").is_some();
+            if let Some(object) = object.filter(|o| o.kind() == "identifier") {
+                let variable = get_node_text(&object, source);
+                if let Some((call, assigned_line)) = tracked.remove(&variable) {
+                    if !optional {
+                        found.push(UncheckedNullableAccess {
+                            variable,
+                            call,
+                            assigned_line,
+                            location: location_of(node),
+                        });
+                    }
+                }
+            }
+        }
+        "identifier" if is_guard_position(node, source) => {
+            tracked.remove(&get_node_text(node, source));
+        }
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_nullable_accesses(&child, source, tracked, found);
+    }
+}
+
+/// Whether an identifier is being tested or overwritten rather than used
+fn is_guard_position(node: &Node, source: &str) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    match parent.kind() {
+        // `if (x)`, `while (x)`
+        "parenthesized_expression" => true,
+        // `x ? a : b`
+        "ternary_expression" => parent
+            .child_by_field_name("condition")
+            .is_some_and(|c| c.id() == node.id()),
+        // `!x`, `typeof x`
+        "unary_expression" => true,
+        // `x!` (TypeScript non-null assertion)
+        "non_null_expression" => true,
+        "binary_expression" => parent
+            .child_by_field_name("operator")
+            .is_some_and(|op| GUARD_OPERATORS.contains(&get_node_text(&op, source).as_str())),
+        // `x = other`
+        "assignment_expression" | "augmented_assignment_expression" => parent
+            .child_by_field_name("left")
+            .is_some_and(|l| l.id() == node.id()),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::common::tests::parse_source;
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_unchecked_nullable_accesses() {
+        let source = r#"
+function greet(users, id) {
+    const user = users.find((u) => u.id === id);
+    return "Hello, " + user.name;
+}
+
+function greetSafely(users, id) {
+    const user = users.find((u) => u.id === id);
+    if (!user) {
+        return "Hello, stranger";
+    }
+    return "Hello, " + user.name;
+}
+
+function title(cache, key) {
+    const entry = cache.get(key);
+    const label = entry && entry.label;
+    const button = document.querySelector(".save");
+    return label + button?.textContent;
+}
+"#;
+        let tree = parse_source(source, Lang::JavaScript);
+
+        let found: Vec<(String, String, usize, usize)> =
+            find_unchecked_nullable_accesses(&tree.root_node(), source)
+                .into_iter()
+                .map(|a| (a.variable, a.call, a.assigned_line, a.location.line))
+                .collect();
+        assert_eq!(found, vec![("user".to_string(), "find".to_string(), 3, 4)]);
+    }
+}
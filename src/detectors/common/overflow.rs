@@ -0,0 +1,160 @@
+//! Integer overflow risks
+
+use tree_sitter::Node;
+
+use crate::duplicate::tokenize_camel_snake;
+use crate::schema::{Location, OverflowRisk};
+
+use super::{first_named, get_node_text, get_node_text_normalized, is_string_literal, visit_all};
+
+/// Operators that can wrap an integer, as plain or compound assignment
+const OVERFLOW_OPERATORS: &[&str] = &["+", "*", "<<", "+=", "*=", "<<="];
+
+/// Nodes holding an operator between `left` and `right` (Rust and C)
+const ARITHMETIC_KINDS: &[&str] = &[
+    "binary_expression",
+    "compound_assignment_expr",
+    "assignment_expression",
+];
+
+/// Name words that mark an operand as a size, length, or index
+const SIZE_WORDS: &[&str] = &[
+    "size", "sizes", "len", "length", "count", "capacity", "cap", "idx", "index", "offset",
+    "width", "height", "stride", "bytes", "nbytes", "elems", "rows", "cols",
+];
+
+/// Items evaluated at compile time, where overflow is a build error
+const CONST_CONTEXT_KINDS: &[&str] = &["const_item", "static_item", "attribute_item"];
+
+/// Find unchecked `+`, `*`, and `<<` on sizes, lengths, and indices
+///
+/// Types aren't known, so only arithmetic with an operand named like a size
+/// (`item_size`, `buf.len()`, `sizeof(T)`) is flagged, which is where a wrap
+/// turns into an undersized allocation or an out-of-bounds index. Float
+/// literals and compile-time constants are skipped; a nested expression is
+/// reported once, at its outermost operator.
+pub fn find_overflow_risks(root: &Node, source: &str) -> Vec<OverflowRisk> {
+    let mut found = Vec::new();
+    visit_all(root, |node| {
+        if !is_overflow_arithmetic(node, source) || !involves_size(node, source) {
+            return;
+        }
+        // The enclosing operator reports the whole expression
+        let mut parent = node.parent();
+        while let Some(p) = parent.filter(|p| p.kind() == "parenthesized_expression") {
+            parent = p.parent();
+        }
+        if parent.is_some_and(|p| is_overflow_arithmetic(&p, source)) {
+            return;
+        }
+        let mut ancestor = node.parent();
+        while let Some(a) = ancestor {
+            if CONST_CONTEXT_KINDS.contains(&a.kind()) {
+                return;
+            }
+            ancestor = a.parent();
+        }
+
+        found.push(OverflowRisk {
+            expression: get_node_text_normalized(node, source),
+            location: Location::new(node.start_position().row + 1, node.start_position().column),
+        });
+    });
+    found
+}
+
+/// Whether a node applies one of the [`OVERFLOW_OPERATORS`] to non-float operands
+fn is_overflow_arithmetic(node: &Node, source: &str) -> bool {
+    if !ARITHMETIC_KINDS.contains(&node.kind()) {
+        return false;
+    }
+    let Some(operator) = node.child_by_field_name("operator") else {
+        return false;
+    };
+    if !OVERFLOW_OPERATORS.contains(&get_node_text(&operator, source).as_str()) {
+        return false;
+    }
+    let (Some(left), Some(right)) = (
+        node.child_by_field_name("left"),
+        node.child_by_field_name("right"),
+    ) else {
+        return false;
+    };
+    let is_float = |n: &Node| {
+        n.kind() == "float_literal"
+            || (n.kind() == "number_literal" && get_node_text(n, source).contains('.'))
+    };
+    !is_float(&left) && !is_float(&right) && !is_string_literal(&left) && !is_string_literal(&right)
+}
+
+/// Whether an arithmetic node has an operand named like a size, directly or
+/// in a nested arithmetic operand
+fn involves_size(node: &Node, source: &str) -> bool {
+    ["left", "right"].iter().any(|field| {
+        node.child_by_field_name(field)
+            .is_some_and(|operand| is_size_operand(&operand, source))
+    })
+}
+
+fn is_size_operand(node: &Node, source: &str) -> bool {
+    match node.kind() {
+        "sizeof_expression" => true,
+        "parenthesized_expression" => {
+            first_named(*node).is_some_and(|n| is_size_operand(&n, source))
+        }
+        _ if is_overflow_arithmetic(node, source) => involves_size(node, source),
+        "identifier" | "field_identifier" | "field_expression" | "scoped_identifier"
+        | "call_expression" => {
+            // `buf.len()` and `self.item_size` are named by their last segment
+            let text = get_node_text(node, source);
+            let name = text.trim_end_matches("()");
+            let last = name.rsplit(['.', ':', '>']).next().unwrap_or(name);
+            // SCREAMING_CASE would otherwise split into single letters
+            let last = if last.chars().any(char::is_lowercase) {
+                last.to_string()
+            } else {
+                last.to_lowercase()
+            };
+            tokenize_camel_snake(&last)
+                .iter()
+                .any(|w| SIZE_WORDS.contains(&w.as_str()))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::common::tests::parse_source;
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_overflow_risks() {
+        let source = r#"
+const MAX_BYTES: usize = 4 * 1024;
+
+fn alloc(item_size: usize, count: usize, a: u32, b: u32) -> Vec<u8> {
+    let n = item_size * count;
+    let sum = a + b;
+    let checked = item_size.checked_mul(count);
+    let scale = 0.5 * 2.0;
+    let end = (offset + buf.len()) * 2;
+    vec![0; n]
+}
+"#;
+        let tree = parse_source(source, Lang::Rust);
+
+        let risks: Vec<(String, usize)> = find_overflow_risks(&tree.root_node(), source)
+            .into_iter()
+            .map(|r| (r.expression, r.location.line))
+            .collect();
+        assert_eq!(
+            risks,
+            vec![
+                ("item_size * count".to_string(), 5),
+                ("(offset + buf.len()) * 2".to_string(), 9),
+            ]
+        );
+    }
+}
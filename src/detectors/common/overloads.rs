@@ -0,0 +1,26 @@
+//! Overload detection
+
+use std::collections::HashMap;
+
+use crate::schema::{SymbolInfo, SymbolKind};
+
+/// Flag symbols that share a name and kind with another symbol in the file
+///
+/// Covers language-level overloads as well as accidental redefinition. Flagged
+/// symbols get line-disambiguated hashes (see [`SymbolInfo::to_symbol_id`]), so
+/// call-graph edges don't collapse onto one of them. Escape locals are skipped
+/// since they are scoped to their enclosing function.
+pub fn mark_overloaded_symbols(symbols: &mut [SymbolInfo]) {
+    let mut counts: HashMap<(String, SymbolKind), usize> = HashMap::new();
+    for symbol in symbols.iter().filter(|s| !s.is_escape_local) {
+        *counts
+            .entry((symbol.name.clone(), symbol.kind))
+            .or_insert(0) += 1;
+    }
+
+    for symbol in symbols.iter_mut().filter(|s| !s.is_escape_local) {
+        if counts[&(symbol.name.clone(), symbol.kind)] > 1 {
+            symbol.overloaded = true;
+        }
+    }
+}
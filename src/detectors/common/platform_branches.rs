@@ -0,0 +1,240 @@
+//! Platform-specific code paths
+
+use tree_sitter::Node;
+
+use crate::schema::{Location, PlatformBranch};
+
+use super::{first_named, get_node_text, get_node_text_normalized, is_string_literal, visit_all};
+
+/// Expressions that name the running OS
+const PLATFORM_SOURCES: &[&str] = &[
+    "process.platform",
+    "os.platform()",
+    "sys.platform",
+    "os.name",
+    "platform.system()",
+    "runtime.GOOS",
+    "std::env::consts::OS",
+    "env::consts::OS",
+];
+
+/// Comparison nodes that can test a platform source against a string
+const PLATFORM_COMPARISON_KINDS: &[&str] = &["binary_expression", "comparison_operator"];
+
+/// Switch statements whose cases can be platform names
+const PLATFORM_SWITCH_KINDS: &[&str] = &["switch_statement", "expression_switch_statement"];
+
+/// Cases of a platform switch
+const PLATFORM_CASE_KINDS: &[&str] = &["switch_case", "expression_case"];
+
+/// Bare Rust `cfg` predicates that name a platform family
+const RUST_CFG_FAMILIES: &[&str] = &["windows", "unix"];
+
+/// Find checks that branch on the operating system
+///
+/// Covers Rust `#[cfg(...)]`/`cfg!(...)` predicates on `target_os`,
+/// `target_family`, `windows`, or `unix`, comparisons of `process.platform`,
+/// `sys.platform`, `os.name`, `runtime.GOOS`, or `env::consts::OS` against a
+/// string, `startswith`/`startsWith` prefix checks on them, and JS/Go
+/// switches over them (one entry per case). Platforms are recorded as written.
+pub fn find_platform_branches(root: &Node, source: &str) -> Vec<PlatformBranch> {
+    let mut found = Vec::new();
+    let mut push = |platform: String, node: &Node| {
+        found.push(PlatformBranch {
+            platform,
+            location: Location::new(node.start_position().row + 1, node.start_position().column),
+        });
+    };
+
+    visit_all(root, |node| {
+        let kind = node.kind();
+        if kind == "attribute_item" || kind == "inner_attribute_item" {
+            let text = get_node_text(node, source);
+            let attribute = text.trim_start_matches(['#', '!', '[']);
+            if let Some(predicate) = attribute.strip_prefix("cfg(") {
+                for platform in rust_cfg_platforms(predicate) {
+                    push(platform, node);
+                }
+            }
+        } else if kind == "macro_invocation" {
+            let is_cfg = node
+                .child_by_field_name("macro")
+                .is_some_and(|m| get_node_text(&m, source) == "cfg");
+            if is_cfg {
+                for platform in rust_cfg_platforms(&get_node_text(node, source)) {
+                    push(platform, node);
+                }
+            }
+        } else if PLATFORM_COMPARISON_KINDS.contains(&kind) {
+            let mut cursor = node.walk();
+            let operands: Vec<Node> = node.named_children(&mut cursor).collect();
+            if let [left, right] = operands.as_slice() {
+                let platform = if is_platform_source(left, source) {
+                    string_value(right, source)
+                } else if is_platform_source(right, source) {
+                    string_value(left, source)
+                } else {
+                    None
+                };
+                if let Some(platform) = platform {
+                    push(platform, node);
+                }
+            }
+        } else if kind == "call" || kind == "call_expression" {
+            let Some(function) = node.child_by_field_name("function") else {
+                return;
+            };
+            let (object, method) = match function.kind() {
+                "attribute" => ("object", "attribute"),
+                "member_expression" => ("object", "property"),
+                _ => return,
+            };
+            let is_prefix_check = function.child_by_field_name(method).is_some_and(|m| {
+                matches!(
+                    get_node_text(&m, source).as_str(),
+                    "startswith" | "startsWith"
+                )
+            });
+            let on_platform = function
+                .child_by_field_name(object)
+                .is_some_and(|o| is_platform_source(&o, source));
+            if is_prefix_check && on_platform {
+                let prefix = node
+                    .child_by_field_name("arguments")
+                    .and_then(|args| first_named(args))
+                    .and_then(|arg| string_value(&arg, source));
+                if let Some(prefix) = prefix {
+                    push(prefix, node);
+                }
+            }
+        } else if PLATFORM_SWITCH_KINDS.contains(&kind) {
+            let on_platform = node.child_by_field_name("value").is_some_and(|v| {
+                let v = if v.kind() == "parenthesized_expression" {
+                    first_named(v).unwrap_or(v)
+                } else {
+                    v
+                };
+                is_platform_source(&v, source)
+            });
+            if !on_platform {
+                return;
+            }
+            visit_all(node, |case| {
+                if !PLATFORM_CASE_KINDS.contains(&case.kind()) {
+                    return;
+                }
+                let Some(value) = case.child_by_field_name("value") else {
+                    return;
+                };
+                let values = if is_string_literal(&value) {
+                    vec![value]
+                } else {
+                    let mut cursor = value.walk();
+                    value.named_children(&mut cursor).collect()
+                };
+                for platform in values.iter().filter_map(|v| string_value(v, source)) {
+                    push(platform, case);
+                }
+            });
+        }
+    });
+
+    found
+}
+
+/// Whether a node is one of the [`PLATFORM_SOURCES`]
+fn is_platform_source(node: &Node, source: &str) -> bool {
+    PLATFORM_SOURCES.contains(&get_node_text_normalized(node, source).as_str())
+}
+
+/// Contents of a plain string literal, without quotes
+fn string_value(node: &Node, source: &str) -> Option<String> {
+    if !is_string_literal(node) {
+        return None;
+    }
+    let text = get_node_text(node, source);
+    let value = text.trim_matches(|c| c == '"' || c == '\'' || c == '`');
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// Platforms named in a Rust `cfg` predicate, in order and without repeats
+///
+/// Picks up `target_os = "..."` and `target_family = "..."` values and the
+/// bare `windows`/`unix` shorthands; other predicates (`test`, `feature`)
+/// are ignored.
+fn rust_cfg_platforms(predicate: &str) -> Vec<String> {
+    let mut platforms: Vec<String> = Vec::new();
+    let mut add = |platform: &str| {
+        if !platforms.iter().any(|p| p == platform) {
+            platforms.push(platform.to_string());
+        }
+    };
+
+    // Outside quotes: even segments; inside: odd segments
+    let segments: Vec<&str> = predicate.split('"').collect();
+    for (i, segment) in segments.iter().enumerate() {
+        if i % 2 == 1 {
+            let key = segments[i - 1].trim_end().trim_end_matches('=').trim_end();
+            if key.ends_with("target_os") || key.ends_with("target_family") {
+                add(segment);
+            }
+            continue;
+        }
+        for word in segment.split(|c: char| !c.is_alphanumeric() && c != '_') {
+            if RUST_CFG_FAMILIES.contains(&word) {
+                add(word);
+            }
+        }
+    }
+    platforms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::common::tests::parse_source;
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_platform_branches() {
+        let platforms = |source: &str, lang: Lang| -> Vec<(String, usize)> {
+            let tree = parse_source(source, lang);
+            find_platform_branches(&tree.root_node(), source)
+                .into_iter()
+                .map(|b| (b.platform, b.location.line))
+                .collect()
+        };
+
+        let rust = r#"
+#[cfg(windows)]
+fn config_dir() -> PathBuf {
+    PathBuf::from(env::var("APPDATA").unwrap())
+}
+
+#[cfg(test)]
+mod tests {}
+
+fn separator() -> char {
+    if cfg!(target_os = "macos") { ':' } else { ';' }
+}
+"#;
+        assert_eq!(
+            platforms(rust, Lang::Rust),
+            vec![("windows".to_string(), 2), ("macos".to_string(), 11)]
+        );
+
+        let js = r#"
+const isWindows = process.platform === 'win32';
+if (process.platform.startsWith('darwin')) {
+    openFinder();
+}
+if (mode === 'fast') {
+    run();
+}
+"#;
+        assert_eq!(
+            platforms(js, Lang::JavaScript),
+            vec![("win32".to_string(), 2), ("darwin".to_string(), 3)]
+        );
+    }
+}
@@ -0,0 +1,72 @@
+//! Positional argument calls
+
+use tree_sitter::Node;
+
+use crate::schema::{Location, PositionalCall};
+
+use super::{get_node_text, visit_all};
+
+/// Positional arguments a call needs before it is recorded
+pub const MIN_POSITIONAL_ARGS: usize = 4;
+
+/// Arguments that name what they pass or forward a collection
+///
+/// Python keywords and splats, and JS options objects and spreads.
+const NAMED_ARGUMENT_KINDS: &[&str] = &[
+    "keyword_argument",
+    "list_splat",
+    "dictionary_splat",
+    "object",
+    "spread_element",
+];
+
+/// Find calls passing at least `min_args` arguments, all by position
+///
+/// Only calls with a plain callee (`f(...)`, `obj.f(...)`) are recorded.
+/// A single keyword argument, splat, or object literal exempts the call,
+/// since the caller already names part of what it passes.
+pub fn find_positional_calls(root: &Node, source: &str, min_args: usize) -> Vec<PositionalCall> {
+    let mut found = Vec::new();
+
+    visit_all(root, |node| {
+        if !matches!(node.kind(), "call" | "call_expression") {
+            return;
+        }
+        let (Some(function), Some(arguments)) = (
+            node.child_by_field_name("function"),
+            node.child_by_field_name("arguments"),
+        ) else {
+            return;
+        };
+        let callee = match function.kind() {
+            "identifier" => function,
+            "attribute" => match function.child_by_field_name("attribute") {
+                Some(name) => name,
+                None => return,
+            },
+            "member_expression" => match function.child_by_field_name("property") {
+                Some(name) => name,
+                None => return,
+            },
+            _ => return,
+        };
+
+        let mut cursor = arguments.walk();
+        let args: Vec<Node> = arguments
+            .named_children(&mut cursor)
+            .filter(|a| a.kind() != "comment")
+            .collect();
+        let is_named = |arg: &Node| NAMED_ARGUMENT_KINDS.contains(&arg.kind());
+        if args.len() < min_args || args.iter().any(is_named) {
+            return;
+        }
+
+        found.push(PositionalCall {
+            callee: get_node_text(&callee, source),
+            args: args.len(),
+            location: Location::new(node.start_position().row + 1, node.start_position().column),
+        });
+    });
+
+    found
+}
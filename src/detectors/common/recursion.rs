@@ -0,0 +1,105 @@
+//! Recursion and nested function detection
+
+use std::collections::HashMap;
+
+use tree_sitter::Node;
+
+use crate::schema::{ControlFlowChange, SemanticSummary, SymbolInfo, SymbolKind};
+
+use super::{find_containing_symbol_by_line, visit_all};
+
+/// Call receivers that refer to the enclosing object (`self.walk()`, `this.walk()`)
+pub(super) const SELF_RECEIVERS: &[&str] = &["self", "Self", "this", "cls"];
+
+/// Mark function-like symbols that call themselves directly
+///
+/// Must run after calls have been attributed to symbols.
+pub fn mark_recursive_symbols(symbols: &mut [SymbolInfo]) {
+    for symbol in symbols.iter_mut() {
+        if !matches!(
+            symbol.kind,
+            SymbolKind::Function | SymbolKind::Method | SymbolKind::Component
+        ) {
+            continue;
+        }
+
+        symbol.is_recursive = symbol.calls.iter().any(|call| {
+            call.name == symbol.name
+                && !call.ref_kind.is_variable_ref()
+                && call
+                    .object
+                    .as_deref()
+                    .map_or(true, |obj| SELF_RECEIVERS.contains(&obj))
+        });
+    }
+}
+
+/// Count functions declared inside other functions and raise the nesting of their control flow
+///
+/// `function_kinds` lists every node kind that introduces a function body,
+/// both named declarations and anonymous closures/lambdas. A function node whose
+/// nearest function-like ancestor lies within a symbol is counted as nested in
+/// that symbol.
+///
+/// Following SonarSource, a nested function adds no increment of its own but
+/// raises the nesting level of every structure inside it, so each control flow
+/// change within a nested function gets one extra level per enclosing nested
+/// function. Must run after control flow has been extracted.
+pub fn count_nested_functions(root: &Node, summary: &mut SemanticSummary, function_kinds: &[&str]) {
+    let mut counts: HashMap<usize, usize> = HashMap::new();
+    // (start, end) positions of each nested function body, as (line, column)
+    let mut nested_ranges: Vec<((usize, usize), (usize, usize))> = Vec::new();
+
+    visit_all(root, |node| {
+        if !function_kinds.contains(&node.kind()) {
+            return;
+        }
+
+        let mut parent = node.parent();
+        while let Some(p) = parent {
+            if function_kinds.contains(&p.kind()) {
+                let line = p.start_position().row + 1;
+                if let Some(idx) = find_containing_symbol_by_line(line, &summary.symbols) {
+                    *counts.entry(idx).or_insert(0) += 1;
+                }
+                let (start, end) = (node.start_position(), node.end_position());
+                nested_ranges.push(((start.row + 1, start.column), (end.row + 1, end.column)));
+                break;
+            }
+            parent = p.parent();
+        }
+    });
+
+    for (idx, count) in counts {
+        summary.symbols[idx].nested_functions = count;
+    }
+
+    if nested_ranges.is_empty() {
+        return;
+    }
+
+    for symbol in summary.symbols.iter_mut() {
+        // A nested function that is itself the symbol doesn't nest its own body
+        let within_symbol: Vec<_> = nested_ranges
+            .iter()
+            .filter(|(start, end)| (start.0, end.0) != (symbol.start_line, symbol.end_line))
+            .copied()
+            .collect();
+        raise_nested_function_depth(&mut symbol.control_flow, &within_symbol);
+    }
+    raise_nested_function_depth(&mut summary.control_flow_changes, &nested_ranges);
+}
+
+/// Add one nesting level for every nested function range enclosing a control flow change
+fn raise_nested_function_depth(
+    control_flow: &mut [ControlFlowChange],
+    nested_ranges: &[((usize, usize), (usize, usize))],
+) {
+    for cf in control_flow {
+        let pos = (cf.location.line, cf.location.column);
+        cf.nesting_depth += nested_ranges
+            .iter()
+            .filter(|(start, end)| *start <= pos && pos < *end)
+            .count();
+    }
+}
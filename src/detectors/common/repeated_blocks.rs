@@ -0,0 +1,222 @@
+//! Repeated statement blocks
+
+use std::collections::HashMap;
+
+use tree_sitter::Node;
+
+use crate::schema::RepeatedBlock;
+
+use super::stub_impls::{STUB_BODY_KINDS, STUB_FUNCTION_KINDS};
+use super::{function_name, location_of, visit_all};
+
+/// Fewest statements a repeated run needs before it is reported
+pub const MIN_REPEATED_STATEMENTS: usize = 4;
+
+/// Find runs of statements that appear twice in the same block
+///
+/// Statements are compared by shape: the kinds of all their named nodes,
+/// so copies that differ only in names and literals still match. Each
+/// statement block inside a function is scanned on its own, longest runs
+/// first; a statement belongs to at most one reported pair. Runs where
+/// every statement has the same shape (five `log` calls in a row) are
+/// repetition, not copy-paste, and are skipped.
+///
+/// Candidates are seeded by hashing every window of
+/// [`MIN_REPEATED_STATEMENTS`] shapes: each window is paired with its next
+/// non-overlapping occurrence and extended as far as the copies agree, so
+/// a block of `n` statements yields at most `n` candidates.
+pub fn find_repeated_blocks(root: &Node, source: &str) -> Vec<RepeatedBlock> {
+    let mut found = Vec::new();
+    visit_all(root, |node| {
+        if !STUB_BODY_KINDS.contains(&node.kind()) {
+            return;
+        }
+        let mut function = node.parent();
+        while let Some(f) = function.filter(|f| !STUB_FUNCTION_KINDS.contains(&f.kind())) {
+            function = f.parent();
+        }
+        let Some(function) = function else {
+            return;
+        };
+
+        // tree-sitter-go wraps a block's statements in a `statement_list`
+        let mut cursor = node.walk();
+        let mut children: Vec<Node> = node.named_children(&mut cursor).collect();
+        if let [list] = children.as_slice() {
+            if list.kind() == "statement_list" {
+                let list = *list;
+                let mut cursor = list.walk();
+                children = list.named_children(&mut cursor).collect();
+            }
+        }
+        let statements: Vec<Node> = children
+            .into_iter()
+            .filter(|c| !c.kind().contains("comment"))
+            .collect();
+        let shapes = intern_shapes(&statements);
+
+        let n = shapes.len();
+        let mut used = vec![false; n];
+        for (len, i, j) in repeated_run_candidates(&shapes) {
+            let run = &shapes[i..i + len];
+            if used[i..i + len].iter().any(|u| *u)
+                || used[j..j + len].iter().any(|u| *u)
+                || run.iter().all(|s| *s == run[0])
+            {
+                continue;
+            }
+            used[i..i + len].fill(true);
+            used[j..j + len].fill(true);
+            found.push(RepeatedBlock {
+                function: function_name(&function, source),
+                statements: len,
+                first_start_line: statements[i].start_position().row + 1,
+                first_end_line: statements[i + len - 1].end_position().row + 1,
+                end_line: statements[j + len - 1].end_position().row + 1,
+                location: location_of(&statements[j]),
+            });
+        }
+    });
+    found.sort_by_key(|b| b.location.line);
+    found
+}
+
+/// Map each statement to a small id shared by every statement of the same shape
+fn intern_shapes(statements: &[Node]) -> Vec<u32> {
+    let mut ids: HashMap<String, u32> = HashMap::new();
+    statements
+        .iter()
+        .map(|s| {
+            let next = ids.len() as u32;
+            *ids.entry(statement_shape(s)).or_insert(next)
+        })
+        .collect()
+}
+
+/// Candidate `(len, first, repeat)` runs, longest first
+///
+/// Each window of [`MIN_REPEATED_STATEMENTS`] shapes is paired with the next
+/// occurrence of the same window that doesn't overlap it, then extended
+/// while the two copies keep matching and stay disjoint.
+fn repeated_run_candidates(shapes: &[u32]) -> Vec<(usize, usize, usize)> {
+    let n = shapes.len();
+    if n < 2 * MIN_REPEATED_STATEMENTS {
+        return Vec::new();
+    }
+
+    let mut windows: HashMap<&[u32], Vec<usize>> = HashMap::new();
+    for i in 0..=n - MIN_REPEATED_STATEMENTS {
+        windows
+            .entry(&shapes[i..i + MIN_REPEATED_STATEMENTS])
+            .or_default()
+            .push(i);
+    }
+
+    let mut candidates = Vec::new();
+    for starts in windows.values() {
+        for &i in starts {
+            // Starts are ascending, so the first one far enough along is the next copy
+            let next = starts.partition_point(|&j| j < i + MIN_REPEATED_STATEMENTS);
+            let Some(&j) = starts.get(next) else {
+                continue;
+            };
+            let mut len = MIN_REPEATED_STATEMENTS;
+            while i + len < j && j + len < n && shapes[i + len] == shapes[j + len] {
+                len += 1;
+            }
+            candidates.push((len, i, j));
+        }
+    }
+    candidates.sort_unstable_by_key(|&(len, i, _)| (std::cmp::Reverse(len), i));
+    candidates
+}
+
+/// Kinds of a statement's named nodes in pre-order, ignoring names and values
+fn statement_shape(node: &Node) -> String {
+    let mut kinds = Vec::new();
+    visit_all(node, |n| {
+        if n.is_named() && !n.kind().contains("comment") {
+            kinds.push(n.kind());
+        }
+    });
+    kinds.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::common::tests::parse_source;
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_repeated_blocks() {
+        let source = r#"
+function syncAll(api, db) {
+    const users = api.fetch("/users");
+    const activeUsers = users.filter(isActive);
+    db.save("users", activeUsers);
+    log.info("synced users", activeUsers.length);
+    metrics.increment("sync.users");
+
+    const orders = api.fetch("/orders");
+    const openOrders = orders.filter(isOpen);
+    db.save("orders", openOrders);
+    log.info("synced orders", openOrders.length);
+    metrics.increment("sync.orders");
+    return true;
+}
+
+function logAll(log) {
+    log.info("a");
+    log.info("b");
+    log.info("c");
+    log.info("d");
+    log.info("e");
+    log.info("f");
+    log.info("g");
+    log.info("h");
+}
+"#;
+        let tree = parse_source(source, Lang::JavaScript);
+
+        let found: Vec<(String, usize, usize, usize, usize, usize)> =
+            find_repeated_blocks(&tree.root_node(), source)
+                .into_iter()
+                .map(|b| {
+                    (
+                        b.function,
+                        b.statements,
+                        b.first_start_line,
+                        b.first_end_line,
+                        b.location.line,
+                        b.end_line,
+                    )
+                })
+                .collect();
+        assert_eq!(found, vec![("syncAll".to_string(), 5, 3, 7, 9, 13)]);
+    }
+
+    #[test]
+    fn test_repeated_blocks_large_body() {
+        use std::path::Path;
+
+        use crate::parsing::parse_and_extract;
+
+        // 1000 copies of a five-statement run: 5000 statements in one body
+        let copy = "    const a = load(i);\n    if (a) { save(a); }\n    total += a.size;\n    \
+                    log.info(\"loaded\", a);\n    count++;\n";
+        let source = format!(
+            "function bulk(load, save, log) {{\n{}}}\n",
+            copy.repeat(1000)
+        );
+
+        let summary = parse_and_extract(Path::new("bulk.js"), &source, Lang::JavaScript).unwrap();
+        let found = summary.repeated_blocks;
+        assert_eq!(found.len(), 500);
+        assert!(found
+            .iter()
+            .all(|b| b.function == "bulk" && b.statements == 5));
+        assert_eq!(found[0].first_start_line, 2);
+        assert_eq!(found[0].location.line, 7);
+    }
+}
@@ -0,0 +1,226 @@
+//! Resource leaks
+
+use tree_sitter::Node;
+
+use crate::schema::{Location, ResourceLeak};
+
+use super::{first_named, get_node_text, get_node_text_normalized, visit_all};
+
+/// Go calls returning something that must be closed
+pub const GO_RESOURCE_ACQUIRERS: &[&str] = &[
+    "os.Open",
+    "os.Create",
+    "os.OpenFile",
+    "sql.Open",
+    "net.Dial",
+    "net.Listen",
+    "http.Get",
+    "http.Post",
+];
+
+/// Python calls returning something that must be closed
+pub const PYTHON_RESOURCE_ACQUIRERS: &[&str] = &[
+    "open",
+    "socket.socket",
+    "sqlite3.connect",
+    "psycopg2.connect",
+    "urlopen",
+    "urllib.request.urlopen",
+];
+
+/// Nodes that bound the scope a resource must be released in
+const RESOURCE_SCOPE_KINDS: &[&str] = &[
+    "function_declaration",
+    "method_declaration",
+    "func_literal",
+    "function_definition",
+    "lambda",
+];
+
+/// Python `with` headers, whose context managers close what they open
+const CONTEXT_MANAGER_KINDS: &[&str] = &["with_clause", "with_item"];
+
+/// Find resources acquired by one of `acquirers` and never released
+///
+/// An acquisition is fine when it is a `with` item, when its variable is
+/// closed (`x.close()`, `defer x.Close()`, or `defer x.Body.Close()` for an
+/// HTTP response) or handed to a `with` anywhere in the enclosing function,
+/// or when it is returned to the caller. An unbound result, such as
+/// `open(path).read()`, is always a leak.
+pub fn find_resource_leaks(root: &Node, source: &str, acquirers: &[&str]) -> Vec<ResourceLeak> {
+    let mut found = Vec::new();
+    visit_all(root, |node| {
+        if node.kind() != "call" && node.kind() != "call_expression" {
+            return;
+        }
+        let Some(function) = node.child_by_field_name("function") else {
+            return;
+        };
+        let call = get_node_text_normalized(&function, source);
+        if !acquirers.contains(&call.as_str()) {
+            return;
+        }
+
+        let mut scope = *root;
+        let mut ancestor = node.parent();
+        while let Some(a) = ancestor {
+            if CONTEXT_MANAGER_KINDS.contains(&a.kind()) {
+                return;
+            }
+            if RESOURCE_SCOPE_KINDS.contains(&a.kind()) {
+                scope = a;
+                break;
+            }
+            ancestor = a.parent();
+        }
+
+        let mut parent = node.parent();
+        while let Some(p) = parent.filter(|p| p.kind() == "expression_list") {
+            parent = p.parent();
+        }
+        let variable = match parent {
+            // Ownership moves to the caller
+            Some(p) if p.kind() == "return_statement" => return,
+            Some(p) if is_binding(&p) => p
+                .child_by_field_name("left")
+                .and_then(|left| {
+                    if left.kind() == "identifier" {
+                        Some(left)
+                    } else {
+                        first_named(left)
+                    }
+                })
+                .map(|name| get_node_text(&name, source))
+                .filter(|name| *name != "_"),
+            _ => None,
+        };
+        if let Some(ref name) = variable {
+            if is_released(&scope, source, name) {
+                return;
+            }
+        }
+
+        found.push(ResourceLeak {
+            call,
+            variable,
+            location: Location::new(node.start_position().row + 1, node.start_position().column),
+        });
+    });
+    found
+}
+
+fn is_binding(node: &Node) -> bool {
+    matches!(
+        node.kind(),
+        "short_var_declaration" | "assignment_statement" | "assignment"
+    )
+}
+
+/// Whether `variable` is closed, used as a context manager, or returned in `scope`
+fn is_released(scope: &Node, source: &str, variable: &str) -> bool {
+    let text = get_node_text(scope, source);
+    let closed = [".Close(", ".close(", ".Body.Close("]
+        .iter()
+        .any(|release| contains_name(&text, &format!("{}{}", variable, release)));
+    if closed {
+        return true;
+    }
+
+    let mut released = false;
+    visit_all(scope, |node| {
+        let handed_off = match node.kind() {
+            "return_statement" => true,
+            kind => CONTEXT_MANAGER_KINDS.contains(&kind),
+        };
+        if !handed_off || released {
+            return;
+        }
+        // Only a bare `return f` / `with f:` hands the resource off, not `return read(f)`
+        let mut pending = vec![*node];
+        while let Some(current) = pending.pop() {
+            let mut cursor = current.walk();
+            for child in current.named_children(&mut cursor) {
+                match child.kind() {
+                    "identifier" if get_node_text(&child, source) == variable => released = true,
+                    "expression_list" | "pattern_list" | "tuple" | "with_item" => {
+                        pending.push(child)
+                    }
+                    _ => {}
+                }
+            }
+        }
+    });
+    released
+}
+
+/// Whether `text` contains `pattern` where it doesn't continue a longer name,
+/// so `f.Close()` isn't found in `buf.Close()`
+fn contains_name(text: &str, pattern: &str) -> bool {
+    text.match_indices(pattern).any(|(i, _)| {
+        text[..i]
+            .chars()
+            .next_back()
+            .map_or(true, |c| !c.is_alphanumeric() && c != '_' && c != '.')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::common::tests::parse_source;
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_resource_leaks() {
+        let leaks = |source: &str, lang: Lang, acquirers: &[&str]| -> Vec<(String, usize)> {
+            let tree = parse_source(source, lang);
+            find_resource_leaks(&tree.root_node(), source, acquirers)
+                .into_iter()
+                .map(|l| (l.variable.unwrap_or_default(), l.location.line))
+                .collect()
+        };
+
+        let go = r#"
+package config
+
+func readLeaky(path string) ([]byte, error) {
+	f, err := os.Open(path)
+	if err != nil {
+		return nil, err
+	}
+	return io.ReadAll(f)
+}
+
+func readClosed(path string) ([]byte, error) {
+	f, err := os.Open(path)
+	if err != nil {
+		return nil, err
+	}
+	defer f.Close()
+	return io.ReadAll(f)
+}
+
+func openLog(path string) (*os.File, error) {
+	f, err := os.Create(path)
+	return f, err
+}
+"#;
+        assert_eq!(
+            leaks(go, Lang::Go, GO_RESOURCE_ACQUIRERS),
+            vec![("f".to_string(), 5)]
+        );
+
+        let python = r#"
+def load(path):
+    with open(path) as fh:
+        return fh.read()
+
+def peek(path):
+    return open(path).readline()
+"#;
+        assert_eq!(
+            leaks(python, Lang::Python, PYTHON_RESOURCE_ACQUIRERS),
+            vec![(String::new(), 7)]
+        );
+    }
+}
@@ -0,0 +1,146 @@
+//! Sensitive data in logs
+
+use std::collections::HashSet;
+
+use tree_sitter::Node;
+
+use crate::duplicate::tokenize_camel_snake;
+use crate::schema::SensitiveLog;
+
+use super::{call_callee, get_node_text, location_of, visit_all};
+
+/// Receivers whose methods write to a log (`console.log`, `self.logger.info`)
+const LOGGER_OBJECTS: &[&str] = &[
+    "console", "logger", "log", "logging", "winston", "pino", "slog",
+];
+
+/// Names, or adjacent name words joined, that hold credentials or personal data
+const SENSITIVE_NAMES: &[&str] = &[
+    "password",
+    "passwd",
+    "pwd",
+    "secret",
+    "token",
+    "apikey",
+    "accesskey",
+    "privatekey",
+    "credentials",
+    "ssn",
+    "creditcard",
+    "cardnumber",
+];
+
+/// Find logging calls that pass a value named like a secret
+///
+/// A call counts as logging when its receiver is a known logger object,
+/// compared by its last segment so `this.logger.warn` qualifies. Every
+/// identifier and property in the arguments is checked word by word, so
+/// `apiKey` and `user.password` are flagged while `tokenizer` is not. Names
+/// of functions called inside the arguments are skipped. Each name is
+/// reported once per call.
+pub fn find_sensitive_logs(root: &Node, source: &str) -> Vec<SensitiveLog> {
+    let mut found = Vec::new();
+    visit_all(root, |node| {
+        let Some((method, Some(object))) = call_callee(node, source) else {
+            return;
+        };
+        let receiver = object.rsplit('.').next().unwrap_or(&object).to_lowercase();
+        if !LOGGER_OBJECTS.contains(&receiver.as_str()) {
+            return;
+        }
+        let Some(arguments) = node.child_by_field_name("arguments") else {
+            return;
+        };
+        let logger = format!("{}.{}", object, method);
+        let mut names: HashSet<String> = HashSet::new();
+        visit_all(&arguments, |inner| {
+            if !inner.kind().ends_with("identifier")
+                || inner.kind() == "type_identifier"
+                || is_callee_name(inner)
+            {
+                return;
+            }
+            let name = get_node_text(inner, source);
+            if is_sensitive_name(&name) && names.insert(name.clone()) {
+                found.push(SensitiveLog {
+                    logger: logger.clone(),
+                    name,
+                    location: location_of(inner),
+                });
+            }
+        });
+    });
+    found
+}
+
+/// Whether a name, split into camelCase/snake_case words, names a secret
+fn is_sensitive_name(name: &str) -> bool {
+    let words = tokenize_camel_snake(name);
+    let is_sensitive = |word: &str| SENSITIVE_NAMES.contains(&word);
+    // `SSN` splits into single letters; `apiKey` into two words
+    is_sensitive(&words.concat())
+        || words.iter().any(|w| is_sensitive(w))
+        || words.windows(2).any(|pair| is_sensitive(&pair.concat()))
+}
+
+/// Whether a name node is the function (or method) being called
+fn is_callee_name(node: &Node) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    let is_function_of = |call: &Node, callee: &Node| {
+        let field = match call.kind() {
+            "call_expression" | "call" => "function",
+            "method_invocation" => "name",
+            _ => return false,
+        };
+        call.child_by_field_name(field)
+            .is_some_and(|f| f.id() == callee.id())
+    };
+    if is_function_of(&parent, node) {
+        return true;
+    }
+    // `auth.getToken()`: the property of the member expression being called
+    let is_member_name = ["property", "attribute", "field"].iter().any(|field| {
+        parent
+            .child_by_field_name(field)
+            .is_some_and(|f| f.id() == node.id())
+    });
+    is_member_name
+        && parent
+            .parent()
+            .is_some_and(|call| is_function_of(&call, &parent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detectors::common::tests::parse_source;
+    use crate::lang::Lang;
+
+    #[test]
+    fn test_sensitive_logs() {
+        let source = r#"
+function login(user, auth) {
+    console.log(user.password);
+    console.log(user.name);
+    this.logger.info(`issued ${apiKey} for ${user.name}`);
+    console.log(auth.getToken(), tokenizer);
+    console.log("password reset requested");
+}
+"#;
+        let tree = parse_source(source, Lang::JavaScript);
+
+        let found: Vec<(String, String, usize)> = find_sensitive_logs(&tree.root_node(), source)
+            .into_iter()
+            .map(|s| (s.logger, s.name, s.location.line))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("console.log".to_string(), "password".to_string(), 3),
+                ("this.logger.info".to_string(), "apiKey".to_string(), 5),
+            ]
+        );
+    }
+}
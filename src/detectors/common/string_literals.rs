@@ -0,0 +1,64 @@
+//! String literals worth naming as constants
+
+use tree_sitter::Node;
+
+use crate::schema::StringLiteral;
+
+use super::{get_node_text, location_of, visit_all};
+
+/// Literals shorter than this (in characters) are too trivial to name
+pub const MIN_LITERAL_LEN: usize = 4;
+
+/// Plain string literal node kinds across grammars
+const PLAIN_STRING_KINDS: &[&str] = &[
+    "string",
+    "string_literal",
+    "interpreted_string_literal",
+    "raw_string_literal",
+];
+
+/// Parents whose strings are module paths or docstrings, not values
+const NON_VALUE_STRING_PARENTS: &[&str] = &[
+    "import_statement",
+    "import_from_statement",
+    "export_statement",
+    "import_spec",
+    "preproc_include",
+    "expression_statement",
+];
+
+/// Find string literals of at least [`MIN_LITERAL_LEN`] characters
+///
+/// Import paths and docstrings (or a JS `"use strict"`) are skipped, as are
+/// interpolated strings since their text isn't a fixed value.
+pub fn find_string_literals(root: &Node, source: &str) -> Vec<StringLiteral> {
+    let mut found = Vec::new();
+    visit_all(root, |node| {
+        if !PLAIN_STRING_KINDS.contains(&node.kind()) {
+            return;
+        }
+        let is_value = node
+            .parent()
+            .map_or(true, |p| !NON_VALUE_STRING_PARENTS.contains(&p.kind()));
+        let mut cursor = node.walk();
+        let is_interpolated = node
+            .named_children(&mut cursor)
+            .any(|c| c.kind() == "interpolation");
+        if !is_value || is_interpolated {
+            return;
+        }
+
+        let text = get_node_text(node, source);
+        let value = text
+            .trim_start_matches(|c: char| c.is_ascii_alphabetic() || c == '@' || c == '#')
+            .trim_end_matches('#')
+            .trim_matches(|c| c == '"' || c == '\'' || c == '`');
+        if value.trim().chars().count() >= MIN_LITERAL_LEN {
+            found.push(StringLiteral {
+                value: value.to_string(),
+                location: location_of(node),
+            });
+        }
+    });
+    found
+}
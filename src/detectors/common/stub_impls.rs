@@ -0,0 +1,118 @@
+//! Stub implementation detection
+
+use tree_sitter::Node;
+
+use crate::schema::{SymbolInfo, SymbolKind};
+
+use super::{find_containing_symbol_by_line, get_node_text, visit_all};
+
+/// Function-like nodes whose `body` field holds the implementation
+pub(super) const STUB_FUNCTION_KINDS: &[&str] = &[
+    "function_item",
+    "function_definition",
+    "function_declaration",
+    "generator_function_declaration",
+    "function_expression",
+    "arrow_function",
+    "method_definition",
+    "method_declaration",
+    "constructor_declaration",
+];
+
+/// Body node kinds that are statement blocks (not expression bodies)
+pub(super) const STUB_BODY_KINDS: &[&str] = &[
+    "block",
+    "statement_block",
+    "constructor_body",
+    "compound_statement",
+];
+
+/// Mark functions whose body is empty or only a not-implemented marker
+///
+/// Comments and docstrings are ignored, so a docstring followed by `pass`
+/// is still a stub. Type-stub declarations (`is_stub`) are skipped since
+/// they never have bodies.
+pub fn mark_stub_implementations(root: &Node, source: &str, symbols: &mut [SymbolInfo]) {
+    let mut stubs = Vec::new();
+
+    visit_all(root, |node| {
+        if !STUB_FUNCTION_KINDS.contains(&node.kind()) {
+            return;
+        }
+        let Some(body) = node.child_by_field_name("body") else {
+            return;
+        };
+        if !STUB_BODY_KINDS.contains(&body.kind()) {
+            return;
+        }
+
+        let mut statements = body_statements(&body);
+        if statements.len() == 1 && statements[0].kind() == "statement_list" {
+            statements = body_statements(&statements[0]);
+        }
+        let is_stub = match statements.as_slice() {
+            [] => true,
+            [only] => is_not_implemented_marker(only, source),
+            _ => false,
+        };
+        if !is_stub {
+            return;
+        }
+
+        // Attribute to the symbol this function defines, not one enclosing it
+        let line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+        if let Some(idx) = find_containing_symbol_by_line(line, symbols) {
+            if symbols[idx].end_line == end_line {
+                stubs.push(idx);
+            }
+        }
+    });
+
+    for idx in stubs {
+        let symbol = &mut symbols[idx];
+        if matches!(symbol.kind, SymbolKind::Function | SymbolKind::Method) && !symbol.is_stub {
+            symbol.is_stub_impl = true;
+        }
+    }
+}
+
+/// Statements of a body, minus comments and docstrings
+pub(super) fn body_statements<'a>(body: &Node<'a>) -> Vec<Node<'a>> {
+    let mut cursor = body.walk();
+    let statements = body
+        .named_children(&mut cursor)
+        .filter(|s| !s.kind().contains("comment"))
+        .filter(|s| {
+            !(s.kind() == "expression_statement"
+                && s.named_child_count() == 1
+                && s.named_child(0).is_some_and(|c| c.kind() == "string"))
+        })
+        .collect();
+    statements
+}
+
+/// Whether a lone statement only marks the function as unimplemented
+fn is_not_implemented_marker(node: &Node, source: &str) -> bool {
+    let text = get_node_text(node, source);
+    let compact: String = text
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '_')
+        .collect::<String>()
+        .to_lowercase();
+
+    match node.kind() {
+        "pass_statement" | "ellipsis" => true,
+        "expression_statement" => node
+            .named_child(0)
+            .is_some_and(|inner| is_not_implemented_marker(&inner, source)),
+        "macro_invocation" => text.starts_with("todo!") || text.starts_with("unimplemented!"),
+        "throw_statement" | "throw_expression" | "raise_statement" => {
+            compact.contains("notimplemented")
+        }
+        "call_expression" | "call" => {
+            compact.starts_with("panic(") && compact.contains("notimplemented")
+        }
+        _ => false,
+    }
+}
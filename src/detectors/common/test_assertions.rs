@@ -0,0 +1,58 @@
+//! Test assertion counting
+
+use std::collections::HashMap;
+
+use tree_sitter::Node;
+
+use crate::schema::{FrameworkEntryPoint, SymbolInfo};
+
+use super::{call_callee, find_containing_symbol_by_line, visit_all};
+
+/// Objects whose methods are all assertions (`assert.Equal(t, ...)`, `require.NoError(...)`)
+const ASSERTION_OBJECTS: &[&str] = &["assert", "require"];
+
+/// Whether a callee name is an assertion (`expect`, `assert!`, `assertEquals`, `assert_eq!`)
+fn is_assertion_name(name: &str) -> bool {
+    name == "expect" || name.starts_with("assert")
+}
+
+/// Count assertions inside each test symbol and record them on the symbol
+///
+/// Only symbols flagged as [`FrameworkEntryPoint::TestFunction`] are counted;
+/// each receives `Some(n)`, so a test with no assertions is distinguishable
+/// from a non-test symbol. Must run after test functions have been marked.
+pub fn count_test_assertions(root: &Node, source: &str, symbols: &mut [SymbolInfo]) {
+    let is_test = |s: &SymbolInfo| s.framework_entry_point == FrameworkEntryPoint::TestFunction;
+    if !symbols.iter().any(is_test) {
+        return;
+    }
+
+    let mut counts: HashMap<usize, usize> = HashMap::new();
+
+    visit_all(root, |node| {
+        let is_assertion = if node.kind() == "assert_statement" {
+            true
+        } else if let Some((name, object)) = call_callee(node, source) {
+            is_assertion_name(&name)
+                || object.is_some_and(|o| ASSERTION_OBJECTS.contains(&o.as_str()))
+        } else {
+            false
+        };
+        if !is_assertion {
+            return;
+        }
+
+        let line = node.start_position().row + 1;
+        if let Some(idx) = find_containing_symbol_by_line(line, symbols) {
+            if is_test(&symbols[idx]) {
+                *counts.entry(idx).or_insert(0) += 1;
+            }
+        }
+    });
+
+    for (idx, symbol) in symbols.iter_mut().enumerate() {
+        if is_test(symbol) {
+            symbol.assertion_count = Some(counts.get(&idx).copied().unwrap_or(0));
+        }
+    }
+}
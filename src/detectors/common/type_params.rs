@@ -0,0 +1,44 @@
+//! Type parameter extraction
+
+use tree_sitter::Node;
+
+use crate::schema::SymbolInfo;
+
+use super::{get_node_text, get_node_text_normalized, visit_all};
+
+/// Children of a type parameter list that are not parameters
+const NON_TYPE_PARAM_KINDS: &[&str] =
+    &["comment", "line_comment", "block_comment", "attribute_item"];
+
+/// Fill in each symbol's generic type parameters
+///
+/// Covers declarations with a `type_parameters` field (Rust, TypeScript,
+/// Java, C#, Go). Each parameter keeps its bound or constraint as written
+/// (`T: Clone`, `T extends Base`); C# `where` clauses are not part of the
+/// list, so only the names are recorded there.
+pub fn extract_type_params(root: &Node, source: &str, symbols: &mut [SymbolInfo]) {
+    visit_all(root, |node| {
+        let Some(params) = node.child_by_field_name("type_parameters") else {
+            return;
+        };
+        let Some(name) = node.child_by_field_name("name") else {
+            return;
+        };
+        let name = get_node_text(&name, source);
+        let line = node.start_position().row + 1;
+        let end_line = node.end_position().row + 1;
+        let Some(symbol) = symbols
+            .iter_mut()
+            .find(|s| s.name == name && s.end_line == end_line && s.start_line <= line)
+        else {
+            return;
+        };
+
+        let mut cursor = params.walk();
+        symbol.type_params = params
+            .named_children(&mut cursor)
+            .filter(|p| !NON_TYPE_PARAM_KINDS.contains(&p.kind()))
+            .map(|p| get_node_text_normalized(&p, source))
+            .collect();
+    });
+}
@@ -0,0 +1,56 @@
+//! Unawaited async calls
+
+use std::collections::HashSet;
+
+use tree_sitter::Node;
+
+use crate::schema::{Location, SymbolInfo, UnawaitedCall};
+
+use super::recursion::SELF_RECEIVERS;
+use super::{call_callee, visit_all};
+
+/// Find calls to same-file async functions whose promise/future is discarded
+///
+/// A call is flagged only when it forms a whole expression statement, so
+/// awaited, assigned, returned, and chained (`.then(...)`) calls are ignored.
+/// Only plain and `self`/`this` calls are matched against the async symbols.
+pub fn find_unawaited_async_calls(
+    root: &Node,
+    source: &str,
+    symbols: &[SymbolInfo],
+) -> Vec<UnawaitedCall> {
+    let async_names: HashSet<&str> = symbols
+        .iter()
+        .filter(|s| s.is_async)
+        .map(|s| s.name.as_str())
+        .collect();
+    if async_names.is_empty() {
+        return Vec::new();
+    }
+
+    let mut unawaited = Vec::new();
+    visit_all(root, |node| {
+        let is_statement = node
+            .parent()
+            .is_some_and(|p| p.kind() == "expression_statement");
+        if !is_statement || node.kind() == "macro_invocation" {
+            return;
+        }
+        let Some((name, object)) = call_callee(node, source) else {
+            return;
+        };
+        let is_local_call = object
+            .as_deref()
+            .map_or(true, |o| SELF_RECEIVERS.contains(&o));
+        if is_local_call && async_names.contains(name.as_str()) {
+            unawaited.push(UnawaitedCall {
+                name,
+                location: Location::new(
+                    node.start_position().row + 1,
+                    node.start_position().column,
+                ),
+            });
+        }
+    });
+    unawaited
+}
@@ -0,0 +1,115 @@
+//! Visitor pattern detection
+
+use std::collections::{HashMap, HashSet};
+
+use crate::schema::{SymbolInfo, SymbolKind};
+
+use super::recursion::SELF_RECEIVERS;
+
+/// Whether a method name reads as a visitor callback (`visit`, `visitCircle`,
+/// `visit_circle`)
+fn is_visit_name(name: &str) -> bool {
+    name.strip_prefix("visit").is_some_and(|rest| {
+        rest.is_empty()
+            || rest.starts_with('_')
+            || rest.starts_with(|c: char| c.is_ascii_uppercase())
+    })
+}
+
+/// Record visitor pattern participants in `SymbolInfo.patterns`
+///
+/// An `accept` method that calls `visit*` on another object is double
+/// dispatch: the method is tagged "double_dispatch" and its class "visitor".
+/// A class declaring two or more `visit*` methods, or one such an `accept`
+/// calls, is tagged "visitor" too. Only classes in the same file are
+/// considered. Must run after calls have been attributed to symbols.
+pub fn detect_visitor_pattern(symbols: &mut [SymbolInfo]) {
+    let containers: Vec<(usize, usize, usize)> = symbols
+        .iter()
+        .enumerate()
+        .filter(|(_, s)| {
+            matches!(
+                s.kind,
+                SymbolKind::Class | SymbolKind::Struct | SymbolKind::Interface | SymbolKind::Trait
+            )
+        })
+        .map(|(idx, s)| (idx, s.start_line, s.end_line))
+        .collect();
+    if containers.is_empty() {
+        return;
+    }
+
+    // Innermost class-like symbol around each method
+    let owner = |idx: usize, symbol: &SymbolInfo| -> Option<usize> {
+        containers
+            .iter()
+            .filter(|&&(c, start, end)| {
+                c != idx && symbol.start_line >= start && symbol.end_line <= end
+            })
+            .min_by_key(|&&(_, start, end)| end - start)
+            .map(|&(c, _, _)| c)
+    };
+    let method_name = |symbol: &SymbolInfo| -> String {
+        symbol
+            .name
+            .rsplit(['.', ':'])
+            .next()
+            .unwrap_or(&symbol.name)
+            .to_string()
+    };
+
+    let mut dispatchers: Vec<usize> = Vec::new();
+    let mut visitor_classes: HashSet<usize> = HashSet::new();
+    let mut dispatched: HashSet<String> = HashSet::new();
+    let mut visit_methods: HashMap<usize, Vec<String>> = HashMap::new();
+
+    for (idx, symbol) in symbols.iter().enumerate() {
+        if !matches!(symbol.kind, SymbolKind::Method | SymbolKind::Function) {
+            continue;
+        }
+        let Some(class) = owner(idx, symbol) else {
+            continue;
+        };
+        let name = method_name(symbol);
+
+        if name == "accept" {
+            let visits: Vec<String> = symbol
+                .calls
+                .iter()
+                .filter(|c| {
+                    c.object
+                        .as_deref()
+                        .is_some_and(|obj| !SELF_RECEIVERS.contains(&obj))
+                })
+                .map(|c| c.name.rsplit('.').next().unwrap_or(&c.name).to_string())
+                .filter(|call| is_visit_name(call))
+                .collect();
+            if !visits.is_empty() {
+                dispatchers.push(idx);
+                visitor_classes.insert(class);
+                dispatched.extend(visits);
+            }
+        } else if is_visit_name(&name) {
+            visit_methods.entry(class).or_default().push(name);
+        }
+    }
+
+    for (class, names) in visit_methods {
+        if names.len() >= 2 || names.iter().any(|n| dispatched.contains(n)) {
+            visitor_classes.insert(class);
+        }
+    }
+
+    let mut tag = |idx: usize, pattern: &str| {
+        let patterns = &mut symbols[idx].patterns;
+        if !patterns.iter().any(|p| p == pattern) {
+            patterns.push(pattern.to_string());
+        }
+    };
+    for idx in dispatchers {
+        tag(idx, "double_dispatch");
+    }
+    for idx in visitor_classes {
+        tag(idx, "visitor");
+    }
+}
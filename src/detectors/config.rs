@@ -1,10 +1,18 @@
 //! Config file detector (JSON, YAML, TOML)
+//!
+//! Also compares same-named config files across a repository (e.g. one
+//! `tsconfig.json` per monorepo package) and reports keys that have drifted.
 
-use crate::detectors::common::{get_node_text, push_unique_insertion, visit_all};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+use crate::detectors::common::{
+    get_node_text, get_node_text_normalized, push_unique_insertion, visit_all,
+};
 use crate::error::Result;
 use crate::lang::Lang;
 use crate::schema::SemanticSummary;
-use tree_sitter::{Node, Tree};
+use tree_sitter::{Node, Parser, Tree};
 
 pub fn extract(summary: &mut SemanticSummary, source: &str, tree: &Tree, lang: Lang) -> Result<()> {
     let root = tree.root_node();
@@ -184,3 +192,274 @@ fn generate_insertions(summary: &mut SemanticSummary) {
             .push(format!("config with {}", key_summary));
     }
 }
+
+// ============================================================================
+// Config Drift
+// ============================================================================
+
+/// One key whose presence or value differs between same-named config files
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyDrift {
+    /// Dotted key path, e.g. `compilerOptions.strict`
+    pub key: String,
+    /// Value per file, in the order of [`DriftReport::files`] (`None` if absent)
+    pub values: Vec<Option<String>>,
+}
+
+impl KeyDrift {
+    /// Whether the key is missing from at least one file
+    pub fn is_missing(&self) -> bool {
+        self.values.iter().any(|v| v.is_none())
+    }
+}
+
+/// Drift between config files that share a file name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriftReport {
+    /// Shared file name, e.g. `tsconfig.json`
+    pub file_name: String,
+    /// Paths of the compared files
+    pub files: Vec<String>,
+    /// Keys present in some files but not others, or with differing values
+    pub drifts: Vec<KeyDrift>,
+}
+
+/// Group same-named config files and report keys that drifted between them
+///
+/// `files` are `(path, source)` pairs. Only JSON, YAML and TOML files are
+/// compared; nested keys are flattened to dotted paths and arrays are compared
+/// as a whole. Groups with a single file or no drift produce no report.
+pub fn config_drift(files: &[(PathBuf, String)]) -> Vec<DriftReport> {
+    let mut groups: BTreeMap<String, Vec<(String, BTreeMap<String, String>)>> = BTreeMap::new();
+
+    for (path, source) in files {
+        let Some(file_name) = path.file_name().and_then(|f| f.to_str()) else {
+            continue;
+        };
+        let Ok(lang) = Lang::from_path(path) else {
+            continue;
+        };
+        let Some(keys) = flatten_config(source, lang) else {
+            continue;
+        };
+        groups
+            .entry(file_name.to_string())
+            .or_default()
+            .push((path.display().to_string(), keys));
+    }
+
+    groups
+        .into_iter()
+        .filter(|(_, members)| members.len() > 1)
+        .filter_map(|(file_name, members)| {
+            let all_keys: BTreeSet<&String> = members.iter().flat_map(|(_, k)| k.keys()).collect();
+
+            let drifts: Vec<KeyDrift> = all_keys
+                .into_iter()
+                .filter_map(|key| {
+                    let values: Vec<Option<String>> =
+                        members.iter().map(|(_, k)| k.get(key).cloned()).collect();
+                    let distinct: BTreeSet<&Option<String>> = values.iter().collect();
+                    (distinct.len() > 1).then(|| KeyDrift {
+                        key: key.clone(),
+                        values,
+                    })
+                })
+                .collect();
+
+            if drifts.is_empty() {
+                return None;
+            }
+            Some(DriftReport {
+                file_name,
+                files: members.into_iter().map(|(path, _)| path).collect(),
+                drifts,
+            })
+        })
+        .collect()
+}
+
+/// Parse a config file into a flat `dotted.key -> value` map
+fn flatten_config(source: &str, lang: Lang) -> Option<BTreeMap<String, String>> {
+    if !matches!(lang, Lang::Json | Lang::Yaml | Lang::Toml) {
+        return None;
+    }
+
+    let mut parser = Parser::new();
+    parser.set_language(&lang.tree_sitter_language()).ok()?;
+    let tree = parser.parse(source, None)?;
+    let root = tree.root_node();
+
+    let mut keys = BTreeMap::new();
+    match lang {
+        Lang::Json => {
+            let mut cursor = root.walk();
+            for child in root.children(&mut cursor) {
+                if child.kind() == "object" {
+                    flatten_json(&child, source, "", &mut keys);
+                }
+            }
+        }
+        Lang::Yaml => flatten_yaml(&root, source, "", &mut keys),
+        _ => flatten_toml(&root, source, &mut keys),
+    }
+    Some(keys)
+}
+
+fn join_key(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+fn flatten_json(node: &Node, source: &str, prefix: &str, keys: &mut BTreeMap<String, String>) {
+    let mut cursor = node.walk();
+    for pair in node.children(&mut cursor) {
+        if pair.kind() != "pair" {
+            continue;
+        }
+        let (Some(key), Some(value)) = (
+            pair.child_by_field_name("key"),
+            pair.child_by_field_name("value"),
+        ) else {
+            continue;
+        };
+        let path = join_key(prefix, get_node_text(&key, source).trim_matches('"'));
+        if value.kind() == "object" {
+            flatten_json(&value, source, &path, keys);
+        } else {
+            keys.insert(path, get_node_text_normalized(&value, source));
+        }
+    }
+}
+
+fn flatten_yaml(node: &Node, source: &str, prefix: &str, keys: &mut BTreeMap<String, String>) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() != "block_mapping_pair" {
+            flatten_yaml(&child, source, prefix, keys);
+            continue;
+        }
+        let Some(key) = child.child_by_field_name("key") else {
+            continue;
+        };
+        let path = join_key(prefix, &get_node_text(&key, source));
+        match child.child_by_field_name("value") {
+            Some(value) if has_block_mapping(&value) => flatten_yaml(&value, source, &path, keys),
+            Some(value) => {
+                keys.insert(path, get_node_text_normalized(&value, source));
+            }
+            None => {
+                keys.insert(path, String::new());
+            }
+        }
+    }
+}
+
+fn has_block_mapping(node: &Node) -> bool {
+    let mut cursor = node.walk();
+    let found = node
+        .children(&mut cursor)
+        .any(|c| c.kind() == "block_mapping");
+    found
+}
+
+fn flatten_toml(root: &Node, source: &str, keys: &mut BTreeMap<String, String>) {
+    let mut cursor = root.walk();
+    for child in root.children(&mut cursor) {
+        match child.kind() {
+            "pair" => flatten_toml_pair(&child, source, "", keys),
+            "table" | "table_array_element" => {
+                let Some(header) = child.named_child(0) else {
+                    continue;
+                };
+                let prefix = get_node_text(&header, source);
+                let mut inner = child.walk();
+                for pair in child.children(&mut inner) {
+                    if pair.kind() == "pair" {
+                        flatten_toml_pair(&pair, source, &prefix, keys);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn flatten_toml_pair(pair: &Node, source: &str, prefix: &str, keys: &mut BTreeMap<String, String>) {
+    let count = pair.named_child_count();
+    let (Some(key), Some(value)) = (
+        pair.named_child(0),
+        pair.named_child(count.saturating_sub(1)),
+    ) else {
+        return;
+    };
+    let path = join_key(prefix, get_node_text(&key, source).trim_matches('"'));
+    keys.insert(path, get_node_text_normalized(&value, source));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tsconfig_drift() {
+        let web = r#"{
+  "extends": "../../tsconfig.base.json",
+  "compilerOptions": {
+    "target": "ES2022",
+    "strict": true,
+    "jsx": "react-jsx"
+  }
+}"#;
+        let api = r#"{
+  "extends": "../../tsconfig.base.json",
+  "compilerOptions": {
+    "target": "ES2022",
+    "strict": false,
+    "jsx": "react-jsx"
+  }
+}"#;
+        let files = vec![
+            (PathBuf::from("packages/web/tsconfig.json"), web.to_string()),
+            (PathBuf::from("packages/api/tsconfig.json"), api.to_string()),
+            (PathBuf::from("packages/api/package.json"), "{}".to_string()),
+        ];
+
+        let reports = config_drift(&files);
+        assert_eq!(reports.len(), 1, "package.json has no sibling to compare");
+
+        let report = &reports[0];
+        assert_eq!(report.file_name, "tsconfig.json");
+        assert_eq!(
+            report.drifts,
+            vec![KeyDrift {
+                key: "compilerOptions.strict".to_string(),
+                values: vec![Some("true".to_string()), Some("false".to_string())],
+            }]
+        );
+        assert!(!report.drifts[0].is_missing());
+    }
+
+    #[test]
+    fn test_missing_key_drift() {
+        let files = vec![
+            (
+                PathBuf::from("a/.prettierrc.json"),
+                r#"{"semi": false, "singleQuote": true}"#.to_string(),
+            ),
+            (
+                PathBuf::from("b/.prettierrc.json"),
+                r#"{"semi": false}"#.to_string(),
+            ),
+        ];
+
+        let reports = config_drift(&files);
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].drifts.len(), 1);
+        assert_eq!(reports[0].drifts[0].key, "singleQuote");
+        assert!(reports[0].drifts[0].is_missing());
+    }
+}
@@ -17,16 +17,24 @@
 use tree_sitter::{Node, Tree};
 
 use crate::detectors::common::{
-    find_containing_symbol_by_line, get_node_text, get_node_text_normalized,
+    count_nested_functions, count_test_assertions, expression_complexity, find_bare_rethrows,
+    find_broad_catches, find_containing_symbol_by_line, find_import_side_effects,
+    find_inconsistent_returns, find_long_member_chains, find_loop_string_concats,
+    find_missing_default_branches, find_overflow_risks, find_platform_branches,
+    find_positional_calls, find_resource_leaks, find_sensitive_logs, find_string_literals,
+    find_unawaited_async_calls, get_node_text, get_node_text_normalized, mark_overloaded_symbols,
+    mark_recursive_symbols, visit_all, GO_RESOURCE_ACQUIRERS, MAX_MEMBER_CHAIN_DEPTH,
+    MIN_POSITIONAL_ARGS, PYTHON_RESOURCE_ACQUIRERS,
 };
 use crate::detectors::grammar::LangGrammar;
 use crate::detectors::variable_refs;
 use crate::error::Result;
 use crate::lang::Lang;
 use crate::schema::{
-    Call, ControlFlowChange, ControlFlowKind, FrameworkEntryPoint, Location, RefKind, RiskLevel,
-    SemanticSummary, StateChange, SymbolInfo, SymbolKind,
+    Argument, Call, ControlFlowChange, ControlFlowKind, FrameworkEntryPoint, Location, RefKind,
+    RiskLevel, SemanticSummary, StateChange, SymbolInfo, SymbolKind,
 };
+use crate::search::is_test_file;
 use crate::utils::truncate_to_char_boundary;
 
 // =============================================================================
@@ -44,11 +52,29 @@ pub fn extract_with_grammar(
 
     // Extract all semantic information
     extract_symbols(summary, &root, source, grammar);
+    mark_overloaded_symbols(&mut summary.symbols);
     extract_imports(summary, &root, source, grammar);
     extract_state_changes(summary, &root, source, grammar);
     extract_control_flow(summary, &root, source, grammar);
+    extract_missing_default_branches(summary, &root, source, grammar);
+    extract_import_side_effects(summary, &root, source, grammar);
     extract_calls(summary, &root, source, grammar);
+    extract_unawaited_async_calls(summary, &root, source, grammar);
+    extract_long_member_chains(summary, &root, grammar);
+    extract_loop_string_concats(summary, &root, source, grammar);
+    extract_expression_complexity(summary, &root, grammar);
+    extract_inconsistent_returns(summary, &root, source, grammar);
+    extract_positional_calls(summary, &root, source, grammar);
+    extract_platform_branches(summary, &root, source, grammar);
+    extract_overflow_risks(summary, &root, source, grammar);
+    extract_resource_leaks(summary, &root, source, grammar);
+    extract_bare_rethrows(summary, &root, source, grammar);
+    extract_broad_catches(summary, &root, source, grammar);
+    extract_sensitive_logs(summary, &root, source, grammar);
+    extract_string_literals(summary, &root, source, grammar);
     extract_variable_references(summary, &root, source, grammar);
+    extract_function_structure(summary, &root, grammar);
+    extract_test_assertions(summary, &root, source);
 
     // Calculate derived metrics
     calculate_complexity(summary);
@@ -71,9 +97,12 @@ struct SymbolCandidate {
     score: i32,
     decorators: Vec<String>,
     is_async: bool,
+    /// Macro and function parameters, for grammars whose detectors don't
+    /// collect their own
+    arguments: Vec<Argument>,
 }
 
-/// Extract all symbols (functions, classes, interfaces, enums)
+/// Extract all symbols (functions, classes, interfaces, enums, macros)
 fn extract_symbols(
     summary: &mut SemanticSummary,
     root: &Node,
@@ -98,9 +127,10 @@ fn extract_symbols(
             is_exported: candidate.is_exported,
             is_default_export: false,
             hash: None,
-            arguments: Vec::new(),
+            arguments: candidate.arguments.clone(),
             props: Vec::new(),
             return_type: None,
+            type_params: Vec::new(),
             calls: Vec::new(),
             control_flow: Vec::new(),
             state_changes: Vec::new(),
@@ -110,6 +140,17 @@ fn extract_symbols(
             framework_entry_point: FrameworkEntryPoint::None,
             is_async: candidate.is_async,
             base_classes: Vec::new(),
+            is_recursive: false,
+            nested_functions: 0,
+            assertion_count: None,
+            overloaded: false,
+            is_stub: false,
+            injected_deps: Vec::new(),
+            naming_violation: None,
+            feature_envy: None,
+            patterns: Vec::new(),
+            is_stub_impl: false,
+            returns_description: None,
         };
         summary.symbols.push(symbol_info);
     }
@@ -149,10 +190,15 @@ fn collect_symbols_recursive(
                 Some(SymbolKind::Trait)
             } else if grammar.enum_nodes.contains(&kind_str) {
                 Some(SymbolKind::Enum)
+            } else if grammar.macro_nodes.contains(&kind_str) {
+                Some(SymbolKind::Macro)
             } else if grammar.module_var_nodes.contains(&kind_str) {
                 // Module-level variable (const, static, top-level declaration)
-                // Only extract if NOT inside a local scope
-                if !is_in_local_scope(&current_node, grammar) {
+                // Only extract if NOT inside a local scope. A valueless #define
+                // is an include guard or flag, not a constant.
+                let is_bare_define = kind_str == "preproc_def"
+                    && current_node.child_by_field_name("value").is_none();
+                if !is_in_local_scope(&current_node, grammar) && !is_bare_define {
                     Some(SymbolKind::Variable)
                 } else {
                     None
@@ -175,6 +221,15 @@ fn collect_symbols_recursive(
                         calculate_symbol_score(&name, &kind, is_exported, filename_stem, grammar);
                     let decorators = extract_decorators(&current_node, source, grammar);
                     let is_async = is_async_node(&current_node);
+                    let arguments = if kind == SymbolKind::Macro {
+                        macro_parameters(&current_node, source)
+                    } else if kind == SymbolKind::Function
+                        && PARAMETER_GRAMMARS.contains(&grammar.name)
+                    {
+                        function_parameters(&current_node, source)
+                    } else {
+                        Vec::new()
+                    };
 
                     candidates.push(SymbolCandidate {
                         name,
@@ -185,6 +240,7 @@ fn collect_symbols_recursive(
                         score,
                         decorators,
                         is_async,
+                        arguments,
                     });
                 }
             }
@@ -323,10 +379,13 @@ fn extract_decorators(node: &Node, source: &str, grammar: &LangGrammar) -> Vec<S
 /// as a direct child of the function definition node (Python, JS/TS).
 /// For JS, `async` appears as a named child with kind "async";
 /// for Python (tree-sitter-python ≥0.23) it is an anonymous first child.
+/// Rust nests it one level down, inside `function_modifiers`.
 pub fn is_async_node(node: &Node) -> bool {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        if child.kind() == "async" {
+        if child.kind() == "async"
+            || (child.kind() == "function_modifiers" && is_async_node(&child))
+        {
             return true;
         }
     }
@@ -377,6 +436,120 @@ fn extract_decorator_text(node: &Node, source: &str) -> Option<String> {
     None
 }
 
+/// Grammars whose function parameters are read here rather than by their detector
+const PARAMETER_GRAMMARS: &[&str] = &["rust", "go"];
+
+/// Parameters of a Rust or Go function
+///
+/// Neither language has default values. A Rust parameter taking an `Option`
+/// is optional, since callers can pass `None`; so is a Go variadic one.
+/// `self` and Go's unnamed parameters are left out.
+pub(crate) fn function_parameters(node: &Node, source: &str) -> Vec<Argument> {
+    let Some(params) = node.child_by_field_name("parameters") else {
+        return Vec::new();
+    };
+
+    let mut arguments = Vec::new();
+    let mut cursor = params.walk();
+    for param in params.named_children(&mut cursor) {
+        let arg_type = param
+            .child_by_field_name("type")
+            .map(|t| get_node_text(&t, source));
+        match param.kind() {
+            "parameter" => {
+                let name = param
+                    .child_by_field_name("pattern")
+                    .map(|p| get_node_text(&p, source))
+                    .unwrap_or_default();
+                let is_optional = arg_type.as_deref().map_or(false, is_option_type);
+                arguments.push(Argument {
+                    name,
+                    arg_type,
+                    default_value: None,
+                    is_optional,
+                    description: None,
+                });
+            }
+            // `a, b int` declares two parameters
+            "parameter_declaration" | "variadic_parameter_declaration" => {
+                let is_optional = param.kind() == "variadic_parameter_declaration";
+                let arg_type = if is_optional {
+                    arg_type.map(|t| format!("...{}", t))
+                } else {
+                    arg_type
+                };
+                let mut names = param.walk();
+                for name in param.children_by_field_name("name", &mut names) {
+                    arguments.push(Argument {
+                        name: get_node_text(&name, source),
+                        arg_type: arg_type.clone(),
+                        default_value: None,
+                        is_optional,
+                        description: None,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    arguments
+}
+
+/// Whether a Rust type is `Option<T>`, however it is qualified
+fn is_option_type(ty: &str) -> bool {
+    ty.starts_with("Option<") || ty.contains("::Option<")
+}
+
+/// Parameters of a macro definition
+///
+/// C function-like macros list them in `preproc_params`, including a
+/// trailing `...`. A Rust `macro_rules!` takes the metavariables bound by its
+/// first rule, typed by fragment specifier (`$x:expr` becomes `x: expr`).
+fn macro_parameters(node: &Node, source: &str) -> Vec<Argument> {
+    let argument = |name: String, arg_type: Option<String>| Argument {
+        name,
+        arg_type,
+        default_value: None,
+        is_optional: false,
+        description: None,
+    };
+
+    if let Some(params) = node.child_by_field_name("parameters") {
+        let mut cursor = params.walk();
+        return params
+            .children(&mut cursor)
+            .filter(|p| p.kind() == "identifier" || p.kind() == "...")
+            .map(|p| argument(get_node_text(&p, source), None))
+            .collect();
+    }
+
+    let mut arguments: Vec<Argument> = Vec::new();
+    let mut cursor = node.walk();
+    let first_rule = node
+        .named_children(&mut cursor)
+        .find(|c| c.kind() == "macro_rule");
+    if let Some(pattern) = first_rule.and_then(|r| r.child_by_field_name("left")) {
+        visit_all(&pattern, |inner| {
+            if inner.kind() != "token_binding_pattern" {
+                return;
+            }
+            let Some(name) = inner.child_by_field_name("name") else {
+                return;
+            };
+            let name = get_node_text(&name, source)
+                .trim_start_matches('$')
+                .to_string();
+            if arguments.iter().all(|a| a.name != name) {
+                let fragment = inner
+                    .child_by_field_name("type")
+                    .map(|t| get_node_text(&t, source));
+                arguments.push(argument(name, fragment));
+            }
+        });
+    }
+    arguments
+}
+
 fn extract_symbol_name(node: &Node, source: &str, grammar: &LangGrammar) -> Option<String> {
     // Try the configured name field first
     if let Some(name_node) = node.child_by_field_name(grammar.name_field) {
@@ -669,6 +842,15 @@ fn extract_control_flow(
 ) {
     let mut results: Vec<ControlFlowChange> = Vec::new();
     collect_control_flow_recursive(root, 0, grammar, &mut results);
+
+    // Attribute each construct to its innermost symbol by line range; the
+    // file-wide list stays complete for file-level risk and complexity
+    for cf in &results {
+        if let Some(symbol_idx) = find_containing_symbol_by_line(cf.location.line, &summary.symbols)
+        {
+            summary.symbols[symbol_idx].control_flow.push(cf.clone());
+        }
+    }
     summary.control_flow_changes.extend(results);
 }
 
@@ -759,10 +941,228 @@ fn map_control_flow_kind(node_kind: &str, grammar: &LangGrammar) -> ControlFlowK
     }
 }
 
+/// Grammars whose `switch`/`match` constructs are checked for a default arm
+///
+/// Rust is omitted because the compiler already enforces exhaustive matches.
+const DEFAULT_BRANCH_GRAMMARS: &[&str] = &["go", "java", "python"];
+
+fn extract_missing_default_branches(
+    summary: &mut SemanticSummary,
+    root: &Node,
+    source: &str,
+    grammar: &LangGrammar,
+) {
+    if DEFAULT_BRANCH_GRAMMARS.contains(&grammar.name) {
+        summary.missing_default_branches = find_missing_default_branches(root, source);
+    }
+}
+
+/// Grammars whose module-level statements are checked for import-time effects
+///
+/// Compiled languages only allow declarations at the top level.
+const IMPORT_SIDE_EFFECT_GRAMMARS: &[&str] = &["python"];
+
+fn extract_import_side_effects(
+    summary: &mut SemanticSummary,
+    root: &Node,
+    source: &str,
+    grammar: &LangGrammar,
+) {
+    // Test modules call their framework at the top level by design
+    if IMPORT_SIDE_EFFECT_GRAMMARS.contains(&grammar.name) && !is_test_file(&summary.file) {
+        summary.import_side_effects = find_import_side_effects(root, source);
+    }
+}
+
 // =============================================================================
 // Call Extraction
 // =============================================================================
 
+/// Grammars where calling an async function without awaiting it is a bug
+///
+/// Rust futures and Python coroutines do nothing until awaited.
+const UNAWAITED_ASYNC_GRAMMARS: &[&str] = &["rust", "python"];
+
+fn extract_unawaited_async_calls(
+    summary: &mut SemanticSummary,
+    root: &Node,
+    source: &str,
+    grammar: &LangGrammar,
+) {
+    if UNAWAITED_ASYNC_GRAMMARS.contains(&grammar.name) {
+        summary.unawaited_async_calls = find_unawaited_async_calls(root, source, &summary.symbols);
+    }
+}
+
+/// Grammars whose member-access chains are checked against the Law of Demeter
+const MEMBER_CHAIN_GRAMMARS: &[&str] = &["java", "python"];
+
+fn extract_long_member_chains(summary: &mut SemanticSummary, root: &Node, grammar: &LangGrammar) {
+    if MEMBER_CHAIN_GRAMMARS.contains(&grammar.name) {
+        summary.long_member_chains = find_long_member_chains(root, MAX_MEMBER_CHAIN_DEPTH);
+    }
+}
+
+/// Grammars where appending to a string copies it (immutable strings)
+///
+/// Rust's `String` grows in place, so `+=` in a loop is fine there.
+const LOOP_CONCAT_GRAMMARS: &[&str] = &["go", "java", "csharp", "python"];
+
+fn extract_loop_string_concats(
+    summary: &mut SemanticSummary,
+    root: &Node,
+    source: &str,
+    grammar: &LangGrammar,
+) {
+    if LOOP_CONCAT_GRAMMARS.contains(&grammar.name) {
+        summary.loop_string_concats = find_loop_string_concats(root, source);
+    }
+}
+
+/// Grammars with expression-level conditionals worth scoring
+const EXPRESSION_COMPLEXITY_GRAMMARS: &[&str] = &["java", "csharp", "python", "c", "cpp"];
+
+fn extract_expression_complexity(
+    summary: &mut SemanticSummary,
+    root: &Node,
+    grammar: &LangGrammar,
+) {
+    if EXPRESSION_COMPLEXITY_GRAMMARS.contains(&grammar.name) {
+        summary.expression_complexity = expression_complexity(root);
+    }
+}
+
+/// Dynamically-typed grammars, where nothing checks that every path returns
+const INCONSISTENT_RETURN_GRAMMARS: &[&str] = &["python"];
+
+fn extract_inconsistent_returns(
+    summary: &mut SemanticSummary,
+    root: &Node,
+    source: &str,
+    grammar: &LangGrammar,
+) {
+    if INCONSISTENT_RETURN_GRAMMARS.contains(&grammar.name) {
+        summary.inconsistent_returns = find_inconsistent_returns(root, source);
+    }
+}
+
+/// Grammars with keyword arguments, where positional calls can name their values
+const POSITIONAL_CALL_GRAMMARS: &[&str] = &["python"];
+
+fn extract_positional_calls(
+    summary: &mut SemanticSummary,
+    root: &Node,
+    source: &str,
+    grammar: &LangGrammar,
+) {
+    if POSITIONAL_CALL_GRAMMARS.contains(&grammar.name) {
+        summary.positional_calls = find_positional_calls(root, source, MIN_POSITIONAL_ARGS);
+    }
+}
+
+/// Grammars with a platform check [`find_platform_branches`] understands
+const PLATFORM_BRANCH_GRAMMARS: &[&str] = &["rust", "python", "go"];
+
+fn extract_platform_branches(
+    summary: &mut SemanticSummary,
+    root: &Node,
+    source: &str,
+    grammar: &LangGrammar,
+) {
+    if PLATFORM_BRANCH_GRAMMARS.contains(&grammar.name) {
+        summary.platform_branches = find_platform_branches(root, source);
+    }
+}
+
+/// Grammars with fixed-width integers that can wrap or overflow silently
+///
+/// Rust panics on overflow only in debug builds; release builds wrap. C++ is
+/// left out because `<<` there is mostly stream output.
+const OVERFLOW_GRAMMARS: &[&str] = &["rust", "c"];
+
+fn extract_overflow_risks(
+    summary: &mut SemanticSummary,
+    root: &Node,
+    source: &str,
+    grammar: &LangGrammar,
+) {
+    if OVERFLOW_GRAMMARS.contains(&grammar.name) {
+        summary.overflow_risks = find_overflow_risks(root, source);
+    }
+}
+
+/// Resource leaks are checked where release is manual; Rust's `Drop` closes
+/// files and connections when they go out of scope
+fn extract_resource_leaks(
+    summary: &mut SemanticSummary,
+    root: &Node,
+    source: &str,
+    grammar: &LangGrammar,
+) {
+    let acquirers = match grammar.name {
+        "go" => GO_RESOURCE_ACQUIRERS,
+        "python" => PYTHON_RESOURCE_ACQUIRERS,
+        _ => return,
+    };
+    summary.resource_leaks = find_resource_leaks(root, source, acquirers);
+}
+
+/// Grammars whose catch clauses (or `map_err` closures) the rethrow check knows
+const BARE_RETHROW_GRAMMARS: &[&str] = &["rust", "python", "java"];
+
+fn extract_bare_rethrows(
+    summary: &mut SemanticSummary,
+    root: &Node,
+    source: &str,
+    grammar: &LangGrammar,
+) {
+    if BARE_RETHROW_GRAMMARS.contains(&grammar.name) {
+        summary.bare_rethrows = find_bare_rethrows(root, source);
+    }
+}
+
+/// Grammars with typed exception handlers, or a typeless `catch`
+const BROAD_CATCH_GRAMMARS: &[&str] = &["python", "java", "csharp"];
+
+fn extract_broad_catches(
+    summary: &mut SemanticSummary,
+    root: &Node,
+    source: &str,
+    grammar: &LangGrammar,
+) {
+    if BROAD_CATCH_GRAMMARS.contains(&grammar.name) {
+        summary.broad_catches = find_broad_catches(root, source);
+    }
+}
+
+/// Grammars whose logging calls [`find_sensitive_logs`] recognizes
+const SENSITIVE_LOG_GRAMMARS: &[&str] = &["python", "java", "go"];
+
+fn extract_sensitive_logs(
+    summary: &mut SemanticSummary,
+    root: &Node,
+    source: &str,
+    grammar: &LangGrammar,
+) {
+    if SENSITIVE_LOG_GRAMMARS.contains(&grammar.name) {
+        summary.sensitive_logs = find_sensitive_logs(root, source);
+    }
+}
+
+/// Grammars whose string literal kinds [`find_string_literals`] knows
+const STRING_LITERAL_GRAMMARS: &[&str] = &["rust", "python", "go", "java", "c", "cpp", "csharp"];
+
+fn extract_string_literals(
+    summary: &mut SemanticSummary,
+    root: &Node,
+    source: &str,
+    grammar: &LangGrammar,
+) {
+    if STRING_LITERAL_GRAMMARS.contains(&grammar.name) {
+        summary.string_literals = find_string_literals(root, source);
+    }
+}
+
 fn extract_calls(summary: &mut SemanticSummary, root: &Node, source: &str, grammar: &LangGrammar) {
     // Collect all calls first with their line numbers
     let mut all_calls: Vec<(Call, usize)> = Vec::new();
@@ -823,6 +1223,7 @@ fn extract_calls(summary: &mut SemanticSummary, root: &Node, source: &str, gramm
 const CONSTRUCTOR_NODE_TYPES: &[&str] = &[
     "object_creation_expression", // C#, Java
     "new_expression",             // JavaScript, TypeScript
+    "struct_expression",          // Rust struct literal
 ];
 
 fn extract_call(node: &Node, source: &str, grammar: &LangGrammar) -> Option<Call> {
@@ -834,6 +1235,7 @@ fn extract_call(node: &Node, source: &str, grammar: &LangGrammar) -> Option<Call
         // Fallback to child(1) since child(0) is typically the "new" keyword
         node.child_by_field_name("type")
             .or_else(|| node.child_by_field_name("constructor"))
+            .or_else(|| node.child_by_field_name("name")) // Rust struct literal
             .or_else(|| node.child(1)) // Skip "new" keyword at child(0)
     } else {
         // Regular function calls
@@ -847,6 +1249,13 @@ fn extract_call(node: &Node, source: &str, grammar: &LangGrammar) -> Option<Call
         return None;
     }
 
+    // `new Foo()`, `Foo { .. }` and Rust's `Foo::new()` create a `Foo`
+    let instantiated_type = if CONSTRUCTOR_NODE_TYPES.contains(&node_kind) {
+        Some(instantiated_type_name(&full_name))
+    } else {
+        full_name.strip_suffix("::new").map(instantiated_type_name)
+    };
+
     // Split into object and method for method calls (e.g., "console.log" -> object="console", name="log")
     let (object, name) = if full_name.contains('.') {
         let parts: Vec<&str> = full_name.rsplitn(2, '.').collect();
@@ -872,8 +1281,8 @@ fn extract_call(node: &Node, source: &str, grammar: &LangGrammar) -> Option<Call
     // Check if this is a React hook
     let is_hook = Call::check_is_hook(&name);
 
-    // Check if this is an I/O operation
-    let is_io = Call::check_is_io(&name);
+    // Check if this is an I/O operation; path calls (`reqwest::get`) by their last segment
+    let is_io = Call::check_is_io(name.rsplit("::").next().unwrap_or(&name));
 
     let location = Location::new(node.start_position().row + 1, node.start_position().column);
 
@@ -885,10 +1294,26 @@ fn extract_call(node: &Node, source: &str, grammar: &LangGrammar) -> Option<Call
         is_hook,
         is_io,
         ref_kind: RefKind::None,
+        is_constructor: instantiated_type.is_some(),
+        instantiated_type,
         location,
     })
 }
 
+/// Bare type name from a constructor path: `std::collections::HashMap::<K, V>` -> `HashMap`
+fn instantiated_type_name(path: &str) -> String {
+    let base = path
+        .split('<')
+        .next()
+        .unwrap_or(path)
+        .trim_end_matches("::");
+    base.rsplit(['.', ':'])
+        .next()
+        .unwrap_or(base)
+        .trim()
+        .to_string()
+}
+
 fn is_inside_try(node: &Node, grammar: &LangGrammar) -> bool {
     let mut current = node.parent();
     while let Some(parent) = current {
@@ -900,6 +1325,78 @@ fn is_inside_try(node: &Node, grammar: &LangGrammar) -> bool {
     false
 }
 
+// =============================================================================
+// Recursion and Nested Functions
+// =============================================================================
+
+/// Node types for anonymous functions (closures, lambdas, function literals)
+const CLOSURE_NODE_TYPES: &[&str] = &[
+    "closure_expression",          // Rust
+    "lambda",                      // Python
+    "func_literal",                // Go
+    "lambda_expression",           // Java, C#, C++
+    "anonymous_method_expression", // C#
+    "lambda_literal",              // Kotlin
+    "anonymous_function",          // Kotlin
+];
+
+/// Record recursion and nested function declarations on each symbol
+///
+/// Recursion is a SonarSource cognitive complexity increment that isn't visible
+/// in control flow; nested functions raise the nesting of the control flow inside them.
+fn extract_function_structure(summary: &mut SemanticSummary, root: &Node, grammar: &LangGrammar) {
+    mark_recursive_symbols(&mut summary.symbols);
+
+    let function_kinds: Vec<&str> = grammar
+        .function_nodes
+        .iter()
+        .chain(CLOSURE_NODE_TYPES)
+        .copied()
+        .collect();
+    count_nested_functions(root, summary, &function_kinds);
+}
+
+// =============================================================================
+// Test Detection
+// =============================================================================
+
+/// Attributes/annotations that mark a function as a test (last path segment)
+const TEST_DECORATORS: &[&str] = &[
+    "test",
+    "rstest",
+    "Test",
+    "ParameterizedTest",
+    "RepeatedTest",
+];
+
+/// Mark test functions and count the assertions each one makes
+///
+/// Rust (`#[test]`, `#[tokio::test]`) and Java (`@Test`) tests are recognised by
+/// attribute; Go tests by the `TestXxx` naming convention in `_test.go` files.
+/// Python tests are marked by the Python detector, which counts them itself.
+fn extract_test_assertions(summary: &mut SemanticSummary, root: &Node, source: &str) {
+    let is_go_test_file = summary.file.ends_with("_test.go");
+
+    for symbol in &mut summary.symbols {
+        if !matches!(symbol.kind, SymbolKind::Function | SymbolKind::Method) {
+            continue;
+        }
+
+        let has_test_decorator = symbol.decorators.iter().any(|d| {
+            let name = d.trim_start_matches("#[").trim_end_matches(']');
+            let name = name.rsplit("::").next().unwrap_or(name);
+            TEST_DECORATORS.contains(&name)
+        });
+        let is_go_test = is_go_test_file && symbol.name.starts_with("Test");
+
+        if has_test_decorator || is_go_test {
+            symbol.framework_entry_point = FrameworkEntryPoint::TestFunction;
+        }
+    }
+
+    count_test_assertions(root, source, &mut summary.symbols);
+}
+
 // =============================================================================
 // Variable Reference Extraction
 // =============================================================================
@@ -989,43 +1486,6 @@ fn determine_risk(summary: &mut SemanticSummary) {
     };
 }
 
-// =============================================================================
-// Utility Functions
-// =============================================================================
-
-fn visit_all<F>(node: &Node, mut callback: F)
-where
-    F: FnMut(&Node),
-{
-    // Iterative traversal using tree-sitter cursor to avoid stack overflow
-    let mut cursor = node.walk();
-    let mut did_visit_children = false;
-
-    loop {
-        if !did_visit_children {
-            callback(&cursor.node());
-
-            // Try to go to first child
-            if cursor.goto_first_child() {
-                did_visit_children = false;
-                continue;
-            }
-        }
-
-        // Try to go to next sibling
-        if cursor.goto_next_sibling() {
-            did_visit_children = false;
-            continue;
-        }
-
-        // Go back to parent
-        if !cursor.goto_parent() {
-            break; // Reached the root, we're done
-        }
-        did_visit_children = true;
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1111,6 +1571,420 @@ def process_data(data):
         );
     }
 
+    /// Test that recursion and nested closures are recorded on Rust symbols
+    #[test]
+    fn test_rust_recursion_and_nested_functions() {
+        let source = r#"
+fn depth(node: &Node) -> usize {
+    let child_depth = |c: &Node| depth(c);
+    fn helper() {}
+    node.children().map(child_depth).max().unwrap_or(0) + 1
+}
+
+fn leaf() -> usize {
+    1
+}
+"#;
+        let tree = parse_source(source, Lang::Rust);
+        let path = PathBuf::from("/test/tree.rs");
+        let summary = extract(&path, source, &tree, Lang::Rust).unwrap();
+
+        let depth = summary.symbols.iter().find(|s| s.name == "depth").unwrap();
+        assert!(depth.is_recursive);
+        assert_eq!(depth.nested_functions, 2);
+
+        let leaf = summary.symbols.iter().find(|s| s.name == "leaf").unwrap();
+        assert!(!leaf.is_recursive);
+        assert_eq!(leaf.nested_functions, 0);
+    }
+
+    /// Test that rustdoc `# Arguments` and `# Returns` sections describe the signature
+    #[test]
+    fn test_rustdoc_argument_descriptions() {
+        let rust = r#"
+/// Open a connection.
+///
+/// # Arguments
+///
+/// * `host` - Server to connect to
+/// * `timeout` - How long to wait, or
+///   forever when `None`
+///
+/// # Returns
+///
+/// The open connection.
+#[must_use]
+pub fn connect(host: &str, timeout: Option<Duration>) -> Conn {
+    open(host, timeout)
+}
+"#;
+        let tree = parse_source(rust, Lang::Rust);
+        let summary = extract(&PathBuf::from("/test/db.rs"), rust, &tree, Lang::Rust).unwrap();
+        let connect = &summary.symbols[0];
+        let descriptions: Vec<Option<&str>> = connect
+            .arguments
+            .iter()
+            .map(|a| a.description.as_deref())
+            .collect();
+        assert_eq!(
+            descriptions,
+            vec![
+                Some("Server to connect to"),
+                Some("How long to wait, or forever when `None`"),
+            ]
+        );
+        assert_eq!(
+            connect.returns_description.as_deref(),
+            Some("The open connection.")
+        );
+    }
+
+    /// Test that Rust `Option` parameters are optional and Go parameters are expanded
+    #[test]
+    fn test_rust_and_go_parameters() {
+        let rust = r#"
+pub fn connect(&self, host: &str, timeout: Option<Duration>) -> Conn {
+    open(host, timeout)
+}
+"#;
+        let tree = parse_source(rust, Lang::Rust);
+        let summary = extract(&PathBuf::from("/test/db.rs"), rust, &tree, Lang::Rust).unwrap();
+        let args: Vec<(&str, bool)> = summary.symbols[0]
+            .arguments
+            .iter()
+            .map(|a| (a.name.as_str(), a.is_optional))
+            .collect();
+        assert_eq!(args, vec![("host", false), ("timeout", true)]);
+        assert!(summary.symbols[0]
+            .arguments
+            .iter()
+            .all(|a| a.default_value.is_none()));
+
+        let go = r#"
+package db
+
+func Connect(host, user string, opts ...Option) *Conn {
+	return open(host, user, opts)
+}
+"#;
+        let tree = parse_source(go, Lang::Go);
+        let summary = extract(&PathBuf::from("/test/db.go"), go, &tree, Lang::Go).unwrap();
+        let connect = summary
+            .symbols
+            .iter()
+            .find(|s| s.name == "Connect")
+            .unwrap();
+        let args: Vec<(&str, Option<&str>, bool)> = connect
+            .arguments
+            .iter()
+            .map(|a| (a.name.as_str(), a.arg_type.as_deref(), a.is_optional))
+            .collect();
+        assert_eq!(
+            args,
+            vec![
+                ("host", Some("string"), false),
+                ("user", Some("string"), false),
+                ("opts", Some("...Option"), true),
+            ]
+        );
+    }
+
+    /// Test that macro_rules! definitions become macro symbols with their metavariables
+    #[test]
+    fn test_rust_macro_rules() {
+        let source = r#"
+#[macro_export]
+macro_rules! foo {
+    ($name:ident, $value:expr) => {
+        let $name = $value;
+    };
+    ($name:ident) => {
+        let $name = Default::default();
+    };
+}
+
+macro_rules! noop {
+    () => {};
+}
+"#;
+        let tree = parse_source(source, Lang::Rust);
+        let path = PathBuf::from("/test/macros.rs");
+        let summary = extract(&path, source, &tree, Lang::Rust).unwrap();
+
+        let foo = summary.symbols.iter().find(|s| s.name == "foo").unwrap();
+        assert_eq!(foo.kind, SymbolKind::Macro);
+        assert!(foo.is_exported);
+        let params: Vec<_> = foo
+            .arguments
+            .iter()
+            .map(|a| (a.name.as_str(), a.arg_type.as_deref()))
+            .collect();
+        assert_eq!(params, [("name", Some("ident")), ("value", Some("expr"))]);
+
+        let noop = summary.symbols.iter().find(|s| s.name == "noop").unwrap();
+        assert_eq!(noop.kind, SymbolKind::Macro);
+        assert!(!noop.is_exported);
+        assert!(noop.arguments.is_empty());
+    }
+
+    /// Test that function-like #defines are macros and object-like ones are constants
+    #[test]
+    fn test_c_macro_definitions() {
+        let source = r#"
+#define MAX(a,b) ((a) > (b) ? (a) : (b))
+#define LOG(fmt, ...) printf(fmt, __VA_ARGS__)
+#define BUFFER_SIZE 4096
+#define UTIL_H
+
+int clamp(int x) {
+    return MAX(x, 0);
+}
+"#;
+        let tree = parse_source(source, Lang::C);
+        let path = PathBuf::from("/test/util.c");
+        let summary = extract(&path, source, &tree, Lang::C).unwrap();
+
+        let max = summary.symbols.iter().find(|s| s.name == "MAX").unwrap();
+        assert_eq!(max.kind, SymbolKind::Macro);
+        let params: Vec<_> = max.arguments.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(params, ["a", "b"]);
+
+        let log = summary.symbols.iter().find(|s| s.name == "LOG").unwrap();
+        assert_eq!(log.kind, SymbolKind::Macro);
+        assert_eq!(log.arguments.len(), 2);
+
+        let size = summary
+            .symbols
+            .iter()
+            .find(|s| s.name == "BUFFER_SIZE")
+            .unwrap();
+        assert_eq!(size.kind, SymbolKind::Variable);
+        assert!(size.arguments.is_empty());
+        assert!(!summary.symbols.iter().any(|s| s.name == "UTIL_H"));
+    }
+
+    /// Test that Go/Java switches and Python matches without a catch-all are flagged
+    #[test]
+    fn test_missing_default_branches() {
+        let flagged_lines = |source: &str, path: &str, lang: Lang| -> Vec<usize> {
+            let tree = parse_source(source, lang);
+            let summary = extract(&PathBuf::from(path), source, &tree, lang).unwrap();
+            summary
+                .missing_default_branches
+                .iter()
+                .map(|l| l.line)
+                .collect()
+        };
+
+        let go = r#"
+package main
+
+func name(x int) string {
+    switch x {
+    case 1:
+        return "one"
+    }
+    switch x {
+    case 2:
+        return "two"
+    default:
+        return "many"
+    }
+}
+"#;
+        assert_eq!(flagged_lines(go, "/test/name.go", Lang::Go), vec![5]);
+
+        let java = r#"
+class Names {
+    String name(int x) {
+        switch (x) {
+            case 1: return "one";
+        }
+        switch (x) {
+            case 2: return "two";
+            default: return "many";
+        }
+    }
+}
+"#;
+        assert_eq!(flagged_lines(java, "/test/Names.java", Lang::Java), vec![4]);
+
+        let python = r#"
+def name(x):
+    match x:
+        case 1:
+            return "one"
+    match x:
+        case 2:
+            return "two"
+        case _:
+            return "many"
+"#;
+        assert_eq!(
+            flagged_lines(python, "/test/names.py", Lang::Python),
+            vec![3]
+        );
+    }
+
+    /// Test that Rust `#[test]` functions are marked and their assertions counted
+    #[test]
+    fn test_rust_test_assertion_counts() {
+        let source = r#"
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[test]
+fn adds() {
+    assert_eq!(add(1, 2), 3);
+    assert!(add(0, 0) == 0);
+}
+
+#[test]
+fn smoke() {
+    add(1, 1);
+}
+"#;
+        let tree = parse_source(source, Lang::Rust);
+        let path = PathBuf::from("/test/math.rs");
+        let summary = extract(&path, source, &tree, Lang::Rust).unwrap();
+
+        let adds = summary.symbols.iter().find(|s| s.name == "adds").unwrap();
+        assert_eq!(adds.assertion_count, Some(2));
+
+        let smoke = summary.symbols.iter().find(|s| s.name == "smoke").unwrap();
+        assert!(smoke.is_assertionless_test());
+
+        let add = summary.symbols.iter().find(|s| s.name == "add").unwrap();
+        assert_eq!(add.assertion_count, None);
+    }
+
+    #[test]
+    fn test_rust_unawaited_future() {
+        let source = r#"
+async fn flush(&self) {}
+
+impl Writer {
+    async fn close(&mut self) {
+        self.flush();
+        self.flush().await;
+        Self::reset();
+    }
+}
+
+fn reset() {}
+"#;
+        let tree = parse_source(source, Lang::Rust);
+        let path = PathBuf::from("/test/writer.rs");
+        let summary = extract(&path, source, &tree, Lang::Rust).unwrap();
+
+        let flagged: Vec<(&str, usize)> = summary
+            .unawaited_async_calls
+            .iter()
+            .map(|c| (c.name.as_str(), c.location.line))
+            .collect();
+        assert_eq!(flagged, vec![("flush", 6)]);
+    }
+
+    #[test]
+    fn test_rust_async_fn_awaited_calls() {
+        let source = r#"
+pub async fn fetch(url: &str) -> Result<String, reqwest::Error> {
+    let body = reqwest::get(url).await?.text().await?;
+    log_fetch(url);
+    Ok(body)
+}
+"#;
+        let tree = parse_source(source, Lang::Rust);
+        let path = PathBuf::from("/test/client.rs");
+        let summary = extract(&path, source, &tree, Lang::Rust).unwrap();
+
+        let fetch = summary.symbols.iter().find(|s| s.name == "fetch").unwrap();
+        assert!(fetch.is_async);
+        assert_eq!(fetch.kind, SymbolKind::Function);
+
+        let get = fetch
+            .calls
+            .iter()
+            .find(|c| c.name == "reqwest::get")
+            .unwrap();
+        assert!(get.is_awaited);
+        assert!(get.is_io);
+        let log = fetch.calls.iter().find(|c| c.name == "log_fetch").unwrap();
+        assert!(!log.is_awaited);
+    }
+
+    /// Test that control flow is attributed to the enclosing function
+    #[test]
+    fn test_control_flow_per_symbol() {
+        let source = r#"
+fn classify(items: &[u32]) -> u32 {
+    let mut total = 0;
+    for item in items {
+        if *item > 10 {
+            total += item;
+        }
+    }
+    total
+}
+
+fn label(n: u32) -> &'static str {
+    match n {
+        0 => "none",
+        _ => "some",
+    }
+}
+"#;
+        let tree = parse_source(source, Lang::Rust);
+        let path = PathBuf::from("/test/classify.rs");
+        let summary = extract(&path, source, &tree, Lang::Rust).unwrap();
+
+        let kinds = |name: &str| -> Vec<(ControlFlowKind, usize)> {
+            let symbol = summary.symbols.iter().find(|s| s.name == name).unwrap();
+            symbol
+                .control_flow
+                .iter()
+                .map(|cf| (cf.kind, cf.nesting_depth))
+                .collect()
+        };
+        assert_eq!(
+            kinds("classify"),
+            vec![(ControlFlowKind::For, 0), (ControlFlowKind::If, 1)]
+        );
+        assert_eq!(kinds("label"), vec![(ControlFlowKind::Match, 0)]);
+        assert_eq!(summary.control_flow_changes.len(), 3);
+    }
+
+    /// Test that Rust struct literals and `::new` calls are flagged as constructors
+    #[test]
+    fn test_rust_constructor_calls() {
+        let source = r#"
+fn build() -> Config {
+    let cache = Cache::new(64);
+    let config = Config { retries: 3, cache };
+    validate(&config);
+    config
+}
+"#;
+        let tree = parse_source(source, Lang::Rust);
+        let path = PathBuf::from("/test/config.rs");
+        let summary = extract(&path, source, &tree, Lang::Rust).unwrap();
+
+        let build = summary.symbols.iter().find(|s| s.name == "build").unwrap();
+        let constructed: Vec<(&str, Option<&str>)> = build
+            .calls
+            .iter()
+            .filter(|c| c.is_constructor)
+            .map(|c| (c.name.as_str(), c.instantiated_type.as_deref()))
+            .collect();
+        assert_eq!(
+            constructed,
+            vec![("Cache::new", Some("Cache")), ("Config", Some("Config"))]
+        );
+        let validate = build.calls.iter().find(|c| c.name == "validate").unwrap();
+        assert!(!validate.is_constructor);
+    }
+
     /// Test that Go functions have calls attributed to symbols
     #[test]
     fn test_go_call_attribution() {
@@ -7,14 +7,19 @@
 //! - State changes (variable declarations)
 //! - Control flow (if, for, switch, select)
 //! - Function calls
+//! - Methods, named after their receiver type (`Server.Handle`)
+//! - Build constraints (`//go:build` lines and `_GOOS_GOARCH.go` file names)
 
 use tree_sitter::{Node, Tree};
 
-use crate::detectors::common::get_node_text;
+use crate::detectors::common::{get_node_text, visit_all};
 use crate::detectors::generic::extract_with_grammar;
 use crate::detectors::grammar::GO_GRAMMAR;
 use crate::error::Result;
-use crate::schema::{FrameworkEntryPoint, RiskLevel, SemanticSummary, SymbolInfo, SymbolKind};
+use crate::schema::{
+    FrameworkEntryPoint, Location, PlatformBranch, RiskLevel, SemanticSummary, SymbolInfo,
+    SymbolKind,
+};
 
 /// Extract semantic information from a Go source file
 pub fn extract(summary: &mut SemanticSummary, source: &str, tree: &Tree) -> Result<()> {
@@ -27,10 +32,178 @@ pub fn extract(summary: &mut SemanticSummary, source: &str, tree: &Tree) -> Resu
     // that merges with what the generic extractor already found
     let root = tree.root_node();
     find_go_type_symbols(summary, &root, source);
+    qualify_receiver_methods(summary, &root, source);
+    extract_build_constraint(summary, &root, source);
 
     Ok(())
 }
 
+// ============================================================================
+// Receiver Methods
+// ============================================================================
+
+/// Rename methods to `Type.Method` and give them the `Method` kind
+///
+/// The generic extractor records `func (s *Server) Handle()` as a plain
+/// `Handle` function, which the call graph can't tell apart from other
+/// `Handle` methods. Exported-ness follows the method name.
+fn qualify_receiver_methods(summary: &mut SemanticSummary, root: &Node, source: &str) {
+    // (start_line, method_name, receiver_type)
+    let mut methods: Vec<(usize, String, String)> = Vec::new();
+    visit_all(root, |node| {
+        if node.kind() != "method_declaration" {
+            return;
+        }
+        let (Some(name), Some(receiver)) = (
+            node.child_by_field_name("name"),
+            node.child_by_field_name("receiver"),
+        ) else {
+            return;
+        };
+        if let Some(receiver_type) = receiver_type_name(&receiver, source) {
+            methods.push((
+                node.start_position().row + 1,
+                get_node_text(&name, source),
+                receiver_type,
+            ));
+        }
+    });
+
+    for (line, name, receiver_type) in methods {
+        let qualified = format!("{}.{}", receiver_type, name);
+        let is_exported = name.starts_with(|c: char| c.is_uppercase());
+        for symbol in summary
+            .symbols
+            .iter_mut()
+            .filter(|s| s.start_line == line && s.name == name)
+        {
+            symbol.name = qualified.clone();
+            symbol.kind = SymbolKind::Method;
+            symbol.is_exported = is_exported;
+        }
+        if summary.start_line == Some(line) && summary.symbol.as_deref() == Some(name.as_str()) {
+            summary.symbol = Some(qualified);
+            summary.symbol_kind = Some(SymbolKind::Method);
+        }
+    }
+}
+
+/// Type named by a receiver list, without pointer or type arguments
+/// (`(s *Stack[T])` -> `Stack`)
+fn receiver_type_name(receiver: &Node, source: &str) -> Option<String> {
+    let mut cursor = receiver.walk();
+    let param = receiver
+        .named_children(&mut cursor)
+        .find(|c| c.kind() == "parameter_declaration")?;
+    let type_text = get_node_text(&param.child_by_field_name("type")?, source);
+    let name = type_text
+        .trim_start_matches('*')
+        .split('[')
+        .next()
+        .unwrap_or_default()
+        .trim();
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+// ============================================================================
+// Build Constraints
+// ============================================================================
+
+/// `GOOS` values, which both build tags and file name suffixes can name
+const GO_OS_TAGS: &[&str] = &[
+    "aix",
+    "android",
+    "darwin",
+    "dragonfly",
+    "freebsd",
+    "hurd",
+    "illumos",
+    "ios",
+    "js",
+    "linux",
+    "netbsd",
+    "openbsd",
+    "plan9",
+    "solaris",
+    "wasip1",
+    "windows",
+    "zos",
+];
+
+/// `GOARCH` values recognized in file name suffixes
+const GO_ARCH_TAGS: &[&str] = &[
+    "386", "amd64", "arm", "arm64", "loong64", "mips", "mips64", "mips64le", "mipsle", "ppc64",
+    "ppc64le", "riscv64", "s390x", "wasm",
+];
+
+/// Record when the file is compiled, and which platforms its tags name
+///
+/// The `//go:build` expression is kept as written; a `_GOOS`, `_GOARCH` or
+/// `_GOOS_GOARCH` file name suffix adds an implied constraint, combined with
+/// `&&`. Each OS tag in the expression (`unix` included, negations as
+/// written) is also recorded as a platform branch at the directive's line.
+fn extract_build_constraint(summary: &mut SemanticSummary, root: &Node, source: &str) {
+    let mut directive = None;
+    let mut cursor = root.walk();
+    for child in root.children(&mut cursor) {
+        // Constraints only count before the package clause
+        if child.kind() == "package_clause" {
+            break;
+        }
+        if child.kind() != "comment" {
+            continue;
+        }
+        let text = get_node_text(&child, source);
+        if let Some(expr) = text.strip_prefix("//go:build ") {
+            directive = Some((expr.trim().to_string(), child));
+            break;
+        }
+    }
+
+    if let Some((expr, node)) = &directive {
+        let tags = expr
+            .split(|c: char| c.is_whitespace() || "()&|".contains(c))
+            .filter(|tag| {
+                let tag = tag.trim_start_matches('!');
+                GO_OS_TAGS.contains(&tag) || tag == "unix"
+            });
+        for tag in tags {
+            summary.platform_branches.push(PlatformBranch {
+                platform: tag.to_string(),
+                location: Location::new(
+                    node.start_position().row + 1,
+                    node.start_position().column,
+                ),
+            });
+        }
+    }
+
+    summary.build_constraint = match (
+        directive.map(|(expr, _)| expr),
+        filename_constraint(&summary.file),
+    ) {
+        (Some(expr), Some(implied)) => Some(format!("({}) && {}", expr, implied)),
+        (expr, implied) => expr.or(implied),
+    };
+}
+
+/// Constraint implied by a `name_GOOS.go`, `name_GOARCH.go` or
+/// `name_GOOS_GOARCH.go` file name (`_test` is ignored)
+fn filename_constraint(file: &str) -> Option<String> {
+    let stem = extract_filename_stem(file);
+    let stem = stem.strip_suffix("_test").unwrap_or(&stem);
+    let parts: Vec<&str> = stem.split('_').collect();
+    match parts.as_slice() {
+        [_, .., os, arch] if GO_OS_TAGS.contains(os) && GO_ARCH_TAGS.contains(arch) => {
+            Some(format!("{} && {}", os, arch))
+        }
+        [_, .., tag] if GO_OS_TAGS.contains(tag) || GO_ARCH_TAGS.contains(tag) => {
+            Some(tag.to_string())
+        }
+        _ => None,
+    }
+}
+
 // ============================================================================
 // Go-Specific Type Declaration Handling
 // ============================================================================
@@ -84,6 +257,7 @@ fn find_go_type_symbols(summary: &mut SemanticSummary, root: &Node, source: &str
                 arguments: Vec::new(),
                 props: Vec::new(),
                 return_type: None,
+                type_params: Vec::new(),
                 calls: Vec::new(),
                 control_flow: Vec::new(),
                 state_changes: Vec::new(),
@@ -93,6 +267,17 @@ fn find_go_type_symbols(summary: &mut SemanticSummary, root: &Node, source: &str
                 framework_entry_point: FrameworkEntryPoint::None,
                 is_async: false,
                 base_classes: Vec::new(),
+                is_recursive: false,
+                nested_functions: 0,
+                assertion_count: None,
+                overloaded: false,
+                is_stub: false,
+                injected_deps: Vec::new(),
+                naming_violation: None,
+                feature_envy: None,
+                patterns: Vec::new(),
+                is_stub_impl: false,
+                returns_description: None,
             };
             summary.symbols.push(symbol_info);
         }
@@ -231,6 +416,73 @@ fn calculate_symbol_score(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lang::Lang;
+    use std::path::Path;
+    use tree_sitter::Parser;
+
+    fn extract_go(file: &str, source: &str) -> SemanticSummary {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&Lang::Go.tree_sitter_language())
+            .unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        crate::extract::extract(Path::new(file), source, &tree, Lang::Go).unwrap()
+    }
+
+    #[test]
+    fn test_build_constraint() {
+        let source = "//go:build linux && !android\n\npackage netpoll\n\nfunc Poll() {}\n";
+        let summary = extract_go("netpoll.go", source);
+        assert_eq!(
+            summary.build_constraint.as_deref(),
+            Some("linux && !android")
+        );
+        let platforms: Vec<(&str, usize)> = summary
+            .platform_branches
+            .iter()
+            .map(|b| (b.platform.as_str(), b.location.line))
+            .collect();
+        assert_eq!(platforms, vec![("linux", 1), ("!android", 1)]);
+
+        // A file name suffix implies a constraint of its own
+        let summary = extract_go("poll_windows_amd64.go", "package netpoll\n");
+        assert_eq!(
+            summary.build_constraint.as_deref(),
+            Some("windows && amd64")
+        );
+        let summary = extract_go("poll.go", "// Package netpoll polls.\npackage netpoll\n");
+        assert_eq!(summary.build_constraint, None);
+    }
+
+    #[test]
+    fn test_receiver_methods() {
+        let source = r#"package server
+
+type Server struct{}
+
+func (s *Server) Handle(path string) error {
+	return s.route(path)
+}
+
+func (s Server) route(path string) error {
+	return nil
+}
+
+func NewServer() *Server {
+	return &Server{}
+}
+"#;
+        let summary = extract_go("server.go", source);
+        let symbols: Vec<(&str, SymbolKind, bool)> = summary
+            .symbols
+            .iter()
+            .filter(|s| s.kind != SymbolKind::Struct)
+            .map(|s| (s.name.as_str(), s.kind, s.is_exported))
+            .collect();
+        assert!(symbols.contains(&("Server.Handle", SymbolKind::Method, true)));
+        assert!(symbols.contains(&("Server.route", SymbolKind::Method, false)));
+        assert!(symbols.contains(&("NewServer", SymbolKind::Function, true)));
+    }
 
     #[test]
     fn test_extract_filename_stem() {
@@ -44,6 +44,10 @@ pub struct LangGrammar {
     /// e.g., ["enum_declaration", "enum_item"]
     pub enum_nodes: &'static [&'static str],
 
+    /// Macro definition nodes
+    /// e.g., ["macro_definition"] for Rust, ["preproc_function_def"] for C
+    pub macro_nodes: &'static [&'static str],
+
     // =========================================================================
     // Control Flow
     // =========================================================================
@@ -168,8 +172,21 @@ pub fn go_is_exported(node: &Node, source: &str) -> bool {
     }
 }
 
-/// Rust: has `pub` visibility modifier
+/// Rust: has `pub` visibility modifier (`#[macro_export]` for macros)
 pub fn rust_is_exported(node: &Node, source: &str) -> bool {
+    // macro_rules! has no visibility; #[macro_export] makes it public
+    if node.kind() == "macro_definition" {
+        let mut prev = node.prev_sibling();
+        while let Some(sibling) = prev.filter(|s| s.kind() == "attribute_item") {
+            let text = sibling.utf8_text(source.as_bytes()).unwrap_or("");
+            if text.contains("macro_export") {
+                return true;
+            }
+            prev = sibling.prev_sibling();
+        }
+        return false;
+    }
+
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         if child.kind() == "visibility_modifier" {
@@ -269,6 +286,7 @@ pub static RUST_GRAMMAR: LangGrammar = LangGrammar {
     class_nodes: &["struct_item"],
     interface_nodes: &["trait_item"],
     enum_nodes: &["enum_item"],
+    macro_nodes: &["macro_definition"],
     control_flow_nodes: &[
         "if_expression",
         "match_expression",
@@ -291,7 +309,7 @@ pub static RUST_GRAMMAR: LangGrammar = LangGrammar {
         "match_expression",
         "closure_expression",
     ],
-    call_nodes: &["call_expression"],
+    call_nodes: &["call_expression", "struct_expression"],
     await_nodes: &["await_expression"],
     import_nodes: &["use_declaration"],
     name_field: "name",
@@ -312,6 +330,7 @@ pub static GO_GRAMMAR: LangGrammar = LangGrammar {
     class_nodes: &[],     // Go uses struct_type inside type_declaration
     interface_nodes: &[], // Go uses interface_type inside type_declaration
     enum_nodes: &[],
+    macro_nodes: &[],
     control_flow_nodes: &[
         "if_statement",
         "for_statement",
@@ -354,6 +373,7 @@ pub static JAVA_GRAMMAR: LangGrammar = LangGrammar {
     class_nodes: &["class_declaration"],
     interface_nodes: &["interface_declaration"],
     enum_nodes: &["enum_declaration"],
+    macro_nodes: &[],
     control_flow_nodes: &[
         "if_statement",
         "for_statement",
@@ -408,6 +428,7 @@ pub static CSHARP_GRAMMAR: LangGrammar = LangGrammar {
     ],
     interface_nodes: &["interface_declaration"],
     enum_nodes: &["enum_declaration"],
+    macro_nodes: &[],
     control_flow_nodes: &[
         "if_statement",
         "for_statement",
@@ -459,6 +480,7 @@ pub static PYTHON_GRAMMAR: LangGrammar = LangGrammar {
     class_nodes: &["class_definition"],
     interface_nodes: &[], // Python uses ABC, not interfaces
     enum_nodes: &[],      // Python enums are classes
+    macro_nodes: &[],
     control_flow_nodes: &[
         "if_statement",
         "for_statement",
@@ -481,7 +503,8 @@ pub static PYTHON_GRAMMAR: LangGrammar = LangGrammar {
     ],
     call_nodes: &["call"],
     await_nodes: &["await"],
-    import_nodes: &["import_statement", "import_from_statement"],
+    // `from x import y` is handled by the Python detector, name by name
+    import_nodes: &["import_statement"],
     name_field: "name",
     value_field: "right",
     type_field: "type",
@@ -505,6 +528,7 @@ pub static JAVASCRIPT_GRAMMAR: LangGrammar = LangGrammar {
     class_nodes: &["class_declaration", "class"],
     interface_nodes: &[], // JS doesn't have interfaces (TS does)
     enum_nodes: &[],
+    macro_nodes: &[],
     control_flow_nodes: &[
         "if_statement",
         "for_statement",
@@ -559,6 +583,7 @@ pub static TYPESCRIPT_GRAMMAR: LangGrammar = LangGrammar {
     class_nodes: &["class_declaration", "class"],
     interface_nodes: &["interface_declaration"],
     enum_nodes: &["enum_declaration"],
+    macro_nodes: &[],
     control_flow_nodes: &[
         "if_statement",
         "for_statement",
@@ -612,6 +637,7 @@ pub static C_GRAMMAR: LangGrammar = LangGrammar {
     class_nodes: &["struct_specifier"],
     interface_nodes: &[],
     enum_nodes: &["enum_specifier"],
+    macro_nodes: &["preproc_function_def"],
     control_flow_nodes: &[
         "if_statement",
         "for_statement",
@@ -622,7 +648,7 @@ pub static C_GRAMMAR: LangGrammar = LangGrammar {
     try_nodes: &[], // C doesn't have try/catch
     var_declaration_nodes: &["declaration"],
     assignment_nodes: &["assignment_expression"],
-    module_var_nodes: &["declaration"], // File-scope declarations
+    module_var_nodes: &["declaration", "preproc_def"], // File-scope declarations, #define constants
     field_nodes: &["field_declaration"],
     local_scope_nodes: &[
         "function_definition",
@@ -653,6 +679,7 @@ pub static CPP_GRAMMAR: LangGrammar = LangGrammar {
     class_nodes: &["struct_specifier", "class_specifier"],
     interface_nodes: &[], // C++ uses abstract classes
     enum_nodes: &["enum_specifier"],
+    macro_nodes: &["preproc_function_def"],
     control_flow_nodes: &[
         "if_statement",
         "for_statement",
@@ -664,7 +691,7 @@ pub static CPP_GRAMMAR: LangGrammar = LangGrammar {
     try_nodes: &["try_statement"],
     var_declaration_nodes: &["declaration"],
     assignment_nodes: &["assignment_expression"],
-    module_var_nodes: &["declaration"], // Namespace/file scope
+    module_var_nodes: &["declaration", "preproc_def"], // Namespace/file scope, #define constants
     field_nodes: &["field_declaration"],
     local_scope_nodes: &[
         "function_definition",
@@ -709,6 +736,7 @@ pub static KOTLIN_GRAMMAR: LangGrammar = LangGrammar {
     class_nodes: &["class_declaration", "object_declaration"],
     interface_nodes: &["interface_declaration"],
     enum_nodes: &["enum_class_body"],
+    macro_nodes: &[],
     control_flow_nodes: &[
         "if_expression",
         "when_expression",
@@ -756,6 +784,7 @@ pub static BASH_GRAMMAR: LangGrammar = LangGrammar {
     class_nodes: &[],
     interface_nodes: &[],
     enum_nodes: &[],
+    macro_nodes: &[],
     control_flow_nodes: &[
         "if_statement",
         "case_statement",
@@ -803,6 +832,7 @@ pub static GRADLE_GRAMMAR: LangGrammar = LangGrammar {
     class_nodes: &["class_definition"],
     interface_nodes: &["interface_definition"],
     enum_nodes: &["enum_definition"],
+    macro_nodes: &[],
     control_flow_nodes: &[
         "if_statement",
         "for_statement",
@@ -857,6 +887,7 @@ pub static HCL_GRAMMAR: LangGrammar = LangGrammar {
     class_nodes: &[],
     interface_nodes: &[],
     enum_nodes: &[],
+    macro_nodes: &[],
     control_flow_nodes: &[
         // HCL uses expressions for conditionals
         "conditional",
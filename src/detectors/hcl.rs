@@ -177,6 +177,7 @@ fn extract_block_symbol(node: &Node, source: &str) -> Option<SymbolInfo> {
         arguments: Vec::new(),
         props: Vec::new(),
         return_type: None,
+        type_params: Vec::new(),
         calls: Vec::new(),
         control_flow: Vec::new(),
         state_changes: Vec::new(),
@@ -186,6 +187,17 @@ fn extract_block_symbol(node: &Node, source: &str) -> Option<SymbolInfo> {
         framework_entry_point: FrameworkEntryPoint::None,
         is_async: false,
         base_classes: Vec::new(),
+        is_recursive: false,
+        nested_functions: 0,
+        assertion_count: None,
+        overloaded: false,
+        is_stub: false,
+        injected_deps: Vec::new(),
+        naming_violation: None,
+        feature_envy: None,
+        patterns: Vec::new(),
+        is_stub_impl: false,
+        returns_description: None,
     })
 }
 
@@ -256,6 +268,8 @@ fn extract_function_call(node: &Node, source: &str) -> Option<Call> {
                 is_hook: false,
                 is_io: false,
                 ref_kind: RefKind::None,
+                is_constructor: false,
+                instantiated_type: None,
                 location: crate::schema::Location {
                     line: node.start_position().row + 1,
                     column: node.start_position().column,
@@ -3,13 +3,34 @@
 //! Extracts semantic information from Java source files using the generic extractor.
 //! Java's class/interface/enum declarations are first-class AST nodes, so the generic
 //! extractor handles them well.
+//!
+//! Spring dependency injection is layered on top: constructor parameters of
+//! stereotype-annotated classes and `@Autowired`/`@Inject` fields are recorded
+//! as the class's injected dependencies. Android `Activity`/`Fragment`
+//! lifecycle callbacks are noted in the insertions.
 
-use tree_sitter::Tree;
+use tree_sitter::{Node, Tree};
 
+use crate::detectors::common::{
+    get_node_text, note_lifecycle_methods, visit_all, ANDROID_LIFECYCLE,
+};
 use crate::detectors::generic::extract_with_grammar;
 use crate::detectors::grammar::JAVA_GRAMMAR;
 use crate::error::Result;
-use crate::schema::SemanticSummary;
+use crate::schema::{SemanticSummary, SymbolKind};
+
+/// Spring stereotypes whose instances the container constructs
+const SPRING_COMPONENT_ANNOTATIONS: &[&str] = &[
+    "Component",
+    "Service",
+    "Repository",
+    "Controller",
+    "RestController",
+    "Configuration",
+];
+
+/// Annotations that request injection on a field or constructor
+const INJECT_ANNOTATIONS: &[&str] = &["Autowired", "Inject"];
 
 /// Extract semantic information from a Java source file
 pub fn extract(summary: &mut SemanticSummary, source: &str, tree: &Tree) -> Result<()> {
@@ -20,5 +41,88 @@ pub fn extract(summary: &mut SemanticSummary, source: &str, tree: &Tree) -> Resu
     // - Control flow: if, for, enhanced_for, while, do, switch, try
     // - Calls: method_invocation
     // - Risk calculation
-    extract_with_grammar(summary, source, tree, &JAVA_GRAMMAR)
+    extract_with_grammar(summary, source, tree, &JAVA_GRAMMAR)?;
+
+    extract_injected_deps(summary, &tree.root_node(), source);
+    note_lifecycle_methods(summary, &tree.root_node(), source, &ANDROID_LIFECYCLE);
+    Ok(())
+}
+
+/// Record Spring-injected dependencies on each class symbol
+fn extract_injected_deps(summary: &mut SemanticSummary, root: &Node, source: &str) {
+    let mut injections: Vec<(String, Vec<String>)> = Vec::new();
+    visit_all(root, |node| {
+        if node.kind() != "class_declaration" {
+            return;
+        }
+        let (Some(name), Some(body)) = (
+            node.child_by_field_name("name"),
+            node.child_by_field_name("body"),
+        ) else {
+            return;
+        };
+        let managed = has_annotation(node, source, SPRING_COMPONENT_ANNOTATIONS);
+
+        let mut deps: Vec<String> = Vec::new();
+        let mut cursor = body.walk();
+        for member in body.named_children(&mut cursor) {
+            match member.kind() {
+                "constructor_declaration"
+                    if managed || has_annotation(&member, source, INJECT_ANNOTATIONS) =>
+                {
+                    let Some(params) = member.child_by_field_name("parameters") else {
+                        continue;
+                    };
+                    let mut inner = params.walk();
+                    for param in params.named_children(&mut inner) {
+                        if let Some(ty) = param.child_by_field_name("type") {
+                            deps.push(get_node_text(&ty, source));
+                        }
+                    }
+                }
+                "field_declaration" if has_annotation(&member, source, INJECT_ANNOTATIONS) => {
+                    if let Some(ty) = member.child_by_field_name("type") {
+                        deps.push(get_node_text(&ty, source));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if !deps.is_empty() {
+            injections.push((get_node_text(&name, source), deps));
+        }
+    });
+
+    for (class_name, deps) in injections {
+        if let Some(symbol) = summary
+            .symbols
+            .iter_mut()
+            .find(|s| s.kind == SymbolKind::Class && s.name == class_name)
+        {
+            symbol.injected_deps = deps;
+        }
+    }
+}
+
+/// Whether a declaration's modifiers carry one of `names` (e.g. `@Service`)
+fn has_annotation(node: &Node, source: &str, names: &[&str]) -> bool {
+    let mut cursor = node.walk();
+    let Some(modifiers) = node.children(&mut cursor).find(|c| c.kind() == "modifiers") else {
+        return false;
+    };
+
+    let mut inner = modifiers.walk();
+    let found = modifiers.named_children(&mut inner).any(|annotation| {
+        matches!(annotation.kind(), "marker_annotation" | "annotation")
+            && annotation
+                .child_by_field_name("name")
+                .map(|n| get_node_text(&n, source))
+                .is_some_and(|name| {
+                    // `@org.springframework.stereotype.Service` -> `Service`
+                    let simple = name.rsplit('.').next().unwrap_or(&name);
+                    names.contains(&simple)
+                })
+    });
+    found
 }
@@ -7,14 +7,23 @@
 use tree_sitter::Node;
 
 use crate::detectors::common::{
-    find_containing_symbol_by_line, get_node_text, visit_all, visit_with_nesting_depth,
+    count_nested_functions, count_test_assertions, expression_complexity, find_bare_rethrows,
+    find_broad_catches, find_containing_symbol_by_line, find_import_side_effects,
+    find_inconsistent_returns, find_long_member_chains, find_loop_string_concats,
+    find_missing_default_branches, find_mixed_async_styles, find_platform_branches,
+    find_positional_calls, find_sensitive_logs, find_string_literals, find_unawaited_async_calls,
+    find_unchecked_nullable_accesses, get_node_text, mark_overloaded_symbols,
+    mark_recursive_symbols, visit_all, visit_with_nesting_depth, MAX_MEMBER_CHAIN_DEPTH,
+    MIN_POSITIONAL_ARGS,
 };
 use crate::error::Result;
 use crate::lang::Lang;
 use crate::schema::{
-    Argument, Call, ControlFlowChange, ControlFlowKind, FrameworkEntryPoint, Location, Prop,
-    RefKind, RiskLevel, SemanticSummary, SymbolInfo, SymbolKind,
+    Argument, Call, ControlFlowChange, ControlFlowKind, DuplicateImport, DuplicateImportKind,
+    FrameworkEntryPoint, Location, Prop, RefKind, RiskLevel, SemanticSummary, SymbolInfo,
+    SymbolKind,
 };
+use crate::search::is_test_file;
 use crate::toon::is_meaningful_call;
 
 // =============================================================================
@@ -37,14 +46,53 @@ pub fn extract_core(
     // Extract symbols (functions, classes, exports)
     find_primary_symbol(summary, root, source, lang);
 
+    // Jest/Vitest/Mocha test cases (`test("...", () => {})`)
+    extract_test_cases(summary, root, source);
+
+    // TS overloads and same-name redefinitions
+    mark_overloads(summary, root, source);
+
+    // Constructor injection (NestJS, Angular)
+    extract_injected_deps(summary, root, source);
+
     // Extract imports
     extract_imports(summary, root, source);
 
+    // Code that runs on import; test files call their framework at the top level
+    if !is_test_file(&summary.file) {
+        summary.import_side_effects = find_import_side_effects(root, source);
+    }
+
     // Extract control flow
     extract_control_flow(summary, root);
+    summary.missing_default_branches = find_missing_default_branches(root, source);
 
     // Extract function calls
     extract_calls(summary, root, source);
+    summary.unawaited_async_calls = find_unawaited_async_calls(root, source, &summary.symbols);
+    summary.mixed_async_styles = find_mixed_async_styles(root, source);
+    summary.unchecked_nullable_accesses = find_unchecked_nullable_accesses(root, source);
+    summary.long_member_chains = find_long_member_chains(root, MAX_MEMBER_CHAIN_DEPTH);
+    summary.loop_string_concats = find_loop_string_concats(root, source);
+    summary.expression_complexity = expression_complexity(root);
+    // TypeScript checks return types itself
+    if matches!(lang, Lang::JavaScript | Lang::Jsx) {
+        summary.inconsistent_returns = find_inconsistent_returns(root, source);
+    }
+    // Many positional arguments where an options object would name them
+    summary.positional_calls = find_positional_calls(root, source, MIN_POSITIONAL_ARGS);
+    summary.platform_branches = find_platform_branches(root, source);
+    summary.bare_rethrows = find_bare_rethrows(root, source);
+    summary.broad_catches = find_broad_catches(root, source);
+    summary.sensitive_logs = find_sensitive_logs(root, source);
+    summary.string_literals = find_string_literals(root, source);
+
+    // Recursion and nested functions (cognitive complexity increments and nesting)
+    mark_recursive_symbols(&mut summary.symbols);
+    count_nested_functions(root, summary, JS_FUNCTION_KINDS);
+
+    // Assertions per test case
+    count_test_assertions(root, source, &mut summary.symbols);
 
     Ok(())
 }
@@ -114,6 +162,7 @@ fn find_primary_symbol(summary: &mut SemanticSummary, root: &Node, source: &str,
                 } else {
                     None
                 },
+                type_params: Vec::new(),
                 calls: Vec::new(),
                 control_flow: Vec::new(),
                 state_changes: Vec::new(),
@@ -123,6 +172,17 @@ fn find_primary_symbol(summary: &mut SemanticSummary, root: &Node, source: &str,
                 framework_entry_point: FrameworkEntryPoint::None,
                 is_async: candidate.is_async,
                 base_classes: candidate.extends_classes.clone(),
+                is_recursive: false,
+                nested_functions: 0,
+                assertion_count: None,
+                overloaded: false,
+                is_stub: false,
+                injected_deps: Vec::new(),
+                naming_violation: None,
+                feature_envy: None,
+                patterns: Vec::new(),
+                is_stub_impl: false,
+                returns_description: None,
             };
 
             summary.symbols.push(symbol_info);
@@ -262,7 +322,12 @@ fn collect_symbol_candidates(
                     }
                 }
             }
-            "function_declaration" | "class_declaration" | "lexical_declaration" => {
+            "function_declaration"
+            | "class_declaration"
+            | "lexical_declaration"
+            | "interface_declaration"
+            | "type_alias_declaration"
+            | "enum_declaration" => {
                 if let Some(mut candidate) =
                     extract_candidate_from_declaration(&child, source, filename_stem, lang)
                 {
@@ -345,6 +410,8 @@ fn extract_commonjs_export(
                             name: get_node_text(&param, source),
                             arg_type: None,
                             default_value: None,
+                            is_optional: false,
+                            description: None,
                         });
                     }
                     let jsx = lang.supports_jsx() && returns_jsx(&right);
@@ -545,7 +612,7 @@ fn extract_candidate_from_declaration(
                 returns_jsx: false,
                 start_line: node.start_position().row + 1,
                 end_line: node.end_position().row + 1,
-                arguments: Vec::new(),
+                arguments: constructor_parameters(node, source),
                 props: Vec::new(),
                 score: 0,
                 is_async: false,
@@ -572,6 +639,8 @@ fn extract_candidate_from_declaration(
                                 name: get_node_text(&param, source),
                                 arg_type: None,
                                 default_value: None,
+                                is_optional: false,
+                                description: None,
                             });
                         }
 
@@ -637,10 +706,76 @@ fn extract_candidate_from_declaration(
             }
             None
         }
+        // TypeScript: `interface User {...}`, `type Props = {...}`, `enum Role {...}`
+        "interface_declaration" | "type_alias_declaration" | "enum_declaration" => {
+            let name_node = node.child_by_field_name("name")?;
+            let kind = match node.kind() {
+                "interface_declaration" => SymbolKind::Interface,
+                "type_alias_declaration" => SymbolKind::TypeAlias,
+                _ => SymbolKind::Enum,
+            };
+            // Object-shaped interfaces and aliases expose their members as props
+            let props = node
+                .child_by_field_name("body")
+                .or_else(|| node.child_by_field_name("value"))
+                .filter(|body| matches!(body.kind(), "interface_body" | "object_type"))
+                .map(|body| extract_member_signatures(&body, source))
+                .unwrap_or_default();
+
+            Some(SymbolCandidate {
+                name: get_node_text(&name_node, source),
+                kind,
+                start_line: node.start_position().row + 1,
+                end_line: node.end_position().row + 1,
+                props,
+                ..Default::default()
+            })
+        }
         _ => None,
     }
 }
 
+/// Members of an interface body or object type as props
+///
+/// `name?: T` gives an optional prop of type `T`; a method signature
+/// `find(id: string): User` gives a prop typed `(id: string): User`.
+fn extract_member_signatures(body: &Node, source: &str) -> Vec<Prop> {
+    let mut props = Vec::new();
+    let mut cursor = body.walk();
+    for member in body.named_children(&mut cursor) {
+        let Some(name_node) = member.child_by_field_name("name") else {
+            continue;
+        };
+        let prop_type = match member.kind() {
+            // The type annotation node includes its leading `:`
+            "property_signature" => member.child_by_field_name("type").map(|n| {
+                get_node_text(&n, source)
+                    .trim_start_matches(':')
+                    .trim()
+                    .to_string()
+            }),
+            "method_signature" => Some(
+                source[name_node.end_byte()..member.end_byte()]
+                    .trim_start_matches('?')
+                    .trim()
+                    .to_string(),
+            ),
+            _ => continue,
+        };
+        let mut member_cursor = member.walk();
+        let is_optional = member
+            .children(&mut member_cursor)
+            .any(|child| child.kind() == "?");
+        props.push(Prop {
+            name: get_node_text(&name_node, source),
+            prop_type,
+            default_value: None,
+            required: !is_optional,
+        });
+    }
+    props
+}
+
 /// Calculate symbol score for prioritization
 fn calculate_symbol_score(candidate: &SymbolCandidate, filename_stem: &str) -> i32 {
     let mut score = 0;
@@ -734,6 +869,25 @@ fn contains_node_kind(node: &Node, kind: &str) -> bool {
     false
 }
 
+/// Parameters of a class's `constructor`, used as the class's init signature
+fn constructor_parameters(class: &Node, source: &str) -> Vec<Argument> {
+    let mut arguments = Vec::new();
+    let Some(body) = class.child_by_field_name("body") else {
+        return arguments;
+    };
+    let mut cursor = body.walk();
+    let constructor = body.named_children(&mut cursor).find(|member| {
+        member.kind() == "method_definition"
+            && member
+                .child_by_field_name("name")
+                .is_some_and(|n| get_node_text(&n, source) == "constructor")
+    });
+    if let Some(params) = constructor.and_then(|c| c.child_by_field_name("parameters")) {
+        extract_parameters(&params, source, &mut arguments, &mut Vec::new());
+    }
+    arguments
+}
+
 /// Extract function parameters
 pub fn extract_parameters(
     params: &Node,
@@ -749,20 +903,33 @@ pub fn extract_parameters(
                     name: get_node_text(&child, source),
                     arg_type: None,
                     default_value: None,
+                    is_optional: false,
+                    description: None,
                 });
             }
+            // TypeScript: `x: T`, `x?: T`, or `x: T = 1`
             "required_parameter" | "optional_parameter" => {
                 let name = child
                     .child_by_field_name("pattern")
                     .map(|n| get_node_text(&n, source))
                     .unwrap_or_default();
-                let arg_type = child
-                    .child_by_field_name("type")
+                // The type annotation node includes its leading `:`
+                let arg_type = child.child_by_field_name("type").map(|n| {
+                    get_node_text(&n, source)
+                        .trim_start_matches(':')
+                        .trim()
+                        .to_string()
+                });
+                let default_value = child
+                    .child_by_field_name("value")
                     .map(|n| get_node_text(&n, source));
+                let is_optional = child.kind() == "optional_parameter" || default_value.is_some();
                 arguments.push(Argument {
                     name,
                     arg_type,
-                    default_value: None,
+                    default_value,
+                    is_optional,
+                    description: None,
                 });
             }
             "assignment_pattern" => {
@@ -775,6 +942,8 @@ pub fn extract_parameters(
                         name,
                         arg_type: None,
                         default_value,
+                        is_optional: true,
+                        description: None,
                     });
                 }
             }
@@ -819,6 +988,276 @@ pub fn extract_object_pattern_as_props(pattern: &Node, source: &str, props: &mut
     }
 }
 
+// =============================================================================
+// Test Case Detection
+// =============================================================================
+
+/// Test-case functions (`test(...)`, `it(...)`) and their modifiers (`it.only(...)`)
+const TEST_CASE_CALLEES: &[&str] = &["test", "it"];
+const TEST_CASE_MODIFIERS: &[&str] = &["only", "skip", "concurrent"];
+
+/// Add a test symbol for each `test("name", () => {...})` / `it(...)` call
+///
+/// Test cases are anonymous callbacks, so without this they would have no
+/// symbol to carry calls, control flow or assertion counts.
+fn extract_test_cases(summary: &mut SemanticSummary, root: &Node, source: &str) {
+    let mut tests = Vec::new();
+
+    visit_all(root, |node| {
+        if node.kind() != "call_expression" {
+            return;
+        }
+        let Some(func) = node.child_by_field_name("function") else {
+            return;
+        };
+        let (name, object) = extract_call_name(&func, source);
+        let is_test_call = match object.as_deref() {
+            None => TEST_CASE_CALLEES.contains(&name.as_str()),
+            Some(obj) => {
+                TEST_CASE_CALLEES.contains(&obj) && TEST_CASE_MODIFIERS.contains(&name.as_str())
+            }
+        };
+        if !is_test_call {
+            return;
+        }
+
+        let Some(args) = node.child_by_field_name("arguments") else {
+            return;
+        };
+        let mut cursor = args.walk();
+        let named: Vec<Node> = args.named_children(&mut cursor).collect();
+        let (Some(title), Some(callback)) = (named.first(), named.get(1)) else {
+            return;
+        };
+        if !matches!(title.kind(), "string" | "template_string")
+            || !matches!(callback.kind(), "arrow_function" | "function_expression")
+        {
+            return;
+        }
+
+        let title = get_node_text(title, source);
+        tests.push(SymbolInfo {
+            name: title
+                .trim_matches(|c| c == '"' || c == '\'' || c == '`')
+                .to_string(),
+            kind: SymbolKind::Function,
+            start_line: node.start_position().row + 1,
+            end_line: node.end_position().row + 1,
+            is_async: crate::detectors::generic::is_async_node(callback),
+            framework_entry_point: FrameworkEntryPoint::TestFunction,
+            ..Default::default()
+        });
+    });
+
+    summary.symbols.extend(tests);
+}
+
+// =============================================================================
+// Overload Detection
+// =============================================================================
+
+/// Merge TS overload signatures into their implementation and flag name clashes
+///
+/// Overload signatures (`function parse(x: string): T;`) never become symbols,
+/// so the implementation is the single merged symbol and is marked overloaded.
+/// Remaining same-name symbols are accidental redefinitions.
+fn mark_overloads(summary: &mut SemanticSummary, root: &Node, source: &str) {
+    let mut overloaded: Vec<String> = Vec::new();
+    visit_all(root, |node| {
+        if node.kind() == "function_signature" {
+            if let Some(name) = node.child_by_field_name("name") {
+                overloaded.push(get_node_text(&name, source));
+            }
+        }
+    });
+
+    for symbol in &mut summary.symbols {
+        if symbol.kind == SymbolKind::Function && overloaded.contains(&symbol.name) {
+            symbol.overloaded = true;
+        }
+    }
+
+    mark_overloaded_symbols(&mut summary.symbols);
+}
+
+// =============================================================================
+// Dependency Injection
+// =============================================================================
+
+/// Class decorators that hand construction over to a DI container
+const DI_CLASS_DECORATORS: &[&str] = &[
+    "Injectable",
+    "Controller",
+    "Module",
+    "Resolver",
+    "WebSocketGateway",
+    "Component",
+    "Directive",
+    "Pipe",
+    "NgModule",
+];
+
+/// Types a container can't resolve by class, so they need an explicit `@Inject`
+const NON_INJECTABLE_TYPES: &[&str] = &[
+    "string", "number", "boolean", "any", "unknown", "object", "symbol", "bigint",
+];
+
+/// Record injected dependencies on DI-managed classes
+///
+/// Constructor parameters of a class decorated with `@Injectable()`,
+/// `@Controller()`, `@Component()` etc. are resolved by type; a parameter
+/// decorator `@Inject(TOKEN)` overrides that with the token. Angular's
+/// `field = inject(Service)` initializers are recorded too.
+fn extract_injected_deps(summary: &mut SemanticSummary, root: &Node, source: &str) {
+    let mut injections: Vec<(String, Vec<String>)> = Vec::new();
+    visit_all(root, |node| {
+        if !matches!(node.kind(), "class_declaration" | "class") {
+            return;
+        }
+        let (Some(name), Some(body)) = (
+            node.child_by_field_name("name"),
+            node.child_by_field_name("body"),
+        ) else {
+            return;
+        };
+
+        // Decorators of exported classes hang off the export statement
+        let mut decorators = decorator_names(node, source);
+        if let Some(parent) = node.parent().filter(|p| p.kind() == "export_statement") {
+            decorators.extend(decorator_names(&parent, source));
+        }
+        let managed = decorators
+            .iter()
+            .any(|d| DI_CLASS_DECORATORS.contains(&d.as_str()));
+
+        let mut deps: Vec<String> = Vec::new();
+        let mut cursor = body.walk();
+        for member in body.named_children(&mut cursor) {
+            match member.kind() {
+                "method_definition" => {
+                    let is_constructor = member
+                        .child_by_field_name("name")
+                        .is_some_and(|n| get_node_text(&n, source) == "constructor");
+                    if let Some(params) = member
+                        .child_by_field_name("parameters")
+                        .filter(|_| is_constructor)
+                    {
+                        deps.extend(constructor_injections(&params, source, managed));
+                    }
+                }
+                "public_field_definition" => {
+                    if let Some(dep) = member
+                        .child_by_field_name("value")
+                        .and_then(|value| inject_call_token(&value, source))
+                    {
+                        deps.push(dep);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if !deps.is_empty() {
+            injections.push((get_node_text(&name, source), deps));
+        }
+    });
+
+    for (class_name, deps) in injections {
+        if let Some(symbol) = summary
+            .symbols
+            .iter_mut()
+            .find(|s| s.kind == SymbolKind::Class && s.name == class_name)
+        {
+            symbol.injected_deps = deps;
+        }
+    }
+}
+
+/// Dependencies injected through constructor parameters
+///
+/// Unmanaged classes only contribute parameters with an explicit `@Inject`.
+fn constructor_injections(params: &Node, source: &str, managed: bool) -> Vec<String> {
+    let mut deps = Vec::new();
+    let mut cursor = params.walk();
+    for param in params.named_children(&mut cursor) {
+        if !matches!(param.kind(), "required_parameter" | "optional_parameter") {
+            continue;
+        }
+
+        let mut token = None;
+        let mut inner = param.walk();
+        for decorator in param
+            .children(&mut inner)
+            .filter(|c| c.kind() == "decorator")
+        {
+            if let Some(call) = decorator
+                .named_child(0)
+                .filter(|c| c.kind() == "call_expression")
+            {
+                if let Some(dep) = inject_call_token(&call, source) {
+                    token = Some(dep);
+                }
+            }
+        }
+
+        let param_type = param
+            .child_by_field_name("type")
+            .and_then(|t| t.named_child(0))
+            .map(|t| get_node_text(&t, source))
+            .filter(|t| !NON_INJECTABLE_TYPES.contains(&t.as_str()));
+
+        match (token, param_type) {
+            (Some(token), _) => deps.push(token),
+            (None, Some(param_type)) if managed => deps.push(param_type),
+            _ => {}
+        }
+    }
+    deps
+}
+
+/// Token of an `inject(X)` / `Inject(X)` call, e.g. `UsersRepository`
+fn inject_call_token(node: &Node, source: &str) -> Option<String> {
+    if node.kind() != "call_expression" {
+        return None;
+    }
+    let function = node.child_by_field_name("function")?;
+    if !matches!(
+        get_node_text(&function, source).as_str(),
+        "inject" | "Inject"
+    ) {
+        return None;
+    }
+    let args = node.child_by_field_name("arguments")?;
+    let token = args.named_child(0)?;
+    Some(
+        get_node_text(&token, source)
+            .trim_matches(|c| c == '"' || c == '\'' || c == '`')
+            .to_string(),
+    )
+}
+
+/// Names of the decorators directly on `node`, e.g. `Injectable` for `@Injectable()`
+fn decorator_names(node: &Node, source: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut cursor = node.walk();
+    for decorator in node
+        .children(&mut cursor)
+        .filter(|c| c.kind() == "decorator")
+    {
+        let text = get_node_text(&decorator, source);
+        let name = text
+            .trim_start_matches('@')
+            .split('(')
+            .next()
+            .unwrap_or_default()
+            .trim();
+        // `@core.Injectable()` -> `Injectable`
+        let name = name.rsplit('.').next().unwrap_or(name);
+        names.push(name.to_string());
+    }
+    names
+}
+
 // =============================================================================
 // Import Extraction
 // =============================================================================
@@ -849,6 +1288,8 @@ pub fn extract_imports(summary: &mut SemanticSummary, root: &Node, source: &str)
             }
         }
     }
+
+    summary.duplicate_imports = find_duplicate_imports(root, source);
 }
 
 /// Check if an import path is local (starts with . or ..)
@@ -938,6 +1379,132 @@ fn normalize_package_name(module: &str) -> String {
     module.to_string()
 }
 
+/// Find imports that repeat an earlier one in the file
+///
+/// A name is a duplicate when the same export of a module is imported again
+/// (even under another alias) or the same local binding is reused. A
+/// namespace import is redundant when the module's names are also imported
+/// individually. Type-only and side-effect imports are ignored.
+fn find_duplicate_imports(root: &Node, source: &str) -> Vec<DuplicateImport> {
+    use std::collections::{HashMap, HashSet};
+
+    let mut duplicates = Vec::new();
+    let mut seen_exports: HashSet<(String, String)> = HashSet::new();
+    let mut seen_locals: HashSet<String> = HashSet::new();
+    // module -> namespace alias
+    let mut namespaces: HashMap<String, String> = HashMap::new();
+    let mut named_modules: HashSet<String> = HashSet::new();
+    let mut flagged_namespaces: HashSet<String> = HashSet::new();
+
+    let mut cursor = root.walk();
+    for import in root.children(&mut cursor) {
+        if import.kind() != "import_statement" || is_type_only_import(&import) {
+            continue;
+        }
+        let Some(clause) = import.child_by_field_name("source") else {
+            continue;
+        };
+        let module = get_node_text(&clause, source);
+        let module = module.trim_matches('"').trim_matches('\'').to_string();
+        let location = Location::new(
+            import.start_position().row + 1,
+            import.start_position().column,
+        );
+
+        for (imported, local) in import_bindings(&import, source) {
+            match imported.as_str() {
+                "*" => {
+                    namespaces.entry(module.clone()).or_insert(local.clone());
+                }
+                "default" => {}
+                _ => {
+                    named_modules.insert(module.clone());
+                }
+            }
+            if let Some(alias) = namespaces.get(&module) {
+                if named_modules.contains(&module) && flagged_namespaces.insert(module.clone()) {
+                    duplicates.push(DuplicateImport {
+                        name: alias.clone(),
+                        source: module.clone(),
+                        kind: DuplicateImportKind::RedundantNamespace,
+                        location,
+                    });
+                }
+            }
+
+            let new_export = seen_exports.insert((module.clone(), imported.clone()));
+            let new_local = seen_locals.insert(local.clone());
+            if !new_export || !new_local {
+                duplicates.push(DuplicateImport {
+                    name: local,
+                    source: module.clone(),
+                    kind: DuplicateImportKind::Duplicate,
+                    location,
+                });
+            }
+        }
+    }
+
+    duplicates
+}
+
+/// (imported name, local binding) pairs of an import statement
+///
+/// Default imports use `default` and namespace imports `*` as the imported name.
+fn import_bindings(import: &Node, source: &str) -> Vec<(String, String)> {
+    let mut bindings = Vec::new();
+    let mut cursor = import.walk();
+    for clause in import.children(&mut cursor) {
+        if clause.kind() != "import_clause" {
+            continue;
+        }
+        let mut inner_cursor = clause.walk();
+        for inner in clause.children(&mut inner_cursor) {
+            match inner.kind() {
+                "identifier" => {
+                    bindings.push(("default".to_string(), get_node_text(&inner, source)));
+                }
+                "namespace_import" => {
+                    let mut ns_cursor = inner.walk();
+                    let alias = inner
+                        .named_children(&mut ns_cursor)
+                        .find(|c| c.kind() == "identifier");
+                    if let Some(alias) = alias {
+                        bindings.push(("*".to_string(), get_node_text(&alias, source)));
+                    }
+                }
+                "named_imports" => {
+                    let mut named_cursor = inner.walk();
+                    for specifier in inner.named_children(&mut named_cursor) {
+                        if specifier.kind() != "import_specifier" || is_type_only_import(&specifier)
+                        {
+                            continue;
+                        }
+                        let Some(name) = specifier.child_by_field_name("name") else {
+                            continue;
+                        };
+                        let imported = get_node_text(&name, source);
+                        let local = specifier
+                            .child_by_field_name("alias")
+                            .map(|a| get_node_text(&a, source))
+                            .unwrap_or_else(|| imported.clone());
+                        bindings.push((imported, local));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    bindings
+}
+
+/// Whether an import statement or specifier is `import type` (TypeScript)
+fn is_type_only_import(node: &Node) -> bool {
+    let mut cursor = node.walk();
+    let is_type = node.children(&mut cursor).any(|c| c.kind() == "type");
+    is_type
+}
+
 // =============================================================================
 // Control Flow Extraction
 // =============================================================================
@@ -952,6 +1519,15 @@ const JS_CONTROL_FLOW_KINDS: &[&str] = &[
     "try_statement",
 ];
 
+/// JavaScript node kinds that introduce a function body
+const JS_FUNCTION_KINDS: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "arrow_function",
+    "method_definition",
+    "generator_function_declaration",
+];
+
 /// Extract control flow patterns with nesting depth for cognitive complexity
 /// and attribute them to symbols based on line ranges
 pub fn extract_control_flow(summary: &mut SemanticSummary, root: &Node) {
@@ -1060,9 +1636,38 @@ pub fn extract_calls(summary: &mut SemanticSummary, root: &Node, source: &str) {
                     is_hook: false,
                     is_io,
                     ref_kind: RefKind::None,
+                    is_constructor: false,
+                    instantiated_type: None,
                     location: Location::new(line, node.start_position().column),
                 };
 
+                all_calls.push((call, line));
+            }
+        } else if node.kind() == "new_expression" {
+            // `new Foo()` / `new ns.Foo<T>()` instantiates `Foo`
+            if let Some(constructor) = node.child_by_field_name("constructor") {
+                let (name, object) = extract_call_name(&constructor, source);
+                if name.is_empty() {
+                    return;
+                }
+
+                let node_start = node.start_byte();
+                let line = node.start_position().row + 1;
+                let call = Call {
+                    instantiated_type: Some(name.clone()),
+                    name,
+                    object,
+                    is_awaited: node
+                        .parent()
+                        .is_some_and(|p| p.kind() == "await_expression"),
+                    in_try: try_ranges
+                        .iter()
+                        .any(|(start, end)| node_start >= *start && node_start < *end),
+                    is_constructor: true,
+                    location: Location::new(line, node.start_position().column),
+                    ..Default::default()
+                };
+
                 all_calls.push((call, line));
             }
         }
@@ -1161,6 +1766,37 @@ mod tests {
         assert_eq!(to_pascal_case("button"), "Button");
     }
 
+    #[test]
+    fn test_duplicate_imports() {
+        let source = r#"
+import { foo, bar } from "./utils";
+import { foo } from "./utils";
+import * as path from "path";
+import { join } from "path";
+import React from "react";
+
+export function run() {
+    return foo(bar, join(path.sep), React);
+}
+"#;
+        let tree = parse_source(source, Lang::JavaScript);
+        let path = PathBuf::from("/test/run.js");
+        let summary = extract(&path, source, &tree, Lang::JavaScript).unwrap();
+
+        let found: Vec<(&str, &str, DuplicateImportKind, usize)> = summary
+            .duplicate_imports
+            .iter()
+            .map(|d| (d.name.as_str(), d.source.as_str(), d.kind, d.location.line))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("foo", "./utils", DuplicateImportKind::Duplicate, 3),
+                ("path", "path", DuplicateImportKind::RedundantNamespace, 5),
+            ]
+        );
+    }
+
     // ==========================================================================
     // Call Attribution Tests
     // ==========================================================================
@@ -1297,6 +1933,131 @@ function processData(data: RawUser[]): User[] {
         assert!(has_awaited, "fetchUsers should have awaited calls");
     }
 
+    #[test]
+    fn test_typescript_optional_parameters() {
+        let source = r#"
+export function connect(host: string, port: number = 5432, tls?: boolean, retries = 3) {
+    return open(host, port, tls, retries);
+}
+"#;
+        let tree = parse_source(source, Lang::TypeScript);
+        let path = PathBuf::from("/test/db.ts");
+        let summary = extract(&path, source, &tree, Lang::TypeScript).unwrap();
+
+        let connect = summary
+            .symbols
+            .iter()
+            .find(|s| s.name == "connect")
+            .unwrap();
+        let args: Vec<(&str, Option<&str>, Option<&str>, bool)> = connect
+            .arguments
+            .iter()
+            .map(|a| {
+                (
+                    a.name.as_str(),
+                    a.arg_type.as_deref(),
+                    a.default_value.as_deref(),
+                    a.is_optional,
+                )
+            })
+            .collect();
+        assert_eq!(
+            args,
+            vec![
+                ("host", Some("string"), None, false),
+                ("port", Some("number"), Some("5432"), true),
+                ("tls", Some("boolean"), None, true),
+                ("retries", None, Some("3"), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_typescript_type_declarations() {
+        let source = r#"
+export interface User {
+    id: string;
+    email?: string;
+    rename(name: string): void;
+}
+
+export type Role = "admin" | "member";
+
+export enum Status {
+    Active,
+    Disabled,
+}
+"#;
+        let tree = parse_source(source, Lang::TypeScript);
+        let path = PathBuf::from("/test/user.ts");
+        let summary = extract(&path, source, &tree, Lang::TypeScript).unwrap();
+
+        assert_eq!(summary.symbol.as_deref(), Some("User"));
+        let kinds: Vec<(&str, SymbolKind)> = summary
+            .symbols
+            .iter()
+            .map(|s| (s.name.as_str(), s.kind))
+            .collect();
+        assert!(kinds.contains(&("User", SymbolKind::Interface)));
+        assert!(kinds.contains(&("Role", SymbolKind::TypeAlias)));
+        assert!(kinds.contains(&("Status", SymbolKind::Enum)));
+
+        let user = summary.symbols.iter().find(|s| s.name == "User").unwrap();
+        let props: Vec<(&str, Option<&str>, bool)> = user
+            .props
+            .iter()
+            .map(|p| (p.name.as_str(), p.prop_type.as_deref(), p.required))
+            .collect();
+        assert_eq!(
+            props,
+            vec![
+                ("id", Some("string"), true),
+                ("email", Some("string"), false),
+                ("rename", Some("(name: string): void"), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jsdoc_param_descriptions() {
+        let source = r#"
+/**
+ * Send a message to a channel.
+ *
+ * @param {string} channel - Channel to post in
+ * @param {{ text: string }} message - Message body,
+ *   trimmed before sending
+ * @param {number} [retries=3] How often to retry
+ * @returns {Promise<boolean>} Whether the message was delivered
+ */
+export async function send(channel, message, retries = 3) {
+    return post(channel, message.text.trim(), retries);
+}
+"#;
+        let tree = parse_source(source, Lang::JavaScript);
+        let path = PathBuf::from("/test/chat.js");
+        let summary = extract(&path, source, &tree, Lang::JavaScript).unwrap();
+
+        let send = summary.symbols.iter().find(|s| s.name == "send").unwrap();
+        let descriptions: Vec<(&str, Option<&str>)> = send
+            .arguments
+            .iter()
+            .map(|a| (a.name.as_str(), a.description.as_deref()))
+            .collect();
+        assert_eq!(
+            descriptions,
+            vec![
+                ("channel", Some("Channel to post in")),
+                ("message", Some("Message body, trimmed before sending")),
+                ("retries", Some("How often to retry")),
+            ]
+        );
+        assert_eq!(
+            send.returns_description.as_deref(),
+            Some("Whether the message was delivered")
+        );
+    }
+
     /// Test that Vue SFC methods have calls attributed to symbols
     #[test]
     fn test_vue_sfc_call_attribution() {
@@ -1575,4 +2336,285 @@ export const globalReducer = (state = initialState, action: GlobalActions) => {
             "globalReducer should have control flow (switch statement), but has none"
         );
     }
+
+    /// Test that switches without a `default` arm are flagged
+    #[test]
+    fn test_switch_missing_default() {
+        let source = r#"
+export function label(kind) {
+    switch (kind) {
+        case "a":
+            return "A";
+        case "b":
+            return "B";
+    }
+}
+
+export function labelOrUnknown(kind) {
+    switch (kind) {
+        case "a":
+            return "A";
+        default:
+            return "?";
+    }
+}
+"#;
+        let tree = parse_source(source, Lang::JavaScript);
+        let path = PathBuf::from("/test/labels.js");
+        let summary = extract(&path, source, &tree, Lang::JavaScript).unwrap();
+
+        let lines: Vec<usize> = summary
+            .missing_default_branches
+            .iter()
+            .map(|l| l.line)
+            .collect();
+        assert_eq!(lines, vec![3], "only the switch without default is flagged");
+    }
+
+    /// Test that NestJS constructor injection is recorded on the class
+    #[test]
+    fn test_injected_deps() {
+        let source = r#"
+import { Inject, Injectable } from '@nestjs/common';
+
+@Injectable()
+export class UsersService {
+    constructor(
+        private readonly usersRepository: UsersRepository,
+        @Inject(CACHE_MANAGER) private cache: Cache,
+        private readonly prefix: string,
+    ) {}
+
+    findAll() {
+        return this.usersRepository.find();
+    }
+}
+
+export class PlainHelper {
+    constructor(private readonly usersRepository: UsersRepository) {}
+}
+"#;
+        let tree = parse_source(source, Lang::TypeScript);
+        let path = PathBuf::from("/test/users.service.ts");
+        let summary = extract(&path, source, &tree, Lang::TypeScript).unwrap();
+
+        let deps = |name: &str| -> Vec<String> {
+            summary
+                .symbols
+                .iter()
+                .find(|s| s.name == name)
+                .map(|s| s.injected_deps.clone())
+                .unwrap_or_default()
+        };
+        assert_eq!(
+            deps("UsersService"),
+            vec!["UsersRepository", "CACHE_MANAGER"]
+        );
+        assert!(
+            deps("PlainHelper").is_empty(),
+            "undecorated classes aren't wired"
+        );
+    }
+
+    /// Test that a class takes its constructor's parameters as its signature
+    #[test]
+    fn test_constructor_parameters_on_class() {
+        let source = r#"
+export class UserService {
+    constructor(private readonly repo: UserRepo, limit = 10) {}
+
+    find(id: string) {
+        return this.repo.get(id);
+    }
+}
+"#;
+        let tree = parse_source(source, Lang::TypeScript);
+        let path = PathBuf::from("/test/user-service.ts");
+        let summary = extract(&path, source, &tree, Lang::TypeScript).unwrap();
+
+        let class = summary
+            .symbols
+            .iter()
+            .find(|s| s.name == "UserService")
+            .unwrap();
+        let args: Vec<(&str, Option<&str>, Option<&str>)> = class
+            .arguments
+            .iter()
+            .map(|a| {
+                (
+                    a.name.as_str(),
+                    a.arg_type.as_deref(),
+                    a.default_value.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            args,
+            vec![("repo", Some("UserRepo"), None), ("limit", None, Some("10"))]
+        );
+    }
+
+    #[test]
+    fn test_new_expression_is_constructor() {
+        let source = r#"
+export function connect(url) {
+    const client = new Client(url);
+    const pool = new db.Pool({ size: 4 });
+    client.start();
+    return pool;
+}
+"#;
+        let tree = parse_source(source, Lang::JavaScript);
+        let path = PathBuf::from("/test/connect.js");
+        let summary = extract(&path, source, &tree, Lang::JavaScript).unwrap();
+
+        let connect = summary
+            .symbols
+            .iter()
+            .find(|s| s.name == "connect")
+            .unwrap();
+        let constructed: Vec<Option<&str>> = connect
+            .calls
+            .iter()
+            .filter(|c| c.is_constructor)
+            .map(|c| c.instantiated_type.as_deref())
+            .collect();
+        assert_eq!(constructed, vec![Some("Client"), Some("Pool")]);
+        assert!(connect
+            .calls
+            .iter()
+            .filter(|c| c.name == "start")
+            .all(|c| !c.is_constructor));
+    }
+
+    /// Test that TS overloads merge and same-name redefinitions are flagged
+    #[test]
+    fn test_overloaded_and_shadowed_symbols() {
+        let source = r#"
+export function parse(input: string): Config;
+export function parse(input: Buffer): Config;
+export function parse(input: string | Buffer): Config {
+    return decode(input);
+}
+
+function format(config: Config): string {
+    return JSON.stringify(config);
+}
+
+function format(config: Config): string {
+    return yaml.dump(config);
+}
+
+export function validate(config: Config): boolean {
+    return true;
+}
+
+export class Loader {
+    load(path: string): Config;
+    load(path: string, fallback: Config): Config;
+    load(path: string, fallback?: Config): Config {
+        return fallback;
+    }
+}
+"#;
+        let tree = parse_source(source, Lang::TypeScript);
+        let path = PathBuf::from("/test/config.ts");
+        let summary = extract(&path, source, &tree, Lang::TypeScript).unwrap();
+
+        let overloaded = |name: &str| -> Vec<bool> {
+            summary
+                .symbols
+                .iter()
+                .filter(|s| s.name == name)
+                .map(|s| s.overloaded)
+                .collect()
+        };
+        assert_eq!(
+            overloaded("parse"),
+            vec![true],
+            "signatures merge into one symbol"
+        );
+        assert_eq!(overloaded("format"), vec![true, true]);
+        assert_eq!(overloaded("validate"), vec![false]);
+
+        let hashes: Vec<String> = summary
+            .symbols
+            .iter()
+            .filter(|s| s.name == "format")
+            .map(|s| s.to_symbol_id("config", &summary.file).hash)
+            .collect();
+        assert_ne!(hashes[0], hashes[1], "redefinitions get distinct hashes");
+    }
+
+    /// Test that calls to async functions without `await` are flagged
+    #[test]
+    fn test_unawaited_async_call() {
+        let source = r#"
+async function saveUser(user) {
+    await db.insert(user);
+}
+
+export async function register(user) {
+    saveUser(user);
+    await saveUser(user);
+    const pending = saveUser(user);
+    saveUser(user).catch(console.error);
+    return saveUser(user);
+}
+"#;
+        let tree = parse_source(source, Lang::JavaScript);
+        let path = PathBuf::from("/test/users.js");
+        let summary = extract(&path, source, &tree, Lang::JavaScript).unwrap();
+
+        let flagged: Vec<(&str, usize)> = summary
+            .unawaited_async_calls
+            .iter()
+            .map(|c| (c.name.as_str(), c.location.line))
+            .collect();
+        assert_eq!(
+            flagged,
+            vec![("saveUser", 7)],
+            "only the floating call is flagged"
+        );
+    }
+
+    /// Test that Jest test cases become symbols with assertion counts
+    #[test]
+    fn test_jest_assertion_counts() {
+        let source = r#"
+describe("math", () => {
+    test("adds numbers", () => {
+        expect(add(1, 2)).toBe(3);
+        expect(add(0, 0)).toBe(0);
+    });
+
+    it("does nothing", () => {
+        add(1, 1);
+    });
+});
+"#;
+        let tree = parse_source(source, Lang::JavaScript);
+        let path = PathBuf::from("/test/math.test.js");
+        let summary = extract(&path, source, &tree, Lang::JavaScript).unwrap();
+
+        let adds = summary
+            .symbols
+            .iter()
+            .find(|s| s.name == "adds numbers")
+            .expect("test case should be a symbol");
+        assert_eq!(
+            adds.framework_entry_point,
+            FrameworkEntryPoint::TestFunction
+        );
+        assert_eq!(adds.assertion_count, Some(2));
+        assert!(!adds.is_assertionless_test());
+
+        let empty = summary
+            .symbols
+            .iter()
+            .find(|s| s.name == "does nothing")
+            .expect("test case should be a symbol");
+        assert_eq!(empty.assertion_count, Some(0));
+        assert!(empty.is_assertionless_test());
+    }
 }
@@ -8,10 +8,13 @@
 //! - JSX elements and component structure
 //! - forwardRef/memo patterns
 //! - styled-components
+//! - Class component lifecycle methods
 
 use tree_sitter::Node;
 
-use crate::detectors::common::{get_node_text, push_unique_insertion, visit_all};
+use crate::detectors::common::{
+    get_node_text, note_lifecycle_methods, push_unique_insertion, visit_all, LifecycleFramework,
+};
 use crate::schema::{
     Call, FrameworkEntryPoint, SemanticSummary, StateChange, SymbolInfo, SymbolKind,
 };
@@ -41,8 +44,24 @@ pub fn enhance(summary: &mut SemanticSummary, root: &Node, source: &str) {
 
     // Detect React root/entry point
     detect_react_entry_points(summary, source);
+
+    // Lifecycle methods of class components
+    note_lifecycle_methods(summary, root, source, &CLASS_COMPONENT_LIFECYCLE);
 }
 
+/// Lifecycle methods of `React.Component`/`PureComponent` subclasses
+const CLASS_COMPONENT_LIFECYCLE: LifecycleFramework = LifecycleFramework {
+    base_classes: &["Component", "PureComponent"],
+    methods: &[
+        ("componentDidMount", "after first render"),
+        ("componentDidUpdate", "after re-render"),
+        ("componentWillUnmount", "before removal"),
+        ("shouldComponentUpdate", "render gate"),
+        ("getSnapshotBeforeUpdate", "pre-commit snapshot"),
+        ("componentDidCatch", "error boundary"),
+    ],
+};
+
 /// Detect React application entry points
 ///
 /// Marks components used with:
@@ -610,9 +629,21 @@ pub fn extract_jsx_insertions(summary: &mut SemanticSummary, root: &Node, source
         }
     });
 
+    apply_tag_insertions(summary, &jsx_tags, has_conditional_render);
+}
+
+/// Apply the markup insertion rules to a list of element tag names
+///
+/// Shared with template-based components (e.g. Svelte markup), so the same
+/// tags produce the same insertions regardless of how they were written.
+pub(crate) fn apply_tag_insertions(
+    summary: &mut SemanticSummary,
+    jsx_tags: &[String],
+    has_conditional_render: bool,
+) {
     // Add PascalCase components to calls (for call graph)
     // This captures component usage like <Button />, <Header>, <Icons.Home />
-    for tag_name in &jsx_tags {
+    for tag_name in jsx_tags {
         // Only capture PascalCase names (React components, not HTML elements)
         if tag_name
             .chars()
@@ -634,19 +665,19 @@ pub fn extract_jsx_insertions(summary: &mut SemanticSummary, root: &Node, source
     }
 
     // Header detection
-    detect_header_pattern(&jsx_tags, summary);
+    detect_header_pattern(jsx_tags, summary);
 
     // Route links count
-    detect_route_links(&jsx_tags, summary);
+    detect_route_links(jsx_tags, summary);
 
     // Dropdown detection
-    detect_dropdown_pattern(&jsx_tags, has_conditional_render, summary);
+    detect_dropdown_pattern(jsx_tags, has_conditional_render, summary);
 
     // Form detection
-    detect_form_pattern(&jsx_tags, summary);
+    detect_form_pattern(jsx_tags, summary);
 
     // List rendering
-    detect_list_pattern(&jsx_tags, summary);
+    detect_list_pattern(jsx_tags, summary);
 }
 
 /// Detect header container pattern
@@ -844,6 +875,36 @@ mod tests {
         assert_eq!(truncate_deps(""), "");
     }
 
+    #[test]
+    fn test_class_component_lifecycle() {
+        let source = r#"
+            import React from "react";
+
+            export class UserList extends React.Component<Props> {
+                componentDidMount() {
+                    this.props.load();
+                }
+
+                render() {
+                    return <ul />;
+                }
+            }
+        "#;
+        let tree = parse_tsx(source);
+        let mut summary = SemanticSummary::default();
+        enhance(&mut summary, &tree.root_node(), source);
+
+        let lifecycle: Vec<&String> = summary
+            .insertions
+            .iter()
+            .filter(|i| i.starts_with("lifecycle:"))
+            .collect();
+        assert_eq!(
+            lifecycle,
+            vec!["lifecycle: UserList.componentDidMount (after first render)"]
+        );
+    }
+
     #[test]
     fn test_extract_effect_hooks_on_mount() {
         let source = r#"
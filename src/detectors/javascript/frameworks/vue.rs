@@ -79,7 +79,7 @@ fn extract_script_tag(source: &str, setup: bool) -> Option<VueSfcScript> {
 }
 
 /// Detect the script language from the opening tag
-fn detect_script_lang(tag: &str) -> Lang {
+pub(crate) fn detect_script_lang(tag: &str) -> Lang {
     // Check for lang="ts" or lang="typescript"
     if tag.contains("lang=\"ts\"")
         || tag.contains("lang='ts'")
@@ -42,6 +42,7 @@ pub mod frameworks;
 
 use tree_sitter::{Parser, Tree};
 
+use crate::detectors::a11y::find_a11y_issues;
 use crate::detectors::common::push_unique_insertion;
 use crate::detectors::variable_refs;
 use crate::error::Result;
@@ -65,6 +66,9 @@ pub fn extract(summary: &mut SemanticSummary, source: &str, tree: &Tree, lang: L
     // Phase 1b: Variable references (include escaping locals for JS/TS/TSX)
     variable_refs::extract_variable_references(summary, &root, source, Some(lang), true);
 
+    // Phase 1c: Accessibility hints on JSX elements
+    summary.a11y_issues = find_a11y_issues(&root, source);
+
     // Phase 2: Detect frameworks from imports and patterns
     let frameworks = detect_frameworks(summary, source);
 
@@ -2,9 +2,11 @@
 //!
 //! Extracts semantic information from Kotlin source files using the generic extractor.
 //! Kotlin shares many concepts with Java but adds null safety, coroutines, and data classes.
+//! Android `Activity`/`Fragment` lifecycle callbacks are noted in the insertions.
 
 use tree_sitter::Tree;
 
+use crate::detectors::common::{note_lifecycle_methods, ANDROID_LIFECYCLE};
 use crate::detectors::generic::extract_with_grammar;
 use crate::detectors::grammar::KOTLIN_GRAMMAR;
 use crate::error::Result;
@@ -18,5 +20,8 @@ pub fn extract(summary: &mut SemanticSummary, source: &str, tree: &Tree) -> Resu
     // - State changes: property_declaration, variable_declaration, assignment
     // - Control flow: if_expression, when_expression, for_statement, while_statement
     // - Calls: call_expression
-    extract_with_grammar(summary, source, tree, &KOTLIN_GRAMMAR)
+    extract_with_grammar(summary, source, tree, &KOTLIN_GRAMMAR)?;
+
+    note_lifecycle_methods(summary, &tree.root_node(), source, &ANDROID_LIFECYCLE);
+    Ok(())
 }
@@ -242,6 +242,7 @@ pub fn get_locals_query(lang: Lang) -> Option<LocalsQuery> {
         Lang::Gradle => include_str!("../../queries/groovy/locals.scm"),
         Lang::Hcl => include_str!("../../queries/hcl/locals.scm"),
         Lang::Vue => include_str!("../../queries/javascript/locals.scm"), // Vue script uses JS
+        Lang::Svelte => include_str!("../../queries/javascript/locals.scm"),
 
         // Config/Markup - no variable references to track
         Lang::Html
@@ -252,6 +253,9 @@ pub fn get_locals_query(lang: Lang) -> Option<LocalsQuery> {
         | Lang::Yaml
         | Lang::Toml
         | Lang::Xml => return None,
+
+        // Registered grammars ship without a locals query
+        Lang::Custom(_) => return None,
     };
 
     LocalsQuery::new(&lang.tree_sitter_language(), query_src)
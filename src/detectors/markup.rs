@@ -1,16 +1,12 @@
 //! Markup language detector (HTML, CSS, SCSS, Markdown)
 
+use crate::detectors::a11y::find_a11y_issues;
 use crate::error::Result;
 use crate::lang::Lang;
 use crate::schema::SemanticSummary;
 use tree_sitter::Tree;
 
-pub fn extract(
-    summary: &mut SemanticSummary,
-    _source: &str,
-    _tree: &Tree,
-    lang: Lang,
-) -> Result<()> {
+pub fn extract(summary: &mut SemanticSummary, source: &str, tree: &Tree, lang: Lang) -> Result<()> {
     // Markup files have simpler extraction - mainly structure
     // For now, just mark as complete with the file info
 
@@ -18,6 +14,7 @@ pub fn extract(
     match lang {
         Lang::Html => {
             summary.insertions.push("HTML document".to_string());
+            summary.a11y_issues = find_a11y_issues(&tree.root_node(), source);
         }
         Lang::Css => {
             summary.insertions.push("CSS stylesheet".to_string());
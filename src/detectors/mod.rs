@@ -93,6 +93,7 @@
 //! DONE: Dockerfile detector implemented (dockerfile.rs)
 //! TODO(SEM-XX): Add Makefile detector (structural patterns)
 
+pub mod a11y;
 pub mod c_family;
 pub mod common;
 pub mod config;
@@ -115,6 +116,7 @@ pub mod markup;
 pub mod python;
 pub mod rust;
 pub mod shell;
+pub mod svelte;
 
 // Re-export key types for convenience
 pub use generic::extract_with_grammar;
@@ -2,14 +2,32 @@
 //!
 //! Extracts semantic information from Python source files using the generic extractor.
 //! Python-specific features like decorator detection are handled in a second pass.
+//!
+//! Functions get their arguments (with defaults) and return types; classes
+//! take the arguments of their `__init__` as their init signature. Type stubs
+//! (`.pyi`) carry signatures without bodies; their symbols are flagged with
+//! `is_stub`, since the stub is the package's declared API surface.
+//!
+//! `from x import y` records each imported name (its alias, if any) as a
+//! dependency, mapped to its package in `import_sources`; relative imports
+//! are also kept as local imports for data flow.
+//!
+//! Framework decorators become insertions: `@app.route("/users")` reads as
+//! `Flask route (/users)`, `@app.get("/items")` in a FastAPI module as
+//! `FastAPI endpoint (GET /items)`, and `@pytest.fixture` as
+//! `pytest fixture (name)`.
+
+use std::collections::HashSet;
 
 use tree_sitter::{Node, Tree};
 
-use crate::detectors::common::get_node_text;
+use crate::detectors::common::{count_test_assertions, get_node_text, visit_all};
 use crate::detectors::generic::extract_with_grammar;
 use crate::detectors::grammar::PYTHON_GRAMMAR;
 use crate::error::Result;
-use crate::schema::{FrameworkEntryPoint, RiskLevel, SemanticSummary};
+use crate::schema::{
+    Argument, FrameworkEntryPoint, RefKind, RiskLevel, SemanticSummary, SymbolKind,
+};
 
 /// Walk the tree and collect base class names for every class definition.
 /// Returns a map of `class_name -> Vec<base_class_name>`.
@@ -71,11 +89,386 @@ pub fn extract(summary: &mut SemanticSummary, source: &str, tree: &Tree) -> Resu
 
     // Python-specific: detect decorated definitions and improve symbol scoring
     let root = tree.root_node();
+    extract_from_imports(summary, &root, source);
     enhance_python_symbols(summary, &root, source);
 
+    // Assertion counts depend on the test flags set above
+    count_test_assertions(&root, source, &mut summary.symbols);
+
+    let is_stub = is_stub_file(&summary.file);
+    extract_signatures(summary, &root, source, is_stub);
+
+    mark_class_instantiations(summary);
+
+    extract_decorator_insertions(summary, &root, source);
+
     Ok(())
 }
 
+// ============================================================================
+// Decorator Insertions
+// ============================================================================
+
+/// HTTP verbs used as route decorators (`@app.get`, `@router.post`, ...)
+const HTTP_VERB_DECORATORS: &[&str] = &["get", "post", "put", "patch", "delete", "head", "options"];
+
+/// Describe framework decorators (routes, endpoints, fixtures) as insertions
+///
+/// Both bare (`@pytest.fixture`) and called (`@app.route("/x")`) decorators
+/// are recognized; the first string argument of a call is taken as the path.
+fn extract_decorator_insertions(summary: &mut SemanticSummary, root: &Node, source: &str) {
+    let is_fastapi = source.contains("fastapi");
+    let mut insertions: Vec<String> = Vec::new();
+
+    visit_all(root, |node| {
+        if node.kind() != "decorated_definition" {
+            return;
+        }
+        let Some(definition) = node.child_by_field_name("definition") else {
+            return;
+        };
+        let def_name = definition
+            .child_by_field_name("name")
+            .map(|n| get_node_text(&n, source))
+            .unwrap_or_default();
+
+        let mut cursor = node.walk();
+        for decorator in node.named_children(&mut cursor) {
+            if decorator.kind() != "decorator" {
+                continue;
+            }
+            let Some(expr) = decorator.named_child(0) else {
+                continue;
+            };
+            let (callee, path, methods) = if expr.kind() == "call" {
+                let callee = expr
+                    .child_by_field_name("function")
+                    .map(|f| get_node_text(&f, source))
+                    .unwrap_or_default();
+                let args = expr.child_by_field_name("arguments");
+                let path = args.and_then(|a| first_string_argument(&a, source));
+                let methods = args
+                    .map(|a| route_methods(&a, source))
+                    .unwrap_or_default();
+                (callee, path, methods)
+            } else {
+                (get_node_text(&expr, source), None, Vec::new())
+            };
+
+            let last = callee.rsplit('.').next().unwrap_or(&callee);
+            let path = path.unwrap_or_else(|| "/".to_string());
+            let insertion = if last == "route" && callee.contains('.') {
+                if methods.is_empty() {
+                    format!("Flask route ({})", path)
+                } else {
+                    format!("Flask route ({} {})", methods.join(", "), path)
+                }
+            } else if HTTP_VERB_DECORATORS.contains(&last) && callee.contains('.') {
+                let framework = if is_fastapi {
+                    "FastAPI endpoint"
+                } else {
+                    "Flask route"
+                };
+                format!("{} ({} {})", framework, last.to_uppercase(), path)
+            } else if callee == "pytest.fixture" || callee == "fixture" {
+                format!("pytest fixture ({})", def_name)
+            } else {
+                continue;
+            };
+            if !insertions.contains(&insertion) {
+                insertions.push(insertion);
+            }
+        }
+    });
+
+    for insertion in insertions {
+        if !summary.insertions.contains(&insertion) {
+            summary.insertions.push(insertion);
+        }
+    }
+}
+
+/// Value of the first positional string argument of a call
+fn first_string_argument(args: &Node, source: &str) -> Option<String> {
+    let mut cursor = args.walk();
+    let first = args.named_children(&mut cursor).next()?;
+    (first.kind() == "string").then(|| string_value(&first, source))
+}
+
+/// Methods listed in a Flask `methods=[...]` keyword argument, uppercased
+fn route_methods(args: &Node, source: &str) -> Vec<String> {
+    let mut methods = Vec::new();
+    let mut cursor = args.walk();
+    for arg in args.named_children(&mut cursor) {
+        if arg.kind() != "keyword_argument"
+            || arg
+                .child_by_field_name("name")
+                .map(|n| get_node_text(&n, source))
+                .as_deref()
+                != Some("methods")
+        {
+            continue;
+        }
+        let Some(value) = arg.child_by_field_name("value") else {
+            continue;
+        };
+        let mut inner = value.walk();
+        for item in value.named_children(&mut inner) {
+            if item.kind() == "string" {
+                methods.push(string_value(&item, source).to_uppercase());
+            }
+        }
+    }
+    methods
+}
+
+/// Text of a string literal without its prefix and quotes
+fn string_value(node: &Node, source: &str) -> String {
+    get_node_text(node, source)
+        .trim_start_matches(|c: char| c.is_ascii_alphabetic())
+        .trim_matches(|c| c == '"' || c == '\'')
+        .to_string()
+}
+
+// ============================================================================
+// From Imports
+// ============================================================================
+
+/// Record the names brought into scope by `from x import y, z as w`
+///
+/// Each name (or its alias) is a dependency mapped to the root package of
+/// its module. A wildcard import records the module itself. Relative modules
+/// (`from ..utils import x`) are local imports, as paths (`../utils`).
+fn extract_from_imports(summary: &mut SemanticSummary, root: &Node, source: &str) {
+    visit_all(root, |node| {
+        if node.kind() != "import_from_statement" {
+            return;
+        }
+        let Some(module_node) = node.child_by_field_name("module_name") else {
+            return;
+        };
+        let module = get_node_text(&module_node, source);
+        let is_relative = module.starts_with('.');
+        if is_relative {
+            let local = relative_module_path(&module);
+            if !local.is_empty() && !summary.local_imports.contains(&local) {
+                summary.local_imports.push(local);
+            }
+        }
+
+        let mut names: Vec<String> = Vec::new();
+        let mut cursor = node.walk();
+        for name in node.children_by_field_name("name", &mut cursor) {
+            let local_name = match name.kind() {
+                "aliased_import" => name
+                    .child_by_field_name("alias")
+                    .map(|alias| get_node_text(&alias, source)),
+                _ => Some(get_node_text(&name, source)),
+            };
+            names.extend(local_name);
+        }
+        if names.is_empty() {
+            // `from x import *`
+            let last = module.trim_start_matches('.').rsplit('.').next();
+            names.extend(last.filter(|m| !m.is_empty()).map(str::to_string));
+        }
+
+        let package = module.split('.').next().unwrap_or_default().to_string();
+        for name in names {
+            if !is_relative {
+                summary.import_sources.insert(name.clone(), package.clone());
+            }
+            if !summary.added_dependencies.contains(&name) {
+                summary.added_dependencies.push(name);
+            }
+        }
+    });
+}
+
+/// Path of a relative module (`.models` -> `models`, `..utils.io` -> `../utils/io`)
+fn relative_module_path(module: &str) -> String {
+    let rest = module.trim_start_matches('.');
+    let parents = module.len() - rest.len() - 1;
+    format!("{}{}", "../".repeat(parents), rest.replace('.', "/"))
+}
+
+// ============================================================================
+// Class Instantiation
+// ============================================================================
+
+/// Flag calls that instantiate a class
+///
+/// Python has no `new`, so a call is treated as a constructor when it names a
+/// class defined in this file or follows the PascalCase class convention
+/// (`User(...)`, `models.User(...)`). ALL_CAPS names are left alone.
+fn mark_class_instantiations(summary: &mut SemanticSummary) {
+    let local_classes: HashSet<String> = summary
+        .symbols
+        .iter()
+        .filter(|s| s.kind == SymbolKind::Class)
+        .map(|s| s.name.clone())
+        .collect();
+    let is_class_call = |name: &str| {
+        local_classes.contains(name)
+            || (name.starts_with(|c: char| c.is_ascii_uppercase())
+                && name.chars().any(|c| c.is_ascii_lowercase()))
+    };
+
+    let symbol_calls = summary.symbols.iter_mut().flat_map(|s| s.calls.iter_mut());
+    for call in summary.calls.iter_mut().chain(symbol_calls) {
+        if call.ref_kind == RefKind::None && is_class_call(&call.name) {
+            call.is_constructor = true;
+            call.instantiated_type = Some(call.name.clone());
+        }
+    }
+}
+
+// ============================================================================
+// Signatures and Type Stubs
+// ============================================================================
+
+/// Return true if the file is a type stub (`.pyi`)
+fn is_stub_file(path: &str) -> bool {
+    path.to_lowercase().ends_with(".pyi")
+}
+
+/// Attach arguments and return types to function symbols, flagging every
+/// symbol as a stub when `is_stub`
+fn extract_signatures(summary: &mut SemanticSummary, root: &Node, source: &str, is_stub: bool) {
+    // (start_line, arguments, return_type) for every function definition
+    let mut signatures: Vec<(usize, Vec<Argument>, Option<String>)> = Vec::new();
+    // (start_line, arguments) for every class that defines `__init__`
+    let mut init_signatures: Vec<(usize, Vec<Argument>)> = Vec::new();
+    visit_all(root, |node| {
+        if node.kind() == "class_definition" {
+            if let Some(params) = init_parameters(node, source) {
+                init_signatures.push((node.start_position().row + 1, params));
+            }
+            return;
+        }
+        if node.kind() != "function_definition" {
+            return;
+        }
+        let arguments = node
+            .child_by_field_name("parameters")
+            .map(|params| extract_parameters(&params, source))
+            .unwrap_or_default();
+        let return_type = node
+            .child_by_field_name("return_type")
+            .map(|ret| get_node_text(&ret, source));
+        signatures.push((node.start_position().row + 1, arguments, return_type));
+    });
+
+    for sym in &mut summary.symbols {
+        sym.is_stub = is_stub;
+        if sym.kind == SymbolKind::Class {
+            if let Some((_, arguments)) = init_signatures
+                .iter()
+                .find(|(line, _)| sym.start_line <= *line && *line <= sym.end_line)
+            {
+                if sym.arguments.is_empty() {
+                    sym.arguments = arguments.clone();
+                }
+            }
+            continue;
+        }
+        if !matches!(sym.kind, SymbolKind::Function | SymbolKind::Method) {
+            continue;
+        }
+        // Decorated stubs start at the decorator, so match the def inside the span
+        if let Some((_, arguments, return_type)) = signatures
+            .iter()
+            .find(|(line, _, _)| sym.start_line <= *line && *line <= sym.end_line)
+        {
+            if sym.arguments.is_empty() {
+                sym.arguments = arguments.clone();
+            }
+            if sym.return_type.is_none() {
+                sym.return_type = return_type.clone();
+            }
+        }
+    }
+
+    // Mirror the primary symbol's signature onto the summary
+    if let Some(primary) = summary
+        .symbols
+        .iter()
+        .find(|s| Some(&s.name) == summary.symbol.as_ref())
+    {
+        summary.arguments = primary.arguments.clone();
+        summary.return_type = primary.return_type.clone();
+    }
+}
+
+/// Parameters of the `__init__` defined directly in a class body
+fn init_parameters(class: &Node, source: &str) -> Option<Vec<Argument>> {
+    let body = class.child_by_field_name("body")?;
+    let mut cursor = body.walk();
+    let init = body.named_children(&mut cursor).find_map(|member| {
+        // `@overload`-style decorators wrap the def
+        let def = if member.kind() == "decorated_definition" {
+            member.child_by_field_name("definition")?
+        } else {
+            member
+        };
+        (def.kind() == "function_definition"
+            && def
+                .child_by_field_name("name")
+                .is_some_and(|n| get_node_text(&n, source) == "__init__"))
+        .then_some(def)
+    })?;
+    let params = init.child_by_field_name("parameters")?;
+    Some(extract_parameters(&params, source))
+}
+
+/// Convert a `parameters` node into arguments, skipping `self`/`cls` and
+/// the bare `*` and `/` separators
+///
+/// Defaulted parameters and `*args`/`**kwargs` are optional.
+fn extract_parameters(params: &Node, source: &str) -> Vec<Argument> {
+    let mut arguments = Vec::new();
+    let mut cursor = params.walk();
+    for param in params.named_children(&mut cursor) {
+        let text = |field: &str| {
+            param
+                .child_by_field_name(field)
+                .map(|n| get_node_text(&n, source))
+        };
+        let argument = match param.kind() {
+            "identifier" | "list_splat_pattern" | "dictionary_splat_pattern" => Argument {
+                name: get_node_text(&param, source),
+                arg_type: None,
+                default_value: None,
+                is_optional: param.kind() != "identifier",
+                description: None,
+            },
+            // The name of a typed parameter is its first (unnamed-field) child
+            "typed_parameter" => Argument {
+                name: param
+                    .named_child(0)
+                    .map(|n| get_node_text(&n, source))
+                    .unwrap_or_default(),
+                arg_type: text("type"),
+                default_value: None,
+                is_optional: false,
+                description: None,
+            },
+            "default_parameter" | "typed_default_parameter" => Argument {
+                name: text("name").unwrap_or_default(),
+                arg_type: text("type"),
+                default_value: text("value"),
+                is_optional: true,
+                description: None,
+            },
+            _ => continue,
+        };
+        if argument.name != "self" && argument.name != "cls" {
+            arguments.push(argument);
+        }
+    }
+    arguments
+}
+
 /// Enhance Python symbols with decorator detection
 /// The generic extractor finds symbols, but doesn't detect Python decorators
 fn enhance_python_symbols(summary: &mut SemanticSummary, root: &Node, source: &str) {
@@ -242,6 +635,189 @@ fn has_important_decorator(node: &Node, source: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lang::Lang;
+    use crate::parsing::parse_and_extract;
+    use std::path::Path;
+
+    #[test]
+    fn test_stub_signatures() {
+        let source = r#"
+from typing import Optional
+
+def fetch_user(user_id: int, *, timeout: float = ...) -> Optional[User]: ...
+
+class Client:
+    def get(self, path: str) -> bytes: ...
+"#;
+        let summary = parse_and_extract(Path::new("client.pyi"), source, Lang::Python).unwrap();
+
+        let fetch = summary
+            .symbols
+            .iter()
+            .find(|s| s.name == "fetch_user")
+            .unwrap();
+        assert!(fetch.is_stub);
+        assert_eq!(fetch.return_type.as_deref(), Some("Optional[User]"));
+        let args: Vec<(&str, Option<&str>)> = fetch
+            .arguments
+            .iter()
+            .map(|a| (a.name.as_str(), a.arg_type.as_deref()))
+            .collect();
+        assert_eq!(
+            args,
+            vec![("user_id", Some("int")), ("timeout", Some("float"))]
+        );
+
+        assert!(summary.symbols.iter().all(|s| s.is_stub));
+
+        // Regular modules are not stubs
+        let summary =
+            crate::extract::extract(Path::new("client.py"), source, &tree, Lang::Python).unwrap();
+        assert!(summary.symbols.iter().all(|s| !s.is_stub));
+    }
+
+    #[test]
+    fn test_class_takes_init_parameters() {
+        let source = r#"
+class Client:
+    def __init__(self, host: str, port: int = 80):
+        self.host = host
+        self.port = port
+
+    def send(self, payload):
+        pass
+"#;
+        let summary = parse_and_extract(Path::new("client.py"), source, Lang::Python).unwrap();
+
+        let client = summary
+            .symbols
+            .iter()
+            .find(|s| s.name == "Client")
+            .unwrap();
+        let args: Vec<(&str, Option<&str>, Option<&str>)> = client
+            .arguments
+            .iter()
+            .map(|a| {
+                (
+                    a.name.as_str(),
+                    a.arg_type.as_deref(),
+                    a.default_value.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            args,
+            vec![("host", Some("str"), None), ("port", Some("int"), Some("80"))]
+        );
+    }
+
+    #[test]
+    fn test_default_parameters() {
+        let source = r#"
+def connect(host, port=5432, *args, timeout: float = 3.0, **options):
+    pass
+"#;
+        let summary = parse_and_extract(Path::new("db.py"), source, Lang::Python).unwrap();
+
+        let connect = &summary.symbols[0];
+        let args: Vec<(&str, Option<&str>, bool)> = connect
+            .arguments
+            .iter()
+            .map(|a| (a.name.as_str(), a.default_value.as_deref(), a.is_optional))
+            .collect();
+        assert_eq!(
+            args,
+            vec![
+                ("host", None, false),
+                ("port", Some("5432"), true),
+                ("*args", None, true),
+                ("timeout", Some("3.0"), true),
+                ("**options", None, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_imports_record_names() {
+        let source = r#"
+import os
+from typing import List, Dict
+from .models import User as Account
+from ..utils.io import *
+"#;
+        let summary = parse_and_extract(Path::new("app/views.py"), source, Lang::Python).unwrap();
+
+        let deps = &summary.added_dependencies;
+        assert!(deps.contains(&"List".to_string()), "{:?}", deps);
+        assert!(deps.contains(&"Dict".to_string()), "{:?}", deps);
+        assert!(deps.contains(&"Account".to_string()), "{:?}", deps);
+        assert!(deps.contains(&"io".to_string()), "{:?}", deps);
+        assert!(deps.contains(&"os".to_string()), "{:?}", deps);
+        assert!(!deps.contains(&"typing".to_string()), "{:?}", deps);
+        assert!(!deps.contains(&"User".to_string()), "{:?}", deps);
+
+        assert_eq!(
+            summary.import_sources.get("List").map(String::as_str),
+            Some("typing")
+        );
+        assert!(!summary.import_sources.contains_key("Account"));
+        assert_eq!(summary.local_imports, vec!["models", "../utils/io"]);
+    }
+
+    #[test]
+    fn test_decorator_insertions() {
+        let source = r#"
+import pytest
+from flask import Flask
+
+app = Flask(__name__)
+
+@app.route("/users", methods=["GET", "POST"])
+def users():
+    return []
+
+@app.route("/health")
+def health():
+    return "ok"
+
+class Service:
+    @staticmethod
+    def build():
+        pass
+
+@pytest.fixture
+def client():
+    return app.test_client()
+"#;
+        let summary = parse_and_extract(Path::new("app.py"), source, Lang::Python).unwrap();
+
+        assert!(summary
+            .insertions
+            .contains(&"Flask route (GET, POST /users)".to_string()));
+        assert!(summary
+            .insertions
+            .contains(&"Flask route (/health)".to_string()));
+        assert!(summary
+            .insertions
+            .contains(&"pytest fixture (client)".to_string()));
+        assert!(!summary.insertions.iter().any(|i| i.contains("staticmethod")));
+
+        let source = r#"
+from fastapi import FastAPI
+
+app = FastAPI()
+
+@app.get("/items/{item_id}")
+async def read_item(item_id: int):
+    return {"id": item_id}
+"#;
+        let tree = parser.parse(source, None).unwrap();
+        let summary =
+            crate::extract::extract(Path::new("main.py"), source, &tree, Lang::Python).unwrap();
+        assert!(summary
+            .insertions
+            .contains(&"FastAPI endpoint (GET /items/{item_id})".to_string()));
+    }
 
     #[test]
     fn test_extract_filename_stem() {
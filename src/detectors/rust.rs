@@ -2,14 +2,32 @@
 //!
 //! Extracts semantic information from Rust source files using the generic extractor.
 //! Rust's struct/enum/trait declarations are first-class AST nodes, so the generic
-//! extractor handles them well.
+//! extractor handles them well, collecting every item in the file. Kinds are then
+//! refined: structs are `Struct` rather than the generic `Class`, and functions
+//! inside an `impl` or `trait` body are `Method`s.
+//!
+//! On top of that, lock usage is checked for deadlock hints: functions that
+//! acquire several `Mutex`/`RwLock` guards, or keep a guard alive across `.await`.
+//! `use` declarations are also recorded with their full module paths, so a
+//! call to an imported name can be traced to the module defining it. A struct
+//! takes the parameters of its inherent `new` as its init signature.
 
-use tree_sitter::Tree;
+use tree_sitter::{Node, Tree};
 
-use crate::detectors::generic::extract_with_grammar;
+use crate::detectors::common::{get_node_text, get_node_text_normalized, visit_all};
+use crate::detectors::generic::{extract_with_grammar, function_parameters};
 use crate::detectors::grammar::RUST_GRAMMAR;
 use crate::error::Result;
-use crate::schema::SemanticSummary;
+use crate::schema::{
+    Argument, Import, ImportedName, Location, LockHazard, LockHazardKind, SemanticSummary,
+    SymbolKind,
+};
+
+/// Guard-returning methods on `Mutex`/`RwLock` (always called without arguments)
+const LOCK_METHODS: &[&str] = &["lock", "read", "write"];
+
+/// Methods that unwrap a `LockResult` into the guard itself
+const GUARD_UNWRAP_METHODS: &[&str] = &["unwrap", "expect"];
 
 /// Extract semantic information from a Rust source file
 pub fn extract(summary: &mut SemanticSummary, source: &str, tree: &Tree) -> Result<()> {
@@ -20,5 +38,694 @@ pub fn extract(summary: &mut SemanticSummary, source: &str, tree: &Tree) -> Resu
     // - Control flow: if, for, while, match, loop
     // - Calls: call_expression
     // - Risk calculation
-    extract_with_grammar(summary, source, tree, &RUST_GRAMMAR)
+    extract_with_grammar(summary, source, tree, &RUST_GRAMMAR)?;
+    refine_symbol_kinds(summary, &tree.root_node(), source);
+
+    summary.lock_hazards = find_lock_hazards(&tree.root_node(), source);
+    attach_constructor_parameters(summary, &tree.root_node(), source);
+    summary.imports = extract_use_paths(&tree.root_node(), source, &summary.file);
+    for import in &summary.imports {
+        // External crates only, matching how JS tracks package sources
+        let root = import.source.split("::").next().unwrap_or_default();
+        if root.is_empty() || root == "crate" || import.is_namespace {
+            continue;
+        }
+        for name in &import.names {
+            let local = name.alias.as_ref().unwrap_or(&name.name);
+            summary
+                .import_sources
+                .insert(local.clone(), root.to_string());
+        }
+    }
+    Ok(())
+}
+
+// =============================================================================
+// Use Paths
+// =============================================================================
+
+/// Path of the module a file defines, e.g. `src/cache/mod.rs` -> `crate::cache`
+///
+/// Segments after the last `src` directory are used; `lib.rs`, `main.rs`
+/// and `mod.rs` name their parent module.
+pub fn module_path_for_file(file: &str) -> Vec<String> {
+    let components: Vec<&str> = file.split(['/', '\\']).filter(|c| !c.is_empty()).collect();
+    let start = components
+        .iter()
+        .rposition(|c| *c == "src")
+        .map_or(0, |i| i + 1);
+
+    let mut path = vec!["crate".to_string()];
+    for (i, component) in components[start..].iter().enumerate() {
+        let segment = if start + i == components.len() - 1 {
+            component.trim_end_matches(".rs")
+        } else {
+            component
+        };
+        path.push(segment.to_string());
+    }
+    if matches!(
+        path.last().map(String::as_str),
+        Some("lib" | "main" | "mod")
+    ) && path.len() > 1
+    {
+        path.pop();
+    }
+    path
+}
+
+/// One [`Import`] per module named by the file's `use` declarations
+///
+/// Use trees are flattened, so `use crate::a::{b::C, D as E}` yields
+/// `crate::a::b` importing `C` and `crate::a` importing `D` as `E`. Paths
+/// starting with `self` or `super` are made absolute against the file's
+/// module; other crates' paths are kept as written.
+pub fn extract_use_paths(root: &Node, source: &str, file: &str) -> Vec<Import> {
+    let module = module_path_for_file(file);
+    let mut imports: Vec<Import> = Vec::new();
+
+    visit_all(root, |node| {
+        if node.kind() != "use_declaration" {
+            return;
+        }
+        let Some(argument) = node.child_by_field_name("argument") else {
+            return;
+        };
+        let mut leaves = Vec::new();
+        collect_use_leaves(&argument, source, &[], &mut leaves);
+
+        for leaf in leaves {
+            let mut segments = resolve_use_path(&leaf.segments, &module);
+            let import = if leaf.glob {
+                Import {
+                    source: segments.join("::"),
+                    names: vec![ImportedName {
+                        name: "*".to_string(),
+                        alias: None,
+                    }],
+                    is_namespace: true,
+                    ..Default::default()
+                }
+            } else {
+                let Some(name) = segments.pop() else {
+                    continue;
+                };
+                Import {
+                    source: segments.join("::"),
+                    names: vec![ImportedName {
+                        name,
+                        alias: leaf.alias,
+                    }],
+                    ..Default::default()
+                }
+            };
+
+            // Names from one module share an entry
+            match imports
+                .iter_mut()
+                .find(|i| i.source == import.source && i.is_namespace == import.is_namespace)
+            {
+                Some(existing) => existing.names.extend(import.names),
+                None => imports.push(import),
+            }
+        }
+    });
+
+    imports
+}
+
+/// One path named by a use tree
+struct UseLeaf {
+    segments: Vec<String>,
+    alias: Option<String>,
+    /// `path::*`; `segments` is the module
+    glob: bool,
+}
+
+/// Flatten a use clause into full paths, prefixing each with `prefix`
+fn collect_use_leaves(node: &Node, source: &str, prefix: &[String], out: &mut Vec<UseLeaf>) {
+    let with_path = |path: Option<Node>| {
+        let mut segments = prefix.to_vec();
+        if let Some(path) = path {
+            segments.extend(split_path(&get_node_text_normalized(&path, source)));
+        }
+        segments
+    };
+
+    match node.kind() {
+        "use_list" => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_use_leaves(&child, source, prefix, out);
+            }
+        }
+        "scoped_use_list" => {
+            let segments = with_path(node.child_by_field_name("path"));
+            if let Some(list) = node.child_by_field_name("list") {
+                collect_use_leaves(&list, source, &segments, out);
+            }
+        }
+        "use_as_clause" => out.push(UseLeaf {
+            segments: with_path(node.child_by_field_name("path")),
+            alias: node
+                .child_by_field_name("alias")
+                .map(|a| get_node_text(&a, source)),
+            glob: false,
+        }),
+        "use_wildcard" => {
+            let text = get_node_text_normalized(node, source);
+            let mut segments = prefix.to_vec();
+            segments.extend(split_path(text.trim_end_matches('*')));
+            out.push(UseLeaf {
+                segments,
+                alias: None,
+                glob: true,
+            });
+        }
+        _ => {
+            let mut segments = with_path(Some(*node));
+            // `a::{self}` imports the module `a` itself
+            if segments.len() > 1 && segments.last().is_some_and(|s| s == "self") {
+                segments.pop();
+            }
+            out.push(UseLeaf {
+                segments,
+                alias: None,
+                glob: false,
+            });
+        }
+    }
+}
+
+/// Segments of a `::`-separated path, without empty ones (`::std`, `a::`)
+fn split_path(path: &str) -> Vec<String> {
+    path.split("::")
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Make a `self::`/`super::` path absolute against `module`
+fn resolve_use_path(segments: &[String], module: &[String]) -> Vec<String> {
+    match segments.first().map(String::as_str) {
+        Some("self") => module.iter().chain(&segments[1..]).cloned().collect(),
+        Some("super") => {
+            let supers = segments.iter().take_while(|s| *s == "super").count();
+            // Never climb above `crate`
+            let keep = module.len().saturating_sub(supers).max(1);
+            module[..keep]
+                .iter()
+                .chain(&segments[supers..])
+                .cloned()
+                .collect()
+        }
+        _ => segments.to_vec(),
+    }
+}
+
+// =============================================================================
+// Symbol Kinds
+// =============================================================================
+
+/// Mark structs as `Struct` and functions in `impl`/`trait` bodies as `Method`
+///
+/// Symbols are matched by name and start line, so a struct and the methods
+/// of its `impl` stay separate entries.
+fn refine_symbol_kinds(summary: &mut SemanticSummary, root: &Node, source: &str) {
+    // (start_line, name, kind)
+    let mut kinds: Vec<(usize, String, SymbolKind)> = Vec::new();
+    visit_all(root, |node| {
+        let kind = match node.kind() {
+            "struct_item" => SymbolKind::Struct,
+            "function_item" if is_associated_item(node) => SymbolKind::Method,
+            _ => return,
+        };
+        if let Some(name) = node.child_by_field_name("name") {
+            kinds.push((
+                node.start_position().row + 1,
+                get_node_text(&name, source),
+                kind,
+            ));
+        }
+    });
+
+    for (line, name, kind) in kinds {
+        for symbol in summary
+            .symbols
+            .iter_mut()
+            .filter(|s| s.start_line == line && s.name == name)
+        {
+            symbol.kind = kind;
+        }
+        if summary.start_line == Some(line) && summary.symbol.as_deref() == Some(name.as_str()) {
+            summary.symbol_kind = Some(kind);
+        }
+    }
+}
+
+/// Whether an item sits directly in an `impl` or `trait` body
+fn is_associated_item(node: &Node) -> bool {
+    node.parent()
+        .filter(|body| body.kind() == "declaration_list")
+        .and_then(|body| body.parent())
+        .is_some_and(|owner| matches!(owner.kind(), "impl_item" | "trait_item"))
+}
+
+// =============================================================================
+// Constructors
+// =============================================================================
+
+/// Give each struct the parameters of `fn new` from its inherent `impl`
+fn attach_constructor_parameters(summary: &mut SemanticSummary, root: &Node, source: &str) {
+    let mut constructors: Vec<(String, Vec<Argument>)> = Vec::new();
+    visit_all(root, |node| {
+        // Trait impls don't define the type's own constructor
+        if node.kind() != "impl_item" || node.child_by_field_name("trait").is_some() {
+            return;
+        }
+        let (Some(ty), Some(body)) = (
+            node.child_by_field_name("type"),
+            node.child_by_field_name("body"),
+        ) else {
+            return;
+        };
+        // `impl<T> Cache<T>` names the type `Cache`
+        let ty = ty.child_by_field_name("type").unwrap_or(ty);
+        let mut cursor = body.walk();
+        let new_fn = body.named_children(&mut cursor).find(|item| {
+            item.kind() == "function_item"
+                && item
+                    .child_by_field_name("name")
+                    .is_some_and(|n| get_node_text(&n, source) == "new")
+        });
+        if let Some(new_fn) = new_fn {
+            constructors.push((
+                get_node_text(&ty, source),
+                function_parameters(&new_fn, source),
+            ));
+        }
+    });
+
+    for (type_name, arguments) in constructors {
+        if let Some(symbol) = summary
+            .symbols
+            .iter_mut()
+            .find(|s| s.kind == SymbolKind::Struct && s.name == type_name && s.arguments.is_empty())
+        {
+            symbol.arguments = arguments;
+        }
+    }
+}
+
+// =============================================================================
+// Lock Hazards
+// =============================================================================
+
+/// Find functions that take multiple locks or hold a guard across `.await`
+fn find_lock_hazards(root: &Node, source: &str) -> Vec<LockHazard> {
+    let mut hazards = Vec::new();
+
+    visit_all(root, |node| {
+        if node.kind() != "function_item" {
+            return;
+        }
+        let (Some(name), Some(body)) = (
+            node.child_by_field_name("name"),
+            node.child_by_field_name("body"),
+        ) else {
+            return;
+        };
+        let function = get_node_text(&name, source);
+
+        // Lock calls and guard bindings belonging to this function, not nested ones
+        let mut lock_calls: Vec<(String, Location)> = Vec::new();
+        let mut held_guards: Vec<(String, Location)> = Vec::new();
+        visit_all(&body, |inner| {
+            if !is_in_function(inner, node) {
+                return;
+            }
+            if let Some(receiver) = lock_receiver(inner, source) {
+                lock_calls.push((receiver, location_of(inner)));
+            } else if inner.kind() == "let_declaration" {
+                if let Some(receiver) = guard_held_across_await(inner, source) {
+                    held_guards.push((receiver, location_of(inner)));
+                }
+            }
+        });
+
+        let mut locks: Vec<String> = Vec::new();
+        for (receiver, _) in &lock_calls {
+            if !locks.contains(receiver) {
+                locks.push(receiver.clone());
+            }
+        }
+        if locks.len() > 1 {
+            // Report where the second distinct lock is taken
+            let location = lock_calls
+                .iter()
+                .find(|(receiver, _)| *receiver == locks[1])
+                .map(|(_, location)| location.clone())
+                .unwrap_or_default();
+            hazards.push(LockHazard {
+                kind: LockHazardKind::MultipleLocks,
+                function: function.clone(),
+                locks,
+                location,
+            });
+        }
+
+        for (receiver, location) in held_guards {
+            hazards.push(LockHazard {
+                kind: LockHazardKind::GuardAcrossAwait,
+                function: function.clone(),
+                locks: vec![receiver],
+                location,
+            });
+        }
+    });
+
+    hazards
+}
+
+/// Receiver of a `.lock()`/`.read()`/`.write()` call, e.g. `self.accounts`
+fn lock_receiver(node: &Node, source: &str) -> Option<String> {
+    let (method, receiver) = method_call(node, source)?;
+    let args = node.child_by_field_name("arguments")?;
+    if !LOCK_METHODS.contains(&method.as_str()) || args.named_child_count() != 0 {
+        return None;
+    }
+    Some(get_node_text_normalized(&receiver, source))
+}
+
+/// Split `receiver.method(..)` into the method name and receiver node
+fn method_call<'a>(node: &Node<'a>, source: &str) -> Option<(String, Node<'a>)> {
+    if node.kind() != "call_expression" {
+        return None;
+    }
+    let function = node.child_by_field_name("function")?;
+    if function.kind() != "field_expression" {
+        return None;
+    }
+    let field = function.child_by_field_name("field")?;
+    let receiver = function.child_by_field_name("value")?;
+    Some((get_node_text(&field, source), receiver))
+}
+
+/// Check a `let` that binds a lock guard against later `.await`s in its block
+///
+/// Guards of async mutexes (`.lock().await`) are designed to be held across
+/// awaits and are skipped, as are guards released with `drop(guard)` first.
+fn guard_held_across_await(let_node: &Node, source: &str) -> Option<String> {
+    let pattern = let_node.child_by_field_name("pattern")?;
+    let guard = get_node_text(&pattern, source).replace("mut ", "");
+    if guard == "_" {
+        return None;
+    }
+
+    // Peel `?` and `.unwrap()`/`.expect(..)` off to reach the lock call itself
+    let mut value = let_node.child_by_field_name("value")?;
+    loop {
+        match value.kind() {
+            "try_expression" => value = value.named_child(0)?,
+            "await_expression" => return None,
+            _ => match method_call(&value, source) {
+                Some((method, receiver)) if GUARD_UNWRAP_METHODS.contains(&method.as_str()) => {
+                    value = receiver;
+                }
+                _ => break,
+            },
+        }
+    }
+    let receiver = lock_receiver(&value, source)?;
+
+    let release = format!("drop({})", guard);
+    let mut next = let_node.next_sibling();
+    while let Some(statement) = next {
+        if get_node_text(&statement, source).contains(&release) {
+            return None;
+        }
+        if contains_direct_await(&statement) {
+            return Some(receiver);
+        }
+        next = statement.next_sibling();
+    }
+    None
+}
+
+/// Whether `node` contains an `.await` that isn't inside a closure or async block
+fn contains_direct_await(node: &Node) -> bool {
+    let mut found = false;
+    visit_all(node, |inner| {
+        if found || inner.kind() != "await_expression" {
+            return;
+        }
+        if inner.id() == node.id() {
+            found = true;
+            return;
+        }
+        let mut current = inner.parent();
+        while let Some(parent) = current {
+            if parent.id() == node.id() {
+                found = true;
+                return;
+            }
+            if matches!(parent.kind(), "closure_expression" | "async_block") {
+                return;
+            }
+            current = parent.parent();
+        }
+    });
+    found
+}
+
+/// Whether the nearest enclosing `function_item` of `node` is `function`
+fn is_in_function(node: &Node, function: &Node) -> bool {
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if parent.kind() == "function_item" {
+            return parent.id() == function.id();
+        }
+        current = parent.parent();
+    }
+    false
+}
+
+fn location_of(node: &Node) -> Location {
+    Location::new(node.start_position().row + 1, node.start_position().column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::Lang;
+    use crate::parsing::parse_and_extract;
+    use std::path::Path;
+
+    #[test]
+    fn test_lock_hazards() {
+        let source = r#"
+use std::sync::Mutex;
+
+impl Bank {
+    fn transfer(&self, amount: u64) {
+        let mut accounts = self.accounts.lock().unwrap();
+        let mut audit = self.audit.lock().unwrap();
+        accounts[0] -= amount;
+        audit.push(amount);
+    }
+
+    fn balance(&self) -> u64 {
+        self.accounts.lock().unwrap().iter().sum()
+    }
+
+    async fn sync(&self) {
+        let accounts = self.accounts.lock().unwrap();
+        upload(&accounts).await;
+    }
+
+    async fn sync_snapshot(&self) {
+        let snapshot = self.accounts.lock().unwrap().clone();
+        upload(&snapshot).await;
+    }
+
+    async fn sync_released(&self) {
+        let accounts = self.accounts.lock().unwrap();
+        let total = accounts.len();
+        drop(accounts);
+        report(total).await;
+    }
+}
+"#;
+        let summary = parse_and_extract(Path::new("bank.rs"), source, Lang::Rust).unwrap();
+
+        let hazards: Vec<(LockHazardKind, &str, Vec<&str>)> = summary
+            .lock_hazards
+            .iter()
+            .map(|h| {
+                (
+                    h.kind,
+                    h.function.as_str(),
+                    h.locks.iter().map(String::as_str).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            hazards,
+            vec![
+                (
+                    LockHazardKind::MultipleLocks,
+                    "transfer",
+                    vec!["self.accounts", "self.audit"]
+                ),
+                (
+                    LockHazardKind::GuardAcrossAwait,
+                    "sync",
+                    vec!["self.accounts"]
+                ),
+            ]
+        );
+        assert_eq!(summary.lock_hazards[0].location.line, 7);
+    }
+
+    fn use_imports(source: &str, file: &str) -> Vec<(String, Vec<(String, Option<String>)>)> {
+        let summary = parse_and_extract(Path::new(file), source, Lang::Rust).unwrap();
+        summary
+            .imports
+            .into_iter()
+            .map(|i| {
+                let names = i.names.into_iter().map(|n| (n.name, n.alias)).collect();
+                (i.source, names)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_all_items_with_refined_kinds() {
+        let source = r#"
+pub struct Cache {
+    entries: Vec<String>,
+}
+
+impl Cache {
+    pub fn get(&self, index: usize) -> Option<&String> {
+        self.entries.get(index)
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+pub fn build() -> Cache {
+    Cache { entries: Vec::new() }
+}
+
+enum Mode {
+    Fast,
+}
+"#;
+        let summary = parse_and_extract(Path::new("src/lib.rs"), source, Lang::Rust).unwrap();
+
+        let mut symbols: Vec<(&str, SymbolKind, bool, usize, usize)> = summary
+            .symbols
+            .iter()
+            .map(|s| {
+                (
+                    s.name.as_str(),
+                    s.kind,
+                    s.is_exported,
+                    s.start_line,
+                    s.end_line,
+                )
+            })
+            .collect();
+        symbols.sort_by_key(|s| s.3);
+        assert_eq!(
+            symbols,
+            vec![
+                ("Cache", SymbolKind::Struct, true, 2, 4),
+                ("get", SymbolKind::Method, true, 7, 9),
+                ("clear", SymbolKind::Method, false, 11, 13),
+                ("build", SymbolKind::Function, true, 16, 18),
+                ("Mode", SymbolKind::Enum, false, 20, 22),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_struct_takes_new_parameters() {
+        let source = r#"
+pub struct Pool {
+    size: usize,
+}
+
+impl Pool {
+    pub fn new(url: &str, size: Option<usize>) -> Self {
+        Pool { size: size.unwrap_or(4) }
+    }
+}
+
+impl Default for Pool {
+    fn default() -> Self {
+        Pool::new("localhost", None)
+    }
+}
+"#;
+        let summary = parse_and_extract(Path::new("pool.rs"), source, Lang::Rust).unwrap();
+
+        let pool = summary.symbols.iter().find(|s| s.name == "Pool").unwrap();
+        let args: Vec<(&str, bool)> = pool
+            .arguments
+            .iter()
+            .map(|a| (a.name.as_str(), a.is_optional))
+            .collect();
+        assert_eq!(args, vec![("url", false), ("size", true)]);
+    }
+
+    #[test]
+    fn test_use_crate_path() {
+        let source = "use crate::a::b::C;\nuse crate::a::{b::D, E as F};\n";
+        assert_eq!(
+            use_imports(source, "src/x/y.rs"),
+            vec![
+                (
+                    "crate::a::b".to_string(),
+                    vec![("C".to_string(), None), ("D".to_string(), None)]
+                ),
+                (
+                    "crate::a".to_string(),
+                    vec![("E".to_string(), Some("F".to_string()))]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_use_super_and_self_resolve_against_file_module() {
+        let source = "use super::X;\nuse self::helpers::Y;\nuse std::collections::HashMap;\n";
+        assert_eq!(
+            use_imports(source, "src/detectors/rust.rs"),
+            vec![
+                (
+                    "crate::detectors".to_string(),
+                    vec![("X".to_string(), None)]
+                ),
+                (
+                    "crate::detectors::rust::helpers".to_string(),
+                    vec![("Y".to_string(), None)]
+                ),
+                (
+                    "std::collections".to_string(),
+                    vec![("HashMap".to_string(), None)]
+                ),
+            ]
+        );
+
+        // A mod.rs file is its directory's module
+        assert_eq!(
+            use_imports("use super::X;\n", "src/cache/mod.rs"),
+            vec![("crate".to_string(), vec![("X".to_string(), None)])]
+        );
+    }
 }
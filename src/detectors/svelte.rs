@@ -0,0 +1,385 @@
+//! Svelte component detector
+//!
+//! A `.svelte` file holds three sections, each handled on its own:
+//! - `<script>`: parsed with the JS/TS grammar and run through the core
+//!   JavaScript extraction (`lang="ts"` selects TypeScript)
+//! - `<style>`: component-scoped CSS, noted as an insertion
+//! - Template markup: parsed with the HTML grammar; its element tags feed the
+//!   same insertion rules the JSX detector applies
+//!
+//! On top of that, the component is named after its file (`user-card.svelte`
+//! becomes `UserCard`), `export let` declarations in the instance script
+//! become props, and `$:` reactive declarations become state changes.
+//!
+//! # Why the HTML grammar
+//!
+//! None of the pinned grammars in `Cargo.toml` parses Svelte, and the Vue
+//! detector already reads its single-file components with the HTML grammar,
+//! so `.svelte` files do the same rather than adding a grammar dependency.
+//! Script blocks are re-parsed with the real JS/TS grammar, so everything in
+//! them is exact. The template is where this falls short:
+//! - `{#if}`, `{#each}`, `{#await}` and `{#key}` blocks are plain text to HTML,
+//!   so block structure is lost. Elements inside blocks, however deeply
+//!   nested, are collected as if they sat at the top level: an `{#each}` row
+//!   counts as one list item, and a conditional render is detected from the
+//!   `{#if` / `{:else` text alone.
+//! - `{expression}` tags and `on:click={...}` handlers are opaque text, so
+//!   calls made only in markup are not recorded; component tags are.
+//! - An unquoted attribute expression containing `>` (`disabled={a > b}`)
+//!   ends the tag early for HTML; the rest of that tag is read as text.
+
+use tree_sitter::{Node, Parser, Tree};
+
+use crate::detectors::common::{
+    compress_initializer, get_node_text, infer_type_from_initializer, push_unique_insertion,
+    visit_all,
+};
+use crate::detectors::javascript::frameworks::{react, vue};
+use crate::detectors::javascript::{core, detect_frameworks};
+use crate::detectors::variable_refs;
+use crate::error::Result;
+use crate::lang::Lang;
+use crate::schema::{Prop, SemanticSummary, StateChange, SymbolInfo, SymbolKind};
+
+/// Extract semantic information from a Svelte component
+pub fn extract(summary: &mut SemanticSummary, source: &str, tree: &Tree) -> Result<()> {
+    let mut props = Vec::new();
+
+    for script in script_blocks(source) {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&script.lang.tree_sitter_language())
+            .expect("Error loading grammar");
+        let Some(script_tree) = parser.parse(&script.content, None) else {
+            continue;
+        };
+
+        let root = script_tree.root_node();
+        core::extract_core(summary, &root, &script.content, script.lang)?;
+        variable_refs::extract_variable_references(
+            summary,
+            &root,
+            &script.content,
+            Some(script.lang),
+            true,
+        );
+        detect_frameworks(summary, &script.content);
+
+        // `context="module"` scripts run once per module: their exports are
+        // module exports, not props, and `$:` has no meaning there
+        if !script.is_module {
+            props.extend(extract_props(&root, &script.content));
+            extract_reactive_declarations(summary, &root, &script.content);
+        }
+    }
+
+    extract_template_insertions(summary, &tree.root_node(), source);
+
+    if source.contains("<style") {
+        push_unique_insertion(
+            &mut summary.insertions,
+            "scoped styles".to_string(),
+            "scoped styles",
+        );
+    }
+
+    let name = component_name(&summary.file);
+    if !name.is_empty() {
+        let end_line = source.lines().count().max(1);
+        summary.symbols.insert(
+            0,
+            SymbolInfo {
+                name: name.clone(),
+                kind: SymbolKind::Component,
+                start_line: 1,
+                end_line,
+                is_exported: true,
+                is_default_export: true,
+                props: props.clone(),
+                ..Default::default()
+            },
+        );
+        summary.symbol = Some(name);
+        summary.symbol_kind = Some(SymbolKind::Component);
+        summary.start_line = Some(1);
+        summary.end_line = Some(end_line);
+    }
+    summary.props = props;
+
+    Ok(())
+}
+
+// =============================================================================
+// Script Sections
+// =============================================================================
+
+/// A `<script>` block from a Svelte component
+struct SvelteScript {
+    /// Script content, padded with blank lines so line numbers match the file
+    content: String,
+    /// The language (ts or js)
+    lang: Lang,
+    /// Whether this is a `<script context="module">` block
+    is_module: bool,
+}
+
+/// Find every `<script>` block in the component
+fn script_blocks(source: &str) -> Vec<SvelteScript> {
+    let mut scripts = Vec::new();
+    let mut offset = 0;
+
+    while let Some(start) = source[offset..].find("<script") {
+        let tag_start = offset + start;
+        let Some(tag_len) = source[tag_start..].find('>') else {
+            break;
+        };
+        let opening_tag = &source[tag_start..tag_start + tag_len + 1];
+        let content_start = tag_start + tag_len + 1;
+        let Some(content_len) = source[content_start..].find("</script>") else {
+            break;
+        };
+        let content_end = content_start + content_len;
+
+        let preceding_lines = source[..content_start].matches('\n').count();
+        scripts.push(SvelteScript {
+            content: format!(
+                "{}{}",
+                "\n".repeat(preceding_lines),
+                &source[content_start..content_end]
+            ),
+            lang: vue::detect_script_lang(opening_tag),
+            is_module: opening_tag.contains("context=\"module\"")
+                || opening_tag.contains("context='module'")
+                || opening_tag.contains(" module"),
+        });
+
+        offset = content_end + "</script>".len();
+    }
+
+    scripts
+}
+
+/// Component props: `export let name` declarations in the instance script
+fn extract_props(root: &Node, source: &str) -> Vec<Prop> {
+    let mut props = Vec::new();
+
+    visit_all(root, |node| {
+        if node.kind() != "export_statement" {
+            return;
+        }
+        let Some(decl) = node.child_by_field_name("declaration") else {
+            return;
+        };
+        let is_let = decl.kind() == "lexical_declaration"
+            && decl.child(0).is_some_and(|kw| kw.kind() == "let");
+        if !is_let {
+            return;
+        }
+
+        let mut cursor = decl.walk();
+        for declarator in decl.named_children(&mut cursor) {
+            if declarator.kind() != "variable_declarator" {
+                continue;
+            }
+            let Some(name) = declarator.child_by_field_name("name") else {
+                continue;
+            };
+            let default_value = declarator
+                .child_by_field_name("value")
+                .map(|v| get_node_text(&v, source));
+            let prop_type = declarator.child_by_field_name("type").map(|t| {
+                get_node_text(&t, source)
+                    .trim_start_matches(':')
+                    .trim()
+                    .to_string()
+            });
+            props.push(Prop {
+                name: get_node_text(&name, source),
+                prop_type,
+                required: default_value.is_none(),
+                default_value,
+            });
+        }
+    });
+
+    props
+}
+
+/// Reactive declarations: `$: doubled = count * 2`
+fn extract_reactive_declarations(summary: &mut SemanticSummary, root: &Node, source: &str) {
+    visit_all(root, |node| {
+        if node.kind() != "labeled_statement" {
+            return;
+        }
+        let is_reactive = node
+            .child_by_field_name("label")
+            .is_some_and(|label| get_node_text(&label, source) == "$");
+        if !is_reactive {
+            return;
+        }
+        let Some(assignment) = node
+            .child_by_field_name("body")
+            .filter(|body| body.kind() == "expression_statement")
+            .and_then(|body| body.named_child(0))
+            .filter(|expr| expr.kind() == "assignment_expression")
+        else {
+            return;
+        };
+        let (Some(left), Some(right)) = (
+            assignment.child_by_field_name("left"),
+            assignment.child_by_field_name("right"),
+        ) else {
+            return;
+        };
+
+        let initializer = get_node_text(&right, source);
+        summary.state_changes.push(StateChange {
+            name: get_node_text(&left, source),
+            state_type: infer_type_from_initializer(&initializer),
+            initializer: compress_initializer(&initializer),
+        });
+    });
+}
+
+// =============================================================================
+// Template Markup
+// =============================================================================
+
+/// Run the JSX insertion rules over the template's element tags
+fn extract_template_insertions(summary: &mut SemanticSummary, root: &Node, source: &str) {
+    let mut tags: Vec<String> = Vec::new();
+
+    visit_all(root, |node| {
+        if node.kind() != "tag_name" {
+            return;
+        }
+        let in_opening_tag = node
+            .parent()
+            .is_some_and(|p| p.kind() == "start_tag" || p.kind() == "self_closing_tag");
+        let tag = get_node_text(node, source);
+        if in_opening_tag && tag != "script" && tag != "style" {
+            tags.push(tag);
+        }
+    });
+
+    let has_conditional_render = source.contains("{#if") || source.contains("{:else");
+    react::apply_tag_insertions(summary, &tags, has_conditional_render);
+}
+
+/// PascalCase component name from the file name (`user-card.svelte` -> `UserCard`)
+fn component_name(file_path: &str) -> String {
+    let stem = std::path::Path::new(file_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("");
+
+    stem.split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn extract_svelte(file: &str, source: &str) -> SemanticSummary {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&Lang::Svelte.tree_sitter_language())
+            .unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        crate::extract::extract(Path::new(file), source, &tree, Lang::Svelte).unwrap()
+    }
+
+    #[test]
+    fn test_svelte_component() {
+        let source = r#"<script lang="ts">
+  export let title: string;
+  export let count = 0;
+
+  $: doubled = count * 2;
+
+  function increment() {
+    count += 1;
+  }
+</script>
+
+<form>
+  <input bind:value={title} />
+  <Button on:click={increment}>{doubled}</Button>
+</form>
+
+<style>
+  form { display: flex; }
+</style>
+"#;
+        let summary = extract_svelte("src/lib/counter-card.svelte", source);
+
+        assert_eq!(summary.symbol.as_deref(), Some("CounterCard"));
+        assert_eq!(summary.symbol_kind, Some(SymbolKind::Component));
+
+        let props: Vec<(&str, bool)> = summary
+            .props
+            .iter()
+            .map(|p| (p.name.as_str(), p.required))
+            .collect();
+        assert_eq!(props, vec![("title", true), ("count", false)]);
+        assert_eq!(summary.props[0].prop_type.as_deref(), Some("string"));
+
+        assert!(summary.state_changes.iter().any(|s| s.name == "doubled"));
+        assert!(summary.calls.iter().any(|c| c.name == "Button"));
+        assert!(summary.insertions.iter().any(|i| i == "form with 1 inputs"));
+        assert!(summary.insertions.iter().any(|i| i == "scoped styles"));
+    }
+
+    #[test]
+    fn test_each_nested_in_if() {
+        let source = r#"<script>
+  export let items = [];
+  export let loading = false;
+</script>
+
+{#if loading}
+  <Spinner />
+{:else}
+  <ul>
+    {#each items as item (item.id)}
+      <li><ItemRow {item} /></li>
+    {/each}
+  </ul>
+{/if}
+<Footer />
+"#;
+        let summary = extract_svelte("src/lib/ItemList.svelte", source);
+
+        assert_eq!(summary.symbol.as_deref(), Some("ItemList"));
+        assert_eq!(summary.props.len(), 2);
+
+        // Components in either branch, inside the loop, and after the blocks
+        for component in ["Spinner", "ItemRow", "Footer"] {
+            assert!(
+                summary.calls.iter().any(|c| c.name == component),
+                "missing call to {}",
+                component
+            );
+        }
+
+        // The `{#each}` body is one static row to the HTML grammar
+        assert!(summary.insertions.iter().any(|i| i == "list with 1 items"));
+    }
+
+    #[test]
+    fn test_component_name() {
+        assert_eq!(component_name("src/lib/counter-card.svelte"), "CounterCard");
+        assert_eq!(component_name("src/routes/+page.svelte"), "Page");
+        assert_eq!(component_name("App.svelte"), "App");
+    }
+}
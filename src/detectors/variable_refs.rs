@@ -109,6 +109,8 @@ pub fn extract_variable_references(
             is_hook: false,
             is_io: false,
             ref_kind,
+            is_constructor: false,
+            instantiated_type: None,
             location: Location::new(reference.line, 0),
         };
 
@@ -172,7 +174,12 @@ fn map_escape_ref_kind(kind: RefKind) -> RefKind {
 
 fn collect_escape_variable_names(root: &Node, source: &str, lang: Lang) -> HashSet<String> {
     match lang {
-        Lang::JavaScript | Lang::TypeScript | Lang::Jsx | Lang::Tsx | Lang::Vue => {
+        Lang::JavaScript
+        | Lang::TypeScript
+        | Lang::Jsx
+        | Lang::Tsx
+        | Lang::Vue
+        | Lang::Svelte => {
             collect_js_escape_names(root, source)
         }
         Lang::CSharp => collect_csharp_escape_names(root, source),
@@ -209,6 +216,7 @@ fn add_escape_local_symbols(summary: &mut SemanticSummary, defs: &[locals::Local
             arguments: Vec::new(),
             props: Vec::new(),
             return_type: None,
+            type_params: Vec::new(),
             calls: Vec::new(),
             control_flow: Vec::new(),
             state_changes: Vec::new(),
@@ -217,6 +225,17 @@ fn add_escape_local_symbols(summary: &mut SemanticSummary, defs: &[locals::Local
             framework_entry_point: FrameworkEntryPoint::None,
             is_async: false,
             base_classes: Vec::new(),
+            is_recursive: false,
+            nested_functions: 0,
+            assertion_count: None,
+            overloaded: false,
+            is_stub: false,
+            injected_deps: Vec::new(),
+            naming_violation: None,
+            feature_envy: None,
+            patterns: Vec::new(),
+            is_stub_impl: false,
+            returns_description: None,
         });
     }
 }
@@ -320,7 +339,12 @@ fn is_csharp_capture_child(parent: &Node, child: &Node) -> bool {
 
 fn is_identifier_node(node: &Node, lang: Lang) -> bool {
     match lang {
-        Lang::JavaScript | Lang::TypeScript | Lang::Jsx | Lang::Tsx | Lang::Vue => {
+        Lang::JavaScript
+        | Lang::TypeScript
+        | Lang::Jsx
+        | Lang::Tsx
+        | Lang::Vue
+        | Lang::Svelte => {
             matches!(node.kind(), "identifier" | "shorthand_property_identifier")
         }
         Lang::CSharp => node.kind() == "identifier",
@@ -892,6 +892,8 @@ mod tests {
                     is_hook: false,
                     is_io: false,
                     ref_kind: RefKind::None,
+                    is_constructor: false,
+                    instantiated_type: None,
                     location: Location::default(),
                 })
                 .collect(),
@@ -925,6 +927,8 @@ mod tests {
                     is_hook: false,
                     is_io: false,
                     ref_kind: RefKind::None,
+                    is_constructor: false,
+                    instantiated_type: None,
                     location: Location::default(),
                 })
                 .collect(),
@@ -915,6 +915,8 @@ mod tests {
                     is_hook: false,
                     is_io: false,
                     ref_kind: RefKind::None,
+                    is_constructor: false,
+                    instantiated_type: None,
                     location: Location::default(),
                 })
                 .collect(),
@@ -948,6 +950,8 @@ mod tests {
                     is_hook: false,
                     is_io: false,
                     ref_kind: RefKind::None,
+                    is_constructor: false,
+                    instantiated_type: None,
                     location: Location::default(),
                 })
                 .collect(),
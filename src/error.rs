@@ -49,6 +49,12 @@ pub enum McpDiffError {
     #[error("Installation error: {message}")]
     InstallError { message: String },
 
+    #[error("Baseline regressions found:\n{report}")]
+    BaselineRegression { report: String },
+
+    #[error("Complexity budgets exceeded:\n{report}")]
+    BudgetExceeded { report: String },
+
     #[error("{0}")]
     Generic(String),
 }
@@ -68,6 +74,8 @@ impl McpDiffError {
     /// - 4: Internal semantic extraction failure
     /// - 5: Git error
     /// - 6: Export error
+    /// - 12: Baseline regression
+    /// - 13: Complexity budget exceeded
     pub fn exit_code(&self) -> ExitCode {
         match self {
             Self::FileNotFound { .. } => ExitCode::from(1),
@@ -84,6 +92,8 @@ impl McpDiffError {
             Self::Serialization(_) => ExitCode::from(8),
             Self::ConfigError { .. } => ExitCode::from(10),
             Self::InstallError { .. } => ExitCode::from(11),
+            Self::BaselineRegression { .. } => ExitCode::from(12),
+            Self::BudgetExceeded { .. } => ExitCode::from(13),
             Self::Generic(_) => ExitCode::from(9),
         }
     }
@@ -4,26 +4,275 @@
 //! source files using language-specific detectors.
 
 use crate::utils::truncate_to_char_boundary;
+use std::fmt;
 use std::path::Path;
-
-/// Minimum fallback length (bytes)
-const MIN_FALLBACK_LEN: usize = 2000;
-
-/// Maximum fallback length (bytes)
-const MAX_FALLBACK_LEN: usize = 10_000;
+use std::sync::Arc;
 
 use tree_sitter::Tree;
 
 use crate::error::Result;
 use crate::lang::Lang;
 use crate::risk::calculate_risk;
-use crate::schema::{SemanticSummary, SymbolId};
+use crate::schema::{SemanticSummary, SymbolId, SymbolInfo, SymbolKind};
+
+/// Options controlling what [`extract_with_options`] records
+#[derive(Clone, Default)]
+pub struct ExtractOptions {
+    /// Never store raw source in `raw_fallback`, even when extraction is
+    /// incomplete
+    pub no_fallback: bool,
+    /// Weights used to pick the file's primary symbol
+    pub scoring: SymbolScoringProfile,
+    /// How much raw source `raw_fallback` keeps
+    pub fallback: FallbackConfig,
+    /// Run in order on every summary before its risk is scored
+    pub transformers: Vec<Arc<dyn SummaryTransformer>>,
+}
+
+impl fmt::Debug for ExtractOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtractOptions")
+            .field("no_fallback", &self.no_fallback)
+            .field("scoring", &self.scoring)
+            .field("fallback", &self.fallback)
+            .field("transformers", &self.transformers.len())
+            .finish()
+    }
+}
+
+/// Extension point for enriching summaries with custom analysis
+///
+/// Transformers registered in [`ExtractOptions::transformers`] run after the
+/// language detectors and the built-in passes, so they see the finished
+/// symbols, calls and insertions. Risk is scored afterwards, which lets a
+/// transformer's insertions count toward it.
+pub trait SummaryTransformer: Send + Sync {
+    /// Modify `summary` in place
+    fn transform(&self, summary: &mut SemanticSummary);
+}
+
+/// Size limits for the raw source kept when extraction is incomplete
+///
+/// Extraction truncates `raw_fallback` with these limits and the TOON
+/// encoders use [`FallbackConfig::compact`] to render it, so every place a
+/// fallback is cut or shown reads the same settings. The defaults keep the
+/// adaptive head and tail window extraction has always used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FallbackConfig {
+    /// Smallest byte window kept; the window is 10% of the source
+    pub min_bytes: usize,
+    /// Largest byte window kept
+    pub max_bytes: usize,
+    /// Source lines kept, head and tail (`None` keeps every line)
+    pub max_lines: Option<usize>,
+    /// Longest fallback, in lines, the encoders show inline; longer ones
+    /// are rendered as `(N lines)`
+    pub inline_lines: usize,
+}
+
+impl Default for FallbackConfig {
+    fn default() -> Self {
+        Self {
+            min_bytes: 2000,
+            max_bytes: 10_000,
+            max_lines: None,
+            inline_lines: 3,
+        }
+    }
+}
+
+impl FallbackConfig {
+    /// Raw source kept for `source`, never cut inside a UTF-8 character
+    ///
+    /// Sources over `max_lines` keep their first two thirds and last third
+    /// of lines around a `[N lines omitted]` marker. The byte window is then
+    /// 10% of the source, clamped between `min_bytes` and `max_bytes`; text
+    /// over it keeps the same head and tail split around `[N bytes omitted]`.
+    pub fn truncate(&self, source: &str) -> String {
+        let text = match self.max_lines {
+            Some(max_lines) => truncate_lines(source, max_lines),
+            None => source.to_string(),
+        };
+
+        let window = (source.len() / 10).max(self.min_bytes).min(self.max_bytes);
+        if text.len() <= window {
+            return text;
+        }
+
+        // Include both head (2/3) and tail (1/3) for context
+        let head_len = window * 2 / 3;
+        let tail_len = window / 3;
+
+        let head = truncate_to_char_boundary(&text, head_len);
+        let omitted = text.len() - head_len - tail_len;
+
+        // Find a valid UTF-8 boundary for tail start
+        let tail_start = text.len().saturating_sub(tail_len);
+        let tail = text
+            .char_indices()
+            .find(|(i, _)| *i >= tail_start)
+            .map(|(i, _)| &text[i..])
+            .unwrap_or("");
+
+        format!("{}...\n\n[{} bytes omitted]\n\n...{}", head, omitted, tail)
+    }
+
+    /// One-line rendering of a fallback for the TOON encoders
+    ///
+    /// A fallback of at most `inline_lines` lines is joined onto one line and
+    /// cut to `max_chars`; a longer one is reported as `(N lines)`.
+    pub fn compact(&self, raw: &str, max_chars: usize) -> String {
+        let line_count = raw.lines().count();
+        if line_count > self.inline_lines {
+            return format!("({} lines)", line_count);
+        }
+
+        let content = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+        if content.len() <= max_chars {
+            content
+        } else {
+            format!(
+                "{}...",
+                truncate_to_char_boundary(&content, max_chars.saturating_sub(3))
+            )
+        }
+    }
+}
+
+/// Keep the first two thirds and last third of `max_lines` lines
+fn truncate_lines(source: &str, max_lines: usize) -> String {
+    let lines: Vec<&str> = source.split_inclusive('\n').collect();
+    if lines.len() <= max_lines {
+        return source.to_string();
+    }
+
+    let tail_lines = max_lines / 3;
+    let head_lines = max_lines - tail_lines;
+    let omitted = lines.len() - max_lines;
+
+    let mut kept: String = lines[..head_lines].concat();
+    if !kept.is_empty() && !kept.ends_with('\n') {
+        kept.push('\n');
+    }
+    kept.push_str(&format!("[{} lines omitted]\n", omitted));
+    kept.push_str(&lines[lines.len() - tail_lines..].concat());
+    kept
+}
+
+/// Weights that decide which symbol becomes a file's primary symbol
+///
+/// The default profile keeps each language's built-in ranking, which favors
+/// exported React components. Any other profile re-ranks the extracted
+/// symbols by these weights.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolScoringProfile {
+    pub component_weight: i32,
+    pub class_weight: i32,
+    pub struct_weight: i32,
+    /// Traits and interfaces
+    pub trait_weight: i32,
+    pub enum_weight: i32,
+    pub function_weight: i32,
+    pub method_weight: i32,
+    /// Every other kind (variables, modules, type aliases)
+    pub other_weight: i32,
+    pub export_bonus: i32,
+    pub default_export_bonus: i32,
+    /// Name equals the file stem
+    pub filename_exact_bonus: i32,
+    /// Name contains the file stem or vice versa
+    pub filename_partial_bonus: i32,
+}
+
+impl Default for SymbolScoringProfile {
+    fn default() -> Self {
+        Self {
+            component_weight: 40,
+            class_weight: 30,
+            struct_weight: 30,
+            trait_weight: 28,
+            enum_weight: 25,
+            function_weight: 10,
+            method_weight: 15,
+            other_weight: 5,
+            export_bonus: 50,
+            default_export_bonus: 20,
+            filename_exact_bonus: 40,
+            filename_partial_bonus: 20,
+        }
+    }
+}
+
+impl SymbolScoringProfile {
+    /// Favors types (structs, traits, enums) over components and default exports
+    pub fn library() -> Self {
+        Self {
+            component_weight: 5,
+            struct_weight: 40,
+            trait_weight: 38,
+            enum_weight: 30,
+            function_weight: 15,
+            method_weight: 10,
+            default_export_bonus: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Base weight for a symbol kind
+    pub fn kind_weight(&self, kind: SymbolKind) -> i32 {
+        match kind {
+            SymbolKind::Component => self.component_weight,
+            SymbolKind::Class => self.class_weight,
+            SymbolKind::Struct => self.struct_weight,
+            SymbolKind::Trait | SymbolKind::Interface => self.trait_weight,
+            SymbolKind::Enum => self.enum_weight,
+            SymbolKind::Function => self.function_weight,
+            SymbolKind::Method => self.method_weight,
+            SymbolKind::Module
+            | SymbolKind::TypeAlias
+            | SymbolKind::Variable
+            | SymbolKind::Macro => self.other_weight,
+        }
+    }
+
+    /// Score a symbol; `filename_stem` is the lowercased file name without extension
+    pub fn score(&self, symbol: &SymbolInfo, filename_stem: &str) -> i32 {
+        let mut score = self.kind_weight(symbol.kind);
+        if symbol.is_exported {
+            score += self.export_bonus;
+        }
+        if symbol.is_default_export {
+            score += self.default_export_bonus;
+        }
+
+        let name_lower = symbol.name.to_lowercase();
+        if name_lower == filename_stem {
+            score += self.filename_exact_bonus;
+        } else if !filename_stem.is_empty()
+            && (name_lower.contains(filename_stem) || filename_stem.contains(&name_lower))
+        {
+            score += self.filename_partial_bonus;
+        }
+        score
+    }
+}
 
 /// Extract semantic information from a parsed source file
 ///
 /// This is the main entry point for semantic extraction. It delegates to
 /// language-specific extractors based on the detected language.
 pub fn extract(file_path: &Path, source: &str, tree: &Tree, lang: Lang) -> Result<SemanticSummary> {
+    extract_with_options(file_path, source, tree, lang, ExtractOptions::default())
+}
+
+/// Extract semantic information with explicit [`ExtractOptions`]
+pub fn extract_with_options(
+    file_path: &Path,
+    source: &str,
+    tree: &Tree,
+    lang: Lang,
+    options: ExtractOptions,
+) -> Result<SemanticSummary> {
     let mut summary = SemanticSummary {
         file: file_path.display().to_string(),
         language: lang.name().to_string(),
@@ -34,6 +283,8 @@ pub fn extract(file_path: &Path, source: &str, tree: &Tree, lang: Lang) -> Resul
     // Vue SFCs need special handling - extract script section first
     if lang.is_vue_sfc() {
         crate::detectors::javascript::extract_vue_sfc(&mut summary, source)?;
+    } else if lang.is_svelte_component() {
+        crate::detectors::svelte::extract(&mut summary, source, tree)?;
     } else {
         match lang.family() {
             crate::lang::LangFamily::JavaScript => {
@@ -78,9 +329,66 @@ pub fn extract(file_path: &Path, source: &str, tree: &Tree, lang: Lang) -> Resul
             crate::lang::LangFamily::Dockerfile => {
                 crate::detectors::dockerfile::extract(&mut summary, source, tree)?;
             }
+            crate::lang::LangFamily::Custom => {
+                if let Some(grammar) = lang.custom_grammar() {
+                    crate::detectors::generic::extract_with_grammar(
+                        &mut summary,
+                        source,
+                        tree,
+                        grammar,
+                    )?;
+                }
+            }
         }
     }
 
+    // `@param`/`@returns` and rustdoc sections, before the primary symbol
+    // can be re-picked below
+    if lang.is_programming_language() {
+        crate::detectors::common::attach_doc_tags(&mut summary, source);
+    }
+
+    if options.scoring != SymbolScoringProfile::default() {
+        apply_scoring_profile(&mut summary, &options.scoring);
+    }
+
+    // Commented-out code, test-parsed with the file's own grammar, and
+    // statement runs copied within a function
+    if lang.is_programming_language() && !lang.is_vue_sfc() && !lang.is_svelte_component() {
+        summary.commented_code =
+            crate::detectors::common::find_commented_code(&tree.root_node(), source, lang);
+        summary.repeated_blocks =
+            crate::detectors::common::find_repeated_blocks(&tree.root_node(), source);
+    }
+
+    // Naming convention lint for each symbol's kind and language
+    crate::detectors::common::check_naming_conventions(&mut summary.symbols, lang);
+
+    // Methods leaning on another object's behavior
+    crate::detectors::common::detect_feature_envy(&mut summary.symbols);
+
+    // Accept/visit pairs (visitor pattern, double dispatch)
+    crate::detectors::common::detect_visitor_pattern(&mut summary.symbols);
+
+    // Empty or not-implemented function bodies
+    if lang.is_programming_language() {
+        crate::detectors::common::mark_stub_implementations(
+            &tree.root_node(),
+            source,
+            &mut summary.symbols,
+        );
+        crate::detectors::common::extract_type_params(
+            &tree.root_node(),
+            source,
+            &mut summary.symbols,
+        );
+    }
+
+    // Caller-registered transformers, before insertions are ordered and scored
+    for transformer in &options.transformers {
+        transformer.transform(&mut summary);
+    }
+
     // Reorder insertions: put state hooks last per spec
     reorder_insertions(&mut summary.insertions);
 
@@ -97,33 +405,8 @@ pub fn extract(file_path: &Path, source: &str, tree: &Tree, lang: Lang) -> Resul
     summary.symbol_id = SymbolId::from_summary(&summary);
 
     // Add raw fallback if extraction was incomplete
-    if !summary.extraction_complete {
-        // Adaptive fallback sizing: 10% of source, clamped between MIN and MAX
-        let adaptive_len = (source.len() / 10).clamp(MIN_FALLBACK_LEN, MAX_FALLBACK_LEN);
-
-        if source.len() > adaptive_len {
-            // Include both head (2/3) and tail (1/3) for context
-            let head_len = adaptive_len * 2 / 3;
-            let tail_len = adaptive_len / 3;
-
-            let head = truncate_to_char_boundary(source, head_len);
-            let omitted = source.len() - head_len - tail_len;
-
-            // Find a valid UTF-8 boundary for tail start
-            let tail_start = source.len().saturating_sub(tail_len);
-            let tail = source
-                .char_indices()
-                .find(|(i, _)| *i >= tail_start)
-                .map(|(i, _)| &source[i..])
-                .unwrap_or("");
-
-            summary.raw_fallback = Some(format!(
-                "{}...\n\n[{} bytes omitted]\n\n...{}",
-                head, omitted, tail
-            ));
-        } else {
-            summary.raw_fallback = Some(source.to_string());
-        }
+    if !summary.extraction_complete && !options.no_fallback {
+        summary.raw_fallback = Some(options.fallback.truncate(source));
     }
 
     Ok(summary)
@@ -144,6 +427,33 @@ fn reorder_insertions(insertions: &mut Vec<String>) {
     insertions.extend(state_hooks);
 }
 
+/// Re-rank symbols by `profile` and make the best one the primary symbol
+///
+/// The sort is stable, so ties keep the language's own order. Escape locals
+/// never become primary.
+fn apply_scoring_profile(summary: &mut SemanticSummary, profile: &SymbolScoringProfile) {
+    let filename_stem = crate::detectors::extract_filename_stem(&summary.file);
+    summary.symbols.sort_by_cached_key(|s| {
+        std::cmp::Reverse(if s.is_escape_local {
+            i32::MIN
+        } else {
+            profile.score(s, &filename_stem)
+        })
+    });
+
+    let Some(primary) = summary.symbols.first().filter(|s| !s.is_escape_local) else {
+        return;
+    };
+    summary.symbol = Some(primary.name.clone());
+    summary.symbol_kind = Some(primary.kind);
+    summary.start_line = Some(primary.start_line);
+    summary.end_line = Some(primary.end_line);
+    summary.public_surface_changed = primary.is_exported;
+    summary.arguments = primary.arguments.clone();
+    summary.props = primary.props.clone();
+    summary.return_type = primary.return_type.clone();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,6 +487,321 @@ export default function AppLayout() {
         assert!(!summary.added_dependencies.is_empty());
     }
 
+    #[test]
+    fn test_naming_violation() {
+        let source = r#"
+export function My_Function() {
+    return 1;
+}
+
+export function parseInput(raw) {
+    return raw.trim();
+}
+"#;
+
+        let tree = parse_source(source, Lang::JavaScript);
+        let path = PathBuf::from("naming.js");
+        let summary = extract(&path, source, &tree, Lang::JavaScript).unwrap();
+
+        let violation = |name: &str| {
+            summary
+                .symbols
+                .iter()
+                .find(|s| s.name == name)
+                .and_then(|s| s.naming_violation.clone())
+        };
+        assert_eq!(
+            violation("My_Function"),
+            Some("expected camelCase or PascalCase".to_string())
+        );
+        assert_eq!(violation("parseInput"), None);
+    }
+
+    #[test]
+    fn test_feature_envy() {
+        let source = r#"
+class InvoicePrinter:
+    def render(self, other):
+        other.load()
+        other.lines()
+        other.subtotal()
+        other.tax()
+        other.currency()
+        self.emit()
+
+    def emit(self):
+        self.flush()
+"#;
+
+        let tree = parse_source(source, Lang::Python);
+        let path = PathBuf::from("printer.py");
+        let summary = extract(&path, source, &tree, Lang::Python).unwrap();
+
+        let envy = |name: &str| {
+            summary
+                .symbols
+                .iter()
+                .find(|s| s.name == name)
+                .and_then(|s| s.feature_envy.clone())
+        };
+        assert_eq!(envy("render"), Some("other".to_string()));
+        assert_eq!(envy("emit"), None);
+    }
+
+    #[test]
+    fn test_visitor_pattern() {
+        let source = r#"
+class ShapeVisitor:
+    def visit_circle(self, circle):
+        return circle.radius
+
+    def visit_square(self, square):
+        return square.side
+
+
+class Circle:
+    def accept(self, visitor):
+        return visitor.visit_circle(self)
+
+
+class Square:
+    def accept(self, visitor):
+        return visitor.visit_square(self)
+
+
+class Plain:
+    def describe(self):
+        return self.name
+"#;
+
+        let tree = parse_source(source, Lang::Python);
+        let path = PathBuf::from("shapes.py");
+        let summary = extract(&path, source, &tree, Lang::Python).unwrap();
+
+        let patterns = |name: &str, start_line: usize| {
+            summary
+                .symbols
+                .iter()
+                .find(|s| s.name == name && s.start_line == start_line)
+                .map(|s| s.patterns.clone())
+                .unwrap_or_default()
+        };
+        assert_eq!(patterns("ShapeVisitor", 2), vec!["visitor"]);
+        assert_eq!(patterns("Circle", 10), vec!["visitor"]);
+        assert_eq!(patterns("Square", 15), vec!["visitor"]);
+        assert_eq!(patterns("accept", 11), vec!["double_dispatch"]);
+        assert!(patterns("Plain", 20).is_empty());
+    }
+
+    #[test]
+    fn test_stub_implementations() {
+        let stubs = |source: &str, file: &str, lang: Lang| -> Vec<String> {
+            let tree = parse_source(source, lang);
+            let summary = extract(&PathBuf::from(file), source, &tree, lang).unwrap();
+            summary
+                .symbols
+                .iter()
+                .filter(|s| s.is_stub_impl)
+                .map(|s| s.name.clone())
+                .collect()
+        };
+
+        let rust = r#"
+pub fn pending(x: i32) -> i32 {
+    todo!()
+}
+
+pub fn done(x: i32) -> i32 {
+    x + 1
+}
+"#;
+        assert_eq!(stubs(rust, "lib.rs", Lang::Rust), vec!["pending"]);
+
+        let python = r#"
+def pending(x):
+    """Not written yet."""
+    pass
+
+
+def done(x):
+    return x + 1
+"#;
+        assert_eq!(stubs(python, "calc.py", Lang::Python), vec!["pending"]);
+
+        let js = r#"
+export function pending(x) {
+    throw new Error("not implemented");
+}
+
+export function done(x) {
+    if (x < 0) {
+        throw new Error("negative");
+    }
+    return x + 1;
+}
+"#;
+        assert_eq!(stubs(js, "calc.js", Lang::JavaScript), vec!["pending"]);
+    }
+
+    #[test]
+    fn test_fallback_line_limit_matches_encoding() {
+        use crate::toon::encode_toon_with_fallback;
+
+        let source: String = (1..=12).map(|i| format!("// note {}\n", i)).collect();
+        let tree = parse_source(&source, Lang::JavaScript);
+        let fallback = FallbackConfig {
+            max_lines: Some(5),
+            ..Default::default()
+        };
+        let options = ExtractOptions {
+            fallback,
+            ..Default::default()
+        };
+        let summary = extract_with_options(
+            &PathBuf::from("notes.js"),
+            &source,
+            &tree,
+            Lang::JavaScript,
+            options,
+        )
+        .unwrap();
+
+        // Four head lines and one tail line around the marker
+        let raw = summary.raw_fallback.as_deref().unwrap();
+        let kept = raw.lines().filter(|l| l.starts_with("// note")).count();
+        assert_eq!(kept, 5, "{}", raw);
+        assert!(
+            raw.contains("// note 4\n[7 lines omitted]\n// note 12"),
+            "{}",
+            raw
+        );
+        assert!(!raw.contains("// note 5"), "{}", raw);
+
+        // The encoder renders those same lines once they fit inline
+        let inline = FallbackConfig {
+            inline_lines: 6,
+            ..fallback
+        };
+        let toon = encode_toon_with_fallback(&summary, &inline);
+        assert!(
+            toon.contains("note 4 [7 lines omitted] // note 12"),
+            "{}",
+            toon
+        );
+        assert!(!toon.contains("note 5"), "{}", toon);
+
+        // and, by default, as a line count of the stored fallback
+        let toon = encode_toon_with_fallback(&summary, &fallback);
+        assert!(toon.contains("(6 lines)"), "{}", toon);
+    }
+
+    #[test]
+    fn test_summary_transformer_adds_insertion() {
+        struct AuditTag;
+
+        impl SummaryTransformer for AuditTag {
+            fn transform(&self, summary: &mut SemanticSummary) {
+                let tag = format!("audited: {}", summary.file);
+                summary.insertions.push(tag);
+            }
+        }
+
+        let source = "export function load(id) { return fetch(`/users/${id}`); }";
+        let tree = parse_source(source, Lang::JavaScript);
+        let options = ExtractOptions {
+            transformers: vec![Arc::new(AuditTag)],
+            ..Default::default()
+        };
+        let summary = extract_with_options(
+            &PathBuf::from("load.js"),
+            source,
+            &tree,
+            Lang::JavaScript,
+            options,
+        )
+        .unwrap();
+
+        assert!(
+            summary.insertions.contains(&"audited: load.js".to_string()),
+            "{:?}",
+            summary.insertions
+        );
+    }
+
+    #[test]
+    fn test_scoring_profile_changes_primary_symbol() {
+        let source = r#"
+export class UserStore {
+    load(id: string) {
+        return fetch(`/users/${id}`);
+    }
+}
+
+export function UserCard({ name }: { name: string }) {
+    return <div>{name}</div>;
+}
+"#;
+        let tree = parse_source(source, Lang::Tsx);
+        let primary = |scoring: SymbolScoringProfile| {
+            let options = ExtractOptions {
+                scoring,
+                ..Default::default()
+            };
+            extract_with_options(
+                &PathBuf::from("user.tsx"),
+                source,
+                &tree,
+                Lang::Tsx,
+                options,
+            )
+            .unwrap()
+            .symbol
+        };
+
+        assert_eq!(
+            primary(SymbolScoringProfile::default()).as_deref(),
+            Some("UserCard")
+        );
+        assert_eq!(
+            primary(SymbolScoringProfile::library()).as_deref(),
+            Some("UserStore")
+        );
+    }
+
+    #[test]
+    fn test_type_params() {
+        let symbol = |source: &str, file: &str, lang: Lang, name: &str| {
+            let tree = parse_source(source, lang);
+            let summary = extract(&PathBuf::from(file), source, &tree, lang).unwrap();
+            summary
+                .symbols
+                .into_iter()
+                .find(|s| s.name == name)
+                .unwrap()
+        };
+
+        let rust = r#"
+pub fn largest<T: PartialOrd + Clone>(items: &[T]) -> T {
+    items[0].clone()
+}
+"#;
+        let largest = symbol(rust, "lib.rs", Lang::Rust, "largest");
+        assert_eq!(largest.type_params, vec!["T: PartialOrd + Clone"]);
+        assert!(
+            crate::toon::symbol_signature(&largest).starts_with("largest<T: PartialOrd + Clone>("),
+            "{}",
+            crate::toon::symbol_signature(&largest)
+        );
+
+        let ts = r#"
+export class Box<T, K extends string = "id"> {
+    constructor(private value: T) {}
+}
+"#;
+        let boxed = symbol(ts, "box.ts", Lang::TypeScript, "Box");
+        assert_eq!(boxed.type_params, vec!["T", "K extends string = \"id\""]);
+    }
+
     #[test]
     fn test_jsx_component_calls_in_summary() {
         let source = r#"
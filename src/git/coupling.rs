@@ -0,0 +1,154 @@
+//! Logical (co-change) coupling from git history
+//!
+//! Files that keep changing in the same commits depend on each other even
+//! when no import says so: a schema and the serializer that mirrors it, a
+//! handler and its hand-written client. [`co_change_coupling`] walks recent
+//! history and ranks file pairs by how often they were committed together.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use super::git_command;
+use crate::error::Result;
+
+/// Commits read from history by [`co_change_coupling`]
+pub const DEFAULT_COUPLING_COMMITS: usize = 500;
+
+/// Pairs reported by [`co_change_coupling`]
+pub const DEFAULT_MAX_COUPLED_PAIRS: usize = 50;
+
+/// Commits touching more files are skipped; mass renames and reformatting
+/// say nothing about coupling and would add a quadratic number of pairs
+const MAX_FILES_PER_COMMIT: usize = 30;
+
+/// Pairs that changed together fewer times are coincidence
+const MIN_SHARED_COMMITS: usize = 2;
+
+/// Two files that tend to change in the same commits
+#[derive(Debug, Clone, PartialEq)]
+pub struct CoChangePair {
+    /// Lexicographically smaller path of the pair
+    pub file_a: String,
+    pub file_b: String,
+    /// Commits that changed both files
+    pub shared_commits: usize,
+    /// `shared_commits` over commits that changed either file (0.0-1.0)
+    pub degree: f64,
+}
+
+/// File pairs most often changed together in the last
+/// [`DEFAULT_COUPLING_COMMITS`] commits
+pub fn co_change_coupling(repo: &Path) -> Result<Vec<CoChangePair>> {
+    co_change_coupling_with_limits(repo, DEFAULT_COUPLING_COMMITS, DEFAULT_MAX_COUPLED_PAIRS)
+}
+
+/// File pairs changed together in at least two of the last `max_commits`
+/// non-merge commits, most shared commits first, capped at `max_pairs`
+pub fn co_change_coupling_with_limits(
+    repo: &Path,
+    max_commits: usize,
+    max_pairs: usize,
+) -> Result<Vec<CoChangePair>> {
+    // One record per commit: a separator line, then the changed paths
+    let output = git_command(
+        &[
+            "log",
+            "--no-merges",
+            "--name-only",
+            "--format=%x1e",
+            &format!("--max-count={}", max_commits),
+        ],
+        Some(repo),
+    )?;
+
+    let mut file_commits: HashMap<&str, usize> = HashMap::new();
+    let mut pair_commits: HashMap<(&str, &str), usize> = HashMap::new();
+    for record in output.split('\u{1e}') {
+        let mut files: Vec<&str> = record
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .collect();
+        files.sort_unstable();
+        files.dedup();
+        if files.len() > MAX_FILES_PER_COMMIT {
+            continue;
+        }
+
+        for (i, a) in files.iter().enumerate() {
+            *file_commits.entry(*a).or_default() += 1;
+            for b in &files[i + 1..] {
+                *pair_commits.entry((*a, *b)).or_default() += 1;
+            }
+        }
+    }
+
+    let mut pairs: Vec<CoChangePair> = pair_commits
+        .into_iter()
+        .filter(|(_, shared)| *shared >= MIN_SHARED_COMMITS)
+        .map(|((a, b), shared)| {
+            let either = file_commits[a] + file_commits[b] - shared;
+            CoChangePair {
+                file_a: a.to_string(),
+                file_b: b.to_string(),
+                shared_commits: shared,
+                degree: shared as f64 / either as f64,
+            }
+        })
+        .collect();
+    pairs.sort_by(|x, y| {
+        y.shared_commits
+            .cmp(&x.shared_commits)
+            .then_with(|| y.degree.total_cmp(&x.degree))
+            .then_with(|| (&x.file_a, &x.file_b).cmp(&(&y.file_a, &y.file_b)))
+    });
+    pairs.truncate(max_pairs);
+
+    Ok(pairs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        git_command(args, Some(dir)).expect("git command failed");
+    }
+
+    fn commit(dir: &Path, files: &[&str], message: &str) {
+        for file in files {
+            let path = dir.join(file);
+            let previous = fs::read_to_string(&path).unwrap_or_default();
+            fs::write(&path, format!("{}{}\n", previous, message)).unwrap();
+        }
+        git(dir, &["add", "-A"]);
+        git(dir, &["commit", "-m", message]);
+    }
+
+    #[test]
+    fn test_files_always_committed_together_are_coupled() {
+        let dir = TempDir::new().unwrap();
+        let repo = dir.path();
+        git(repo, &["init", "-b", "main"]);
+        git(repo, &["config", "user.email", "test@example.com"]);
+        git(repo, &["config", "user.name", "Test User"]);
+
+        commit(repo, &["schema.rs", "serializer.rs"], "add user");
+        commit(repo, &["schema.rs", "serializer.rs"], "add email");
+        commit(repo, &["schema.rs", "serializer.rs", "main.rs"], "add age");
+        commit(repo, &["main.rs"], "wire up");
+
+        let pairs = co_change_coupling(repo).unwrap();
+        assert_eq!(pairs.len(), 1, "{:?}", pairs);
+        assert_eq!(pairs[0].file_a, "schema.rs");
+        assert_eq!(pairs[0].file_b, "serializer.rs");
+        assert_eq!(pairs[0].shared_commits, 3);
+        assert_eq!(pairs[0].degree, 1.0);
+
+        // A shallower history sees too few shared commits
+        let recent = co_change_coupling_with_limits(repo, 2, 10).unwrap();
+        assert!(recent.is_empty(), "{:?}", recent);
+    }
+}
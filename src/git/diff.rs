@@ -4,8 +4,11 @@
 
 use std::path::Path;
 
-use super::git_command;
+use super::{get_file_at_ref, git_command};
 use crate::error::{McpDiffError, Result};
+use crate::lang::Lang;
+use crate::parsing::parse_and_extract;
+use crate::schema::SemanticSummary;
 
 /// Type of change to a file
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -213,6 +216,43 @@ pub fn get_unstaged_changes(cwd: Option<&Path>) -> Result<Vec<ChangedFile>> {
     parse_name_status_output(&output)
 }
 
+/// Summaries of the files changed between two refs, as they were before and
+/// after the change
+///
+/// Each side is read with `git show` at its ref. Renamed files are keyed by
+/// their new path on both sides so the two can be paired; added files have
+/// no before summary and deleted files no after summary. Files in
+/// unsupported languages, or that fail to parse, are left out.
+pub(super) fn summarize_changed_files(
+    from_ref: &str,
+    to_ref: &str,
+    repo_root: &Path,
+) -> Result<(Vec<SemanticSummary>, Vec<SemanticSummary>)> {
+    let changed_files = get_changed_files(from_ref, to_ref, Some(repo_root))?;
+
+    let extract_at = |path: &str, git_ref: &str, key: &str| -> Option<SemanticSummary> {
+        let lang = Lang::from_path(Path::new(path)).ok()?;
+        let source = get_file_at_ref(path, git_ref, Some(repo_root)).ok()??;
+        let mut summary = parse_and_extract(&repo_root.join(path), &source, lang).ok()?;
+        summary.file = key.to_string();
+        Some(summary)
+    };
+
+    let mut before = Vec::new();
+    let mut after = Vec::new();
+    for change in &changed_files {
+        if change.change_type != ChangeType::Added {
+            let old_path = change.old_path.as_deref().unwrap_or(&change.path);
+            before.extend(extract_at(old_path, from_ref, &change.path));
+        }
+        if change.change_type != ChangeType::Deleted {
+            after.extend(extract_at(&change.path, to_ref, &change.path));
+        }
+    }
+
+    Ok((before, after))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
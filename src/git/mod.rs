@@ -5,7 +5,11 @@
 
 mod branch;
 mod commit;
+mod coupling;
 mod diff;
+mod overview_diff;
+mod patch;
+mod semver;
 
 pub use branch::{
     detect_base_branch, get_current_branch, get_merge_base, get_remote_url, is_git_repo,
@@ -14,10 +18,21 @@ pub use commit::{
     get_commits_since, get_file_at_ref, get_last_commit, get_parent_commit, get_repo_root,
     CommitInfo,
 };
+pub use coupling::{
+    co_change_coupling, co_change_coupling_with_limits, CoChangePair, DEFAULT_COUPLING_COMMITS,
+    DEFAULT_MAX_COUPLED_PAIRS,
+};
 pub use diff::{
     get_changed_files, get_commit_changed_files, get_staged_changes, get_uncommitted_changes,
     get_unstaged_changes, ChangeType, ChangedFile,
 };
+pub use overview_diff::{
+    diff_overview, diff_overviews, HighRiskEntry, ModuleRiskChange, OverviewDiff,
+};
+pub use patch::{analyze_patch, parse_unified_diff, PatchFile, PatchHunk, PatchSymbol};
+pub use semver::{
+    classify_api_changes, semver_impact, ApiChange, ApiChangeKind, SemverBump, SemverReport,
+};
 
 use std::path::Path;
 use std::process::Command;
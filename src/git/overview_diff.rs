@@ -0,0 +1,184 @@
+//! Module-level risk movement between two refs
+//!
+//! Answers "did this release make the codebase better or worse": every file
+//! changed between the refs is summarized on both sides, each side is grouped
+//! into modules the way the repository overview groups them, and the modules
+//! whose risk or symbol count moved are reported along with files that became
+//! high risk. Unchanged files are identical on both sides, so only changed
+//! files are read.
+
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use serde::Serialize;
+
+use super::diff::summarize_changed_files;
+use crate::error::Result;
+use crate::schema::{RiskLevel, SemanticSummary};
+use crate::shard::extract_module_name;
+use crate::toon::generate_repo_overview;
+
+/// One module's risk and symbol count on each side of the diff
+///
+/// `None` risk means the module had no changed files on that side (it was
+/// added or removed).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ModuleRiskChange {
+    pub module: String,
+    pub risk_before: Option<RiskLevel>,
+    pub risk_after: Option<RiskLevel>,
+    pub symbols_before: usize,
+    pub symbols_after: usize,
+}
+
+impl ModuleRiskChange {
+    /// Whether the module's risk went up (a new module counts from low)
+    pub fn is_riskier(&self) -> bool {
+        self.risk_after.unwrap_or_default() > self.risk_before.unwrap_or_default()
+    }
+
+    /// Whether the module's risk went down (a removed module counts to low)
+    pub fn is_safer(&self) -> bool {
+        self.risk_after.unwrap_or_default() < self.risk_before.unwrap_or_default()
+    }
+}
+
+/// A file that is high risk after the diff but was not before
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HighRiskEntry {
+    pub file: String,
+    /// The file's primary symbol, if it has one
+    pub symbol: Option<String>,
+}
+
+/// Module risk movement and new high-risk files between two sides
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct OverviewDiff {
+    /// Modules whose risk or symbol count changed, by name
+    pub modules: Vec<ModuleRiskChange>,
+    pub new_high_risk: Vec<HighRiskEntry>,
+}
+
+/// (risk, symbol count) per module
+fn module_totals(summaries: &[SemanticSummary]) -> HashMap<String, (RiskLevel, usize)> {
+    let overview = generate_repo_overview(summaries, ".");
+    let mut totals: HashMap<String, (RiskLevel, usize)> = overview
+        .modules
+        .iter()
+        .map(|m| (m.name.clone(), (m.risk, 0)))
+        .collect();
+    for summary in summaries {
+        if let Some(entry) = totals.get_mut(&extract_module_name(&summary.file)) {
+            entry.1 += summary.symbols.len();
+        }
+    }
+    totals
+}
+
+/// Compare module risk between summaries of the same changed files
+///
+/// `before` and `after` should be keyed by the same paths; a file present on
+/// one side only was added or deleted.
+pub fn diff_overviews(before: &[SemanticSummary], after: &[SemanticSummary]) -> OverviewDiff {
+    let old = module_totals(before);
+    let new = module_totals(after);
+
+    let names: BTreeSet<&str> = old.keys().chain(new.keys()).map(String::as_str).collect();
+    let modules = names
+        .into_iter()
+        .map(|name| ModuleRiskChange {
+            module: name.to_string(),
+            risk_before: old.get(name).map(|t| t.0),
+            risk_after: new.get(name).map(|t| t.0),
+            symbols_before: old.get(name).map_or(0, |t| t.1),
+            symbols_after: new.get(name).map_or(0, |t| t.1),
+        })
+        .filter(|m| m.risk_before != m.risk_after || m.symbols_before != m.symbols_after)
+        .collect();
+
+    let was_high: HashMap<&str, bool> = before
+        .iter()
+        .map(|s| (s.file.as_str(), s.behavioral_risk == RiskLevel::High))
+        .collect();
+    let new_high_risk = after
+        .iter()
+        .filter(|s| s.behavioral_risk == RiskLevel::High)
+        .filter(|s| !was_high.get(s.file.as_str()).copied().unwrap_or(false))
+        .map(|s| HighRiskEntry {
+            file: s.file.clone(),
+            symbol: s.symbol.clone(),
+        })
+        .collect();
+
+    OverviewDiff {
+        modules,
+        new_high_risk,
+    }
+}
+
+/// Module risk movement from `base_ref` to `target_ref`
+///
+/// Unlike a branch diff, the refs are compared directly rather than from
+/// their merge base, so two commits or two release tags can be compared.
+/// Both sides are read with `git show`; renamed files are compared under
+/// their new path.
+pub fn diff_overview(base_ref: &str, target_ref: &str, repo_root: &Path) -> Result<OverviewDiff> {
+    let (before, after) = summarize_changed_files(base_ref, target_ref, repo_root)?;
+
+    Ok(diff_overviews(&before, &after))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::git_command;
+    use crate::shard::extract_module_name;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn git(dir: &Path, args: &[&str]) {
+        git_command(args, Some(dir)).expect("git command failed");
+    }
+
+    fn commit(dir: &Path, file: &str, content: &str, message: &str) {
+        let path = dir.join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, content).unwrap();
+        git(dir, &["add", "-A"]);
+        git(dir, &["commit", "-m", message]);
+    }
+
+    #[test]
+    fn test_module_gaining_high_risk_function_is_riskier() {
+        let dir = TempDir::new().unwrap();
+        let repo = dir.path();
+        git(repo, &["init", "-b", "main"]);
+        git(repo, &["config", "user.email", "test@example.com"]);
+        git(repo, &["config", "user.name", "Test User"]);
+
+        let helper = "function label(user) {\n    return user.name;\n}\n";
+        commit(repo, "src/api/users.ts", helper, "add label");
+        commit(repo, "src/ui/button.ts", helper, "add button");
+
+        let risky = format!(
+            "{}\nexport function syncUsers(ids) {{\n    for (const id of ids) {{\n        \
+             if (id) {{\n            upload(id);\n        }}\n    }}\n}}\n",
+            helper
+        );
+        commit(repo, "src/api/users.ts", &risky, "sync users");
+
+        let diff = diff_overview("HEAD~1", "HEAD", repo).unwrap();
+        let api = extract_module_name("src/api/users.ts");
+        assert_eq!(diff.modules.len(), 1, "{:?}", diff.modules);
+        let module = &diff.modules[0];
+        assert_eq!(module.module, api);
+        assert_eq!(module.risk_before, Some(RiskLevel::Low));
+        assert_eq!(module.risk_after, Some(RiskLevel::High));
+        assert!(module.is_riskier());
+        assert_eq!((module.symbols_before, module.symbols_after), (1, 2));
+
+        assert_eq!(diff.new_high_risk.len(), 1, "{:?}", diff.new_high_risk);
+        assert_eq!(diff.new_high_risk[0].file, "src/api/users.ts");
+        assert_eq!(diff.new_high_risk[0].symbol.as_deref(), Some("syncUsers"));
+    }
+}
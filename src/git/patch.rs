@@ -0,0 +1,261 @@
+//! Unified diff (patch) analysis
+//!
+//! Parses `git diff` / `diff -u` output and reports the symbols each change
+//! touches without either revision checked out. The new side of every hunk is
+//! laid out at its original line numbers, with blank lines in the gaps, and
+//! parsed with the file's grammar; tree-sitter's error recovery copes with the
+//! code missing between hunks. A symbol is only found when its definition is
+//! inside a hunk, so diffs generated with `--function-context` (`-W`) give the
+//! most complete results.
+
+use std::path::Path;
+
+use super::ChangeType;
+use crate::error::Result;
+use crate::lang::Lang;
+use crate::parsing::parse_and_extract;
+use crate::schema::{RiskLevel, SymbolKind};
+
+/// One file's section of a unified diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatchFile {
+    /// Path on the new side (old side for deletions)
+    pub path: String,
+    /// Added, modified, or deleted
+    pub change_type: ChangeType,
+    /// Hunks in diff order
+    pub hunks: Vec<PatchHunk>,
+}
+
+/// New-side content of one hunk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatchHunk {
+    /// First new-side line (1-based)
+    pub new_start: usize,
+    /// New-side lines (context and additions) in order
+    pub lines: Vec<String>,
+    /// New-side lines that were added, or that follow a removal
+    pub changed_lines: Vec<usize>,
+}
+
+/// A symbol whose definition spans a changed line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatchSymbol {
+    pub file: String,
+    pub name: String,
+    pub kind: SymbolKind,
+    pub start_line: usize,
+    pub end_line: usize,
+    pub risk: RiskLevel,
+    pub change_type: ChangeType,
+}
+
+impl PatchFile {
+    /// New-side source with every hunk at its own line numbers
+    pub fn reconstruct(&self) -> String {
+        let mut lines: Vec<&str> = Vec::new();
+        for hunk in &self.hunks {
+            let first = hunk.new_start.saturating_sub(1);
+            while lines.len() < first {
+                lines.push("");
+            }
+            lines.extend(hunk.lines.iter().map(String::as_str));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Parse unified diff text into per-file hunks
+///
+/// Hunk bodies are consumed by their header counts, so removed lines that
+/// themselves start with `--` are not mistaken for file headers.
+pub fn parse_unified_diff(diff: &str) -> Vec<PatchFile> {
+    let mut files: Vec<PatchFile> = Vec::new();
+    let mut old_path: Option<String> = None;
+    let mut new_line = 0usize;
+    let mut old_remaining = 0usize;
+    let mut new_remaining = 0usize;
+
+    for line in diff.lines() {
+        if old_remaining > 0 || new_remaining > 0 {
+            let Some(hunk) = files.last_mut().and_then(|f| f.hunks.last_mut()) else {
+                continue;
+            };
+            match line.as_bytes().first() {
+                Some(b'+') => {
+                    hunk.lines.push(line[1..].to_string());
+                    if hunk.changed_lines.last() != Some(&new_line) {
+                        hunk.changed_lines.push(new_line);
+                    }
+                    new_line += 1;
+                    new_remaining = new_remaining.saturating_sub(1);
+                }
+                Some(b'-') => {
+                    if hunk.changed_lines.last() != Some(&new_line) {
+                        hunk.changed_lines.push(new_line);
+                    }
+                    old_remaining = old_remaining.saturating_sub(1);
+                }
+                // "\ No newline at end of file"
+                Some(b'\\') => {}
+                _ => {
+                    hunk.lines.push(line.get(1..).unwrap_or("").to_string());
+                    new_line += 1;
+                    old_remaining = old_remaining.saturating_sub(1);
+                    new_remaining = new_remaining.saturating_sub(1);
+                }
+            }
+            continue;
+        }
+
+        if line.starts_with("diff ") {
+            old_path = None;
+        } else if let Some(path) = line.strip_prefix("--- ") {
+            old_path = diff_path(path);
+        } else if let Some(path) = line.strip_prefix("+++ ") {
+            let (path, change_type) = match (old_path.take(), diff_path(path)) {
+                (None, Some(new)) => (new, ChangeType::Added),
+                (Some(old), None) => (old, ChangeType::Deleted),
+                (Some(_), Some(new)) => (new, ChangeType::Modified),
+                (None, None) => continue,
+            };
+            files.push(PatchFile {
+                path,
+                change_type,
+                hunks: Vec::new(),
+            });
+        } else if let Some(header) = line.strip_prefix("@@ ") {
+            let (Some(file), Some((old_count, new_start, new_count))) =
+                (files.last_mut(), parse_hunk_header(header))
+            else {
+                continue;
+            };
+            new_line = new_start;
+            old_remaining = old_count;
+            new_remaining = new_count;
+            file.hunks.push(PatchHunk {
+                new_start,
+                lines: Vec::new(),
+                changed_lines: Vec::new(),
+            });
+        }
+    }
+
+    files
+}
+
+/// Report the symbols touched by each added or modified file in a diff
+///
+/// Deleted files are skipped; new files are parsed whole. Files in
+/// unsupported languages are ignored.
+pub fn analyze_patch(diff_text: &str) -> Result<Vec<PatchSymbol>> {
+    let mut touched = Vec::new();
+
+    for file in parse_unified_diff(diff_text) {
+        if file.change_type == ChangeType::Deleted {
+            continue;
+        }
+        let path = Path::new(&file.path);
+        let Ok(lang) = Lang::from_path(path) else {
+            continue;
+        };
+
+        let summary = parse_and_extract(path, &file.reconstruct(), lang)?;
+        let changed: Vec<usize> = file
+            .hunks
+            .iter()
+            .flat_map(|h| h.changed_lines.iter().copied())
+            .collect();
+
+        for symbol in summary.symbols.iter().filter(|s| !s.is_escape_local) {
+            if changed
+                .iter()
+                .any(|&line| line >= symbol.start_line && line <= symbol.end_line)
+            {
+                touched.push(PatchSymbol {
+                    file: file.path.clone(),
+                    name: symbol.name.clone(),
+                    kind: symbol.kind,
+                    start_line: symbol.start_line,
+                    end_line: symbol.end_line,
+                    risk: symbol.behavioral_risk,
+                    change_type: file.change_type,
+                });
+            }
+        }
+    }
+
+    Ok(touched)
+}
+
+/// Strip `a/`/`b/` prefixes and trailing timestamps; `None` for `/dev/null`
+fn diff_path(raw: &str) -> Option<String> {
+    let path = raw.split('\t').next().unwrap_or(raw).trim();
+    if path == "/dev/null" {
+        return None;
+    }
+    let path = path
+        .strip_prefix("a/")
+        .or_else(|| path.strip_prefix("b/"))
+        .unwrap_or(path);
+    Some(path.to_string())
+}
+
+/// Parse `-a,b +c,d @@ ...` into (old count, new start, new count)
+fn parse_hunk_header(header: &str) -> Option<(usize, usize, usize)> {
+    let range = |range: &str| -> Option<(usize, usize)> {
+        match range.split_once(',') {
+            Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+            None => Some((range.parse().ok()?, 1)),
+        }
+    };
+
+    let mut parts = header.split_whitespace();
+    let (_, old_count) = range(parts.next()?.strip_prefix('-')?)?;
+    let (new_start, new_count) = range(parts.next()?.strip_prefix('+')?)?;
+    Some((old_count, new_start, new_count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analyze_patch_reports_touched_symbol() {
+        let diff = "\
+diff --git a/src/math.rs b/src/math.rs
+index 1111111..2222222 100644
+--- a/src/math.rs
++++ b/src/math.rs
+@@ -1,9 +1,9 @@
+ pub fn add(a: i32, b: i32) -> i32 {
+     a + b
+ }
+
+ pub fn scale(value: i32, factor: i32) -> i32 {
+-    value * factor
++    value.saturating_mul(factor)
+ }
+
+ pub fn unchanged() {}
+diff --git a/src/old.rs b/src/old.rs
+deleted file mode 100644
+--- a/src/old.rs
++++ /dev/null
+@@ -1,3 +0,0 @@
+-pub fn gone() {
+-    todo!()
+-}
+";
+        let files = parse_unified_diff(diff);
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].hunks[0].changed_lines, vec![6]);
+        assert_eq!(files[1].change_type, ChangeType::Deleted);
+
+        let touched = analyze_patch(diff).unwrap();
+        let names: Vec<&str> = touched.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["scale"]);
+        assert_eq!(touched[0].file, "src/math.rs");
+        assert_eq!(touched[0].change_type, ChangeType::Modified);
+    }
+}
@@ -0,0 +1,230 @@
+//! Semver impact classification for a diff between two refs
+//!
+//! Compares the exported symbols of every changed file on both sides of the
+//! diff: a public symbol that disappears, is made private, or whose
+//! signature changes is a breaking (major) change, a new public symbol is a
+//! minor change, and anything else is a patch.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use super::diff::summarize_changed_files;
+use super::get_merge_base;
+use crate::error::Result;
+use crate::schema::SemanticSummary;
+use crate::toon::symbol_signature;
+
+/// Suggested version bump, ordered by severity
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SemverBump {
+    /// Internal-only changes
+    #[default]
+    Patch,
+    /// New public API
+    Minor,
+    /// Removed or changed public API
+    Major,
+}
+
+impl SemverBump {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Patch => "patch",
+            Self::Minor => "minor",
+            Self::Major => "major",
+        }
+    }
+}
+
+/// How a public symbol changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiChangeKind {
+    Added,
+    Removed,
+    /// Still defined, but no longer public
+    VisibilityReduced,
+    SignatureChanged,
+}
+
+impl ApiChangeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Added => "added",
+            Self::Removed => "removed",
+            Self::VisibilityReduced => "visibility_reduced",
+            Self::SignatureChanged => "signature_changed",
+        }
+    }
+
+    /// Bump this change requires on its own
+    pub fn bump(&self) -> SemverBump {
+        match self {
+            Self::Added => SemverBump::Minor,
+            Self::Removed | Self::VisibilityReduced | Self::SignatureChanged => SemverBump::Major,
+        }
+    }
+}
+
+/// One change to the public API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiChange {
+    pub file: String,
+    pub symbol: String,
+    pub kind: ApiChangeKind,
+    /// Signature after the change (before it, for removals)
+    pub signature: String,
+}
+
+/// Public API changes and the bump they call for
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SemverReport {
+    pub bump: SemverBump,
+    pub changes: Vec<ApiChange>,
+}
+
+/// (file, name, kind) -> signatures; overloads share a key
+type PublicApi = BTreeMap<(String, String, &'static str), BTreeSet<String>>;
+
+fn public_api(summaries: &[SemanticSummary]) -> PublicApi {
+    let mut api = PublicApi::new();
+    for summary in summaries {
+        for symbol in summary.symbols.iter().filter(|s| s.is_exported) {
+            api.entry((
+                summary.file.clone(),
+                symbol.name.clone(),
+                symbol.kind.as_str(),
+            ))
+            .or_default()
+            .insert(symbol_signature(symbol));
+        }
+    }
+    api
+}
+
+/// Classify the public API difference between two sets of summaries
+///
+/// `before` and `after` should cover the same changed files, keyed by the
+/// same path on both sides. Files present on only one side contribute all
+/// their exports as removed or added.
+pub fn classify_api_changes(before: &[SemanticSummary], after: &[SemanticSummary]) -> SemverReport {
+    let old_api = public_api(before);
+    let new_api = public_api(after);
+    // Every symbol on the new side, exported or not
+    let still_defined: BTreeSet<(&str, &str, &str)> = after
+        .iter()
+        .flat_map(|summary| {
+            summary
+                .symbols
+                .iter()
+                .map(|s| (summary.file.as_str(), s.name.as_str(), s.kind.as_str()))
+        })
+        .collect();
+    let mut changes = Vec::new();
+
+    for ((file, name, kind), old_sigs) in &old_api {
+        let change = match new_api.get(&(file.clone(), name.clone(), *kind)) {
+            None if still_defined.contains(&(file.as_str(), name.as_str(), *kind)) => {
+                Some((ApiChangeKind::VisibilityReduced, old_sigs))
+            }
+            None => Some((ApiChangeKind::Removed, old_sigs)),
+            Some(new_sigs) if new_sigs != old_sigs => {
+                Some((ApiChangeKind::SignatureChanged, new_sigs))
+            }
+            Some(_) => None,
+        };
+        if let Some((kind, sigs)) = change {
+            changes.push(ApiChange {
+                file: file.clone(),
+                symbol: name.clone(),
+                kind,
+                signature: sigs.iter().cloned().collect::<Vec<_>>().join(" | "),
+            });
+        }
+    }
+    for ((file, name, kind), new_sigs) in &new_api {
+        if !old_api.contains_key(&(file.clone(), name.clone(), *kind)) {
+            changes.push(ApiChange {
+                file: file.clone(),
+                symbol: name.clone(),
+                kind: ApiChangeKind::Added,
+                signature: new_sigs.iter().cloned().collect::<Vec<_>>().join(" | "),
+            });
+        }
+    }
+
+    SemverReport {
+        bump: changes
+            .iter()
+            .map(|c| c.kind.bump())
+            .max()
+            .unwrap_or_default(),
+        changes,
+    }
+}
+
+/// Classify the semver impact of the changes from `base_ref` to `target_ref`
+///
+/// Both sides are read with `git show`, so neither needs to be checked out.
+/// Renamed files are compared under their new path.
+pub fn semver_impact(base_ref: &str, target_ref: &str, repo_root: &Path) -> Result<SemverReport> {
+    let merge_base = get_merge_base(base_ref, target_ref, Some(repo_root))
+        .unwrap_or_else(|_| base_ref.to_string());
+    let (before, after) = summarize_changed_files(&merge_base, target_ref, repo_root)?;
+
+    Ok(classify_api_changes(&before, &after))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::Lang;
+    use crate::parsing::parse_and_extract;
+
+    fn summarize(source: &str) -> SemanticSummary {
+        parse_and_extract(Path::new("src/api.ts"), source, Lang::TypeScript).unwrap()
+    }
+
+    #[test]
+    fn test_signature_change_is_major() {
+        let before =
+            summarize("export function fetchUser(id: string): User { return load(id); }\n");
+        let after = summarize(
+            "export function fetchUser(id: string, opts: Options): User { return load(id); }\n",
+        );
+
+        let report = classify_api_changes(&[before], &[after]);
+        assert_eq!(report.bump, SemverBump::Major);
+        assert_eq!(report.changes.len(), 1);
+        assert_eq!(report.changes[0].symbol, "fetchUser");
+        assert_eq!(report.changes[0].kind, ApiChangeKind::SignatureChanged);
+    }
+
+    #[test]
+    fn test_new_public_function_is_minor() {
+        let before =
+            summarize("export function fetchUser(id: string): User { return load(id); }\n");
+        let after = summarize(
+            "export function fetchUser(id: string): User { return load(id); }\n\
+             export function deleteUser(id: string): void { remove(id); }\n\
+             function helper(): void {}\n",
+        );
+
+        let report = classify_api_changes(&[before], &[after]);
+        assert_eq!(report.bump, SemverBump::Minor);
+        assert_eq!(report.changes.len(), 1, "{:?}", report.changes);
+        assert_eq!(report.changes[0].symbol, "deleteUser");
+        assert_eq!(report.changes[0].kind, ApiChangeKind::Added);
+    }
+
+    #[test]
+    fn test_made_private_is_major() {
+        let before =
+            summarize("export function fetchUser(id: string): User { return load(id); }\n");
+        let after = summarize("function fetchUser(id: string): User { return load(id); }\n");
+
+        let report = classify_api_changes(&[before], &[after]);
+        assert_eq!(report.bump, SemverBump::Major);
+        assert_eq!(report.changes.len(), 1, "{:?}", report.changes);
+        assert_eq!(report.changes[0].kind, ApiChangeKind::VisibilityReduced);
+    }
+}
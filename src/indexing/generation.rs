@@ -4,11 +4,13 @@
 //! combining Rayon's parallel iteration with optional progress reporting.
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::SyncSender;
 
 use rayon::prelude::*;
 
+use crate::error::{McpDiffError, Result};
 use crate::parsing::parse_and_extract;
 use crate::{Lang, SemanticSummary};
 
@@ -87,42 +89,9 @@ pub fn analyze_files_parallel(
                 }
             }
 
-            // Determine language from file extension
-            let lang = match Lang::from_path(file_path) {
-                Ok(l) => l,
-                Err(e) => {
-                    if verbose {
-                        eprintln!("Skipping {}: {}", file_path.display(), e);
-                    }
-                    return None;
-                }
-            };
-
-            // Read file contents
-            let source = match fs::read_to_string(file_path) {
-                Ok(s) => s,
-                Err(e) => {
-                    errors.fetch_add(1, Ordering::Relaxed);
-                    if verbose {
-                        eprintln!("Error reading {}: {}", file_path.display(), e);
-                    }
-                    return None;
-                }
-            };
-
-            total_bytes.fetch_add(source.len(), Ordering::Relaxed);
-
-            // Parse and extract semantic summary
-            match parse_and_extract(file_path, &source, lang) {
-                Ok(summary) => Some(summary),
-                Err(e) => {
-                    errors.fetch_add(1, Ordering::Relaxed);
-                    if verbose {
-                        eprintln!("Error parsing {}: {}", file_path.display(), e);
-                    }
-                    None
-                }
-            }
+            let (summary, bytes) = analyze_file(file_path, verbose, &errors);
+            total_bytes.fetch_add(bytes, Ordering::Relaxed);
+            summary
         })
         .collect();
 
@@ -138,6 +107,148 @@ pub fn analyze_files_parallel(
     }
 }
 
+/// [`analyze_files_parallel`] on a dedicated pool of `threads` workers.
+///
+/// `None` uses the global Rayon pool, as [`analyze_files_parallel`] does;
+/// `Some(1)` analyzes the files one at a time.
+pub fn analyze_files_with_threads(
+    files: &[PathBuf],
+    threads: Option<usize>,
+    progress: Option<IndexingProgressCallback>,
+    verbose: bool,
+) -> Result<IndexGenerationResult> {
+    with_thread_pool(threads, || analyze_files_parallel(files, progress, verbose))
+}
+
+/// Run `op` on a dedicated Rayon pool of `threads` workers.
+///
+/// Every parallel iterator inside `op` runs on that pool rather than the
+/// global one, so the cap covers all of `op`'s work, not just file analysis.
+/// `None` runs `op` on the global pool; `Some(0)` sizes the pool to the
+/// number of CPUs.
+pub fn with_thread_pool<R, F>(threads: Option<usize>, op: F) -> Result<R>
+where
+    F: FnOnce() -> R + Send,
+    R: Send,
+{
+    let Some(threads) = threads else {
+        return Ok(op());
+    };
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()
+        .map_err(|e| McpDiffError::ConfigError {
+            message: format!("Failed to start {} worker threads: {}", threads, e),
+        })?;
+    Ok(pool.install(op))
+}
+
+/// One file's result from [`analyze_files_streaming`].
+#[derive(Debug, Clone)]
+pub struct AnalyzedFile {
+    /// Position of the file in the input slice
+    pub index: usize,
+    /// Semantic summary, or `None` if the file was skipped or failed
+    pub summary: Option<SemanticSummary>,
+    /// Bytes of source code read
+    pub bytes: usize,
+}
+
+/// Totals from [`analyze_files_streaming`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StreamingStats {
+    /// Files that produced a summary
+    pub analyzed: usize,
+    /// Total bytes of source code processed
+    pub total_bytes: usize,
+    /// Number of files that failed to process
+    pub errors: usize,
+}
+
+/// Analyze files in parallel, sending each result as soon as it is ready.
+///
+/// Every input file produces exactly one [`AnalyzedFile`], including files
+/// that fail, so the receiver can tell when a group of files is complete.
+/// With a bounded channel the workers block while the receiver catches up,
+/// so the receiver must not run on the Rayon pool. Stops early if the
+/// receiver hangs up.
+pub fn analyze_files_streaming(
+    files: &[PathBuf],
+    sender: SyncSender<AnalyzedFile>,
+    verbose: bool,
+) -> StreamingStats {
+    let analyzed = AtomicUsize::new(0);
+    let errors = AtomicUsize::new(0);
+    let total_bytes = AtomicUsize::new(0);
+
+    let _ = files
+        .par_iter()
+        .enumerate()
+        .try_for_each(|(index, file_path)| {
+            let (summary, bytes) = analyze_file(file_path, verbose, &errors);
+            if summary.is_some() {
+                analyzed.fetch_add(1, Ordering::Relaxed);
+            }
+            total_bytes.fetch_add(bytes, Ordering::Relaxed);
+            sender.send(AnalyzedFile {
+                index,
+                summary,
+                bytes,
+            })
+        });
+
+    StreamingStats {
+        analyzed: analyzed.load(Ordering::Relaxed),
+        total_bytes: total_bytes.load(Ordering::Relaxed),
+        errors: errors.load(Ordering::Relaxed),
+    }
+}
+
+/// Read and parse one file, returning its summary and the bytes read.
+///
+/// Unsupported languages are skipped silently; read and parse failures are
+/// counted in `errors`.
+fn analyze_file(
+    file_path: &Path,
+    verbose: bool,
+    errors: &AtomicUsize,
+) -> (Option<SemanticSummary>, usize) {
+    // Determine language from file extension
+    let lang = match Lang::from_path(file_path) {
+        Ok(l) => l,
+        Err(e) => {
+            if verbose {
+                eprintln!("Skipping {}: {}", file_path.display(), e);
+            }
+            return (None, 0);
+        }
+    };
+
+    // Read file contents
+    let source = match fs::read_to_string(file_path) {
+        Ok(s) => s,
+        Err(e) => {
+            errors.fetch_add(1, Ordering::Relaxed);
+            if verbose {
+                eprintln!("Error reading {}: {}", file_path.display(), e);
+            }
+            return (None, 0);
+        }
+    };
+
+    // Parse and extract semantic summary
+    match parse_and_extract(file_path, &source, lang) {
+        Ok(summary) => (Some(summary), source.len()),
+        Err(e) => {
+            errors.fetch_add(1, Ordering::Relaxed);
+            if verbose {
+                eprintln!("Error parsing {}: {}", file_path.display(), e);
+            }
+            (None, source.len())
+        }
+    }
+}
+
 /// Backward-compatible function that returns (summaries, total_bytes).
 ///
 /// This matches the signature of the original `analyze_files_with_stats`
@@ -179,6 +290,36 @@ mod tests {
         assert_eq!(result.summaries.len(), 0);
     }
 
+    #[test]
+    fn test_single_thread_matches_default_pool() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut files = Vec::new();
+        for i in 0..8 {
+            let path = dir.path().join(format!("module_{}.rs", i));
+            fs::write(
+                &path,
+                format!("pub fn handler_{i}(x: u32) -> u32 {{\n    helper_{i}(x) + 1\n}}\n"),
+            )
+            .unwrap();
+            files.push(path);
+        }
+
+        let default = analyze_files_parallel(&files, None, false);
+        let single = analyze_files_with_threads(&files, Some(1), None, false).unwrap();
+
+        assert_eq!(single.summaries.len(), files.len());
+        assert_eq!(single.total_bytes, default.total_bytes);
+        assert_eq!(single.errors, default.errors);
+        assert_eq!(
+            serde_json::to_value(&single.summaries).unwrap(),
+            serde_json::to_value(&default.summaries).unwrap()
+        );
+        assert_eq!(
+            with_thread_pool(Some(1), rayon::current_num_threads).unwrap(),
+            1
+        );
+    }
+
     #[test]
     fn test_analyze_files_with_stats_compat() {
         let files: Vec<PathBuf> = vec![];
@@ -25,6 +25,7 @@ mod generation;
 
 pub use files::{collect_files, collect_files_recursive, should_skip_path};
 pub use generation::{
-    analyze_files_parallel, analyze_files_with_stats, IndexGenerationResult,
-    IndexingProgressCallback,
+    analyze_files_parallel, analyze_files_streaming, analyze_files_with_stats,
+    analyze_files_with_threads, with_thread_pool, AnalyzedFile, IndexGenerationResult,
+    IndexingProgressCallback, StreamingStats,
 };
@@ -2,8 +2,10 @@
 
 use serde::{Deserialize, Serialize};
 use std::path::Path;
+use std::sync::RwLock;
 use tree_sitter::Language;
 
+use crate::detectors::grammar::LangGrammar;
 use crate::error::{McpDiffError, Result};
 
 /// Supported programming languages
@@ -33,15 +35,55 @@ pub enum Lang {
     Markdown,
     /// Vue Single File Component (.vue)
     Vue,
+    /// Svelte component (.svelte)
+    Svelte,
     /// Shell/Bash scripts
     Bash,
     /// Gradle build files (Groovy-based)
     Gradle,
     /// Dockerfile container definitions
     Dockerfile,
+    /// Grammar registered at runtime with [`register_language`]; the value
+    /// identifies the registration, and deserializing an id that isn't
+    /// registered in this process fails
+    Custom(#[serde(deserialize_with = "deserialize_custom_id")] u16),
 }
 
 impl Lang {
+    /// All built-in languages, in declaration order
+    ///
+    /// Languages added with [`register_language`] are not included.
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::TypeScript,
+            Self::Tsx,
+            Self::JavaScript,
+            Self::Jsx,
+            Self::Rust,
+            Self::Python,
+            Self::Go,
+            Self::Java,
+            Self::C,
+            Self::Cpp,
+            Self::CSharp,
+            Self::Kotlin,
+            Self::Html,
+            Self::Css,
+            Self::Scss,
+            Self::Json,
+            Self::Yaml,
+            Self::Toml,
+            Self::Xml,
+            Self::Hcl,
+            Self::Markdown,
+            Self::Vue,
+            Self::Svelte,
+            Self::Bash,
+            Self::Gradle,
+            Self::Dockerfile,
+        ]
+    }
+
     /// Detect language from file path extension or filename
     pub fn from_path(path: &Path) -> Result<Self> {
         // First try filename-based detection (for Dockerfile, Makefile, etc.)
@@ -86,15 +128,23 @@ impl Lang {
             "tf" | "hcl" | "tfvars" => Ok(Self::Hcl),
             "md" | "markdown" => Ok(Self::Markdown),
             "vue" => Ok(Self::Vue),
+            "svelte" => Ok(Self::Svelte),
             "sh" | "bash" | "zsh" | "fish" => Ok(Self::Bash),
             "gradle" => Ok(Self::Gradle),
             "dockerfile" => Ok(Self::Dockerfile),
-            _ => Err(McpDiffError::UnsupportedLanguage {
-                extension: ext.to_string(),
-            }),
+            other => {
+                custom_lang_for_extension(other).ok_or_else(|| McpDiffError::UnsupportedLanguage {
+                    extension: ext.to_string(),
+                })
+            }
         }
     }
 
+    /// Detect the language family from a file extension string
+    pub fn family_from_extension(ext: &str) -> Result<LangFamily> {
+        Self::from_extension(ext).map(|lang| lang.family())
+    }
+
     /// Detect language from filename (for files without extensions like Dockerfile)
     pub fn from_filename(filename: &str) -> Option<Self> {
         match filename.to_lowercase().as_str() {
@@ -129,16 +179,23 @@ impl Lang {
             Self::Hcl => "hcl",
             Self::Markdown => "markdown",
             Self::Vue => "vue",
+            Self::Svelte => "svelte",
             Self::Bash => "bash",
             Self::Gradle => "gradle",
             Self::Dockerfile => "dockerfile",
+            Self::Custom(id) => custom_lang(*id).map_or("custom", |c| c.grammar.name),
         }
     }
 
     /// Get the tree-sitter Language for parsing
     ///
-    /// Note: Vue SFCs use HTML grammar for overall structure.
-    /// The script section is extracted and parsed separately.
+    /// Note: Vue SFCs and Svelte components use HTML grammar for overall
+    /// structure. The script section is extracted and parsed separately.
+    ///
+    /// # Panics
+    ///
+    /// For a `Lang::Custom` built by hand with an id [`register_language`]
+    /// never returned. Ids from registration or deserialization are valid.
     pub fn tree_sitter_language(&self) -> Language {
         match self {
             Self::TypeScript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
@@ -152,7 +209,7 @@ impl Lang {
             Self::Cpp => tree_sitter_cpp::LANGUAGE.into(),
             Self::CSharp => tree_sitter_c_sharp::LANGUAGE.into(),
             Self::Kotlin => tree_sitter_kotlin_ng::LANGUAGE.into(),
-            Self::Html | Self::Vue => tree_sitter_html::LANGUAGE.into(),
+            Self::Html | Self::Vue | Self::Svelte => tree_sitter_html::LANGUAGE.into(),
             Self::Css => tree_sitter_css::language().into(),
             Self::Scss => tree_sitter_scss::language().into(),
             Self::Json => tree_sitter_json::LANGUAGE.into(),
@@ -167,15 +224,30 @@ impl Lang {
             // is updated to tree-sitter 0.25. The actual parsing is text-based in
             // detectors/dockerfile.rs
             Self::Dockerfile => tree_sitter_bash::LANGUAGE.into(),
+            Self::Custom(id) => custom_lang(*id)
+                .expect("Lang::Custom id was not returned by register_language")
+                .language
+                .clone(),
+        }
+    }
+
+    /// Grammar config for a language added with [`register_language`]
+    pub fn custom_grammar(&self) -> Option<&'static LangGrammar> {
+        match self {
+            Self::Custom(id) => custom_lang(*id).map(|c| c.grammar),
+            _ => None,
         }
     }
 
     /// Get the language family for shared extraction logic
     pub fn family(&self) -> LangFamily {
         match self {
-            Self::TypeScript | Self::Tsx | Self::JavaScript | Self::Jsx | Self::Vue => {
-                LangFamily::JavaScript
-            }
+            Self::TypeScript
+            | Self::Tsx
+            | Self::JavaScript
+            | Self::Jsx
+            | Self::Vue
+            | Self::Svelte => LangFamily::JavaScript,
             Self::Rust => LangFamily::Rust,
             Self::Python => LangFamily::Python,
             Self::Go => LangFamily::Go,
@@ -189,6 +261,7 @@ impl Lang {
             Self::Bash => LangFamily::Shell,
             Self::Gradle => LangFamily::Gradle,
             Self::Dockerfile => LangFamily::Dockerfile,
+            Self::Custom(_) => LangFamily::Custom,
         }
     }
 
@@ -239,9 +312,14 @@ impl Lang {
             Self::Hcl => &["tf", "hcl", "tfvars"],
             Self::Markdown => &["md", "markdown"],
             Self::Vue => &["vue"],
+            Self::Svelte => &["svelte"],
             Self::Bash => &["sh", "bash", "zsh", "fish"],
             Self::Gradle => &["gradle"],
             Self::Dockerfile => &["dockerfile"],
+            Self::Custom(id) => match custom_lang(*id) {
+                Some(custom) => std::slice::from_ref(&custom.extension),
+                None => &[],
+            },
         }
     }
 
@@ -249,6 +327,11 @@ impl Lang {
     pub fn is_vue_sfc(&self) -> bool {
         matches!(self, Self::Vue)
     }
+
+    /// Check if this is a Svelte component
+    pub fn is_svelte_component(&self) -> bool {
+        matches!(self, Self::Svelte)
+    }
 }
 
 /// Language families for grouping similar extraction logic
@@ -282,6 +365,8 @@ pub enum LangFamily {
     Gradle,
     /// Dockerfile/Containerfile
     Dockerfile,
+    /// Grammars registered at runtime
+    Custom,
 }
 
 impl LangFamily {
@@ -302,8 +387,128 @@ impl LangFamily {
             Self::Shell => "shell",
             Self::Gradle => "gradle",
             Self::Dockerfile => "dockerfile",
+            Self::Custom => "custom",
         }
     }
+
+    /// Get the file extensions of every language in this family
+    pub fn extensions(&self) -> Vec<&'static str> {
+        Lang::all()
+            .into_iter()
+            .filter(|lang| lang.family() == *self)
+            .flat_map(|lang| lang.extensions().iter().copied())
+            .collect()
+    }
+}
+
+// ============================================================================
+// Runtime Language Registration
+// ============================================================================
+
+/// A grammar added with [`register_language`]
+struct CustomLang {
+    extension: &'static str,
+    language: Language,
+    grammar: &'static LangGrammar,
+}
+
+/// Registered grammars, indexed by the id in `Lang::Custom`
+static CUSTOM_LANGS: RwLock<Vec<&'static CustomLang>> = RwLock::new(Vec::new());
+
+/// Register a tree-sitter grammar for a file extension with no built-in support
+///
+/// Files with `extension` (with or without the leading dot) are then detected
+/// by [`Lang::from_extension`], parsed with `language`, and extracted by the
+/// generic extractor using the node mappings in `grammar`. Registering the
+/// same extension again replaces its grammar and returns the same [`Lang`].
+/// Each registration is kept for the life of the process, so register at
+/// startup rather than per file.
+///
+/// # Errors
+///
+/// Returns `McpDiffError::ConfigError` if `extension` is empty or already
+/// handled by a built-in language.
+pub fn register_language(
+    extension: &str,
+    language: Language,
+    grammar: &'static LangGrammar,
+) -> Result<Lang> {
+    let extension = extension.trim_start_matches('.').to_lowercase();
+    if extension.is_empty() {
+        return Err(McpDiffError::ConfigError {
+            message: "Cannot register a language without an extension".to_string(),
+        });
+    }
+    if let Ok(lang) = Lang::from_extension(&extension) {
+        if !matches!(lang, Lang::Custom(_)) {
+            return Err(McpDiffError::ConfigError {
+                message: format!(
+                    "Extension .{} is already handled by {}",
+                    extension,
+                    lang.name()
+                ),
+            });
+        }
+    }
+    let entry: &'static CustomLang = Box::leak(Box::new(CustomLang {
+        extension: Box::leak(extension.into_boxed_str()),
+        language,
+        grammar,
+    }));
+
+    let mut langs = CUSTOM_LANGS.write().unwrap_or_else(|e| e.into_inner());
+    let id = match langs.iter().position(|c| c.extension == entry.extension) {
+        Some(idx) => {
+            langs[idx] = entry;
+            idx
+        }
+        None => {
+            langs.push(entry);
+            langs.len() - 1
+        }
+    };
+    let id = u16::try_from(id).map_err(|_| McpDiffError::ConfigError {
+        message: "Too many registered languages".to_string(),
+    })?;
+    Ok(Lang::Custom(id))
+}
+
+/// Registered language for a (lowercase) extension
+fn custom_lang_for_extension(extension: &str) -> Option<Lang> {
+    let langs = CUSTOM_LANGS.read().unwrap_or_else(|e| e.into_inner());
+    langs
+        .iter()
+        .position(|c| c.extension == extension)
+        .and_then(|idx| u16::try_from(idx).ok())
+        .map(Lang::Custom)
+}
+
+/// Registration behind a `Lang::Custom` id
+///
+/// Registrations are never removed and deserialization rejects unknown ids,
+/// so `None` only for a `Lang::Custom` built by hand.
+fn custom_lang(id: u16) -> Option<&'static CustomLang> {
+    let langs = CUSTOM_LANGS.read().unwrap_or_else(|e| e.into_inner());
+    langs.get(usize::from(id)).copied()
+}
+
+/// Deserialize a `Lang::Custom` id, failing when it isn't registered here
+///
+/// A `Lang` serialized by another process may name a grammar this process
+/// never registered; that is an unsupported language, not a valid `Lang`.
+fn deserialize_custom_id<'de, D>(deserializer: D) -> std::result::Result<u16, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let id = u16::deserialize(deserializer)?;
+    if custom_lang(id).is_none() {
+        return Err(serde::de::Error::custom(
+            McpDiffError::UnsupportedLanguage {
+                extension: format!("<custom language {}>", id),
+            },
+        ));
+    }
+    Ok(id)
 }
 
 #[cfg(test)]
@@ -330,6 +535,7 @@ mod tests {
         assert_eq!(Lang::from_extension("toml").unwrap(), Lang::Toml);
         assert_eq!(Lang::from_extension("md").unwrap(), Lang::Markdown);
         assert_eq!(Lang::from_extension("vue").unwrap(), Lang::Vue);
+        assert_eq!(Lang::from_extension("svelte").unwrap(), Lang::Svelte);
     }
 
     #[test]
@@ -346,6 +552,73 @@ mod tests {
         assert!(Lang::from_extension("xyz").is_err());
     }
 
+    #[test]
+    fn test_register_custom_language() {
+        use crate::detectors::grammar::default_is_exported;
+        use crate::schema::SymbolKind;
+
+        // Toy grammar borrowing Python's parser
+        static FOO_GRAMMAR: LangGrammar = LangGrammar {
+            name: "foo",
+            function_nodes: &["function_definition"],
+            class_nodes: &["class_definition"],
+            interface_nodes: &[],
+            enum_nodes: &[],
+            macro_nodes: &[],
+            control_flow_nodes: &["if_statement", "for_statement", "while_statement"],
+            try_nodes: &[],
+            var_declaration_nodes: &[],
+            assignment_nodes: &["assignment"],
+            module_var_nodes: &[],
+            field_nodes: &[],
+            local_scope_nodes: &["function_definition", "block"],
+            call_nodes: &["call"],
+            await_nodes: &[],
+            import_nodes: &[],
+            name_field: "name",
+            value_field: "right",
+            type_field: "",
+            body_field: "body",
+            params_field: "parameters",
+            condition_field: "condition",
+            is_exported: default_is_exported,
+            uppercase_is_export: false,
+            visibility_modifiers: &[],
+            decorator_nodes: &[],
+        };
+
+        assert!(Lang::from_extension("foo").is_err());
+        let lang =
+            register_language(".foo", tree_sitter_python::LANGUAGE.into(), &FOO_GRAMMAR).unwrap();
+        assert_eq!(Lang::from_extension("foo").unwrap(), lang);
+        assert_eq!(Lang::from_path(Path::new("src/widget.foo")).unwrap(), lang);
+        assert_eq!(lang.name(), "foo");
+        assert_eq!(lang.family(), LangFamily::Custom);
+
+        // Registered ids round-trip; unknown ones are an error, not a panic
+        let json = serde_json::to_string(&lang).unwrap();
+        assert_eq!(serde_json::from_str::<Lang>(&json).unwrap(), lang);
+        assert!(serde_json::from_str::<Lang>(r#"{"Custom":65535}"#).is_err());
+        assert_eq!(Lang::Custom(u16::MAX).name(), "custom");
+        assert!(Lang::Custom(u16::MAX).extensions().is_empty());
+        assert!(Lang::Custom(u16::MAX).custom_grammar().is_none());
+        assert!(
+            register_language("rs", tree_sitter_python::LANGUAGE.into(), &FOO_GRAMMAR).is_err()
+        );
+
+        let summary = crate::parsing::parse_and_extract(
+            Path::new("src/widget.foo"),
+            "def render(widget):\n    return draw(widget)\n",
+            lang,
+        )
+        .unwrap();
+        assert_eq!(summary.language, "foo");
+        assert!(summary
+            .symbols
+            .iter()
+            .any(|s| s.name == "render" && s.kind == SymbolKind::Function));
+    }
+
     #[test]
     fn test_jsx_support() {
         assert!(Lang::Tsx.supports_jsx());
@@ -361,6 +634,7 @@ mod tests {
         assert_eq!(Lang::TypeScript.family(), LangFamily::JavaScript);
         assert_eq!(Lang::Tsx.family(), LangFamily::JavaScript);
         assert_eq!(Lang::Vue.family(), LangFamily::JavaScript);
+        assert_eq!(Lang::Svelte.family(), LangFamily::JavaScript);
         assert_eq!(Lang::Rust.family(), LangFamily::Rust);
         assert_eq!(Lang::Python.family(), LangFamily::Python);
         assert_eq!(Lang::C.family(), LangFamily::CFamily);
@@ -369,6 +643,75 @@ mod tests {
         assert_eq!(Lang::Html.family(), LangFamily::Markup);
     }
 
+    #[test]
+    fn test_all_languages_have_extensions() {
+        // Exhaustive match: adding a variant without updating `Lang::all()`
+        // fails to compile here, and the count assertion below catches omissions.
+        fn variant_count(lang: Lang) -> usize {
+            match lang {
+                Lang::TypeScript
+                | Lang::Tsx
+                | Lang::JavaScript
+                | Lang::Jsx
+                | Lang::Rust
+                | Lang::Python
+                | Lang::Go
+                | Lang::Java
+                | Lang::C
+                | Lang::Cpp
+                | Lang::CSharp
+                | Lang::Kotlin
+                | Lang::Html
+                | Lang::Css
+                | Lang::Scss
+                | Lang::Json
+                | Lang::Yaml
+                | Lang::Toml
+                | Lang::Xml
+                | Lang::Hcl
+                | Lang::Markdown
+                | Lang::Vue
+                | Lang::Svelte
+                | Lang::Bash
+                | Lang::Gradle
+                | Lang::Dockerfile => 26,
+                // Registered at runtime, never listed by all()
+                Lang::Custom(_) => 0,
+            }
+        }
+
+        let all = Lang::all();
+        let unique: std::collections::HashSet<_> = all.iter().copied().collect();
+        assert_eq!(unique.len(), all.len());
+        assert_eq!(all.len(), variant_count(Lang::Rust));
+
+        for lang in all {
+            assert!(
+                !lang.extensions().is_empty(),
+                "{:?} has no extensions",
+                lang
+            );
+            for ext in lang.extensions() {
+                assert_eq!(Lang::from_extension(ext).unwrap(), lang);
+            }
+        }
+    }
+
+    #[test]
+    fn test_family_extensions() {
+        let js = LangFamily::JavaScript.extensions();
+        for ext in ["ts", "tsx", "js", "jsx"] {
+            assert!(js.contains(&ext), "javascript family missing {}", ext);
+            assert_eq!(
+                Lang::family_from_extension(ext).unwrap(),
+                LangFamily::JavaScript
+            );
+        }
+        assert!(!js.contains(&"rs"));
+        assert_eq!(LangFamily::CFamily.extensions().len(), 8);
+        assert!(Lang::family_from_extension("unknown").is_err());
+    }
+
     #[test]
     fn test_vue_sfc() {
         assert!(Lang::Vue.is_vue_sfc());
@@ -112,6 +112,9 @@ pub mod indexing;
 pub mod installer;
 pub mod lang;
 pub mod lint;
+#[cfg(feature = "lsp")]
+pub mod lsp;
+pub mod markdown;
 pub mod mcp_server;
 pub mod module_registry;
 pub mod overlay;
@@ -137,17 +140,32 @@ pub use utils::{truncate_to_char_boundary, truncate_with_ellipsis};
 
 pub use cli::{Cli, OperationMode, OutputFormat};
 pub use error::{McpDiffError, Result};
-pub use extract::extract;
-pub use lang::{Lang, LangFamily};
-pub use risk::calculate_risk;
+pub use extract::{
+    extract, extract_with_options, ExtractOptions, FallbackConfig, SummaryTransformer,
+    SymbolScoringProfile,
+};
+pub use lang::{register_language, Lang, LangFamily};
+pub use risk::{calculate_risk, explain_risk, RiskFactor};
 pub use schema::{
-    Argument, Call, ControlFlowChange, ControlFlowKind, Import, ImportedName, JsxElement, Location,
-    ModuleGroup, Prop, RepoOverview, RepoStats, RiskLevel, SemanticDiff, SemanticSummary,
-    StateChange, SurfaceDelta, SymbolId, SymbolKind, SCHEMA_VERSION,
+    A11yIssue, A11yIssueKind, Argument, BareRethrow, BroadCatch, Call, CommentedCodeBlock,
+    ControlFlowChange, ControlFlowKind, DuplicateImport, DuplicateImportKind, Import, ImportedName,
+    InconsistentReturn, JsxElement, Location, LockHazard, LockHazardKind, LoopStringConcat,
+    MemberChain, MixedAsyncStyle, ModuleGroup, OverflowRisk, PlatformBranch, PositionalCall, Prop,
+    RepeatedBlock, RepoOverview, RepoStats, ResourceLeak, RiskLevel, SemanticDiff, SemanticSummary,
+    SensitiveLog, StateChange, StringLiteral, SurfaceDelta, SymbolChangeKind, SymbolDiff, SymbolId,
+    SymbolKind, UnawaitedCall, UncheckedNullableAccess, VisibilityChange, SCHEMA_VERSION,
 };
 // Note: Call is included above for function call tracking
-pub use tokens::{format_analysis_compact, format_analysis_report, TokenAnalysis, TokenAnalyzer};
-pub use toon::{encode_toon, encode_toon_clean, encode_toon_directory, generate_repo_overview};
+pub use markdown::{encode_markdown, encode_markdown_directory};
+pub use tokens::{
+    format_analysis_compact, format_analysis_report, Encoding, TokenAnalysis, TokenAnalyzer,
+};
+pub use toon::{
+    encode_symbol_toon, encode_toon, encode_toon_clean, encode_toon_clean_with_fallback,
+    encode_toon_clean_with_mode, encode_toon_directory, encode_toon_summary_only,
+    encode_toon_symbols_only, encode_toon_with_fallback, generate_repo_overview, summary_outline,
+    symbol_table, try_encode_toon, update_overview, AbsentValue, ToonMode, STABLE_SCHEMA_KEYS,
+};
 
 // Re-export git module types
 pub use git::{
@@ -160,7 +178,7 @@ pub use git::{
 pub use cache::{
     get_cache_base_dir, list_cached_repos, normalize_kind, prune_old_caches, CacheDir, CacheMeta,
     IndexingStatus, LayeredIndexMeta, RipgrepSearchResult, SearchWithFallbackResult,
-    SourceFileInfo, SymbolIndexEntry,
+    SourceFileInfo, SymbolIndexEntry, WarmStatus,
 };
 
 // Re-export shard module types
@@ -198,9 +216,13 @@ pub use test_runner::{
 
 // Re-export static analysis types
 pub use analysis::{
-    analyze_call_graph, analyze_module, analyze_repo,
-    format_analysis_report as format_static_analysis_report, CallGraphAnalysis, ModuleMetrics,
-    RepoAnalysis, SymbolComplexity,
+    analyze_call_graph, analyze_module, analyze_repo, check_budgets, compute_module_coupling,
+    duplicate_literals, entry_points, find_positional_arg_calls, find_primitive_param_groups,
+    format_analysis_report as format_static_analysis_report, format_analysis_sarif,
+    format_heatmap_toon, metrics_to_csv, trace_call_path, untested_symbols, BudgetViolation,
+    CallGraphAnalysis, ComplexityBudgets, DuplicateLiteral, EntryPoint, EntryPointKind, FileHeat,
+    ModuleBudget, ModuleMetrics, PositionalArgCall, PrimitiveParamGroup, RepoAnalysis,
+    SymbolComplexity, SARIF_COMPLEXITY_RULE, SARIF_CYCLE_RULE,
 };
 
 // Re-export server types (SEM-98, SEM-99, SEM-101, SEM-102, SEM-104)
@@ -239,8 +261,8 @@ pub use paths::{
 
 // Re-export indexing utilities (CLI/MCP unification - DEDUP-102)
 pub use indexing::{
-    analyze_files_parallel, collect_files, collect_files_recursive, should_skip_path,
-    IndexGenerationResult, IndexingProgressCallback,
+    analyze_files_parallel, analyze_files_with_threads, collect_files, collect_files_recursive,
+    should_skip_path, with_thread_pool, IndexGenerationResult, IndexingProgressCallback,
 };
 
 // Re-export parsing utilities (CLI/MCP unification - DEDUP-103)
@@ -0,0 +1,29 @@
+//! Semfora LSP Binary
+//!
+//! A minimal language server over stdio exposing document symbols and hover
+//! from the semantic extractor. Built only with the `lsp` feature.
+//!
+//! # Usage
+//!
+//! ```bash
+//! cargo build --release --features lsp --bin semfora-lsp
+//! semfora-lsp   # point the editor's LSP client at this command
+//! ```
+
+use std::io;
+
+use semfora_engine::lsp::LspServer;
+
+fn main() -> anyhow::Result<()> {
+    // stdout carries the protocol, so logs go to stderr
+    tracing_subscriber::fmt()
+        .with_writer(io::stderr)
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::from_default_env()
+                .add_directive("semfora_engine=warn".parse().unwrap()),
+        )
+        .init();
+
+    LspServer::new().run(io::stdin().lock(), io::stdout().lock())?;
+    Ok(())
+}
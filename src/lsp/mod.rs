@@ -0,0 +1,276 @@
+//! Minimal Language Server Protocol support (`semfora-lsp`)
+//!
+//! Speaks just enough LSP over stdio for editors to show Semfora's view of a
+//! file:
+//! - `textDocument/documentSymbol` lists the extracted symbols
+//! - `textDocument/hover` shows the enclosing symbol's signature and
+//!   cognitive complexity
+//!
+//! Open buffers are tracked with full-text sync so unsaved edits are
+//! reflected; documents are re-extracted on every request.
+
+use std::collections::HashMap;
+use std::io::{BufRead, Read, Write};
+use std::path::PathBuf;
+
+use serde_json::{json, Value};
+
+use crate::analysis::calculate_symbol_cognitive_complexity;
+use crate::error::{McpDiffError, Result};
+use crate::lang::Lang;
+use crate::parsing::parse_and_extract;
+use crate::schema::{SemanticSummary, SymbolInfo, SymbolKind};
+use crate::toon::symbol_signature;
+
+/// JSON-RPC error code for methods the server doesn't implement
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// LSP `TextDocumentSyncKind.Full`
+const SYNC_FULL: u8 = 1;
+
+// ============================================================================
+// Message Framing
+// ============================================================================
+
+/// Read one `Content-Length`-framed JSON-RPC message (`None` at end of input)
+pub fn read_message<R: BufRead>(reader: &mut R) -> Result<Option<Value>> {
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        if let Some(len) = line.strip_prefix("Content-Length:") {
+            content_length = len.trim().parse::<usize>().ok();
+        }
+    }
+
+    let len = content_length
+        .ok_or_else(|| McpDiffError::Generic("LSP message without Content-Length".to_string()))?;
+    let mut body = vec![0; len];
+    reader.read_exact(&mut body)?;
+    serde_json::from_slice(&body)
+        .map(Some)
+        .map_err(|e| McpDiffError::Serialization(format!("Invalid LSP message: {}", e)))
+}
+
+/// Write one `Content-Length`-framed JSON-RPC message
+pub fn write_message<W: Write>(writer: &mut W, message: &Value) -> Result<()> {
+    let body = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    writer.flush()?;
+    Ok(())
+}
+
+// ============================================================================
+// Server
+// ============================================================================
+
+/// Stdio language server backed by the semantic extractor
+#[derive(Debug, Default)]
+pub struct LspServer {
+    /// Text of open documents by URI
+    documents: HashMap<String, String>,
+}
+
+impl LspServer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serve messages until `exit` or end of input
+    pub fn run<R: BufRead, W: Write>(&mut self, mut reader: R, mut writer: W) -> Result<()> {
+        while let Some(message) = read_message(&mut reader)? {
+            if message.get("method").and_then(Value::as_str) == Some("exit") {
+                break;
+            }
+            if let Some(response) = self.handle(&message) {
+                write_message(&mut writer, &response)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Handle one message, returning the response for requests
+    ///
+    /// Notifications (messages without an `id`) never produce a response.
+    pub fn handle(&mut self, message: &Value) -> Option<Value> {
+        let method = message.get("method")?.as_str()?;
+        let null = Value::Null;
+        let params = message.get("params").unwrap_or(&null);
+
+        let result = match method {
+            "initialize" => Ok(json!({
+                "capabilities": {
+                    "textDocumentSync": SYNC_FULL,
+                    "documentSymbolProvider": true,
+                    "hoverProvider": true,
+                },
+                "serverInfo": {
+                    "name": "semfora-lsp",
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+            })),
+            "initialized" | "shutdown" => Ok(Value::Null),
+            "textDocument/didOpen" => {
+                let doc = &params["textDocument"];
+                if let (Some(uri), Some(text)) = (doc["uri"].as_str(), doc["text"].as_str()) {
+                    self.documents.insert(uri.to_string(), text.to_string());
+                }
+                Ok(Value::Null)
+            }
+            "textDocument/didChange" => {
+                let text = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str());
+                if let (Some(uri), Some(text)) = (document_uri(params), text) {
+                    self.documents.insert(uri.to_string(), text.to_string());
+                }
+                Ok(Value::Null)
+            }
+            "textDocument/didClose" => {
+                if let Some(uri) = document_uri(params) {
+                    self.documents.remove(uri);
+                }
+                Ok(Value::Null)
+            }
+            "textDocument/documentSymbol" => Ok(self.document_symbols(params)),
+            "textDocument/hover" => Ok(self.hover(params)),
+            _ => Err(format!("Method not found: {}", method)),
+        };
+
+        let id = message.get("id")?.clone();
+        Some(match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(error) => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": METHOD_NOT_FOUND, "message": error },
+            }),
+        })
+    }
+
+    /// Extract the document, preferring the open buffer over the file on disk
+    fn summary_for(&self, uri: &str) -> Option<SemanticSummary> {
+        let path = uri_to_path(uri)?;
+        let source = match self.documents.get(uri) {
+            Some(text) => text.clone(),
+            None => std::fs::read_to_string(&path).ok()?,
+        };
+        let lang = Lang::from_path(&path).ok()?;
+        parse_and_extract(&path, &source, lang).ok()
+    }
+
+    fn document_symbols(&self, params: &Value) -> Value {
+        let Some(summary) = document_uri(params).and_then(|uri| self.summary_for(uri)) else {
+            return json!([]);
+        };
+
+        let symbols: Vec<Value> = summary
+            .symbols
+            .iter()
+            .filter(|s| !s.is_escape_local)
+            .map(|s| {
+                let range = line_range(s);
+                json!({
+                    "name": s.name,
+                    "detail": symbol_signature(s),
+                    "kind": lsp_symbol_kind(s.kind),
+                    "range": range,
+                    "selectionRange": range,
+                })
+            })
+            .collect();
+        Value::Array(symbols)
+    }
+
+    fn hover(&self, params: &Value) -> Value {
+        let Some(summary) = document_uri(params).and_then(|uri| self.summary_for(uri)) else {
+            return Value::Null;
+        };
+        let Some(line) = params["position"]["line"].as_u64() else {
+            return Value::Null;
+        };
+        let line = line as usize + 1;
+
+        // Innermost symbol spanning the cursor line
+        let Some(symbol) = summary
+            .symbols
+            .iter()
+            .filter(|s| !s.is_escape_local && s.start_line <= line && line <= s.end_line)
+            .min_by_key(|s| s.end_line - s.start_line)
+        else {
+            return Value::Null;
+        };
+
+        let value = format!(
+            "```{}\n{}\n```\n\n{} · cognitive complexity {}",
+            summary.language,
+            symbol_signature(symbol),
+            symbol.kind.as_str(),
+            calculate_symbol_cognitive_complexity(symbol)
+        );
+        json!({
+            "contents": { "kind": "markdown", "value": value },
+            "range": line_range(symbol),
+        })
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn document_uri(params: &Value) -> Option<&str> {
+    params["textDocument"]["uri"].as_str()
+}
+
+/// Convert a `file://` URI to a path, decoding `%XX` escapes
+fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let encoded = uri.strip_prefix("file://")?;
+    let bytes = encoded.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).unwrap_or("");
+            if let Ok(byte) = u8::from_str_radix(hex, 16) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8(decoded).ok().map(PathBuf::from)
+}
+
+/// LSP range covering a symbol's lines (LSP lines are 0-indexed)
+fn line_range(symbol: &SymbolInfo) -> Value {
+    json!({
+        "start": { "line": symbol.start_line.saturating_sub(1), "character": 0 },
+        "end": { "line": symbol.end_line, "character": 0 },
+    })
+}
+
+/// Map to the LSP `SymbolKind` enumeration
+fn lsp_symbol_kind(kind: SymbolKind) -> u8 {
+    match kind {
+        SymbolKind::Module => 2,
+        SymbolKind::Class => 5,
+        SymbolKind::Method => 6,
+        SymbolKind::Enum => 10,
+        SymbolKind::Interface | SymbolKind::Trait => 11,
+        // LSP has no macro kind
+        SymbolKind::Function | SymbolKind::Component | SymbolKind::Macro => 12,
+        SymbolKind::Variable => 13,
+        SymbolKind::Struct => 23,
+        SymbolKind::TypeAlias => 26,
+    }
+}
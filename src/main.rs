@@ -6,10 +6,11 @@
 use std::process::ExitCode;
 
 use semfora_engine::analyze_repo_tokens;
-use semfora_engine::cli::{Cli, Commands, ConfigOperation};
+use semfora_engine::cli::{Cli, Commands, ConfigOperation, OutputFormat};
 use semfora_engine::commands::{
-    run_analyze, run_cache, run_commit, run_index, run_lint, run_query, run_search, run_serve,
-    run_test, run_trace, run_validate, CommandContext,
+    run_analyze, run_baseline, run_cache, run_commit, run_index, run_lint, run_list_languages,
+    run_query, run_search, run_serve, run_test, run_trace, run_validate, sarif_unsupported,
+    CommandContext,
 };
 use semfora_engine::installer::{
     self, agents::AgentScope, print_available_clients, ConfigArgs, SetupArgs, UninstallArgs,
@@ -34,6 +35,11 @@ fn main() -> ExitCode {
 fn run() -> semfora_engine::Result<String> {
     let cli = Cli::parse_args();
 
+    // Only repository-wide `validate` writes SARIF (it checks its own scope)
+    if cli.format == OutputFormat::Sarif && !matches!(cli.command, Commands::Validate(_)) {
+        return Err(sarif_unsupported("this command"));
+    }
+
     // Create shared context for command handlers
     let ctx = CommandContext::from_cli(cli.format, cli.verbose, cli.progress);
 
@@ -50,6 +56,8 @@ fn run() -> semfora_engine::Result<String> {
 
         Commands::Validate(args) => run_validate(&args, &ctx),
 
+        Commands::Baseline(args) => run_baseline(&args, &ctx),
+
         // ============================================
         // Index & Cache Management
         // ============================================
@@ -147,6 +155,8 @@ fn run() -> semfora_engine::Result<String> {
             run_benchmark(&dir_path)
         }
 
+        Commands::Languages => run_list_languages(&ctx),
+
         // ============================================
         // MCP Server Mode
         // ============================================
@@ -0,0 +1,205 @@
+//! GitHub-flavored Markdown output
+//!
+//! For pasting analysis into PR descriptions and wikis. A file summary becomes
+//! a section with its symbol signatures in a code fence and a bullet list of
+//! calls and risk; a directory becomes tables of its most complex symbols and
+//! of its modules.
+
+use crate::analysis::calculate_symbol_cognitive_complexity;
+use crate::schema::{Call, RefKind, RepoOverview, SemanticSummary, SymbolInfo};
+use crate::toon::{is_meaningful_call, symbol_signature};
+
+/// Rows in the directory complexity table
+const COMPLEXITY_TABLE_ROWS: usize = 20;
+
+/// Calls listed per symbol before the rest are counted
+const CALLS_PER_SYMBOL: usize = 10;
+
+/// Encode one file's summary as a Markdown section
+pub fn encode_markdown(summary: &SemanticSummary) -> String {
+    let mut out = format!("## {}\n\n", summary.file);
+    out.push_str(&format!(
+        "**Language:** {} · **Risk:** {}\n\n",
+        summary.language,
+        summary.behavioral_risk.as_str()
+    ));
+
+    if !summary.symbols.is_empty() {
+        out.push_str(&format!("```{}\n", summary.language));
+        for symbol in &summary.symbols {
+            out.push_str(&symbol_signature(symbol));
+            out.push('\n');
+        }
+        out.push_str("```\n\n");
+    }
+
+    for symbol in &summary.symbols {
+        out.push_str(&format!(
+            "- `{}` ({}, L{}-{}): {} risk",
+            symbol.name,
+            symbol.kind.as_str(),
+            symbol.start_line,
+            symbol.end_line,
+            symbol.behavioral_risk.as_str()
+        ));
+        let calls = call_list(&symbol.calls);
+        if !calls.is_empty() {
+            out.push_str("; calls ");
+            out.push_str(&calls);
+        }
+        out.push('\n');
+    }
+
+    // Calls outside any symbol (module-level code)
+    let file_calls = call_list(&summary.calls);
+    if !file_calls.is_empty() {
+        out.push_str(&format!("- Module-level calls: {}\n", file_calls));
+    }
+    if !summary.added_dependencies.is_empty() {
+        let deps: Vec<String> = summary
+            .added_dependencies
+            .iter()
+            .map(|d| format!("`{}`", d))
+            .collect();
+        out.push_str(&format!("- Dependencies: {}\n", deps.join(", ")));
+    }
+
+    out
+}
+
+/// Encode a directory analysis as Markdown tables
+///
+/// The complexity table ranks the symbols of `summaries` by cognitive
+/// complexity and is left out when `summaries` is empty; the module table
+/// comes from `overview`.
+pub fn encode_markdown_directory(overview: &RepoOverview, summaries: &[SemanticSummary]) -> String {
+    let stats = &overview.stats;
+    let mut out = String::from("## Repository analysis\n\n");
+    out.push_str(&format!(
+        "**Files:** {} · **Lines:** {} · **High risk:** {} · **Medium risk:** {}\n\n",
+        stats.total_files, stats.total_lines, stats.high_risk, stats.medium_risk
+    ));
+
+    let mut complex: Vec<(usize, &SemanticSummary, &SymbolInfo)> = Vec::new();
+    for summary in summaries {
+        for symbol in &summary.symbols {
+            let cognitive = calculate_symbol_cognitive_complexity(symbol);
+            if cognitive > 0 {
+                complex.push((cognitive, summary, symbol));
+            }
+        }
+    }
+    complex.sort_by(|a, b| b.0.cmp(&a.0));
+
+    if !complex.is_empty() {
+        out.push_str("### Complexity\n\n");
+        out.push_str("| Symbol | File | Lines | Cognitive | Risk |\n");
+        out.push_str("|---|---|---:|---:|---|\n");
+        for (cognitive, summary, symbol) in complex.iter().take(COMPLEXITY_TABLE_ROWS) {
+            out.push_str(&format!(
+                "| `{}` | {} | {}-{} | {} | {} |\n",
+                table_cell(&symbol.name),
+                table_cell(&summary.file),
+                symbol.start_line,
+                symbol.end_line,
+                cognitive,
+                symbol.behavioral_risk.as_str()
+            ));
+        }
+        out.push('\n');
+    }
+
+    if !overview.modules.is_empty() {
+        out.push_str("### Modules\n\n");
+        out.push_str("| Module | Purpose | Files | Risk |\n");
+        out.push_str("|---|---|---:|---|\n");
+        for module in &overview.modules {
+            out.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                table_cell(&module.name),
+                table_cell(&module.purpose),
+                module.file_count,
+                module.risk.as_str()
+            ));
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Backticked, deduplicated function calls, e.g. "`db.query`, `load`"
+fn call_list(calls: &[Call]) -> String {
+    let mut names: Vec<String> = Vec::new();
+    for call in calls {
+        if call.ref_kind != RefKind::None || !is_meaningful_call(&call.name, call.object.as_deref())
+        {
+            continue;
+        }
+        let name = match &call.object {
+            Some(object) => format!("`{}.{}`", object, call.name),
+            None => format!("`{}`", call.name),
+        };
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let more = names.len().saturating_sub(CALLS_PER_SYMBOL);
+    names.truncate(CALLS_PER_SYMBOL);
+    let mut list = names.join(", ");
+    if more > 0 {
+        list.push_str(&format!(" (+{} more)", more));
+    }
+    list
+}
+
+/// Escape a value for a table cell; a bare `|` would end the cell
+fn table_cell(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lang::Lang;
+    use crate::parsing::parse_and_extract;
+    use crate::toon::generate_repo_overview;
+    use std::path::Path;
+
+    #[test]
+    fn test_markdown_output() {
+        let source = r#"
+export async function fetchUser(id: string): Promise<User> {
+    if (!id) {
+        throw new Error("missing id");
+    }
+    for (const attempt of [1, 2, 3]) {
+        if (attempt > 1) {
+            await sleep(attempt);
+        }
+    }
+    return await db.query(id);
+}
+"#;
+        let summary =
+            parse_and_extract(Path::new("src/api/users.ts"), source, Lang::TypeScript).unwrap();
+
+        let file = encode_markdown(&summary);
+        assert!(file.starts_with("## src/api/users.ts\n"), "{}", file);
+        assert!(file.contains("```typescript\n"), "{}", file);
+        assert!(file.contains("fetchUser(id: string)"), "{}", file);
+        assert!(file.contains("- `fetchUser` (function"), "{}", file);
+
+        let overview = generate_repo_overview(std::slice::from_ref(&summary), "src");
+        let repo = encode_markdown_directory(&overview, &[summary]);
+        assert!(repo.contains("## Repository analysis"), "{}", repo);
+        assert!(repo.contains("| Symbol | File | Lines | Cognitive | Risk |"));
+        assert!(
+            repo.lines()
+                .any(|line| line.starts_with("| `fetchUser` | src/api/users.ts |")),
+            "{}",
+            repo
+        );
+    }
+}
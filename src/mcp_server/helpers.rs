@@ -11,12 +11,15 @@ use std::collections::{HashMap, HashSet};
 
 use super::formatting::toon_header;
 use crate::cache::{
-    load_function_signatures as cache_load_function_signatures, split_respecting_quotes,
+    load_function_signatures as cache_load_function_signatures, split_respecting_quotes, CacheMeta,
+    CachedSummary, SourceFileInfo,
 };
 use crate::duplicate::DuplicateDetector;
 use crate::indexing::{
+    analyze_files_streaming as indexing_analyze_files_streaming,
     analyze_files_with_stats as indexing_analyze_files_with_stats,
     collect_files as indexing_collect_files, should_skip_path as indexing_should_skip_path,
+    AnalyzedFile,
 };
 use crate::{extract_module_name, CacheDir, Lang, SemanticSummary, ShardWriter, SymbolIndexEntry};
 
@@ -48,6 +51,8 @@ pub struct IndexGenerationResult {
     pub duration_ms: u64,
     /// Number of files analyzed
     pub files_analyzed: usize,
+    /// Number of unchanged files whose summaries were kept (incremental runs)
+    pub files_reused: usize,
     /// Number of modules written
     pub modules_written: usize,
     /// Number of symbols written
@@ -187,20 +192,40 @@ fn collect_source_files_recursive(
 // Index Generation
 // ============================================================================
 
+/// Summaries buffered between the analysis workers and the shard writer
+const INDEX_STREAM_CAPACITY: usize = 256;
+
 /// Generate a sharded index for a directory.
 ///
 /// This is the core indexing logic used by both `generate_index` (explicit)
 /// and `ensure_index` (auto-generation). Returns statistics about what was generated.
+///
+/// With `incremental`, files whose mtime and size still match their record in
+/// `summaries.jsonl` keep that summary and only stale or new files are
+/// analyzed. Without a compatible previous index (first run, schema change)
+/// the whole directory is analyzed as usual.
 pub fn generate_index_internal(
     dir_path: &Path,
     max_depth: usize,
     extensions: &[String],
+    incremental: bool,
 ) -> Result<IndexGenerationResult, String> {
+    if incremental {
+        let has_compatible_index = CacheDir::for_repo(dir_path)
+            .ok()
+            .and_then(|cache| cache.load_source_meta())
+            .is_some_and(|meta| meta.is_compatible());
+        if has_compatible_index {
+            return generate_index_incremental(dir_path, max_depth, extensions);
+        }
+    }
+
     let start = std::time::Instant::now();
 
     // Create shard writer
     let mut shard_writer = ShardWriter::new(dir_path)
         .map_err(|e| format!("Failed to initialize shard writer: {}", e))?;
+    log_written_summaries(&mut shard_writer, dir_path);
 
     // Collect files
     let files = indexing_collect_files(dir_path, max_depth, extensions);
@@ -209,51 +234,171 @@ pub fn generate_index_internal(
         return Ok(IndexGenerationResult {
             duration_ms: start.elapsed().as_millis() as u64,
             files_analyzed: 0,
+            files_reused: 0,
             modules_written: 0,
             symbols_written: 0,
             compression_pct: 0.0,
         });
     }
 
-    // Analyze files
-    let (summaries, total_bytes) = indexing_analyze_files_with_stats(&files);
+    // Analyze files in parallel and stream the summaries to the shard writer,
+    // which writes each module shard as soon as all of its files are in
+    let dir_str = dir_path.display().to_string();
+    let (sender, receiver) = std::sync::mpsc::sync_channel(INDEX_STREAM_CAPACITY);
+    let (stats, analysis) = std::thread::scope(|scope| {
+        let analysis = scope.spawn(|| indexing_analyze_files_streaming(&files, sender, false));
+        let stats = shard_writer.write_all_streaming(&dir_str, &files, receiver);
+        (stats, analysis.join())
+    });
+    let stats = stats.map_err(|e| format!("Failed to write shards: {}", e))?;
+    let analysis = analysis.map_err(|_| "File analysis panicked".to_string())?;
+    let total_bytes = analysis.total_bytes;
+
+    record_index_state(dir_path, &files);
 
-    // Add summaries to shard writer
-    shard_writer.add_summaries(summaries.clone());
+    Ok(IndexGenerationResult {
+        duration_ms: start.elapsed().as_millis() as u64,
+        files_analyzed: analysis.analyzed,
+        files_reused: 0,
+        modules_written: stats.modules_written,
+        symbols_written: stats.symbols_written,
+        compression_pct: compression_pct(total_bytes, stats.total_bytes()),
+    })
+}
 
-    // Write all shards
-    let dir_str = dir_path.display().to_string();
-    let stats = shard_writer
-        .write_all(&dir_str)
-        .map_err(|e| format!("Failed to write shards: {}", e))?;
+/// Regenerate the index, re-analyzing only files changed since the last run.
+///
+/// Unchanged files reuse their cached summaries, files that no longer exist
+/// are dropped, and both are streamed through
+/// [`ShardWriter::write_all_streaming`] like a full generation: reused
+/// summaries are sent first, then each stale file as its analysis finishes.
+fn generate_index_incremental(
+    dir_path: &Path,
+    max_depth: usize,
+    extensions: &[String],
+) -> Result<IndexGenerationResult, String> {
+    let start = std::time::Instant::now();
 
-    // Set the indexed SHA and status hash for staleness tracking
-    if let Ok(cache) = CacheDir::for_repo(dir_path) {
-        if let Ok(sha) = crate::git::git_command(&["rev-parse", "HEAD"], Some(dir_path)) {
-            let _ = cache.set_indexed_sha(&sha);
-        }
-        // Also save status hash so we don't re-index the same uncommitted changes
-        if let Some(status_hash) = cache.compute_status_hash() {
-            let _ = cache.set_status_hash(&status_hash);
+    let cache =
+        CacheDir::for_repo(dir_path).map_err(|e| format!("Failed to access cache: {}", e))?;
+    let files = indexing_collect_files(dir_path, max_depth, extensions);
+
+    let mut cached: HashMap<String, CachedSummary> = cache
+        .load_summaries()
+        .into_iter()
+        .filter(|record| !record.source.is_stale(dir_path))
+        .map(|record| (record.source.path.clone(), record))
+        .collect();
+
+    // Reuse unchanged files that have a cached summary; analyze the rest.
+    // Removed files are never looked up, so they drop out of the index.
+    let mut reused: Vec<AnalyzedFile> = Vec::new();
+    let mut stale_files: Vec<PathBuf> = Vec::new();
+    let mut stale_indices: Vec<usize> = Vec::new();
+    for (index, file) in files.iter().enumerate() {
+        let relative = file
+            .strip_prefix(dir_path)
+            .unwrap_or(file)
+            .to_string_lossy();
+        match cached.remove(relative.as_ref()) {
+            Some(record) => reused.push(AnalyzedFile {
+                index,
+                bytes: record.source.size as usize,
+                summary: Some(record.summary),
+            }),
+            None => {
+                stale_files.push(file.clone());
+                stale_indices.push(index);
+            }
         }
     }
+    let files_reused = reused.len();
+    let reused_bytes: usize = reused.iter().map(|r| r.bytes).sum();
 
-    // Calculate compression
-    let compression = if total_bytes > 0 {
-        ((total_bytes as f64 - stats.total_bytes() as f64) / total_bytes as f64) * 100.0
-    } else {
-        0.0
-    };
+    let mut shard_writer = ShardWriter::new(dir_path)
+        .map_err(|e| format!("Failed to initialize shard writer: {}", e))?;
+    log_written_summaries(&mut shard_writer, dir_path);
+
+    // Stale files are analyzed as their own list, so their results are
+    // renumbered into `files` on the way to the writer
+    let dir_str = dir_path.display().to_string();
+    let (sender, receiver) = std::sync::mpsc::sync_channel(INDEX_STREAM_CAPACITY);
+    let (stale_sender, stale_receiver) = std::sync::mpsc::sync_channel(INDEX_STREAM_CAPACITY);
+    let (stats, analysis) = std::thread::scope(|scope| {
+        let analysis =
+            scope.spawn(|| indexing_analyze_files_streaming(&stale_files, stale_sender, false));
+        scope.spawn(move || {
+            for result in reused {
+                if sender.send(result).is_err() {
+                    return;
+                }
+            }
+            for result in stale_receiver {
+                let index = stale_indices[result.index];
+                if sender.send(AnalyzedFile { index, ..result }).is_err() {
+                    return;
+                }
+            }
+        });
+        let stats = shard_writer.write_all_streaming(&dir_str, &files, receiver);
+        (stats, analysis.join())
+    });
+    let stats = stats.map_err(|e| format!("Failed to write shards: {}", e))?;
+    let analysis = analysis.map_err(|_| "File analysis panicked".to_string())?;
+
+    record_index_state(dir_path, &files);
 
     Ok(IndexGenerationResult {
         duration_ms: start.elapsed().as_millis() as u64,
-        files_analyzed: summaries.len(),
+        files_analyzed: analysis.analyzed,
+        files_reused,
         modules_written: stats.modules_written,
         symbols_written: stats.symbols_written,
-        compression_pct: compression,
+        compression_pct: compression_pct(reused_bytes + analysis.total_bytes, stats.total_bytes()),
     })
 }
 
+/// Have the shard writer append each summary to `summaries.jsonl` as its
+/// module is written, for the next incremental run
+fn log_written_summaries(shard_writer: &mut ShardWriter, dir_path: &Path) {
+    if let Ok(log) = CacheDir::for_repo(dir_path).and_then(|cache| cache.summary_log(dir_path)) {
+        shard_writer.log_summaries(log);
+    }
+}
+
+/// Save the git SHA, status hash and per-file metadata for staleness
+/// tracking and incremental reindexing
+///
+/// The summaries themselves are logged by the shard writer, see
+/// [`log_written_summaries`].
+fn record_index_state(dir_path: &Path, files: &[PathBuf]) {
+    let Ok(cache) = CacheDir::for_repo(dir_path) else {
+        return;
+    };
+    if let Ok(sha) = crate::git::git_command(&["rev-parse", "HEAD"], Some(dir_path)) {
+        let _ = cache.set_indexed_sha(&sha);
+    }
+    // Also save status hash so we don't re-index the same uncommitted changes
+    if let Some(status_hash) = cache.compute_status_hash() {
+        let _ = cache.set_status_hash(&status_hash);
+    }
+    // And each file's mtime/size, so the next incremental run can skip it
+    let source_files = files
+        .iter()
+        .filter_map(|file| SourceFileInfo::from_path(file, dir_path))
+        .collect();
+    let _ = cache.save_source_meta(&CacheMeta::new(source_files));
+}
+
+/// Percentage saved by the shards relative to the source bytes analyzed
+fn compression_pct(source_bytes: usize, shard_bytes: usize) -> f64 {
+    if source_bytes > 0 {
+        ((source_bytes as f64 - shard_bytes as f64) / source_bytes as f64) * 100.0
+    } else {
+        0.0
+    }
+}
+
 // ============================================================================
 // Partial Reindexing
 // ============================================================================
@@ -429,8 +574,15 @@ pub fn partial_reindex(
 
 /// Update symbol shards for the given summaries
 fn update_symbol_shards(cache: &CacheDir, summaries: &[SemanticSummary]) -> Result<(), String> {
+    use crate::cache::StringTable;
     use crate::schema::SymbolId;
 
+    // Shards of untouched files keep their references, so extend the table
+    // instead of rebuilding it; the next full index drops unused entries
+    let mut strings = StringTable::load(cache).map_err(|e| e.to_string())?;
+    strings.intern_repeated(summaries);
+    strings.save(cache).map_err(|e| e.to_string())?;
+
     for summary in summaries {
         let namespace = SymbolId::namespace_from_path(&summary.file);
 
@@ -438,8 +590,12 @@ fn update_symbol_shards(cache: &CacheDir, summaries: &[SemanticSummary]) -> Resu
         if !summary.symbols.is_empty() {
             for symbol_info in &summary.symbols {
                 let symbol_id = symbol_info.to_symbol_id(&namespace, &summary.file);
-                let toon =
-                    crate::shard::encode_symbol_shard_from_info(summary, symbol_info, &symbol_id);
+                let toon = crate::shard::encode_symbol_shard_from_info(
+                    summary,
+                    symbol_info,
+                    &symbol_id,
+                    &strings,
+                );
                 let path = cache.symbol_path(&symbol_id.hash);
 
                 if let Err(e) = fs::write(&path, toon) {
@@ -475,6 +631,8 @@ pub struct FreshnessResult {
     pub refresh_type: RefreshType,
     /// Number of files updated (if partial refresh)
     pub files_updated: usize,
+    /// Number of unchanged files whose cached summaries were kept (full refresh)
+    pub files_reused: usize,
     /// Time taken for refresh in milliseconds
     pub duration_ms: u64,
 }
@@ -529,7 +687,7 @@ pub fn ensure_fresh_index(
     let overview_path = cache.repo_overview_path();
     if !overview_path.exists() {
         // No index exists - do full generation
-        let result = generate_index_internal(repo_path, 10, &[])?;
+        let result = generate_index_internal(repo_path, 10, &[], false)?;
 
         // Re-get cache after generation (it may have been created)
         let cache = CacheDir::for_repo(repo_path)
@@ -540,6 +698,7 @@ pub fn ensure_fresh_index(
             refreshed: true,
             refresh_type: RefreshType::Full,
             files_updated: result.files_analyzed,
+            files_reused: result.files_reused,
             duration_ms: start.elapsed().as_millis() as u64,
         });
     }
@@ -554,6 +713,7 @@ pub fn ensure_fresh_index(
             refreshed: false,
             refresh_type: RefreshType::None,
             files_updated: 0,
+            files_reused: 0,
             duration_ms: start.elapsed().as_millis() as u64,
         });
     }
@@ -570,12 +730,13 @@ pub fn ensure_fresh_index(
             refreshed: true,
             refresh_type: RefreshType::Partial,
             files_updated: result.files_reindexed,
+            files_reused: 0,
             duration_ms: start.elapsed().as_millis() as u64,
         });
     }
 
-    // Too many changes or can't determine - full reindex
-    let result = generate_index_internal(repo_path, 10, &[])?;
+    // Too many changes or can't determine - regenerate, reusing unchanged files
+    let result = generate_index_internal(repo_path, 10, &[], true)?;
 
     // Update the indexed SHA after full reindex
     if let Ok(sha) = crate::git::git_command(&["rev-parse", "HEAD"], Some(&cache.repo_root)) {
@@ -587,6 +748,7 @@ pub fn ensure_fresh_index(
         refreshed: true,
         refresh_type: RefreshType::Full,
         files_updated: result.files_analyzed,
+        files_reused: result.files_reused,
         duration_ms: start.elapsed().as_millis() as u64,
     })
 }
@@ -603,6 +765,10 @@ pub fn format_freshness_note(result: &FreshnessResult) -> Option<String> {
             "⚡ Index refreshed ({} files updated in {}ms)",
             result.files_updated, result.duration_ms
         )),
+        RefreshType::Full if result.files_reused > 0 => Some(format!(
+            "🔄 Index regenerated ({} files in {}ms, {} unchanged files reused)",
+            result.files_updated, result.duration_ms, result.files_reused
+        )),
         RefreshType::Full => Some(format!(
             "🔄 Index regenerated ({} files in {}ms)",
             result.files_updated, result.duration_ms
@@ -1156,6 +1322,7 @@ mod tests {
             is_async: false,
             return_type: String::new(),
             ext_package: String::new(),
+            logic_free: false,
             base_classes: String::new(),
         }
     }
@@ -1254,6 +1421,7 @@ mod tests {
             refreshed: false,
             refresh_type: RefreshType::None,
             files_updated: 0,
+            files_reused: 0,
             duration_ms: 0,
         };
         assert!(format_freshness_note(&result).is_none());
@@ -1266,6 +1434,7 @@ mod tests {
             refreshed: true,
             refresh_type: RefreshType::Partial,
             files_updated: 5,
+            files_reused: 0,
             duration_ms: 123,
         };
         let note = format_freshness_note(&result).unwrap();
@@ -1281,12 +1450,29 @@ mod tests {
             refreshed: true,
             refresh_type: RefreshType::Full,
             files_updated: 100,
+            files_reused: 0,
             duration_ms: 500,
         };
         let note = format_freshness_note(&result).unwrap();
         assert!(note.contains("100 files"));
         assert!(note.contains("500ms"));
         assert!(note.contains("🔄"));
+        assert!(!note.contains("reused"));
+    }
+
+    #[test]
+    fn test_format_freshness_note_full_incremental() {
+        let result = FreshnessResult {
+            cache: CacheDir::for_repo(&std::env::temp_dir()).unwrap(),
+            refreshed: true,
+            refresh_type: RefreshType::Full,
+            files_updated: 3,
+            files_reused: 97,
+            duration_ms: 80,
+        };
+        let note = format_freshness_note(&result).unwrap();
+        assert!(note.contains("3 files in 80ms"));
+        assert!(note.contains("97 unchanged files reused"));
     }
 
     // ========================================================================
@@ -1461,6 +1647,7 @@ mod tests {
         let result = IndexGenerationResult {
             duration_ms: 500,
             files_analyzed: 100,
+            files_reused: 0,
             modules_written: 10,
             symbols_written: 500,
             compression_pct: 75.5,
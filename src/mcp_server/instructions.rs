@@ -89,7 +89,7 @@ TOTAL: ~6,000 tokens
 | `get_file` | ~500 | File or module symbols |
 | `get_source` | ~400/50 lines | Use for final edits |
 
-## The 18 Tools
+## The 19 Tools
 
 ### Query-Driven (Most Efficient)
 - **search**: Unified search - runs BOTH symbol AND semantic by default (prefer hybrid)!
@@ -102,6 +102,7 @@ TOTAL: ~6,000 tokens
 - **get_symbol**: Symbol details by hash (supports batch with `hashes: [...]`)
 - **get_source**: Surgical source code read (by hash or file+lines)
 - **get_file**: File symbols OR module symbols (mutually exclusive params)
+- **get_module**: Module summary - purpose, files, risk, top symbols with signatures
 - **get_callers**: Who calls this function? (impact analysis)
 
 ### Repository Analysis
@@ -64,6 +64,7 @@ Use when: User asks to review changes, PR review, diff analysis.
 | `search` (semantic only) | ~800 | Conceptual queries |
 | `search` (raw) | ~1k | Comments, strings, TODOs |
 | `get_file` | ~500 | File or module symbols |
+| `get_module` | ~300 | Module orientation |
 | `get_source` | ~400/50 lines | Final code reads for editing |
 | `get_callgraph(summary_only)` | ~300 | Architecture coupling |
 | `get_callgraph(full)` | ~2-6k | Only when need edge details |
@@ -86,7 +87,7 @@ Results from tools include symbol hashes. USE THEM:
 - Names vary by project (e.g., `semfora_pm.db` not `database`, `semfora_pm.tui` not `ui`)
 - **NEVER guess module names** - they must match exactly
 
-## The 18 Tools - Detailed
+## The 19 Tools - Detailed
 
 ### Start Here
 - **get_context**: Git + project info (~200 tokens). USE FIRST on every session.
@@ -104,6 +105,7 @@ Results from tools include symbol hashes. USE THEM:
 - **get_file**: Symbols in file (use `file_path`) OR module (use `module`). Mutually exclusive.
 - **get_symbol**: Full semantic details by hash. Supports batch with `hashes: [...]` (max 20).
 - **get_source**: Code extraction by hash or file+lines. Use for final edits.
+- **get_module**: Module summary - purpose, file count, risk, top symbols with signatures.
 
 ### Analysis
 - **analyze**: Unified analysis - auto-detects file, directory, or module scope.
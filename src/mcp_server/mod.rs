@@ -28,17 +28,18 @@ use crate::{
     // CLI types for MCP->CLI handler consolidation
     cli::{
         AnalyzeArgs, CommitArgs, IndexArgs, IndexOperation, LintArgs, LintOperation, OutputFormat,
-        SearchArgs, SymbolScope, TestArgs, ValidateArgs,
+        ScoringProfileArg, SearchArgs, SymbolScope, TestArgs, ValidateArgs,
     },
     commands::{
         run_analyze, run_commit, run_duplicates, run_file_symbols, run_get_callers,
-        run_get_callgraph, run_get_source, run_get_symbol, run_index, run_lint, run_overview,
-        run_search, run_test, run_validate, CommandContext,
+        run_get_callgraph, run_get_source, run_get_symbol, run_index, run_lint, run_module_summary,
+        run_overview, run_search, run_test, run_validate, CommandContext,
     },
     server::ServerState,
     test_runner::{self},
     utils::truncate_to_char_boundary,
     CacheDir,
+    WarmStatus,
 };
 
 // Re-export types for external use
@@ -114,6 +115,19 @@ impl McpDiffServer {
         self.server_state.as_ref()
     }
 
+    /// Load a repository's symbol index and call graph into memory
+    ///
+    /// Later tool calls read them from memory instead of disk until the
+    /// index changes, so calling this at startup avoids a slow first query.
+    pub async fn preload(&self, repo_path: &Path) -> crate::Result<WarmStatus> {
+        let repo_path = repo_path.to_path_buf();
+        tokio::task::spawn_blocking(move || CacheDir::for_repo(&repo_path)?.preload())
+            .await
+            .map_err(|e| crate::McpDiffError::ConfigError {
+                message: format!("Preload task failed: {}", e),
+            })?
+    }
+
     /// Resolve a path relative to the working directory
     async fn resolve_path(&self, path: &str) -> PathBuf {
         let path = Path::new(path);
@@ -208,6 +222,7 @@ impl McpDiffServer {
                 } else {
                     output.push_str("index_status: \"fresh\"\n");
                 }
+                output.push_str(&format!("warm: {}\n", cache.warm_status().warm));
 
                 // Try to read project type and entry points from overview
                 let overview_path = cache.repo_overview_path();
@@ -306,6 +321,10 @@ impl McpDiffServer {
             uncommitted: false,
             commit: None,
             all_commits: false,
+            patch: None,
+            compare: None,
+            semver: false,
+            overview: false,
             base: None,
             max_depth: request.max_depth.unwrap_or(10),
             extensions: request.extensions.clone().unwrap_or_default(),
@@ -325,6 +344,9 @@ impl McpDiffServer {
             analyze_tokens: None,
             compare_compact: false,
             print_ast: false,
+            no_fallback: false,
+            scoring_profile: ScoringProfileArg::Default,
+            explain: false,
         };
 
         // Select output format based on MCP request
@@ -376,6 +398,10 @@ impl McpDiffServer {
             uncommitted: false,
             commit: None,
             all_commits: false,
+            patch: None,
+            compare: None,
+            semver: false,
+            overview: false,
             base: None,
             max_depth: 10,
             extensions: vec![],
@@ -392,6 +418,9 @@ impl McpDiffServer {
             analyze_tokens: None,
             compare_compact: false,
             print_ast: false,
+            no_fallback: false,
+            scoring_profile: ScoringProfileArg::Default,
+            explain: false,
         };
 
         let ctx = CommandContext {
@@ -823,6 +852,8 @@ impl McpDiffServer {
                     incremental: false,
                     max_depth: request.max_depth.unwrap_or(10),
                     extensions: request.extensions.clone().unwrap_or_default(),
+                    skip_logic_free: false,
+                    threads: None,
                 },
             }
         } else {
@@ -1131,7 +1162,7 @@ Output is token-optimized: duplicates are grouped by module with counts and simi
                     let staleness = check_cache_staleness_detailed(&cache, 3600);
                     if staleness.is_stale {
                         // Silently refresh the index
-                        let _ = generate_index_internal(&repo_path, 10, &[]);
+                        let _ = generate_index_internal(&repo_path, 10, &[], true);
                     }
                 }
             }
@@ -1305,6 +1336,41 @@ Output is token-optimized: duplicates are grouped by module with counts and simi
             ))])),
         }
     }
+
+    #[tool(
+        description = "Summarize a module: purpose, file count, risk, and its riskiest symbols with signatures. Higher-level than get_file(module) - use it to orient in a module before drilling into symbols. Use module names from get_overview."
+    )]
+    async fn get_module(
+        &self,
+        Parameters(request): Parameters<GetModuleRequest>,
+    ) -> Result<CallToolResult, McpError> {
+        let repo_path = match &request.path {
+            Some(p) => self.resolve_path(p).await,
+            None => self.get_working_dir().await,
+        };
+
+        if let Err(e) = self.ensure_index(&repo_path).await {
+            return Ok(CallToolResult::error(vec![Content::text(e)]));
+        }
+
+        let limit = request.limit.unwrap_or(10).min(50);
+
+        // Create command context (MCP uses TOON format)
+        let ctx = CommandContext {
+            format: OutputFormat::Toon,
+            verbose: false,
+            progress: false,
+        };
+
+        // Delegate to CLI handler
+        match run_module_summary(Some(&repo_path), &request.module, limit, &ctx) {
+            Ok(output) => Ok(CallToolResult::success(vec![Content::text(output)])),
+            Err(e) => Ok(CallToolResult::error(vec![Content::text(format!(
+                "Failed to get module: {}",
+                e
+            ))])),
+        }
+    }
 }
 
 /// Format test results as compact TOON output
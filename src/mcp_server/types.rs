@@ -899,6 +899,23 @@ pub struct GetCallersRequest {
     pub include_source: Option<bool>,
 }
 
+/// Get a module-level summary: purpose, file count, risk, and top symbols.
+/// Answers "show me module X" without listing every symbol.
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetModuleRequest {
+    /// Module name (from get_overview)
+    #[schemars(description = "Module name to summarize (use names from get_overview)")]
+    pub module: String,
+
+    /// Repository path (defaults to current directory)
+    #[schemars(description = "Path to the repository root (defaults to current directory)")]
+    pub path: Option<String>,
+
+    /// Maximum top symbols to include (default: 10, max: 50)
+    #[schemars(description = "Maximum top symbols to include (default: 10, max: 50)")]
+    pub limit: Option<usize>,
+}
+
 // ============================================================================
 // Validation Request Types (Phase 4)
 // ============================================================================
@@ -1643,6 +1643,8 @@ mod tests {
                 name: "x".to_string(),
                 arg_type: Some("i32".to_string()),
                 default_value: None,
+                is_optional: false,
+                description: None,
             }],
             ..Default::default()
         };
@@ -1655,11 +1657,15 @@ mod tests {
                     name: "x".to_string(),
                     arg_type: Some("i32".to_string()),
                     default_value: None,
+                    is_optional: false,
+                    description: None,
                 },
                 Argument {
                     name: "y".to_string(),
                     arg_type: Some("i32".to_string()),
                     default_value: None,
+                    is_optional: false,
+                    description: None,
                 },
             ],
             ..Default::default()
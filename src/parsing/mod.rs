@@ -21,7 +21,7 @@
 use std::path::Path;
 
 use crate::error::McpDiffError;
-use crate::extract::extract;
+use crate::extract::{extract_with_options, ExtractOptions};
 use crate::lang::Lang;
 use crate::SemanticSummary;
 
@@ -49,7 +49,7 @@ pub fn parse_and_extract(
     source: &str,
     lang: Lang,
 ) -> Result<SemanticSummary, McpDiffError> {
-    parse_and_extract_with_options(file_path, source, lang, false)
+    parse_and_extract_with_options(file_path, source, lang, false, ExtractOptions::default())
 }
 
 /// Parse source code and extract semantic summary with debug options.
@@ -62,6 +62,7 @@ pub fn parse_and_extract(
 /// * `source` - The source code to parse
 /// * `lang` - The programming language
 /// * `print_ast` - If true, prints the AST to stderr for debugging
+/// * `options` - Extraction options (see [`ExtractOptions`])
 ///
 /// # Errors
 ///
@@ -71,6 +72,7 @@ pub fn parse_and_extract_with_options(
     source: &str,
     lang: Lang,
     print_ast: bool,
+    options: ExtractOptions,
 ) -> Result<SemanticSummary, McpDiffError> {
     let mut parser = tree_sitter::Parser::new();
     parser
@@ -95,7 +97,7 @@ pub fn parse_and_extract_with_options(
         eprintln!("=================");
     }
 
-    extract(file_path, source, &tree, lang)
+    extract_with_options(file_path, source, &tree, lang, options)
 }
 
 #[cfg(test)]
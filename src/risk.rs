@@ -1,7 +1,26 @@
 //! Behavioral risk calculation
 
+use std::fmt;
+
+use serde::Serialize;
+
 use crate::schema::{RiskLevel, SemanticSummary};
 
+/// One contribution to a behavioral risk score
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RiskFactor {
+    /// What contributed, e.g. "3 I/O or network insertions"
+    pub factor: String,
+    /// Points added to the score
+    pub points: usize,
+}
+
+impl fmt::Display for RiskFactor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: +{}", self.factor, self.points)
+    }
+}
+
 /// Calculate behavioral risk level from a semantic summary
 ///
 /// Risk scoring (tuned for practical use):
@@ -11,60 +30,82 @@ use crate::schema::{RiskLevel, SemanticSummary};
 /// - +2 for I/O or network calls
 /// - +3 for public API changes
 /// - +3 for persistence operations
+/// - +2 per lock hazard (Rust deadlock hints)
 pub fn calculate_risk(summary: &SemanticSummary) -> RiskLevel {
-    let mut score = 0;
+    let score = explain_risk(summary).iter().map(|f| f.points).sum();
+    RiskLevel::from_score(score)
+}
+
+/// The factors behind [`calculate_risk`], largest contribution first
+///
+/// Only factors that added points are listed; their points sum to the score
+/// the risk level was derived from.
+pub fn explain_risk(summary: &SemanticSummary) -> Vec<RiskFactor> {
+    let mut factors = Vec::new();
+    let mut add = |factor: String, points: usize| {
+        if points > 0 {
+            factors.push(RiskFactor { factor, points });
+        }
+    };
 
     // +1 per new import, capped at 3 (imports are normal, not risky)
-    score += summary.added_dependencies.len().min(3);
+    let imports = summary.added_dependencies.len();
+    add(format!("{} new imports", imports), imports.min(3));
 
     // +1 per state variable
-    score += summary.state_changes.len();
+    let state = summary.state_changes.len();
+    add(format!("{} state variables", state), state);
 
     // Control flow: graduated scoring instead of +2 per item
     // This prevents normal Rust files with many if/match from being "high risk"
     let cf_count = summary.control_flow_changes.len();
-    if cf_count > 0 {
-        score += 1; // Base: has control flow
-    }
-    if cf_count > 5 {
-        score += 1; // Moderate complexity
-    }
-    if cf_count > 15 {
-        score += 1; // High complexity
-    }
+    let cf_points = [0, 5, 15].iter().filter(|&&n| cf_count > n).count();
+    add(format!("{} control flow changes", cf_count), cf_points);
 
     // +2 for I/O or network calls (detected via insertions)
-    for insertion in &summary.insertions {
-        let lower = insertion.to_lowercase();
-        if lower.contains("network")
-            || lower.contains("fetch")
-            || lower.contains("invoke")
-            || lower.contains("i/o")
-            || lower.contains("file")
-        {
-            score += 2;
-        }
-    }
+    let io = count_insertions(summary, &["network", "fetch", "invoke", "i/o", "file"]);
+    add(format!("{} I/O or network insertions", io), io * 2);
 
     // +3 for public API changes
     if summary.public_surface_changed {
-        score += 3;
+        add("public surface changed".to_string(), 3);
     }
 
     // +3 for persistence operations
-    for insertion in &summary.insertions {
-        let lower = insertion.to_lowercase();
-        if lower.contains("storage")
-            || lower.contains("database")
-            || lower.contains("persist")
-            || lower.contains("localstorage")
-            || lower.contains("sessionstorage")
-        {
-            score += 3;
-        }
-    }
+    let persistence = count_insertions(
+        summary,
+        &[
+            "storage",
+            "database",
+            "persist",
+            "localstorage",
+            "sessionstorage",
+        ],
+    );
+    add(
+        format!("{} persistence insertions", persistence),
+        persistence * 3,
+    );
 
-    RiskLevel::from_score(score)
+    // +2 per lock hazard (multiple locks or guard held across await)
+    let locks = summary.lock_hazards.len();
+    add(format!("{} lock hazards", locks), locks * 2);
+
+    // Stable sort keeps scoring order among equal contributions
+    factors.sort_by(|a, b| b.points.cmp(&a.points));
+    factors
+}
+
+/// Insertions mentioning any of `keywords`, case-insensitively
+fn count_insertions(summary: &SemanticSummary, keywords: &[&str]) -> usize {
+    summary
+        .insertions
+        .iter()
+        .filter(|insertion| {
+            let lower = insertion.to_lowercase();
+            keywords.iter().any(|k| lower.contains(k))
+        })
+        .count()
 }
 
 #[cfg(test)]
@@ -95,6 +136,22 @@ mod tests {
         assert_eq!(calculate_risk(&summary), RiskLevel::Medium);
     }
 
+    #[test]
+    fn test_lock_hazard_raises_risk() {
+        use crate::schema::{LockHazard, LockHazardKind};
+
+        let summary = SemanticSummary {
+            lock_hazards: vec![LockHazard {
+                kind: LockHazardKind::MultipleLocks,
+                function: "transfer".to_string(),
+                locks: vec!["self.accounts".to_string(), "self.audit".to_string()],
+                location: Location::default(),
+            }],
+            ..Default::default()
+        };
+        assert_eq!(calculate_risk(&summary), RiskLevel::Medium);
+    }
+
     #[test]
     fn test_high_risk_control_flow() {
         // High risk now requires more substantial changes
@@ -131,4 +188,31 @@ mod tests {
         // 2 network + 3 public = 5 = high
         assert_eq!(calculate_risk(&summary), RiskLevel::High);
     }
+
+    #[test]
+    fn test_explain_risk_dominant_factor() {
+        let nested = |depth| ControlFlowChange {
+            kind: ControlFlowKind::If,
+            location: Location::default(),
+            nesting_depth: depth,
+        };
+        let summary = SemanticSummary {
+            control_flow_changes: (0..4).map(nested).collect(),
+            insertions: vec![
+                "network call introduced".to_string(),
+                "file I/O via fs.readFile".to_string(),
+                "fetch call to /api/users".to_string(),
+            ],
+            ..Default::default()
+        };
+
+        let factors = explain_risk(&summary);
+        assert_eq!(factors[0].factor, "3 I/O or network insertions");
+        assert_eq!(factors[0].points, 6);
+        assert_eq!(factors[1].to_string(), "4 control flow changes: +1");
+        assert_eq!(factors.len(), 2, "{:?}", factors);
+
+        let score: usize = factors.iter().map(|f| f.points).sum();
+        assert_eq!(RiskLevel::from_score(score), calculate_risk(&summary));
+    }
 }
@@ -96,6 +96,11 @@ pub struct SymbolInfo {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub return_type: Option<String>,
 
+    /// Generic type parameters with their bounds, e.g. "T: Clone",
+    /// "K extends string"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_params: Vec<String>,
+
     /// Function calls within this symbol's body
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub calls: Vec<Call>,
@@ -129,13 +134,74 @@ pub struct SymbolInfo {
     /// Comma-separated names, e.g. "BaseModel,Serializable"
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub base_classes: Vec<String>,
+
+    /// Whether this function/method calls itself directly
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_recursive: bool,
+
+    /// Number of functions declared inside this symbol's body
+    /// (closures, lambdas, inner functions)
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub nested_functions: usize,
+
+    /// Number of assertion calls in this test (`None` for non-test symbols)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assertion_count: Option<usize>,
+
+    /// Whether another symbol in the same file shares this name and kind
+    /// (TS overloads, Java/C# overloads, accidental redefinition)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub overloaded: bool,
+
+    /// Whether this is a signature-only declaration from a type stub (`.pyi`)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_stub: bool,
+
+    /// Dependencies injected by a DI container (Spring, NestJS, Angular):
+    /// constructor parameter types or `@Inject` tokens, for classes only
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub injected_deps: Vec<String>,
+
+    /// Naming convention the symbol breaks for its kind and language,
+    /// e.g. "expected camelCase"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub naming_violation: Option<String>,
+
+    /// Object this method calls into more than its own (feature envy),
+    /// suggesting the method belongs on that object
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feature_envy: Option<String>,
+
+    /// Design patterns this symbol takes part in, e.g. "visitor",
+    /// "double_dispatch"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub patterns: Vec<String>,
+
+    /// Whether the body is empty or only a not-implemented marker
+    /// (`todo!()`, `pass`, `throw new Error("not implemented")`)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_stub_impl: bool,
+
+    /// What the return value means, from the doc comment (`@returns`,
+    /// rustdoc `# Returns`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub returns_description: Option<String>,
 }
 
 impl SymbolInfo {
     /// Create a SymbolId for this symbol given a namespace and file path
     pub fn to_symbol_id(&self, namespace: &str, file_path: &str) -> SymbolId {
         let arity = self.arguments.len() + self.props.len();
-        SymbolId::new(namespace, &self.name, self.kind, arity, file_path)
+        if !self.overloaded {
+            return SymbolId::new(namespace, &self.name, self.kind, arity, file_path);
+        }
+
+        // Same-name symbols in one file would otherwise share a hash;
+        // the start line keeps each definition distinct.
+        let disambiguated = format!("{}#L{}", self.name, self.start_line);
+        let mut id = SymbolId::new(namespace, &disambiguated, self.kind, arity, file_path);
+        id.symbol = self.name.clone();
+        id
     }
 
     /// Calculate behavioral risk from calls and control flow
@@ -161,6 +227,11 @@ impl SymbolInfo {
 
         RiskLevel::from_score(score)
     }
+
+    /// Whether this is a test that contains no assertions
+    pub fn is_assertionless_test(&self) -> bool {
+        self.assertion_count == Some(0)
+    }
 }
 
 impl SymbolId {
@@ -532,6 +603,189 @@ impl SemanticDiff {
     }
 }
 
+/// How a symbol differs between two versions of a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+impl SymbolChangeKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Added => "added",
+            Self::Removed => "removed",
+            Self::Modified => "modified",
+        }
+    }
+}
+
+/// Which way a symbol's visibility moved between two versions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VisibilityChange {
+    /// Became public
+    Exposed,
+    /// Stopped being public; breaks outside callers even if the
+    /// signature is unchanged
+    Hidden,
+}
+
+impl VisibilityChange {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Exposed => "exposed",
+            Self::Hidden => "hidden",
+        }
+    }
+}
+
+/// Symbol-level change between two versions of a file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SymbolDiff {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub change: SymbolChangeKind,
+
+    /// Signature before the change (removed, or modified with a new signature)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature_before: Option<String>,
+
+    /// Signature after the change (added, or modified with a new signature)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature_after: Option<String>,
+
+    /// Callees that only the new version calls
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub calls_added: Vec<String>,
+
+    /// Callees that only the old version calls
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub calls_removed: Vec<String>,
+
+    /// Whether the symbol became public or stopped being public
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility_change: Option<VisibilityChange>,
+
+    pub risk_before: Option<RiskLevel>,
+    pub risk_after: Option<RiskLevel>,
+}
+
+impl SymbolDiff {
+    /// Compare the symbols of two versions of a file
+    ///
+    /// Symbols are paired by name and kind, in source order for overloads.
+    /// Unchanged symbols are omitted.
+    pub fn between(before: &SemanticSummary, after: &SemanticSummary) -> Vec<Self> {
+        type Groups<'a> = std::collections::BTreeMap<(&'a str, &'static str), Vec<&'a SymbolInfo>>;
+        fn group(summary: &SemanticSummary) -> Groups<'_> {
+            let mut groups = Groups::new();
+            for symbol in &summary.symbols {
+                groups
+                    .entry((symbol.name.as_str(), symbol.kind.as_str()))
+                    .or_default()
+                    .push(symbol);
+            }
+            groups
+        }
+        let old_groups = group(before);
+        let new_groups = group(after);
+
+        let mut diffs = Vec::new();
+        for (key, old_symbols) in &old_groups {
+            let new_symbols = new_groups.get(key).map(Vec::as_slice).unwrap_or_default();
+            for (i, old) in old_symbols.iter().enumerate() {
+                match new_symbols.get(i) {
+                    Some(new) => diffs.extend(Self::modified(old, new)),
+                    None => diffs.push(Self::one_sided(old, SymbolChangeKind::Removed)),
+                }
+            }
+        }
+        for (key, new_symbols) in &new_groups {
+            let old_count = old_groups.get(key).map_or(0, Vec::len);
+            for new in new_symbols.iter().skip(old_count) {
+                diffs.push(Self::one_sided(new, SymbolChangeKind::Added));
+            }
+        }
+        diffs
+    }
+
+    /// Symbols of two versions of a file whose visibility changed
+    pub fn visibility_changes(before: &SemanticSummary, after: &SemanticSummary) -> Vec<Self> {
+        Self::between(before, after)
+            .into_iter()
+            .filter(|d| d.visibility_change.is_some())
+            .collect()
+    }
+
+    /// Whether the symbol's signature differs between the versions
+    pub fn signature_changed(&self) -> bool {
+        self.change == SymbolChangeKind::Modified && self.signature_after.is_some()
+    }
+
+    fn one_sided(symbol: &SymbolInfo, change: SymbolChangeKind) -> Self {
+        let signature = Some(crate::toon::symbol_signature(symbol));
+        let risk = Some(symbol.behavioral_risk);
+        let added = change == SymbolChangeKind::Added;
+        Self {
+            name: symbol.name.clone(),
+            kind: symbol.kind,
+            change,
+            signature_before: if added { None } else { signature.clone() },
+            signature_after: if added { signature } else { None },
+            calls_added: Vec::new(),
+            calls_removed: Vec::new(),
+            visibility_change: None,
+            risk_before: if added { None } else { risk },
+            risk_after: if added { risk } else { None },
+        }
+    }
+
+    fn modified(old: &SymbolInfo, new: &SymbolInfo) -> Option<Self> {
+        let call_names = |symbol: &SymbolInfo| -> std::collections::BTreeSet<String> {
+            symbol
+                .calls
+                .iter()
+                .map(|c| match &c.object {
+                    Some(object) => format!("{}.{}", object, c.name),
+                    None => c.name.clone(),
+                })
+                .collect()
+        };
+        let old_calls = call_names(old);
+        let new_calls = call_names(new);
+        let old_signature = crate::toon::symbol_signature(old);
+        let new_signature = crate::toon::symbol_signature(new);
+        let signature_changed = old_signature != new_signature;
+        let visibility_change = match (old.is_exported, new.is_exported) {
+            (false, true) => Some(VisibilityChange::Exposed),
+            (true, false) => Some(VisibilityChange::Hidden),
+            _ => None,
+        };
+
+        let diff = Self {
+            name: new.name.clone(),
+            kind: new.kind,
+            change: SymbolChangeKind::Modified,
+            signature_before: signature_changed.then_some(old_signature),
+            signature_after: signature_changed.then_some(new_signature),
+            calls_added: new_calls.difference(&old_calls).cloned().collect(),
+            calls_removed: old_calls.difference(&new_calls).cloned().collect(),
+            visibility_change,
+            risk_before: Some(old.behavioral_risk),
+            risk_after: Some(new.behavioral_risk),
+        };
+        let changed = signature_changed
+            || !diff.calls_added.is_empty()
+            || !diff.calls_removed.is_empty()
+            || visibility_change.is_some()
+            || old.behavioral_risk != new.behavioral_risk;
+        changed.then_some(diff)
+    }
+}
+
 // ============================================================================
 // Repository Overview
 // ============================================================================
@@ -558,12 +812,25 @@ pub struct RepoOverview {
     pub entry_points: Vec<String>,
 
     /// Internal data flow (file -> files it imports from)
+    #[serde(serialize_with = "serialize_sorted")]
     pub data_flow: HashMap<String, Vec<String>>,
 
     /// Total statistics
     pub stats: RepoStats,
 }
 
+/// Serialize a map with its keys in order, so encoded overviews are
+/// byte-for-byte stable across runs
+fn serialize_sorted<S, V>(map: &HashMap<String, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+    V: Serialize,
+{
+    map.iter()
+        .collect::<std::collections::BTreeMap<_, _>>()
+        .serialize(serializer)
+}
+
 /// A group of related files (by directory or purpose)
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct ModuleGroup {
@@ -598,6 +865,7 @@ pub struct RepoStats {
     pub low_risk: usize,
 
     /// Files by language
+    #[serde(serialize_with = "serialize_sorted")]
     pub by_language: HashMap<String, usize>,
 
     /// Total API endpoints
@@ -608,6 +876,10 @@ pub struct RepoStats {
 
     /// Total React components
     pub components: usize,
+
+    /// Files without behavioral content left out of the counts above
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub logic_free_files: usize,
 }
 
 /// Complete semantic summary of a file
@@ -633,7 +905,7 @@ pub struct SemanticSummary {
     ///
     /// This captures every exported symbol, solving the "single symbol per file"
     /// limitation. Each SymbolInfo contains full semantic data for that symbol.
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub symbols: Vec<SymbolInfo>,
 
     /// Start line of the primary symbol (1-indexed)
@@ -660,7 +932,7 @@ pub struct SemanticSummary {
     pub added_dependencies: Vec<String>,
 
     /// Local file imports (for data flow tracking)
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub local_imports: Vec<String>,
 
     /// Mapping of imported symbol names to their source packages
@@ -669,6 +941,11 @@ pub struct SemanticSummary {
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub import_sources: HashMap<String, String>,
 
+    /// Imports with their full module paths (Rust `use` declarations,
+    /// with `crate::`/`super::`/`self::` resolved against this file's module)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub imports: Vec<Import>,
+
     /// State variable changes
     pub state_changes: Vec<StateChange>,
 
@@ -678,6 +955,108 @@ pub struct SemanticSummary {
     /// Function calls detected
     pub calls: Vec<Call>,
 
+    /// `switch`/`match` constructs that lack a default/catch-all arm
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_default_branches: Vec<Location>,
+
+    /// Top-level statements that run when the module is imported
+    /// (calls, writes to objects or globals); non-empty means the module
+    /// has import-time side effects
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub import_side_effects: Vec<Location>,
+
+    /// Accessibility issues found on JSX/HTML elements (heuristic)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub a11y_issues: Vec<A11yIssue>,
+
+    /// Calls to same-file async functions whose promise/future is discarded
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unawaited_async_calls: Vec<UnawaitedCall>,
+
+    /// Functions mixing `await` with `.then()` chains
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mixed_async_styles: Vec<MixedAsyncStyle>,
+
+    /// Member accesses on values from calls that may return null/undefined
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unchecked_nullable_accesses: Vec<UncheckedNullableAccess>,
+
+    /// Functions that take several locks or hold a guard across `.await` (Rust)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lock_hazards: Vec<LockHazard>,
+
+    /// Imports that repeat an earlier import from the same module
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub duplicate_imports: Vec<DuplicateImport>,
+
+    /// Comment blocks whose contents parse as code of the file's language
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commented_code: Vec<CommentedCodeBlock>,
+
+    /// Statement runs copied within one function body, worth a helper
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repeated_blocks: Vec<RepeatedBlock>,
+
+    /// Member-access chains deeper than the Law of Demeter threshold
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub long_member_chains: Vec<MemberChain>,
+
+    /// String concatenations (`+=`) repeated inside a loop body
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub loop_string_concats: Vec<LoopStringConcat>,
+
+    /// Code paths taken only on some operating systems
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub platform_branches: Vec<PlatformBranch>,
+
+    /// Condition under which the file is compiled at all, e.g. `linux && amd64`
+    /// from a Go `//go:build` line and `_linux_amd64.go` file name suffix
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build_constraint: Option<String>,
+
+    /// Unchecked integer arithmetic on sizes, lengths, or indices
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub overflow_risks: Vec<OverflowRisk>,
+
+    /// Files, connections, and sockets opened without a matching close
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resource_leaks: Vec<ResourceLeak>,
+
+    /// Caught errors rethrown unchanged, without context or logging
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bare_rethrows: Vec<BareRethrow>,
+
+    /// Handlers that catch every exception, or all but the most basic ones
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub broad_catches: Vec<BroadCatch>,
+
+    /// Logging calls passing values named like credentials or personal data
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sensitive_logs: Vec<SensitiveLog>,
+
+    /// Functions that return a value on some paths and nothing on others
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inconsistent_returns: Vec<InconsistentReturn>,
+
+    /// Calls passing many arguments positionally, without keywords or an
+    /// options object; resolved against the repository's own functions by
+    /// [`find_positional_arg_calls`](crate::analysis::find_positional_arg_calls)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub positional_calls: Vec<PositionalCall>,
+
+    /// String literals worth naming as a constant; repeats across files are
+    /// reported by [`duplicate_literals`](crate::analysis::duplicate_literals)
+    ///
+    /// Only that in-process pass reads them, so they are not serialized: a
+    /// summary's JSON, TOON and shards stay free of every literal in the file.
+    #[serde(skip)]
+    pub string_literals: Vec<StringLiteral>,
+
+    /// Expression-level complexity from nested ternaries and chained
+    /// optional access, which statement-based cognitive complexity misses
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub expression_complexity: usize,
+
     /// Whether the public API surface changed
     pub public_surface_changed: bool,
 
@@ -698,6 +1077,37 @@ pub struct SemanticSummary {
     pub extraction_complete: bool,
 }
 
+impl SemanticSummary {
+    /// Whether the file has no behavioral content: no calls, control flow, or
+    /// state changes in the file or any of its symbols
+    ///
+    /// True for re-export barrels, type-only modules, and data files. Variable
+    /// references don't count as calls.
+    pub fn is_empty_of_logic(&self) -> bool {
+        let no_calls = |calls: &[Call]| calls.iter().all(|c| c.ref_kind != RefKind::None);
+        no_calls(&self.calls)
+            && self.control_flow_changes.is_empty()
+            && self.state_changes.is_empty()
+            && self.symbols.iter().all(|s| {
+                no_calls(&s.calls) && s.control_flow.is_empty() && s.state_changes.is_empty()
+            })
+    }
+
+    /// Symbols whose line range overlaps `start_line..=end_line`
+    ///
+    /// For attributing an edit to symbols: an edit that spans a boundary
+    /// touches the symbols on both sides, so any overlap counts, not only
+    /// containment. Escape-local symbols are left out, as they are part of
+    /// their enclosing symbol. Returned in file order.
+    pub fn symbols_changed_in_range(&self, start_line: usize, end_line: usize) -> Vec<&SymbolInfo> {
+        let (start, end) = (start_line.min(end_line), start_line.max(end_line));
+        self.symbols
+            .iter()
+            .filter(|s| !s.is_escape_local && s.start_line <= end && s.end_line >= start)
+            .collect()
+    }
+}
+
 /// Kind of symbol being analyzed
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Hash)]
 #[serde(rename_all = "lowercase")]
@@ -725,6 +1135,8 @@ pub enum SymbolKind {
     TypeAlias,
     /// Variable declaration (module-level constant, static, or class field)
     Variable,
+    /// Macro definition (Rust `macro_rules!`, C function-like `#define`)
+    Macro,
 }
 
 impl SymbolKind {
@@ -742,6 +1154,7 @@ impl SymbolKind {
             Self::Module => "module",
             Self::TypeAlias => "type_alias",
             Self::Variable => "variable",
+            Self::Macro => "macro",
         }
     }
 
@@ -759,6 +1172,7 @@ impl SymbolKind {
             "module" | "mod" => Self::Module,
             "type_alias" | "type" => Self::TypeAlias,
             "variable" | "var" | "const" | "static" | "field" => Self::Variable,
+            "macro" | "macro_rules" | "define" => Self::Macro,
             _ => Self::Function, // Default fallback
         }
     }
@@ -907,6 +1321,15 @@ pub struct Argument {
     /// Default value
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_value: Option<String>,
+
+    /// Callers may leave it out: it has a default, is marked optional
+    /// (`x?: T`), is variadic, or takes an `Option` in Rust
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_optional: bool,
+
+    /// Description from the doc comment (`@param`, rustdoc `# Arguments`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 /// State variable change
@@ -1008,8 +1431,324 @@ impl Location {
     }
 }
 
+/// Accessibility issue on a single JSX/HTML element
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct A11yIssue {
+    /// Kind of issue
+    pub kind: A11yIssueKind,
+
+    /// Element tag name (e.g. "img", "div")
+    pub element: String,
+
+    /// Location of the element in source
+    pub location: Location,
+}
+
+/// Kind of accessibility issue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum A11yIssueKind {
+    /// `<img>` without an `alt` attribute
+    MissingAlt,
+    /// `<button>` with no text content or `aria-label`
+    ButtonWithoutText,
+    /// Click handler on a non-interactive element without a `role`
+    ClickOnNonInteractive,
+    /// Form control with no associated label
+    MissingFormLabel,
+}
+
+impl A11yIssueKind {
+    /// Get the string representation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MissingAlt => "missing_alt",
+            Self::ButtonWithoutText => "button_without_text",
+            Self::ClickOnNonInteractive => "click_on_non_interactive",
+            Self::MissingFormLabel => "missing_form_label",
+        }
+    }
+}
+
+/// Call to an async function whose result is neither awaited nor used
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnawaitedCall {
+    /// Name of the async function being called
+    pub name: String,
+
+    /// Location of the call in source
+    pub location: Location,
+}
+
+/// Function that both awaits promises and chains `.then()` on them
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MixedAsyncStyle {
+    /// Function name (`<anonymous>` if it has none)
+    pub function: String,
+
+    /// Line of the first `.then()` call in the function
+    pub then_line: usize,
+
+    /// Location of the function
+    pub location: Location,
+}
+
+/// Property access on a variable assigned from a possibly-empty lookup
+/// (`find`, `querySelector`, `get`, ...) with no guard in between
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UncheckedNullableAccess {
+    /// Variable holding the lookup result
+    pub variable: String,
+
+    /// Method whose result was assigned, e.g. "find"
+    pub call: String,
+
+    /// Line of the assignment
+    pub assigned_line: usize,
+
+    /// Location of the unguarded access
+    pub location: Location,
+}
+
+/// Lock usage inside one function that hints at deadlock risk
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockHazard {
+    /// Kind of hazard
+    pub kind: LockHazardKind,
+
+    /// Function containing the lock calls
+    pub function: String,
+
+    /// Receivers being locked (e.g. "self.accounts")
+    pub locks: Vec<String>,
+
+    /// Location of the lock call that triggered the hazard
+    pub location: Location,
+}
+
+/// Kind of lock hazard
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LockHazardKind {
+    /// Two or more distinct locks acquired in the same function
+    MultipleLocks,
+    /// A lock guard bound to a variable is still alive at an `.await`
+    GuardAcrossAwait,
+}
+
+impl LockHazardKind {
+    /// Get the string representation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MultipleLocks => "multiple_locks",
+            Self::GuardAcrossAwait => "guard_across_await",
+        }
+    }
+}
+
+/// Import that repeats an earlier one in the same file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DuplicateImport {
+    /// Imported name (the namespace alias for redundant namespace imports)
+    pub name: String,
+
+    /// Module imported from
+    pub source: String,
+
+    /// What makes the import redundant
+    pub kind: DuplicateImportKind,
+
+    /// Location of the later import
+    pub location: Location,
+}
+
+/// Kind of duplicate import
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateImportKind {
+    /// The same name imported again
+    Duplicate,
+    /// A namespace import alongside named imports from the same module
+    RedundantNamespace,
+}
+
+impl DuplicateImportKind {
+    /// Get the string representation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Duplicate => "duplicate",
+            Self::RedundantNamespace => "redundant_namespace",
+        }
+    }
+
+    /// How to clean the import up
+    pub fn suggestion(&self) -> &'static str {
+        match self {
+            Self::Duplicate => "remove the repeated import",
+            Self::RedundantNamespace => "use either the namespace or the named imports",
+        }
+    }
+}
+
+/// Chained member access such as `a.b.c.d.e()`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemberChain {
+    /// Number of segments, counting the receiver (`a.b.c` is 3)
+    pub depth: usize,
+
+    /// Location of the outermost expression in the chain
+    pub location: Location,
+}
+
+/// Function whose return paths disagree on shape, e.g. a dict on one
+/// branch and `None` (or falling off the end) on another
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InconsistentReturn {
+    /// Function name (`<anonymous>` if it has none)
+    pub function: String,
+
+    /// Distinct return shapes seen: `value`, `none`, `bare`, `implicit`
+    pub shapes: Vec<String>,
+
+    /// Location of the function
+    pub location: Location,
+}
+
+/// Call that passes several arguments by position only
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PositionalCall {
+    /// Called function or method name (without receiver)
+    pub callee: String,
+
+    /// Number of positional arguments
+    pub args: usize,
+
+    /// Location of the call
+    pub location: Location,
+}
+
+/// String literal in code, without its quotes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StringLiteral {
+    /// Literal contents, e.g. `Content-Type`
+    pub value: String,
+
+    /// Location of the literal
+    pub location: Location,
+}
+
+/// OS-specific check, e.g. `#[cfg(windows)]` or `process.platform === 'win32'`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlatformBranch {
+    /// Platform as written in the check: `windows`, `win32`, `darwin`, `nt`
+    pub platform: String,
+
+    /// Location of the check
+    pub location: Location,
+}
+
+/// Error handler that passes the error on unchanged
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BareRethrow {
+    /// Name the caught error is bound to; `None` for a bare `raise`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// Location of the catch clause or `map_err` call
+    pub location: Location,
+}
+
+/// Exception handler too broad to tell expected failures from bugs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BroadCatch {
+    /// Caught type, e.g. `Exception`; `*` for a handler with no type
+    pub caught: String,
+
+    /// Location of the handler
+    pub location: Location,
+}
+
+/// Logging call whose arguments include a secret-looking name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SensitiveLog {
+    /// Logging call, e.g. `console.log` or `logger.info`
+    pub logger: String,
+
+    /// Sensitive identifier or property passed to it, e.g. `password`
+    pub name: String,
+
+    /// Location of the identifier
+    pub location: Location,
+}
+
+/// Resource acquired without a release in the same function
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceLeak {
+    /// Acquiring call, e.g. `os.Open` or `open`
+    pub call: String,
+
+    /// Variable holding the resource; `None` when the result isn't bound
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variable: Option<String>,
+
+    /// Location of the acquiring call
+    pub location: Location,
+}
+
+/// Unchecked `+`, `*`, or `<<` on a size, length, or index that could wrap
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OverflowRisk {
+    /// The arithmetic expression, whitespace-normalized
+    pub expression: String,
+
+    /// Location of the expression
+    pub location: Location,
+}
+
+/// String built up with `+=` inside a loop; a builder or join is cheaper
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoopStringConcat {
+    /// Variable being appended to
+    pub variable: String,
+
+    /// Location of the concatenation
+    pub location: Location,
+}
+
+/// Run of comments that looks like disabled code rather than prose
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommentedCodeBlock {
+    /// First line of the block (1-indexed)
+    pub start_line: usize,
+
+    /// Last line of the block (1-indexed, inclusive)
+    pub end_line: usize,
+}
+
+/// Run of statements repeated, up to names and literals, in the same body
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepeatedBlock {
+    /// Function containing both copies (`<anonymous>` if it has none)
+    pub function: String,
+
+    /// Number of statements in each copy
+    pub statements: usize,
+
+    /// First line of the earlier copy
+    pub first_start_line: usize,
+
+    /// Last line of the earlier copy
+    pub first_end_line: usize,
+
+    /// Last line of the repeat
+    pub end_line: usize,
+
+    /// Location of the repeat's first statement
+    pub location: Location,
+}
+
 /// Behavioral risk level
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum RiskLevel {
     /// Low risk (0-1 points)
@@ -1222,6 +1961,15 @@ pub struct Call {
     #[serde(skip)]
     pub ref_kind: RefKind,
 
+    /// Whether this call creates an object (`new Foo()`, `Foo::new()`,
+    /// `Foo { .. }`, or a Python class call)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_constructor: bool,
+
+    /// Type being instantiated, for constructor calls
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instantiated_type: Option<String>,
+
     /// Source location
     #[serde(skip)]
     pub location: Location,
@@ -1262,7 +2010,7 @@ impl Call {
 }
 
 /// Import statement
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Import {
     /// Module source path
     pub source: String,
@@ -1271,19 +2019,22 @@ pub struct Import {
     pub names: Vec<ImportedName>,
 
     /// Whether this is a default import
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub is_default: bool,
 
     /// Whether this is a namespace import
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub is_namespace: bool,
 }
 
 /// Individual imported name
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ImportedName {
     /// Original name
     pub name: String,
 
     /// Alias (if renamed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub alias: Option<String>,
 }
 
@@ -1320,6 +2071,35 @@ mod tests {
         assert!(!Call::check_is_io("map"));
     }
 
+    #[test]
+    fn test_symbols_changed_in_range() {
+        let symbol = |name: &str, start_line: usize, end_line: usize| SymbolInfo {
+            name: name.to_string(),
+            start_line,
+            end_line,
+            ..Default::default()
+        };
+        let summary = SemanticSummary {
+            symbols: vec![
+                symbol("parse", 1, 10),
+                symbol("validate", 12, 20),
+                symbol("render", 22, 30),
+            ],
+            ..Default::default()
+        };
+
+        // Tail of `parse` through the head of `validate`
+        let names: Vec<&str> = summary
+            .symbols_changed_in_range(8, 14)
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["parse", "validate"]);
+
+        // The blank line between symbols touches neither
+        assert!(summary.symbols_changed_in_range(11, 11).is_empty());
+    }
+
     #[test]
     fn test_symbol_kind_str() {
         assert_eq!(SymbolKind::Function.as_str(), "function");
@@ -1525,6 +2305,32 @@ mod tests {
         assert!(!SymbolId::is_two_part_hash("short"));
     }
 
+    #[test]
+    fn test_symbol_diff_visibility_change() {
+        use crate::lang::Lang;
+        use crate::parsing::parse_and_extract;
+        use std::path::Path;
+
+        let summarize = |source: &str| {
+            parse_and_extract(Path::new("src/store.rs"), source, Lang::Rust).unwrap()
+        };
+        let before = summarize("pub fn load(id: u32) -> u32 {\n    id\n}\n");
+        let after = summarize("fn load(id: u32) -> u32 {\n    id\n}\n");
+
+        let diffs = SymbolDiff::visibility_changes(&before, &after);
+        assert_eq!(diffs.len(), 1, "{:?}", diffs);
+        assert_eq!(diffs[0].name, "load");
+        assert_eq!(diffs[0].change, SymbolChangeKind::Modified);
+        assert_eq!(diffs[0].visibility_change, Some(VisibilityChange::Hidden));
+        assert!(!diffs[0].signature_changed());
+
+        let reverted = SymbolDiff::visibility_changes(&after, &before);
+        assert_eq!(
+            reverted[0].visibility_change,
+            Some(VisibilityChange::Exposed)
+        );
+    }
+
     #[test]
     fn test_semantic_diff_new_file() {
         let after = SemanticSummary {
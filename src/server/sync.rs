@@ -360,6 +360,7 @@ impl LayerSynchronizer {
                     is_async: symbol.is_async,
                     return_type: symbol.return_type.clone().unwrap_or_default(),
                     ext_package: String::new(),
+                    logic_free: false,
                     base_classes: symbol.base_classes.join(","),
                 };
 
@@ -4,29 +4,41 @@
 //! - repo_overview.toon - High-level architecture
 //! - modules/{name}.toon - Per-module semantic slices
 //! - symbols/{hash}.toon - Individual symbol details
+//! - strings.json - Repeated long values shared by symbol shards
 //! - graphs/*.toon - Dependency and call graphs
 
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::io::{BufWriter, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::Receiver;
 use std::sync::Arc;
 
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use crate::analysis::{calculate_cognitive_complexity, max_nesting_depth};
+use crate::analysis::{
+    calculate_cognitive_complexity, calculate_symbol_cognitive_complexity, max_nesting_depth,
+    structural_cognitive_increments,
+};
 use crate::bm25::{extract_terms_from_file_path, Bm25Document};
-use crate::cache::{CacheDir, IndexingStatus, SourceFileInfo};
+use crate::cache::{
+    CacheDir, IndexingStatus, SeenInitializers, SourceFileInfo, StringTable, SummaryLog,
+    SymbolIndexEntry,
+};
 use crate::duplicate::FunctionSignature;
 use crate::error::Result;
+use crate::indexing::AnalyzedFile;
 use crate::module_registry::ModuleRegistrySqlite;
 use crate::schema::{
-    CallGraphEdge, RefKind, RepoOverview, RiskLevel, SemanticSummary, SymbolId, SymbolInfo,
-    SymbolKind, SCHEMA_VERSION,
+    Call, CallGraphEdge, RefKind, RepoOverview, RiskLevel, SemanticSummary, StateChange, SymbolId,
+    SymbolInfo, SymbolKind, SCHEMA_VERSION,
+};
+use crate::toon::{
+    encode_toon, exclude_flagged_stats, generate_repo_overview_with_modules, is_meaningful_call,
+    overview_summary,
 };
-use crate::toon::{encode_toon, generate_repo_overview_with_modules, is_meaningful_call};
 use rusqlite::Connection;
 
 /// Package version from Cargo.toml
@@ -335,19 +347,29 @@ pub struct ShardWriter {
     /// Repository root path (for computing relative module paths)
     repo_root: String,
 
-    /// Summaries organized by FULL module path (before optimal stripping)
-    /// Keys are raw dotted paths like "src.game.player"
-    modules: HashMap<String, Vec<SemanticSummary>>,
+    /// Indices into `files` (and `all_summaries`, when written in one batch),
+    /// organized by FULL module path (before optimal stripping). Keys are raw
+    /// dotted paths like "src.game.player"
+    modules: HashMap<String, Vec<usize>>,
 
-    /// All summaries for graph building
+    /// Summaries added with `add_summaries`; streamed summaries are not kept
     all_summaries: Vec<SemanticSummary>,
 
+    /// What the overview, graph, index, and search stages read from each file
+    files: Vec<FileIndex>,
+
+    /// Where written summaries are recorded for the next incremental run
+    summary_log: Option<SummaryLog>,
+
     /// Repository overview
     overview: Option<RepoOverview>,
 
     /// Indexing progress
     progress: IndexingStatus,
 
+    /// Leave logic-free files out of the overview stats and complexity metrics
+    skip_logic_free: bool,
+
     /// Module name registry (computed at write time)
     module_registry: Option<ModuleRegistry>,
 }
@@ -370,8 +392,11 @@ impl ShardWriter {
             repo_root,
             modules: HashMap::new(),
             all_summaries: Vec::new(),
+            files: Vec::new(),
+            summary_log: None,
             overview: None,
             progress: IndexingStatus::default(),
+            skip_logic_free: false,
             module_registry: None,
         })
     }
@@ -386,24 +411,49 @@ impl ShardWriter {
             repo_root: String::new(), // Will use extract_module_name fallback
             modules: HashMap::new(),
             all_summaries: Vec::new(),
+            files: Vec::new(),
+            summary_log: None,
             overview: None,
             progress: IndexingStatus::default(),
+            skip_logic_free: false,
             module_registry: None,
         })
     }
 
+    /// Leave files without behavioral content out of repository metrics
+    ///
+    /// See [`SemanticSummary::is_empty_of_logic`]. Their symbols are still
+    /// indexed, flagged `logic_free` so complexity reports can skip them.
+    pub fn set_skip_logic_free(&mut self, skip: bool) {
+        self.skip_logic_free = skip;
+    }
+
+    /// Record each written summary in `summaries.jsonl`
+    ///
+    /// The log is finished when the shards are, replacing the previous one.
+    pub fn log_summaries(&mut self, log: SummaryLog) {
+        self.summary_log = Some(log);
+    }
+
     /// Add summaries to be sharded
     pub fn add_summaries(&mut self, summaries: Vec<SemanticSummary>) {
         // Organize by full module path (relative to repo root)
-        for summary in &summaries {
+        for summary in summaries {
             let module_name = self.compute_module_path(&summary.file);
             self.modules
                 .entry(module_name)
                 .or_insert_with(Vec::new)
-                .push(summary.clone());
+                .push(self.all_summaries.len());
+            self.all_summaries.push(summary);
         }
+    }
 
-        self.all_summaries.extend(summaries);
+    /// Summaries belonging to one module, in the order they were added
+    fn module_summaries<'a>(
+        &'a self,
+        indices: &'a [usize],
+    ) -> impl Iterator<Item = &'a SemanticSummary> + 'a {
+        indices.iter().map(|&idx| &self.all_summaries[idx])
     }
 
     /// Compute the full module path for a file (relative to repo root).
@@ -475,14 +525,14 @@ impl ShardWriter {
         let entries: Vec<(String, String, String)> = self
             .modules
             .iter()
-            .map(|(full_path, summaries)| {
+            .map(|(full_path, indices)| {
                 let short_name = registry
                     .get_short(full_path)
                     .cloned()
                     .unwrap_or_else(|| full_path.clone());
-                let file_path = summaries
+                let file_path = indices
                     .first()
-                    .map(|s| s.file.clone())
+                    .map(|&idx| self.files[idx].overview.file.clone())
                     .unwrap_or_default();
                 (full_path.clone(), short_name, file_path)
             })
@@ -513,10 +563,10 @@ impl ShardWriter {
     fn build_file_to_module_map(&self) -> HashMap<String, String> {
         let mut map = HashMap::new();
 
-        for (full_module_path, summaries) in &self.modules {
+        for (full_module_path, indices) in &self.modules {
             let optimal_name = self.get_optimal_module_name(full_module_path);
-            for summary in summaries {
-                map.insert(summary.file.clone(), optimal_name.clone());
+            for &idx in indices {
+                map.insert(self.files[idx].overview.file.clone(), optimal_name.clone());
             }
         }
 
@@ -535,17 +585,170 @@ impl ShardWriter {
         progress: Option<ShardProgressCallback>,
     ) -> Result<ShardStats> {
         let mut stats = ShardStats::default();
-        let mut progress_state = self.load_progress_state();
 
         // Compute optimal module names using conflict-aware stripping
         emit_progress(&progress, "Module registry", 0, 1);
         self.compute_module_registry();
+        emit_progress(&progress, "Module registry", 1, 1);
+
+        let skip_logic_free = self.skip_logic_free;
+        let module_names: Vec<String> = self
+            .all_summaries
+            .iter()
+            .map(|s| self.get_optimal_module_name(&self.compute_module_path(&s.file)))
+            .collect();
+        self.files = self
+            .all_summaries
+            .par_iter()
+            .zip(module_names.par_iter())
+            .map(|(summary, module_name)| FileIndex::new(summary, module_name, skip_logic_free))
+            .collect();
+
+        if let Some(mut log) = self.summary_log.take() {
+            log.append(&self.all_summaries)?;
+            log.finish()?;
+        }
+
+        self.write_stages(dir_path, &progress, &mut stats)?;
+        Ok(stats)
+    }
+
+    /// Write all shards from summaries streamed in by the analysis stage.
+    ///
+    /// `files` is the full list being analyzed (see
+    /// [`analyze_files_streaming`](crate::indexing::analyze_files_streaming)),
+    /// and each result refers to it by index. Module names are planned from
+    /// that list up front, so as soon as a module's last file arrives its
+    /// module and symbol shards are written, its summaries are logged (see
+    /// [`log_summaries`](Self::log_summaries)), and they are dropped. Only
+    /// the compact [`FileIndex`] the later stages read is kept, so peak
+    /// memory is bounded by the modules still waiting on files rather than
+    /// by the repository.
+    ///
+    /// The string table is built as modules arrive, so a long initializer's
+    /// first occurrence may be written inline where a batch write would
+    /// reference it. Files are put back in input order before the later
+    /// stages, so otherwise the result matches [`write_all`](Self::write_all)
+    /// over the same files, except that a module whose files all fail still
+    /// reserves its name.
+    pub fn write_all_streaming(
+        &mut self,
+        dir_path: &str,
+        files: &[PathBuf],
+        results: Receiver<AnalyzedFile>,
+    ) -> Result<ShardStats> {
+        let mut stats = ShardStats::default();
+        let mut progress_state = self.load_progress_state();
+        let mut streamed = StreamedFiles {
+            summary_log: self.summary_log.take(),
+            ..Default::default()
+        };
+
+        let file_modules: Vec<String> = files
+            .iter()
+            .map(|file| self.compute_module_path(&file.to_string_lossy()))
+            .collect();
+        let mut pending: HashMap<&str, usize> = HashMap::new();
+        for module in &file_modules {
+            *pending.entry(module.as_str()).or_insert(0) += 1;
+        }
+        let full_paths: Vec<String> = pending.keys().map(|m| m.to_string()).collect();
+        self.module_registry = Some(ModuleRegistry::from_full_paths(&full_paths));
+
+        let mut open: HashMap<&str, Vec<(usize, SemanticSummary)>> = HashMap::new();
+
+        for result in results {
+            let Some(module) = file_modules.get(result.index) else {
+                continue;
+            };
+            if let Some(summary) = result.summary {
+                open.entry(module.as_str())
+                    .or_default()
+                    .push((result.index, summary));
+            }
+            let remaining = pending.entry(module.as_str()).or_insert(1);
+            *remaining = remaining.saturating_sub(1);
+            if *remaining == 0 {
+                if let Some(summaries) = open.remove(module.as_str()) {
+                    self.flush_module(module, summaries, &mut streamed, &mut stats)?;
+                }
+            }
+        }
+
+        // The sender stopped early; write whatever arrived
+        for (module, summaries) in open {
+            self.flush_module(module, summaries, &mut streamed, &mut stats)?;
+        }
+        stats.files_written += stats.modules_written + stats.symbols_written;
+        stats.string_table_bytes = streamed.strings.save(&self.cache)?;
+        if let Some(log) = streamed.summary_log {
+            log.finish()?;
+        }
+        self.mark_stage_completed("module_shards", &mut progress_state)?;
+        self.mark_stage_completed("symbol_shards", &mut progress_state)?;
+
+        streamed.files.sort_by_key(|(idx, _)| *idx);
+        for (_, file) in streamed.files {
+            let module = self.compute_module_path(&file.overview.file);
+            self.modules
+                .entry(module)
+                .or_default()
+                .push(self.files.len());
+            self.files.push(file);
+        }
+
+        self.write_stages(dir_path, &None, &mut stats)?;
+        Ok(stats)
+    }
+
+    /// Write one streamed module's module and symbol shards, then keep only
+    /// its files' [`FileIndex`]
+    fn flush_module(
+        &self,
+        full_module_path: &str,
+        mut summaries: Vec<(usize, SemanticSummary)>,
+        streamed: &mut StreamedFiles,
+        stats: &mut ShardStats,
+    ) -> Result<()> {
+        summaries.sort_by_key(|(idx, _)| *idx);
+        let (indices, summaries): (Vec<usize>, Vec<SemanticSummary>) =
+            summaries.into_iter().unzip();
+        self.write_module_shard(full_module_path, &summaries, stats)?;
+
+        streamed
+            .strings
+            .intern_repeated_so_far(&mut streamed.seen, &summaries);
+        for summary in &summaries {
+            self.write_file_symbol_shards(summary, &streamed.strings, stats)?;
+        }
+
+        if let Some(log) = streamed.summary_log.as_mut() {
+            log.append(&summaries)?;
+        }
+
+        let module_name = self.get_optimal_module_name(full_module_path);
+        let indexed = summaries
+            .iter()
+            .map(|summary| FileIndex::new(summary, &module_name, self.skip_logic_free));
+        streamed.files.extend(indices.into_iter().zip(indexed));
+        Ok(())
+    }
+
+    /// Run every stage after the module registry is computed
+    ///
+    /// Stages recorded as completed in `progress.json` are skipped.
+    fn write_stages(
+        &mut self,
+        dir_path: &str,
+        progress: &Option<ShardProgressCallback>,
+        stats: &mut ShardStats,
+    ) -> Result<()> {
+        let mut progress_state = self.load_progress_state();
 
         // Persist registry to SQLite (Phase 2 - enables incremental indexing)
-        emit_progress(&progress, "Module registry", 1, 1);
-        emit_progress(&progress, "Persist registry", 0, 1);
+        emit_progress(progress, "Persist registry", 0, 1);
         self.persist_module_registry()?;
-        emit_progress(&progress, "Persist registry", 1, 1);
+        emit_progress(progress, "Persist registry", 1, 1);
 
         // Generate overview first (fast, gives agents something to work with)
         if !self.stage_completed(
@@ -553,25 +756,25 @@ impl ShardWriter {
             &[self.cache.repo_overview_path()],
             &progress_state,
         ) {
-            emit_progress(&progress, "Repo overview", 0, 1);
-            self.write_repo_overview(dir_path, &mut stats)?;
-            emit_progress(&progress, "Repo overview", 1, 1);
+            emit_progress(progress, "Repo overview", 0, 1);
+            self.write_repo_overview(dir_path, stats)?;
+            emit_progress(progress, "Repo overview", 1, 1);
             self.mark_stage_completed("repo_overview", &mut progress_state)?;
         }
 
         // Write module shards (using optimal names from registry)
         if !self.stage_completed("module_shards", &[], &progress_state) {
-            emit_progress(&progress, "Module shards", 0, 1);
-            self.write_module_shards(&mut stats)?;
-            emit_progress(&progress, "Module shards", 1, 1);
+            emit_progress(progress, "Module shards", 0, 1);
+            self.write_module_shards(stats)?;
+            emit_progress(progress, "Module shards", 1, 1);
             self.mark_stage_completed("module_shards", &mut progress_state)?;
         }
 
         // Write symbol shards
         if !self.stage_completed("symbol_shards", &[], &progress_state) {
-            emit_progress(&progress, "Symbol shards", 0, 1);
-            self.write_symbol_shards(&mut stats, &progress)?;
-            emit_progress(&progress, "Symbol shards", 1, 1);
+            emit_progress(progress, "Symbol shards", 0, 1);
+            self.write_symbol_shards(stats, progress)?;
+            emit_progress(progress, "Symbol shards", 1, 1);
             self.mark_stage_completed("symbol_shards", &mut progress_state)?;
         }
 
@@ -585,7 +788,7 @@ impl ShardWriter {
             ],
             &progress_state,
         ) {
-            self.write_graph_shards(&mut stats, &progress)?;
+            self.write_graph_shards(stats, progress)?;
             self.mark_stage_completed("graph_shards", &mut progress_state)?;
         }
 
@@ -595,9 +798,9 @@ impl ShardWriter {
             &[self.cache.symbol_index_path()],
             &progress_state,
         ) {
-            emit_progress(&progress, "Symbol index", 0, 1);
-            self.write_symbol_index(&mut stats)?;
-            emit_progress(&progress, "Symbol index", 1, 1);
+            emit_progress(progress, "Symbol index", 0, 1);
+            self.write_symbol_index(stats)?;
+            emit_progress(progress, "Symbol index", 1, 1);
             self.mark_stage_completed("symbol_index", &mut progress_state)?;
         }
 
@@ -607,9 +810,9 @@ impl ShardWriter {
             &[self.cache.signature_index_path()],
             &progress_state,
         ) {
-            emit_progress(&progress, "Signature index", 0, 1);
-            self.write_signature_index(&mut stats)?;
-            emit_progress(&progress, "Signature index", 1, 1);
+            emit_progress(progress, "Signature index", 0, 1);
+            self.write_signature_index(stats)?;
+            emit_progress(progress, "Signature index", 1, 1);
             self.mark_stage_completed("signature_index", &mut progress_state)?;
         }
 
@@ -619,13 +822,13 @@ impl ShardWriter {
             &[self.cache.bm25_index_path()],
             &progress_state,
         ) {
-            emit_progress(&progress, "BM25 index", 0, 1);
-            self.write_bm25_index(&mut stats, &progress)?;
-            emit_progress(&progress, "BM25 index", 1, 1);
+            emit_progress(progress, "BM25 index", 0, 1);
+            self.write_bm25_index(stats, progress)?;
+            emit_progress(progress, "BM25 index", 1, 1);
             self.mark_stage_completed("bm25_index", &mut progress_state)?;
         }
 
-        Ok(stats)
+        Ok(())
     }
 
     /// Write the repository overview
@@ -633,11 +836,15 @@ impl ShardWriter {
         // Build file-to-module mapping for consistent naming with module shards
         let file_to_module = self.build_file_to_module_map();
 
-        let overview = generate_repo_overview_with_modules(
-            &self.all_summaries,
-            dir_path,
-            Some(&file_to_module),
-        );
+        let files: Vec<SemanticSummary> = self.files.iter().map(|f| f.overview.clone()).collect();
+        let mut overview =
+            generate_repo_overview_with_modules(&files, dir_path, Some(&file_to_module));
+        if self.skip_logic_free {
+            exclude_flagged_stats(
+                &mut overview,
+                self.files.iter().map(|f| (&f.overview, f.logic_free)),
+            );
+        }
         self.overview = Some(overview.clone());
 
         // Create TOON output with metadata
@@ -713,21 +920,33 @@ impl ShardWriter {
     ///
     /// Uses the module registry to get optimal (shortened) names for shards.
     fn write_module_shards(&self, stats: &mut ShardStats) -> Result<()> {
-        for (full_module_path, summaries) in &self.modules {
-            // Get the optimal shortened name from the registry
-            let optimal_name = self.get_optimal_module_name(full_module_path);
+        for (full_module_path, indices) in &self.modules {
+            let summaries: Vec<SemanticSummary> = self.module_summaries(indices).cloned().collect();
+            self.write_module_shard(full_module_path, &summaries, stats)?;
+        }
 
-            let toon = encode_module_shard(&optimal_name, summaries, &self.cache.repo_root);
-            let path = self.cache.module_path(&optimal_name);
+        stats.files_written += stats.modules_written;
+        Ok(())
+    }
 
-            let mut file = fs::File::create(&path)?;
-            file.write_all(toon.as_bytes())?;
+    /// Write a single module shard under its optimal name
+    fn write_module_shard(
+        &self,
+        full_module_path: &str,
+        summaries: &[SemanticSummary],
+        stats: &mut ShardStats,
+    ) -> Result<()> {
+        // Get the optimal shortened name from the registry
+        let optimal_name = self.get_optimal_module_name(full_module_path);
 
-            stats.module_bytes += toon.len();
-            stats.modules_written += 1;
-        }
+        let toon = encode_module_shard(&optimal_name, summaries, &self.cache.repo_root);
+        let path = self.cache.module_path(&optimal_name);
 
-        stats.files_written += stats.modules_written;
+        let mut file = fs::File::create(&path)?;
+        file.write_all(toon.as_bytes())?;
+
+        stats.module_bytes += toon.len();
+        stats.modules_written += 1;
         Ok(())
     }
 
@@ -753,31 +972,43 @@ impl ShardWriter {
                 }
             })
             .sum();
-        let mut processed = 0usize;
+        let start = stats.symbols_written;
+
+        // Every indexed symbol's shard is rewritten below, so build the table
+        // from scratch rather than extending it; values from removed files drop out
+        let mut strings = StringTable::default();
+        strings.intern_repeated(&self.all_summaries);
+        stats.string_table_bytes = strings.save(&self.cache)?;
 
         for summary in &self.all_summaries {
-            let namespace = SymbolId::namespace_from_path(&summary.file);
-
-            // If we have symbols in the new multi-symbol format, use those
-            if !summary.symbols.is_empty() {
-                for symbol_info in &summary.symbols {
-                    let symbol_id = symbol_info.to_symbol_id(&namespace, &summary.file);
-                    let toon = encode_symbol_shard_from_info(summary, symbol_info, &symbol_id);
-                    let path = self.cache.symbol_path(&symbol_id.hash);
-
-                    let mut file = fs::File::create(&path)?;
-                    file.write_all(toon.as_bytes())?;
-
-                    stats.symbol_bytes += toon.len();
-                    stats.symbols_written += 1;
-                    processed += 1;
-                    if processed % 1000 == 0 || processed == total_symbols {
-                        emit_progress(progress, "Symbol shards", processed, total_symbols.max(1));
-                    }
-                }
-            } else if let Some(ref symbol_id) = summary.symbol_id {
-                // Fallback to old single-symbol format for backward compatibility
-                let toon = encode_symbol_shard(summary);
+            let before = stats.symbols_written - start;
+            self.write_file_symbol_shards(summary, &strings, stats)?;
+            let processed = stats.symbols_written - start;
+            if processed / 1000 > before / 1000
+                || (processed > before && processed == total_symbols)
+            {
+                emit_progress(progress, "Symbol shards", processed, total_symbols.max(1));
+            }
+        }
+
+        stats.files_written += stats.symbols_written;
+        Ok(())
+    }
+
+    /// Write the symbol shards for one file
+    fn write_file_symbol_shards(
+        &self,
+        summary: &SemanticSummary,
+        strings: &StringTable,
+        stats: &mut ShardStats,
+    ) -> Result<()> {
+        let namespace = SymbolId::namespace_from_path(&summary.file);
+
+        // If we have symbols in the new multi-symbol format, use those
+        if !summary.symbols.is_empty() {
+            for symbol_info in &summary.symbols {
+                let symbol_id = symbol_info.to_symbol_id(&namespace, &summary.file);
+                let toon = encode_symbol_shard_from_info(summary, symbol_info, &symbol_id, strings);
                 let path = self.cache.symbol_path(&symbol_id.hash);
 
                 let mut file = fs::File::create(&path)?;
@@ -785,14 +1016,18 @@ impl ShardWriter {
 
                 stats.symbol_bytes += toon.len();
                 stats.symbols_written += 1;
-                processed += 1;
-                if processed % 1000 == 0 || processed == total_symbols {
-                    emit_progress(progress, "Symbol shards", processed, total_symbols.max(1));
-                }
             }
-        }
+        } else if let Some(ref symbol_id) = summary.symbol_id {
+            // Fallback to old single-symbol format for backward compatibility
+            let toon = encode_symbol_shard(summary);
+            let path = self.cache.symbol_path(&symbol_id.hash);
 
-        stats.files_written += stats.symbols_written;
+            let mut file = fs::File::create(&path)?;
+            file.write_all(toon.as_bytes())?;
+
+            stats.symbol_bytes += toon.len();
+            stats.symbols_written += 1;
+        }
         Ok(())
     }
 
@@ -803,20 +1038,15 @@ impl ShardWriter {
         progress: &Option<ShardProgressCallback>,
     ) -> Result<()> {
         // Build and write call graph
-        emit_progress(progress, "Call graph", 0, self.all_summaries.len());
-        let call_graph = build_call_graph(&self.all_summaries, progress);
+        emit_progress(progress, "Call graph", 0, self.files.len());
+        let call_graph = build_call_graph(&self.files, progress);
         let graph_bytes = write_call_graph(&self.cache.call_graph_path(), &call_graph)?;
         stats.graph_bytes += graph_bytes;
-        emit_progress(
-            progress,
-            "Call graph",
-            self.all_summaries.len(),
-            self.all_summaries.len(),
-        );
+        emit_progress(progress, "Call graph", self.files.len(), self.files.len());
 
         // Build and write import graph
         emit_progress(progress, "Import graph", 0, 1);
-        let import_graph = build_import_graph(&self.all_summaries);
+        let import_graph = build_import_graph(&self.files);
         let import_graph_toon = encode_import_graph(&import_graph);
         fs::write(self.cache.import_graph_path(), &import_graph_toon)?;
         stats.graph_bytes += import_graph_toon.len();
@@ -827,7 +1057,7 @@ impl ShardWriter {
 
         // Build and write module graph
         emit_progress(progress, "Module graph", 0, 1);
-        let module_graph = build_module_graph(&self.modules, &file_to_module);
+        let module_graph = build_module_graph(&self.modules, &self.files, &file_to_module);
         let module_graph_toon = encode_module_graph(&module_graph);
         fs::write(self.cache.module_graph_path(), &module_graph_toon)?;
         stats.graph_bytes += module_graph_toon.len();
@@ -841,129 +1071,36 @@ impl ShardWriter {
     ///
     /// Now writes entries for ALL symbols in summary.symbols, not just the primary one.
     fn write_symbol_index(&self, stats: &mut ShardStats) -> Result<()> {
-        use crate::cache::SymbolIndexEntry;
-
         let path = self.cache.symbol_index_path();
         let mut file = fs::File::create(&path)?;
 
-        // Build file-to-module mapping for proper module names from registry
-        let file_to_module = self.build_file_to_module_map();
-
-        for summary in &self.all_summaries {
-            let namespace = SymbolId::namespace_from_path(&summary.file);
-            // Get the optimal module name from registry, fallback to extraction
-            let module_name = file_to_module
-                .get(&summary.file)
-                .cloned()
-                .unwrap_or_else(|| extract_module_name(&summary.file));
-
-            // If we have symbols in the new multi-symbol format, use those
-            if !summary.symbols.is_empty() {
-                for symbol_info in &summary.symbols {
-                    let symbol_id = symbol_info.to_symbol_id(&namespace, &summary.file);
-
-                    // Calculate cognitive complexity from control flow
-                    // If symbol has its own control_flow, use that
-                    // Otherwise, filter summary's control_flow_changes by symbol's line range
-                    let (cc, nest) = if !symbol_info.control_flow.is_empty() {
-                        (
-                            calculate_cognitive_complexity(&symbol_info.control_flow),
-                            max_nesting_depth(&symbol_info.control_flow),
-                        )
-                    } else {
-                        // Filter file-level control flow by symbol's line range
-                        let symbol_cf: Vec<_> = summary
-                            .control_flow_changes
-                            .iter()
-                            .filter(|cf| {
-                                cf.location.line >= symbol_info.start_line
-                                    && cf.location.line <= symbol_info.end_line
-                            })
-                            .cloned()
-                            .collect();
-                        (
-                            calculate_cognitive_complexity(&symbol_cf),
-                            max_nesting_depth(&symbol_cf),
-                        )
-                    };
-
-                    let entry = SymbolIndexEntry {
-                        symbol: symbol_info.name.clone(),
-                        hash: symbol_id.hash.clone(),
-                        semantic_hash: symbol_id.semantic_hash.clone(),
-                        kind: format!("{:?}", symbol_info.kind).to_lowercase(),
-                        module: module_name.clone(),
-                        file: summary.file.clone(),
-                        lines: format!("{}-{}", symbol_info.start_line, symbol_info.end_line),
-                        risk: format!("{:?}", symbol_info.behavioral_risk).to_lowercase(),
-                        cognitive_complexity: cc,
-                        max_nesting: nest,
-                        is_escape_local: symbol_info.is_escape_local,
-                        framework_entry_point: symbol_info.framework_entry_point,
-                        is_exported: symbol_info.is_exported,
-                        decorators: symbol_info.decorators.join(","),
-                        arity: symbol_info.arguments.len() + symbol_info.props.len(),
-                        is_async: symbol_info.is_async,
-                        return_type: symbol_info.return_type.clone().unwrap_or_default(),
-                        ext_package: String::new(),
-                        base_classes: symbol_info.base_classes.join(","),
-                    };
-
-                    // Write as JSONL (one JSON object per line)
-                    let json = serde_json::to_string(&entry).map_err(|e| {
-                        crate::McpDiffError::ExtractionFailure {
-                            message: format!("Failed to serialize symbol index entry: {}", e),
-                        }
-                    })?;
-                    writeln!(file, "{}", json)?;
+        let mut entries: Vec<&SymbolIndexEntry> =
+            self.files.iter().flat_map(|f| &f.symbols).collect();
+
+        // Summaries arrive in parallel-processing order; sorting makes the
+        // file byte-identical across runs over the same input
+        let order_key = |e: &&SymbolIndexEntry| {
+            let lines = line_range_key(&e.lines);
+            (
+                e.module.clone(),
+                e.file.clone(),
+                lines,
+                e.symbol.clone(),
+                e.hash.clone(),
+            )
+        };
+        entries.sort_by_cached_key(order_key);
 
-                    stats.index_entries += 1;
+        for entry in &entries {
+            // Write as JSONL (one JSON object per line)
+            let json = serde_json::to_string(entry).map_err(|e| {
+                crate::McpDiffError::ExtractionFailure {
+                    message: format!("Failed to serialize symbol index entry: {}", e),
                 }
-            } else if let Some(ref symbol_id) = summary.symbol_id {
-                // Fallback to old single-symbol format - use summary's control flow
-                let cc = calculate_cognitive_complexity(&summary.control_flow_changes);
-                let nest = max_nesting_depth(&summary.control_flow_changes);
-
-                let entry = SymbolIndexEntry {
-                    symbol: summary.symbol.clone().unwrap_or_default(),
-                    hash: symbol_id.hash.clone(),
-                    semantic_hash: symbol_id.semantic_hash.clone(),
-                    kind: summary
-                        .symbol_kind
-                        .map(|k| format!("{:?}", k).to_lowercase())
-                        .unwrap_or_else(|| "unknown".to_string()),
-                    module: module_name,
-                    file: summary.file.clone(),
-                    lines: match (summary.start_line, summary.end_line) {
-                        (Some(s), Some(e)) => format!("{}-{}", s, e),
-                        (Some(s), None) => format!("{}", s),
-                        _ => String::new(),
-                    },
-                    risk: format!("{:?}", summary.behavioral_risk).to_lowercase(),
-                    cognitive_complexity: cc,
-                    max_nesting: nest,
-                    is_escape_local: false,
-                    framework_entry_point: summary.framework_entry_point,
-                    is_exported: false,
-                    decorators: String::new(),
-                    arity: summary.arguments.len() + summary.props.len(),
-                    is_async: false,
-                    return_type: summary.return_type.clone().unwrap_or_default(),
-                    ext_package: String::new(),
-                    base_classes: String::new(),
-                };
-
-                // Write as JSONL (one JSON object per line)
-                let json = serde_json::to_string(&entry).map_err(|e| {
-                    crate::McpDiffError::ExtractionFailure {
-                        message: format!("Failed to serialize symbol index entry: {}", e),
-                    }
-                })?;
-                writeln!(file, "{}", json)?;
-
-                stats.index_entries += 1;
-            }
+            })?;
+            writeln!(file, "{}", json)?;
         }
+        stats.index_entries += entries.len();
 
         stats.index_bytes = fs::metadata(&path)?.len() as usize;
         stats.files_written += 1;
@@ -978,91 +1115,16 @@ impl ShardWriter {
         let path = self.cache.signature_index_path();
         let mut file = fs::File::create(&path)?;
 
-        // Build file-to-module mapping for proper module names from registry
-        let file_to_module = self.build_file_to_module_map();
-
-        for summary in &self.all_summaries {
-            let namespace = SymbolId::namespace_from_path(&summary.file);
-            // Get the optimal module name from registry, fallback to extraction
-            let module_name = file_to_module
-                .get(&summary.file)
-                .cloned()
-                .unwrap_or_else(|| extract_module_name(&summary.file));
-
-            // If we have symbols in the new multi-symbol format, use those
-            if !summary.symbols.is_empty() {
-                for symbol_info in &summary.symbols {
-                    // Skip non-function symbols (classes, interfaces, etc. don't get signatures)
-                    if !matches!(
-                        symbol_info.kind,
-                        SymbolKind::Function | SymbolKind::Method | SymbolKind::Component
-                    ) {
-                        continue;
-                    }
-
-                    let symbol_id = symbol_info.to_symbol_id(&namespace, &summary.file);
-                    let signature = FunctionSignature::from_symbol_info(
-                        symbol_info,
-                        &symbol_id.hash,
-                        &summary.file,
-                        &module_name,
-                        None, // Use default boilerplate config
-                    );
-
-                    // Write as JSONL (one JSON object per line)
-                    let json = serde_json::to_string(&signature).map_err(|e| {
-                        crate::McpDiffError::ExtractionFailure {
-                            message: format!("Failed to serialize signature: {}", e),
-                        }
-                    })?;
-                    writeln!(file, "{}", json)?;
-
-                    stats.signature_entries += 1;
+        for signature in self.files.iter().flat_map(|f| &f.signatures) {
+            // Write as JSONL (one JSON object per line)
+            let json = serde_json::to_string(signature).map_err(|e| {
+                crate::McpDiffError::ExtractionFailure {
+                    message: format!("Failed to serialize signature: {}", e),
                 }
-            } else if let Some(ref symbol_id) = summary.symbol_id {
-                // Fallback to old single-symbol format
-                // Create a minimal SymbolInfo from the summary
-                if let Some(ref name) = summary.symbol {
-                    let symbol_info = SymbolInfo {
-                        name: name.clone(),
-                        kind: summary.symbol_kind.unwrap_or_default(),
-                        start_line: summary.start_line.unwrap_or(1),
-                        end_line: summary.end_line.unwrap_or(1),
-                        is_exported: true,
-                        is_default_export: false,
-                        hash: Some(symbol_id.hash.clone()),
-                        arguments: summary.arguments.clone(),
-                        props: summary.props.clone(),
-                        return_type: summary.return_type.clone(),
-                        calls: summary.calls.clone(),
-                        control_flow: summary.control_flow_changes.clone(),
-                        state_changes: summary.state_changes.clone(),
-                        behavioral_risk: summary.behavioral_risk,
-                        decorators: Vec::new(),
-                        is_escape_local: false,
-                        framework_entry_point: summary.framework_entry_point,
-                        is_async: false,
-                        base_classes: Vec::new(),
-                    };
-
-                    let signature = FunctionSignature::from_symbol_info(
-                        &symbol_info,
-                        &symbol_id.hash,
-                        &summary.file,
-                        &module_name,
-                        None,
-                    );
-
-                    let json = serde_json::to_string(&signature).map_err(|e| {
-                        crate::McpDiffError::ExtractionFailure {
-                            message: format!("Failed to serialize signature: {}", e),
-                        }
-                    })?;
-                    writeln!(file, "{}", json)?;
+            })?;
+            writeln!(file, "{}", json)?;
 
-                    stats.signature_entries += 1;
-                }
-            }
+            stats.signature_entries += 1;
         }
 
         stats.signature_bytes = fs::metadata(&path).map(|m| m.len() as usize).unwrap_or(0);
@@ -1081,110 +1143,10 @@ impl ShardWriter {
     ) -> Result<()> {
         let entries = AtomicUsize::new(0);
         let total_length = AtomicUsize::new(0);
-        let total_docs: usize = self
-            .all_summaries
-            .iter()
-            .map(|summary| {
-                if !summary.symbols.is_empty() {
-                    summary.symbols.len()
-                } else if summary.symbol_id.is_some() {
-                    1
-                } else {
-                    0
-                }
-            })
-            .sum();
-
-        // Build file-to-module mapping for proper module names from registry
-        let file_to_module = self.build_file_to_module_map();
+        let total_docs: usize = self.files.iter().map(|f| f.bm25_docs.len()).sum();
 
         emit_progress(progress, "BM25 index", 0, total_docs.max(1));
 
-        let docs_by_summary: Vec<Vec<(Bm25Document, Vec<String>)>> = self
-            .all_summaries
-            .par_iter()
-            .map(|summary| {
-                let namespace = SymbolId::namespace_from_path(&summary.file);
-                // Get the optimal module name from registry, fallback to extraction
-                let module_name = file_to_module
-                    .get(&summary.file)
-                    .cloned()
-                    .unwrap_or_else(|| extract_module_name(&summary.file));
-
-                let file_terms = extract_terms_from_file_path(&summary.file);
-                let module_terms = crate::bm25::tokenize(&module_name);
-
-                let mut docs: Vec<(Bm25Document, Vec<String>)> = Vec::new();
-
-                // If we have symbols in the new multi-symbol format, use those
-                if !summary.symbols.is_empty() {
-                    for symbol_info in &summary.symbols {
-                        let symbol_id = symbol_info.to_symbol_id(&namespace, &summary.file);
-                        let kind_str = format!("{:?}", symbol_info.kind).to_lowercase();
-
-                        // Extract searchable terms from this symbol
-                        let mut terms = Vec::new();
-                        terms.extend(crate::bm25::tokenize(&symbol_info.name));
-                        terms.extend(file_terms.iter().cloned());
-                        terms.push(kind_str.clone());
-                        terms.extend(module_terms.iter().cloned());
-                        let mut seen = std::collections::HashSet::new();
-                        terms.retain(|t| seen.insert(t.clone()));
-
-                        let doc = Bm25Document {
-                            hash: symbol_id.hash,
-                            symbol: symbol_info.name.clone(),
-                            file: summary.file.clone(),
-                            lines: format!("{}-{}", symbol_info.start_line, symbol_info.end_line),
-                            kind: kind_str,
-                            module: module_name.clone(),
-                            risk: format!("{:?}", symbol_info.behavioral_risk).to_lowercase(),
-                            doc_length: 0, // Will be set by add_document
-                        };
-
-                        docs.push((doc, terms));
-                    }
-                } else if let Some(ref symbol_id) = summary.symbol_id {
-                    // Fallback to old single-symbol format
-                    let kind_str = summary
-                        .symbol_kind
-                        .map(|k| format!("{:?}", k).to_lowercase())
-                        .unwrap_or_else(|| "unknown".to_string());
-
-                    let mut terms = Vec::new();
-                    terms.extend(crate::bm25::tokenize(
-                        summary.symbol.as_deref().unwrap_or(""),
-                    ));
-                    terms.extend(file_terms.iter().cloned());
-                    terms.push(kind_str.clone());
-                    terms.extend(module_terms.iter().cloned());
-                    let mut seen = std::collections::HashSet::new();
-                    terms.retain(|t| seen.insert(t.clone()));
-
-                    let doc = Bm25Document {
-                        hash: symbol_id.hash.clone(),
-                        symbol: summary.symbol.clone().unwrap_or_default(),
-                        file: summary.file.clone(),
-                        lines: match (summary.start_line, summary.end_line) {
-                            (Some(s), Some(e)) => format!("{}-{}", s, e),
-                            (Some(s), None) => format!("{}", s),
-                            _ => String::new(),
-                        },
-                        kind: kind_str,
-                        module: module_name,
-                        risk: format!("{:?}", summary.behavioral_risk).to_lowercase(),
-                        doc_length: 0,
-                    };
-
-                    docs.push((doc, terms));
-                }
-
-                docs
-            })
-            .collect();
-        let docs: Vec<(Bm25Document, Vec<String>)> =
-            docs_by_summary.into_iter().flatten().collect();
-
         let db_path = self.cache.bm25_index_path();
         let mut conn =
             Connection::open(&db_path).map_err(|e| crate::McpDiffError::ExtractionFailure {
@@ -1219,9 +1181,9 @@ impl ShardWriter {
                     message: format!("Failed to prepare BM25 term insert: {}", e),
                 })?;
 
-            for (doc, terms) in docs {
+            for (doc, terms) in self.files.iter().flat_map(|f| &f.bm25_docs) {
                 let doc_length = terms.len() as u32;
-                let doc_id = doc.hash.clone();
+                let doc_id = &doc.hash;
                 let inserted = insert_doc
                     .execute(rusqlite::params![
                         doc_id,
@@ -1272,6 +1234,11 @@ impl ShardWriter {
                 message: format!("Failed to write BM25 meta: {}", e),
             }
         })?;
+        crate::bm25::write_bm25_term_filter(&conn).map_err(|e| {
+            crate::McpDiffError::ExtractionFailure {
+                message: format!("Failed to write BM25 term filter: {}", e),
+            }
+        })?;
 
         let path = self.cache.bm25_index_path();
         stats.bm25_bytes = fs::metadata(&path).map(|m| m.len() as usize).unwrap_or(0);
@@ -1290,6 +1257,62 @@ impl ShardWriter {
     }
 }
 
+/// What the stages after the module and symbol shards read from one file
+///
+/// Built once per file, so [`ShardWriter::write_all_streaming`] can drop a
+/// module's summaries as soon as its shards are written.
+struct FileIndex {
+    /// The fields the repository overview reads (see `overview_summary`)
+    overview: SemanticSummary,
+
+    /// Whether the file has no behavioral content (see
+    /// [`SemanticSummary::is_empty_of_logic`])
+    logic_free: bool,
+
+    /// References for the call graph, before resolution
+    calls: CallRefs,
+
+    /// Entries for `symbol_index.jsonl`
+    symbols: Vec<SymbolIndexEntry>,
+
+    /// Entries for the function signature index
+    signatures: Vec<FunctionSignature>,
+
+    /// BM25 documents and their search terms
+    bm25_docs: Vec<(Bm25Document, Vec<String>)>,
+}
+
+impl FileIndex {
+    /// Index `summary`, which belongs to the module named `module_name`
+    fn new(summary: &SemanticSummary, module_name: &str, skip_logic_free: bool) -> Self {
+        let logic_free = summary.is_empty_of_logic();
+        Self {
+            overview: overview_summary(summary),
+            logic_free,
+            calls: CallRefs::new(summary),
+            symbols: symbol_index_entries(summary, module_name, skip_logic_free && logic_free),
+            signatures: function_signatures(summary, module_name),
+            bm25_docs: bm25_documents(summary, module_name),
+        }
+    }
+}
+
+/// State carried across modules by [`ShardWriter::write_all_streaming`]
+#[derive(Default)]
+struct StreamedFiles {
+    /// String table extended as each module's symbol shards are written
+    strings: StringTable,
+
+    /// Long initializers seen once so far, for extending `strings`
+    seen: SeenInitializers,
+
+    /// Where each module's summaries are recorded once written
+    summary_log: Option<SummaryLog>,
+
+    /// Each written file's index, by its position in the input
+    files: Vec<(usize, FileIndex)>,
+}
+
 /// Statistics about the sharding operation
 #[derive(Debug, Default)]
 pub struct ShardStats {
@@ -1331,6 +1354,9 @@ pub struct ShardStats {
 
     /// Bytes written for BM25 index
     pub bm25_bytes: usize,
+
+    /// Bytes written for the shared string table
+    pub string_table_bytes: usize,
 }
 
 impl ShardStats {
@@ -1343,6 +1369,7 @@ impl ShardStats {
             + self.index_bytes
             + self.signature_bytes
             + self.bm25_bytes
+            + self.string_table_bytes
     }
 }
 
@@ -1537,6 +1564,279 @@ pub(crate) fn encode_module_shard(
     lines.join("\n")
 }
 
+/// Cognitive complexity and maximum nesting depth of one symbol
+///
+/// Uses the symbol's own control flow. Only when the extractor attributed
+/// none of the file's control flow to symbols is the file-level list filtered
+/// by the symbol's line range instead: once any symbol carries its own flow,
+/// a symbol without any really has none, and the line range of a class or
+/// impl would otherwise hand it every method's branches.
+fn symbol_complexity(summary: &SemanticSummary, symbol_info: &SymbolInfo) -> (usize, usize) {
+    let attributed = summary.symbols.iter().any(|s| !s.control_flow.is_empty());
+    if attributed {
+        return (
+            calculate_symbol_cognitive_complexity(symbol_info),
+            max_nesting_depth(&symbol_info.control_flow),
+        );
+    }
+
+    let symbol_cf: Vec<_> = summary
+        .control_flow_changes
+        .iter()
+        .filter(|cf| {
+            cf.location.line >= symbol_info.start_line && cf.location.line <= symbol_info.end_line
+        })
+        .cloned()
+        .collect();
+    (
+        calculate_cognitive_complexity(&symbol_cf) + structural_cognitive_increments(symbol_info),
+        max_nesting_depth(&symbol_cf),
+    )
+}
+
+/// Symbol index entries for one file's symbols
+///
+/// `logic_free` marks every entry; see [`ShardWriter::set_skip_logic_free`].
+fn symbol_index_entries(
+    summary: &SemanticSummary,
+    module_name: &str,
+    logic_free: bool,
+) -> Vec<SymbolIndexEntry> {
+    let namespace = SymbolId::namespace_from_path(&summary.file);
+    let mut entries = Vec::new();
+
+    // If we have symbols in the new multi-symbol format, use those
+    if !summary.symbols.is_empty() {
+        for symbol_info in &summary.symbols {
+            let symbol_id = symbol_info.to_symbol_id(&namespace, &summary.file);
+
+            let (cc, nest) = symbol_complexity(summary, symbol_info);
+
+            let entry = SymbolIndexEntry {
+                symbol: symbol_info.name.clone(),
+                hash: symbol_id.hash.clone(),
+                semantic_hash: symbol_id.semantic_hash.clone(),
+                kind: format!("{:?}", symbol_info.kind).to_lowercase(),
+                module: module_name.to_string(),
+                file: summary.file.clone(),
+                lines: format!("{}-{}", symbol_info.start_line, symbol_info.end_line),
+                risk: format!("{:?}", symbol_info.behavioral_risk).to_lowercase(),
+                cognitive_complexity: cc,
+                max_nesting: nest,
+                is_escape_local: symbol_info.is_escape_local,
+                framework_entry_point: symbol_info.framework_entry_point,
+                is_exported: symbol_info.is_exported,
+                decorators: symbol_info.decorators.join(","),
+                arity: symbol_info.arguments.len() + symbol_info.props.len(),
+                is_async: symbol_info.is_async,
+                return_type: symbol_info.return_type.clone().unwrap_or_default(),
+                ext_package: String::new(),
+                logic_free,
+                base_classes: symbol_info.base_classes.join(","),
+            };
+
+            entries.push(entry);
+        }
+    } else if let Some(ref symbol_id) = summary.symbol_id {
+        // Fallback to old single-symbol format - use summary's control flow
+        let cc = calculate_cognitive_complexity(&summary.control_flow_changes);
+        let nest = max_nesting_depth(&summary.control_flow_changes);
+
+        let entry = SymbolIndexEntry {
+            symbol: summary.symbol.clone().unwrap_or_default(),
+            hash: symbol_id.hash.clone(),
+            semantic_hash: symbol_id.semantic_hash.clone(),
+            kind: summary
+                .symbol_kind
+                .map(|k| format!("{:?}", k).to_lowercase())
+                .unwrap_or_else(|| "unknown".to_string()),
+            module: module_name.to_string(),
+            file: summary.file.clone(),
+            lines: match (summary.start_line, summary.end_line) {
+                (Some(s), Some(e)) => format!("{}-{}", s, e),
+                (Some(s), None) => format!("{}", s),
+                _ => String::new(),
+            },
+            risk: format!("{:?}", summary.behavioral_risk).to_lowercase(),
+            cognitive_complexity: cc,
+            max_nesting: nest,
+            is_escape_local: false,
+            framework_entry_point: summary.framework_entry_point,
+            is_exported: false,
+            decorators: String::new(),
+            arity: summary.arguments.len() + summary.props.len(),
+            is_async: false,
+            return_type: summary.return_type.clone().unwrap_or_default(),
+            ext_package: String::new(),
+            logic_free,
+            base_classes: String::new(),
+        };
+
+        entries.push(entry);
+    }
+
+    entries
+}
+
+/// Duplicate-detection signatures for one file's functions, methods, and
+/// components
+fn function_signatures(summary: &SemanticSummary, module_name: &str) -> Vec<FunctionSignature> {
+    let namespace = SymbolId::namespace_from_path(&summary.file);
+    let mut signatures = Vec::new();
+
+    // If we have symbols in the new multi-symbol format, use those
+    if !summary.symbols.is_empty() {
+        for symbol_info in &summary.symbols {
+            // Skip non-function symbols (classes, interfaces, etc. don't get signatures)
+            if !matches!(
+                symbol_info.kind,
+                SymbolKind::Function | SymbolKind::Method | SymbolKind::Component
+            ) {
+                continue;
+            }
+
+            let symbol_id = symbol_info.to_symbol_id(&namespace, &summary.file);
+            let signature = FunctionSignature::from_symbol_info(
+                symbol_info,
+                &symbol_id.hash,
+                &summary.file,
+                module_name,
+                None, // Use default boilerplate config
+            );
+
+            signatures.push(signature);
+        }
+    } else if let Some(ref symbol_id) = summary.symbol_id {
+        // Fallback to old single-symbol format
+        // Create a minimal SymbolInfo from the summary
+        if let Some(ref name) = summary.symbol {
+            let symbol_info = SymbolInfo {
+                name: name.clone(),
+                kind: summary.symbol_kind.unwrap_or_default(),
+                start_line: summary.start_line.unwrap_or(1),
+                end_line: summary.end_line.unwrap_or(1),
+                is_exported: true,
+                is_default_export: false,
+                hash: Some(symbol_id.hash.clone()),
+                arguments: summary.arguments.clone(),
+                props: summary.props.clone(),
+                return_type: summary.return_type.clone(),
+                type_params: Vec::new(),
+                calls: summary.calls.clone(),
+                control_flow: summary.control_flow_changes.clone(),
+                state_changes: summary.state_changes.clone(),
+                behavioral_risk: summary.behavioral_risk,
+                decorators: Vec::new(),
+                is_escape_local: false,
+                framework_entry_point: summary.framework_entry_point,
+                is_async: false,
+                base_classes: Vec::new(),
+                is_recursive: false,
+                nested_functions: 0,
+                assertion_count: None,
+                overloaded: false,
+                is_stub: false,
+                injected_deps: Vec::new(),
+                naming_violation: None,
+                feature_envy: None,
+                patterns: Vec::new(),
+                is_stub_impl: false,
+                returns_description: None,
+            };
+
+            let signature = FunctionSignature::from_symbol_info(
+                &symbol_info,
+                &symbol_id.hash,
+                &summary.file,
+                module_name,
+                None,
+            );
+
+            signatures.push(signature);
+        }
+    }
+
+    signatures
+}
+
+/// BM25 documents, with their search terms, for one file's symbols
+fn bm25_documents(
+    summary: &SemanticSummary,
+    module_name: &str,
+) -> Vec<(Bm25Document, Vec<String>)> {
+    let namespace = SymbolId::namespace_from_path(&summary.file);
+
+    let file_terms = extract_terms_from_file_path(&summary.file);
+    let module_terms = crate::bm25::tokenize(module_name);
+
+    let mut docs: Vec<(Bm25Document, Vec<String>)> = Vec::new();
+
+    // If we have symbols in the new multi-symbol format, use those
+    if !summary.symbols.is_empty() {
+        for symbol_info in &summary.symbols {
+            let symbol_id = symbol_info.to_symbol_id(&namespace, &summary.file);
+            let kind_str = format!("{:?}", symbol_info.kind).to_lowercase();
+
+            // Extract searchable terms from this symbol
+            let mut terms = Vec::new();
+            terms.extend(crate::bm25::tokenize(&symbol_info.name));
+            terms.extend(file_terms.iter().cloned());
+            terms.push(kind_str.clone());
+            terms.extend(module_terms.iter().cloned());
+            let mut seen = std::collections::HashSet::new();
+            terms.retain(|t| seen.insert(t.clone()));
+
+            let doc = Bm25Document {
+                hash: symbol_id.hash,
+                symbol: symbol_info.name.clone(),
+                file: summary.file.clone(),
+                lines: format!("{}-{}", symbol_info.start_line, symbol_info.end_line),
+                kind: kind_str,
+                module: module_name.to_string(),
+                risk: format!("{:?}", symbol_info.behavioral_risk).to_lowercase(),
+                doc_length: 0, // Will be set by add_document
+            };
+
+            docs.push((doc, terms));
+        }
+    } else if let Some(ref symbol_id) = summary.symbol_id {
+        // Fallback to old single-symbol format
+        let kind_str = summary
+            .symbol_kind
+            .map(|k| format!("{:?}", k).to_lowercase())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        let mut terms = Vec::new();
+        terms.extend(crate::bm25::tokenize(
+            summary.symbol.as_deref().unwrap_or(""),
+        ));
+        terms.extend(file_terms.iter().cloned());
+        terms.push(kind_str.clone());
+        terms.extend(module_terms.iter().cloned());
+        let mut seen = std::collections::HashSet::new();
+        terms.retain(|t| seen.insert(t.clone()));
+
+        let doc = Bm25Document {
+            hash: symbol_id.hash.clone(),
+            symbol: summary.symbol.clone().unwrap_or_default(),
+            file: summary.file.clone(),
+            lines: match (summary.start_line, summary.end_line) {
+                (Some(s), Some(e)) => format!("{}-{}", s, e),
+                (Some(s), None) => format!("{}", s),
+                _ => String::new(),
+            },
+            kind: kind_str,
+            module: module_name.to_string(),
+            risk: format!("{:?}", summary.behavioral_risk).to_lowercase(),
+            doc_length: 0,
+        };
+
+        docs.push((doc, terms));
+    }
+
+    docs
+}
+
 /// Encode a single symbol shard (legacy format)
 pub(crate) fn encode_symbol_shard(summary: &SemanticSummary) -> String {
     let mut lines = Vec::new();
@@ -1554,10 +1854,12 @@ pub(crate) fn encode_symbol_shard(summary: &SemanticSummary) -> String {
 ///
 /// This creates a complete symbol shard from a SymbolInfo struct,
 /// combining file-level metadata from the summary with symbol-specific data.
+/// State-change initializers found in `strings` are written as references.
 pub(crate) fn encode_symbol_shard_from_info(
     summary: &SemanticSummary,
     symbol_info: &SymbolInfo,
     symbol_id: &SymbolId,
+    strings: &StringTable,
 ) -> String {
     let mut lines = Vec::new();
 
@@ -1630,6 +1932,27 @@ pub(crate) fn encode_symbol_shard_from_info(
         lines.push(format!("control_flow[{}]: {}", cf.len(), cf.join(",")));
     }
 
+    // State changes (long repeated initializers live in the string table)
+    if !symbol_info.state_changes.is_empty() {
+        lines.push(format!(
+            "state_changes[{}]{{name,type,init}}:",
+            symbol_info.state_changes.len()
+        ));
+        for state in &symbol_info.state_changes {
+            let state_type = if state.state_type.is_empty() {
+                "_"
+            } else {
+                &state.state_type
+            };
+            lines.push(format!(
+                "  {},{},{}",
+                state.name,
+                state_type,
+                strings.encode(&state.initializer)
+            ));
+        }
+    }
+
     // Calls - use symbol-level calls (symbol_info.calls) which are correctly attributed
     // during extraction via find_containing_symbol_by_line
     if !symbol_info.calls.is_empty() {
@@ -1694,27 +2017,134 @@ pub(crate) fn encode_symbol_shard_from_info(
     lines.join("\n")
 }
 
-/// Build a lookup map from symbol name to their SymbolIds
-/// Returns: name -> Vec<(hash, namespace)> for disambiguation
-fn build_symbol_lookup(summaries: &[SemanticSummary]) -> HashMap<String, Vec<(String, String)>> {
-    let mut lookup: HashMap<String, Vec<(String, String)>> = HashMap::new();
+/// The parts of one file the call graph reads, before names are resolved
+struct CallRefs {
+    /// `"{file_hash}:"`, for preferring same-file matches during resolution
+    same_file_prefix: String,
+
+    /// Imported name -> package, for labelling external calls
+    import_sources: HashMap<String, String>,
+
+    /// Symbols the file defines, as (name, hash, namespace)
+    definitions: Vec<(String, String, String)>,
+
+    /// Each calling symbol's hash and the names it references, in source order
+    callers: Vec<(String, Vec<(String, RefKind)>)>,
+}
+
+impl CallRefs {
+    fn new(summary: &SemanticSummary) -> Self {
+        use crate::overlay::compute_symbol_hash;
+
+        // Compute file hash once for this file (used to prefer same-file call resolution)
+        let caller_file_hash = crate::overlay::extract_file_hash(
+            summary
+                .symbol_id
+                .as_ref()
+                .map(|s| s.hash.as_str())
+                .unwrap_or(""),
+        )
+        .to_string();
+        // Fallback: compute from file path if no symbol_id
+        let caller_file_hash = if caller_file_hash.is_empty() {
+            format!("{:08x}", crate::schema::fnv1a_hash(&summary.file) as u32)
+        } else {
+            caller_file_hash
+        };
+        let same_file_prefix = if caller_file_hash.is_empty() {
+            String::new()
+        } else {
+            format!("{}:", caller_file_hash)
+        };
+
+        let mut definitions = Vec::new();
+        let mut callers = Vec::new();
 
-    for summary in summaries {
         if let Some(ref symbol_id) = summary.symbol_id {
-            lookup
-                .entry(symbol_id.symbol.clone())
-                .or_default()
-                .push((symbol_id.hash.clone(), symbol_id.namespace.clone()));
+            definitions.push((
+                symbol_id.symbol.clone(),
+                symbol_id.hash.clone(),
+                symbol_id.namespace.clone(),
+            ));
         }
 
-        // Also index symbols from the symbols array
+        // Each symbol's own calls, then calls in its state_changes initializers
+        let namespace = SymbolId::namespace_from_path(&summary.file);
         for symbol in &summary.symbols {
-            let hash = crate::overlay::compute_symbol_hash(symbol, &summary.file);
-            let namespace = SymbolId::namespace_from_path(&summary.file);
+            let hash = compute_symbol_hash(symbol, &summary.file);
+            definitions.push((symbol.name.clone(), hash.clone(), namespace.clone()));
+
+            let mut refs = call_refs(&symbol.calls);
+            refs.extend(initializer_refs(&symbol.state_changes));
+            if !refs.is_empty() {
+                callers.push((hash, refs));
+            }
+        }
+
+        // Also file-level calls
+        if let Some(ref symbol_id) = summary.symbol_id {
+            let mut refs = call_refs(&summary.calls);
+            refs.extend(initializer_refs(&summary.state_changes));
+            // Include both functions (lowercase) and components (PascalCase) from dependencies
+            // Only exclude Rust-style namespace paths (::) - these are always call kind
+            refs.extend(
+                summary
+                    .added_dependencies
+                    .iter()
+                    .filter(|dep| !dep.contains("::"))
+                    .map(|dep| (dep.clone(), RefKind::None)),
+            );
+            if !refs.is_empty() {
+                callers.push((symbol_id.hash.clone(), refs));
+            }
+        }
+
+        Self {
+            same_file_prefix,
+            import_sources: summary.import_sources.clone(),
+            definitions,
+            callers,
+        }
+    }
+}
+
+/// Names referenced by `calls`, qualified with their object when there is one
+fn call_refs(calls: &[Call]) -> Vec<(String, RefKind)> {
+    calls
+        .iter()
+        .map(|c| match c.object {
+            Some(ref obj) => {
+                let mut call_name = String::with_capacity(obj.len() + 1 + c.name.len());
+                call_name.push_str(obj);
+                call_name.push('.');
+                call_name.push_str(&c.name);
+                (call_name, c.ref_kind)
+            }
+            None => (c.name.clone(), c.ref_kind),
+        })
+        .collect()
+}
+
+/// Functions called from state_changes initializers (always call kind)
+fn initializer_refs(states: &[StateChange]) -> impl Iterator<Item = (String, RefKind)> + '_ {
+    states
+        .iter()
+        .filter(|state| !state.initializer.is_empty())
+        .filter_map(|state| extract_call_from_initializer(&state.initializer))
+        .map(|call_name| (call_name, RefKind::None))
+}
+
+/// Build a lookup map from symbol name to their SymbolIds
+/// Returns: name -> Vec<(hash, namespace)> for disambiguation
+fn build_symbol_lookup(files: &[FileIndex]) -> HashMap<String, Vec<(String, String)>> {
+    let mut lookup: HashMap<String, Vec<(String, String)>> = HashMap::new();
+
+    for file in files {
+        for (name, hash, namespace) in &file.calls.definitions {
             lookup
-                .entry(symbol.name.clone())
+                .entry(name.clone())
                 .or_default()
-                .push((hash, namespace));
+                .push((hash.clone(), namespace.clone()));
         }
     }
 
@@ -1802,83 +2232,45 @@ fn resolve_call_to_hash(
     }
 }
 
-/// Build call graph from summaries with resolved symbol hashes
+/// Build call graph from indexed files with resolved symbol hashes
 /// Parallelized with Rayon for better performance on large codebases
 /// Returns edges with edge_kind to distinguish calls from variable reads/writes
 fn build_call_graph(
-    summaries: &[SemanticSummary],
+    files: &[FileIndex],
     progress: &Option<ShardProgressCallback>,
 ) -> HashMap<String, Vec<CallGraphEdge>> {
-    use crate::overlay::compute_symbol_hash;
-
-    let total = summaries.len();
+    let total = files.len();
 
     // Build lookup for resolving call names to hashes (must be done before parallel phase)
-    let symbol_lookup = build_symbol_lookup(summaries);
+    let symbol_lookup = build_symbol_lookup(files);
 
     // Progress tracking
     let processed = AtomicUsize::new(0);
 
-    let graph_fast: ahash::AHashMap<String, Vec<CallGraphEdge>> = summaries
+    let graph_fast: ahash::AHashMap<String, Vec<CallGraphEdge>> = files
         .par_iter()
         .with_min_len(32)
         .fold(
             ahash::AHashMap::new,
-            |mut local_graph: ahash::AHashMap<String, Vec<CallGraphEdge>>, summary| {
+            |mut local_graph: ahash::AHashMap<String, Vec<CallGraphEdge>>, file| {
                 let current = processed.fetch_add(1, Ordering::Relaxed) + 1;
                 if total > 100 && (current % 500 == 0 || current == total) {
                     emit_progress(progress, "Call graph", current, total);
                 }
 
-                // Compute file hash once for this file (used to prefer same-file call resolution)
-                let caller_file_hash = crate::overlay::extract_file_hash(
-                    summary
-                        .symbol_id
-                        .as_ref()
-                        .map(|s| s.hash.as_str())
-                        .unwrap_or(""),
-                )
-                .to_string();
-                // Fallback: compute from file path if no symbol_id
-                let caller_file_hash = if caller_file_hash.is_empty() {
-                    format!("{:08x}", crate::schema::fnv1a_hash(&summary.file) as u32)
-                } else {
-                    caller_file_hash
-                };
-                let same_file_prefix = if caller_file_hash.is_empty() {
-                    String::new()
-                } else {
-                    format!("{}:", caller_file_hash)
-                };
-
-                // Process each symbol in the file
-                for symbol in &summary.symbols {
-                    let hash = compute_symbol_hash(symbol, &summary.file);
+                let calls = &file.calls;
+                for (caller, refs) in &calls.callers {
                     let mut edges: Vec<CallGraphEdge> = Vec::new();
                     let mut seen: ahash::AHashSet<(String, RefKind)> = ahash::AHashSet::new();
 
-                    // Extract from the symbol's own calls array
-                    for c in &symbol.calls {
-                        let resolved = if let Some(ref obj) = c.object {
-                            let mut call_name = String::with_capacity(obj.len() + 1 + c.name.len());
-                            call_name.push_str(obj);
-                            call_name.push('.');
-                            call_name.push_str(&c.name);
-                            resolve_call_to_hash(
-                                &call_name,
-                                &symbol_lookup,
-                                &same_file_prefix,
-                                &summary.import_sources,
-                            )
-                        } else {
-                            resolve_call_to_hash(
-                                &c.name,
-                                &symbol_lookup,
-                                &same_file_prefix,
-                                &summary.import_sources,
-                            )
-                        };
-                        let edge = CallGraphEdge::new(resolved, c.ref_kind);
+                    for (name, ref_kind) in refs {
+                        let resolved = resolve_call_to_hash(
+                            name,
+                            &symbol_lookup,
+                            &calls.same_file_prefix,
+                            &calls.import_sources,
+                        );
+                        let edge = CallGraphEdge::new(resolved, *ref_kind);
 
                         // Deduplicate by callee+edge_kind
                         if seen.insert((edge.callee.clone(), edge.edge_kind)) {
@@ -1886,104 +2278,7 @@ fn build_call_graph(
                         }
                     }
 
-                    // Extract function calls from symbol's state_changes initializers (always call kind)
-                    for state in &symbol.state_changes {
-                        if !state.initializer.is_empty() {
-                            if let Some(call_name) =
-                                extract_call_from_initializer(&state.initializer)
-                            {
-                                let resolved = resolve_call_to_hash(
-                                    &call_name,
-                                    &symbol_lookup,
-                                    &same_file_prefix,
-                                    &summary.import_sources,
-                                );
-                                let edge = CallGraphEdge::call(resolved);
-                                if seen.insert((edge.callee.clone(), edge.edge_kind)) {
-                                    edges.push(edge);
-                                }
-                            }
-                        }
-                    }
-
-                    if !edges.is_empty() {
-                        local_graph.entry(hash).or_default().extend(edges);
-                    }
-                }
-
-                // Also process file-level calls
-                if let Some(ref symbol_id) = summary.symbol_id {
-                    let mut edges: Vec<CallGraphEdge> = Vec::new();
-                    let mut seen: ahash::AHashSet<(String, RefKind)> = ahash::AHashSet::new();
-
-                    for c in &summary.calls {
-                        let resolved = if let Some(ref obj) = c.object {
-                            let mut call_name = String::with_capacity(obj.len() + 1 + c.name.len());
-                            call_name.push_str(obj);
-                            call_name.push('.');
-                            call_name.push_str(&c.name);
-                            resolve_call_to_hash(
-                                &call_name,
-                                &symbol_lookup,
-                                &same_file_prefix,
-                                &summary.import_sources,
-                            )
-                        } else {
-                            resolve_call_to_hash(
-                                &c.name,
-                                &symbol_lookup,
-                                &same_file_prefix,
-                                &summary.import_sources,
-                            )
-                        };
-                        let edge = CallGraphEdge::new(resolved, c.ref_kind);
-                        if seen.insert((edge.callee.clone(), edge.edge_kind)) {
-                            edges.push(edge);
-                        }
-                    }
-
-                    for state in &summary.state_changes {
-                        if !state.initializer.is_empty() {
-                            if let Some(call_name) =
-                                extract_call_from_initializer(&state.initializer)
-                            {
-                                let resolved = resolve_call_to_hash(
-                                    &call_name,
-                                    &symbol_lookup,
-                                    &same_file_prefix,
-                                    &summary.import_sources,
-                                );
-                                let edge = CallGraphEdge::call(resolved);
-                                if seen.insert((edge.callee.clone(), edge.edge_kind)) {
-                                    edges.push(edge);
-                                }
-                            }
-                        }
-                    }
-
-                    // Include both functions (lowercase) and components (PascalCase) from dependencies
-                    // Only exclude Rust-style namespace paths (::) - these are always call kind
-                    for dep in &summary.added_dependencies {
-                        if !dep.contains("::") {
-                            let resolved = resolve_call_to_hash(
-                                dep,
-                                &symbol_lookup,
-                                &same_file_prefix,
-                                &summary.import_sources,
-                            );
-                            let edge = CallGraphEdge::call(resolved);
-                            if seen.insert((edge.callee.clone(), edge.edge_kind)) {
-                                edges.push(edge);
-                            }
-                        }
-                    }
-
-                    if !edges.is_empty() {
-                        local_graph
-                            .entry(symbol_id.hash.clone())
-                            .or_default()
-                            .extend(edges);
-                    }
+                    local_graph.entry(caller.clone()).or_default().extend(edges);
                 }
 
                 local_graph
@@ -2143,13 +2438,14 @@ fn write_call_graph(path: &Path, graph: &HashMap<String, Vec<CallGraphEdge>>) ->
     Ok(bytes)
 }
 
-/// Build import graph from summaries
-fn build_import_graph(summaries: &[SemanticSummary]) -> HashMap<String, Vec<String>> {
+/// Build import graph from indexed files
+fn build_import_graph(files: &[FileIndex]) -> HashMap<String, Vec<String>> {
     let mut graph: HashMap<String, Vec<String>> = HashMap::new();
 
-    for summary in summaries {
-        if !summary.local_imports.is_empty() {
-            graph.insert(summary.file.clone(), summary.local_imports.clone());
+    for file in files {
+        let overview = &file.overview;
+        if !overview.local_imports.is_empty() {
+            graph.insert(overview.file.clone(), overview.local_imports.clone());
         }
     }
 
@@ -2178,16 +2474,17 @@ fn encode_import_graph(graph: &HashMap<String, Vec<String>>) -> String {
 
 /// Build module dependency graph
 fn build_module_graph(
-    modules: &HashMap<String, Vec<SemanticSummary>>,
+    modules: &HashMap<String, Vec<usize>>,
+    files: &[FileIndex],
     file_to_module: &HashMap<String, String>,
 ) -> HashMap<String, Vec<String>> {
     let mut graph: HashMap<String, Vec<String>> = HashMap::new();
 
-    for (module_name, summaries) in modules {
+    for (module_name, indices) in modules {
         let mut deps: Vec<String> = Vec::new();
 
-        for summary in summaries {
-            for import in &summary.local_imports {
+        for file in indices.iter().map(|&idx| &files[idx]) {
+            for import in &file.overview.local_imports {
                 // Get the optimal module name from registry, fallback to extraction
                 let import_module = file_to_module
                     .get(import)
@@ -2227,6 +2524,12 @@ fn encode_module_graph(graph: &HashMap<String, Vec<String>>) -> String {
     lines.join("\n")
 }
 
+/// Numeric (start, end) of a `"10-20"` line range, so line 9 sorts before 10
+fn line_range_key(lines: &str) -> (usize, usize) {
+    let (start, end) = lines.split_once('-').unwrap_or((lines, lines));
+    (start.parse().unwrap_or(0), end.parse().unwrap_or(0))
+}
+
 /// Extract module/namespace from file path.
 ///
 /// Returns the path-based namespace (directory structure after src/).
@@ -2993,10 +3296,18 @@ mod tests {
     // build_call_graph() tests (integration-style)
     // ========================================================================
 
+    /// Index summaries as the writer does before its graph stage
+    fn index_files(summaries: &[SemanticSummary]) -> Vec<FileIndex> {
+        summaries
+            .iter()
+            .map(|s| FileIndex::new(s, &extract_module_name(&s.file), false))
+            .collect()
+    }
+
     #[test]
     fn test_build_call_graph_empty() {
         let summaries: Vec<SemanticSummary> = vec![];
-        let graph = build_call_graph(&summaries, &None);
+        let graph = build_call_graph(&index_files(&summaries), &None);
         assert!(
             graph.is_empty(),
             "Empty summaries should produce empty graph"
@@ -3022,7 +3333,7 @@ mod tests {
             ..Default::default()
         }];
 
-        let graph = build_call_graph(&summaries, &None);
+        let graph = build_call_graph(&index_files(&summaries), &None);
         // No calls means no edges in the graph
         assert!(
             graph.is_empty() || graph.values().all(|v| v.is_empty()),
@@ -3071,7 +3382,7 @@ mod tests {
             },
         ];
 
-        let graph = build_call_graph(&summaries, &None);
+        let graph = build_call_graph(&index_files(&summaries), &None);
         // Should have at least one entry for main calling helper
         assert!(!graph.is_empty(), "Should produce a call graph with edges");
     }
@@ -3094,6 +3405,271 @@ mod tests {
         assert_eq!(stats.total_bytes(), 15000);
     }
 
+    #[test]
+    fn test_string_table_shrinks_symbol_shards() {
+        use crate::schema::StateChange;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let cache = CacheDir {
+            root: temp_dir.path().join("cache"),
+            repo_root: temp_dir.path().to_path_buf(),
+            repo_hash: "strings".to_string(),
+        };
+
+        let config =
+            "{ retries: 3, backoff: 'exponential', timeoutMs: 30000, region: 'eu-west-1' }";
+        let summary = SemanticSummary {
+            file: "src/clients.ts".to_string(),
+            language: "typescript".to_string(),
+            symbols: (0..50)
+                .map(|i| SymbolInfo {
+                    name: format!("client{}", i),
+                    kind: SymbolKind::Function,
+                    start_line: i * 3 + 1,
+                    end_line: i * 3 + 3,
+                    state_changes: vec![StateChange {
+                        name: "options".to_string(),
+                        state_type: "const".to_string(),
+                        initializer: config.to_string(),
+                    }],
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        let mut writer = ShardWriter::with_cache(cache.clone()).unwrap();
+        writer.add_summaries(vec![summary.clone()]);
+        let mut stats = ShardStats::default();
+        writer.write_symbol_shards(&mut stats, &None).unwrap();
+
+        let namespace = SymbolId::namespace_from_path(&summary.file);
+        let inline_bytes: usize = summary
+            .symbols
+            .iter()
+            .map(|s| {
+                let id = s.to_symbol_id(&namespace, &summary.file);
+                encode_symbol_shard_from_info(&summary, s, &id, &StringTable::default()).len()
+            })
+            .sum();
+        assert!(stats.symbol_bytes + stats.string_table_bytes < inline_bytes);
+
+        // Shards on disk hold a reference; reading resolves it back
+        let id = summary.symbols[0].to_symbol_id(&namespace, &summary.file);
+        let raw = fs::read_to_string(cache.symbol_path(&id.hash)).unwrap();
+        assert!(!raw.contains("exponential"));
+        let shard = cache.read_symbol_shard(&id.hash).unwrap();
+        let expected_line = format!("  options,const,{}", serde_json::to_string(config).unwrap());
+        assert!(shard.lines().any(|line| line == expected_line));
+    }
+
+    #[test]
+    fn test_streaming_write_matches_batch() {
+        use crate::indexing::{analyze_files_parallel, analyze_files_streaming};
+        use std::collections::BTreeMap;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let sources = [
+            (
+                "src/api/users.ts",
+                "import { query } from '../db/pool';\nexport function getUser(id: string) { return query(id); }\n",
+            ),
+            (
+                "src/api/orders.ts",
+                "export function listOrders() { return []; }\n",
+            ),
+            (
+                "src/db/pool.ts",
+                "export function query(sql: string) { return sql; }\n",
+            ),
+            (
+                "src/util/strings.ts",
+                "export function slugify(s: string) { return s.toLowerCase(); }\n",
+            ),
+        ];
+        let files: Vec<PathBuf> = sources
+            .iter()
+            .map(|(path, source)| {
+                let full = temp_dir.path().join(path);
+                fs::create_dir_all(full.parent().unwrap()).unwrap();
+                fs::write(&full, source).unwrap();
+                full
+            })
+            .collect();
+        let cache_at = |name: &str| CacheDir {
+            root: temp_dir.path().join(name),
+            repo_root: temp_dir.path().to_path_buf(),
+            repo_hash: name.to_string(),
+        };
+        let dir_str = temp_dir.path().display().to_string();
+
+        let batch_cache = cache_at("batch");
+        let mut batch = ShardWriter::with_cache(batch_cache.clone()).unwrap();
+        batch.add_summaries(analyze_files_parallel(&files, None, false).summaries);
+        let batch_stats = batch.write_all(&dir_str).unwrap();
+
+        let stream_cache = cache_at("stream");
+        let mut streamed = ShardWriter::with_cache(stream_cache.clone()).unwrap();
+        streamed.log_summaries(stream_cache.summary_log(temp_dir.path()).unwrap());
+        let (sender, receiver) = std::sync::mpsc::sync_channel(1);
+        let stream_stats = std::thread::scope(|scope| {
+            scope.spawn(|| analyze_files_streaming(&files, sender, false));
+            streamed
+                .write_all_streaming(&dir_str, &files, receiver)
+                .unwrap()
+        });
+
+        // Module shards, minus their generation timestamps
+        let module_shards = |cache: &CacheDir| -> BTreeMap<String, String> {
+            fs::read_dir(cache.modules_dir())
+                .unwrap()
+                .map(|entry| {
+                    let path = entry.unwrap().path();
+                    let content = fs::read_to_string(&path).unwrap();
+                    let stable: Vec<&str> = content
+                        .lines()
+                        .filter(|line| !line.contains("generated_at"))
+                        .collect();
+                    (
+                        path.file_name().unwrap().to_string_lossy().to_string(),
+                        stable.join("\n"),
+                    )
+                })
+                .collect()
+        };
+
+        assert_eq!(batch_stats.modules_written, 3);
+        assert_eq!(stream_stats.modules_written, batch_stats.modules_written);
+        assert_eq!(stream_stats.symbols_written, batch_stats.symbols_written);
+        assert_eq!(module_shards(&stream_cache), module_shards(&batch_cache));
+        assert_eq!(
+            fs::read_to_string(stream_cache.symbol_index_path()).unwrap(),
+            fs::read_to_string(batch_cache.symbol_index_path()).unwrap()
+        );
+        assert_eq!(
+            fs::read_to_string(stream_cache.signature_index_path()).unwrap(),
+            fs::read_to_string(batch_cache.signature_index_path()).unwrap()
+        );
+
+        // Summaries were logged as their modules were written
+        assert_eq!(stream_cache.load_summaries().len(), files.len());
+    }
+
+    #[test]
+    fn test_symbol_index_is_deterministic() {
+        use crate::indexing::analyze_files_parallel;
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let sources = [
+            (
+                "src/api/users.ts",
+                "export function getUser(id: string) { return id; }\n\n\
+                 export function deleteUser(id: string) { return id; }\n",
+            ),
+            (
+                "src/api/orders.ts",
+                "export function listOrders() { return []; }\n",
+            ),
+            (
+                "src/db/pool.ts",
+                "export function query(sql: string) { return sql; }\n",
+            ),
+        ];
+        let files: Vec<PathBuf> = sources
+            .iter()
+            .map(|(path, source)| {
+                let full = temp_dir.path().join(path);
+                fs::create_dir_all(full.parent().unwrap()).unwrap();
+                fs::write(&full, source).unwrap();
+                full
+            })
+            .collect();
+        let dir_str = temp_dir.path().display().to_string();
+        let summaries = analyze_files_parallel(&files, None, false).summaries;
+
+        // Same input, summaries arriving in opposite orders
+        let index_bytes = |name: &str, reverse: bool| -> Vec<u8> {
+            let cache = CacheDir {
+                root: temp_dir.path().join(name),
+                repo_root: temp_dir.path().to_path_buf(),
+                repo_hash: name.to_string(),
+            };
+            let mut ordered = summaries.clone();
+            if reverse {
+                ordered.reverse();
+            }
+            let mut writer = ShardWriter::with_cache(cache.clone()).unwrap();
+            writer.add_summaries(ordered);
+            writer.write_all(&dir_str).unwrap();
+            fs::read(cache.symbol_index_path()).unwrap()
+        };
+
+        let first = index_bytes("first", false);
+        assert_eq!(first, index_bytes("second", true));
+        assert_eq!(String::from_utf8(first).unwrap().lines().count(), 4);
+    }
+
+    #[test]
+    fn test_symbol_complexity_line_range_fallback() {
+        use crate::schema::{ControlFlowChange, ControlFlowKind, Location};
+
+        let cf = |kind, line, nesting_depth| ControlFlowChange {
+            kind,
+            location: Location { line, column: 0 },
+            nesting_depth,
+        };
+        let file_flow = vec![
+            cf(ControlFlowKind::For, 3, 0),
+            cf(ControlFlowKind::If, 4, 1),
+        ];
+        let symbol = |name: &str, start_line, end_line, control_flow| SymbolInfo {
+            name: name.to_string(),
+            start_line,
+            end_line,
+            control_flow,
+            ..Default::default()
+        };
+
+        // Per-symbol flow: the enclosing impl keeps none of its method's branches
+        let attributed = SemanticSummary {
+            symbols: vec![
+                symbol("Cart", 1, 10, vec![]),
+                symbol("total", 2, 9, file_flow.clone()),
+            ],
+            control_flow_changes: file_flow.clone(),
+            ..Default::default()
+        };
+        assert_eq!(
+            symbol_complexity(&attributed, &attributed.symbols[0]),
+            (0, 0)
+        );
+        assert_eq!(
+            symbol_complexity(&attributed, &attributed.symbols[1]),
+            (3, 1)
+        );
+
+        // No per-symbol flow at all: fall back to line ranges
+        let unattributed = SemanticSummary {
+            symbols: vec![
+                symbol("total", 2, 9, vec![]),
+                symbol("other", 11, 12, vec![]),
+            ],
+            control_flow_changes: file_flow,
+            ..Default::default()
+        };
+        assert_eq!(
+            symbol_complexity(&unattributed, &unattributed.symbols[0]),
+            (3, 1)
+        );
+        assert_eq!(
+            symbol_complexity(&unattributed, &unattributed.symbols[1]),
+            (0, 0)
+        );
+    }
+
     #[test]
     fn test_shard_stats_default() {
         let stats = ShardStats::default();
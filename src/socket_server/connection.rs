@@ -334,7 +334,7 @@ impl ConnectionState {
                 let cache = ctx.get_cache_for_scope(scope);
                 let symbol_path = cache.symbol_path(hash);
                 if symbol_path.exists() {
-                    let content = std::fs::read_to_string(&symbol_path)?;
+                    let content = cache.read_symbol_shard(hash)?;
                     Ok(
                         serde_json::json!({ "symbol": content, "scope": scope.unwrap_or("base_branch") }),
                     )
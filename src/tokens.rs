@@ -4,10 +4,14 @@
 //! of TOON (Token-Oriented Object Notation) encoding compared to other formats.
 //!
 //! This module uses a simplified BPE-style estimation that approximates
-//! token counts for models like GPT-4, Claude, etc.
+//! token counts for models like GPT-4, Claude, etc. With the `tiktoken`
+//! feature, [`TokenAnalyzer::with_encoding`] counts with a real tokenizer
+//! instead.
 
 use std::collections::HashMap;
 
+use crate::lang::{Lang, LangFamily};
+
 /// Token analysis result comparing different formats
 #[derive(Debug, Clone, Default)]
 pub struct TokenAnalysis {
@@ -44,10 +48,57 @@ pub struct TokenBreakdown {
     pub whitespace: usize,
 }
 
+/// A tiktoken encoding, for exact counts with the `tiktoken` feature
+///
+/// | Encoding      | Models                                              |
+/// |---------------|-----------------------------------------------------|
+/// | `cl100k_base` | GPT-4, GPT-4 Turbo, GPT-3.5 Turbo, text-embedding-3 |
+/// | `o200k_base`  | GPT-4o, GPT-4o mini, GPT-4.1, o1, o3, o4-mini       |
+///
+/// Claude and other models use tokenizers of their own; `cl100k_base` is a
+/// reasonable stand-in for them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Cl100kBase,
+    O200kBase,
+}
+
+impl Encoding {
+    /// Encoding used by an OpenAI model name (`gpt-4o-mini` -> `O200kBase`)
+    pub fn for_model(model: &str) -> Option<Self> {
+        let model = model.to_lowercase();
+        let o200k = ["gpt-4o", "gpt-4.1", "o1", "o3", "o4"];
+        let cl100k = [
+            "gpt-4",
+            "gpt-3.5",
+            "text-embedding-3",
+            "text-embedding-ada-002",
+        ];
+        if o200k.iter().any(|prefix| model.starts_with(prefix)) {
+            Some(Self::O200kBase)
+        } else if cl100k.iter().any(|prefix| model.starts_with(prefix)) {
+            Some(Self::Cl100kBase)
+        } else {
+            None
+        }
+    }
+
+    /// The encoding's tiktoken name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Cl100kBase => "cl100k_base",
+            Self::O200kBase => "o200k_base",
+        }
+    }
+}
+
 /// AI Token Analyzer using BPE-style estimation
 pub struct TokenAnalyzer {
     /// Common programming tokens that are typically single tokens in BPE
     common_tokens: HashMap<&'static str, usize>,
+    /// Real tokenizer, used instead of the estimate when present
+    #[cfg(feature = "tiktoken")]
+    bpe: Option<tiktoken_rs::CoreBPE>,
 }
 
 impl Default for TokenAnalyzer {
@@ -149,11 +200,133 @@ impl TokenAnalyzer {
             common_tokens.insert(*token, 1);
         }
 
-        Self { common_tokens }
+        Self {
+            common_tokens,
+            #[cfg(feature = "tiktoken")]
+            bpe: None,
+        }
     }
 
-    /// Estimate token count for a string using BPE-style rules
+    /// Create a token analyzer that counts with a tiktoken encoding
+    ///
+    /// Counts are exact when the `tiktoken` feature is enabled; without it
+    /// (or if the encoding fails to load) this is the same as [`Self::new`].
+    pub fn with_encoding(encoding: Encoding) -> Self {
+        #[cfg(feature = "tiktoken")]
+        {
+            let bpe = match encoding {
+                Encoding::Cl100kBase => tiktoken_rs::cl100k_base(),
+                Encoding::O200kBase => tiktoken_rs::o200k_base(),
+            };
+            Self {
+                bpe: bpe.ok(),
+                ..Self::new()
+            }
+        }
+        #[cfg(not(feature = "tiktoken"))]
+        {
+            let _ = encoding;
+            Self::new()
+        }
+    }
+
+    /// Whether counts come from a real tokenizer rather than the estimate
+    pub fn is_exact(&self) -> bool {
+        #[cfg(feature = "tiktoken")]
+        {
+            self.bpe.is_some()
+        }
+        #[cfg(not(feature = "tiktoken"))]
+        {
+            false
+        }
+    }
+
+    /// Create a token analyzer that also knows the keywords of `lang`
+    ///
+    /// The default mappings lean towards JS/TS; keywords such as Go's `defer`
+    /// or Python's `yield` would otherwise be estimated as multi-token words.
+    pub fn for_lang(lang: Lang) -> Self {
+        let mut analyzer = Self::new();
+
+        let keywords: &[&'static str] = match lang.family() {
+            LangFamily::Go => &[
+                "func",
+                "package",
+                "defer",
+                "go",
+                "chan",
+                "select",
+                "range",
+                "map",
+                "fallthrough",
+                "goto",
+                "nil",
+                "iota",
+                "make",
+                "len",
+                "err",
+            ],
+            LangFamily::Rust => &[
+                "trait", "match", "mut", "ref", "where", "dyn", "crate", "super", "unsafe", "move",
+                "loop", "Some", "Ok", "Err", "Self", "Option", "Result", "Vec", "String",
+            ],
+            LangFamily::Python => &[
+                "elif", "yield", "with", "as", "pass", "raise", "except", "finally", "global",
+                "nonlocal", "assert", "del", "in", "is", "not", "and", "or",
+            ],
+            LangFamily::Java | LangFamily::CSharp | LangFamily::Kotlin => &[
+                "extends",
+                "implements",
+                "abstract",
+                "final",
+                "protected",
+                "void",
+                "override",
+                "namespace",
+                "package",
+                "val",
+                "fun",
+                "when",
+                "object",
+                "companion",
+            ],
+            LangFamily::CFamily => &[
+                "include",
+                "define",
+                "typedef",
+                "sizeof",
+                "unsigned",
+                "void",
+                "namespace",
+                "template",
+                "virtual",
+                "nullptr",
+            ],
+            LangFamily::Shell => &[
+                "then", "fi", "do", "done", "esac", "echo", "local", "export",
+            ],
+            _ => &[],
+        };
+
+        for token in keywords {
+            analyzer.common_tokens.insert(*token, 1);
+        }
+
+        analyzer
+    }
+
+    /// Count tokens for a string: exactly with a loaded encoding, otherwise
+    /// estimated with BPE-style rules
+    ///
+    /// Special-token markers such as `<|endoftext|>` in the text are counted
+    /// as the ordinary text they are, not as single control tokens.
     pub fn count_tokens(&self, text: &str) -> usize {
+        #[cfg(feature = "tiktoken")]
+        if let Some(bpe) = &self.bpe {
+            return bpe.encode_ordinary(text).len();
+        }
+
         let mut total = 0;
         let mut chars = text.chars().peekable();
         let mut current_word = String::new();
@@ -524,6 +697,53 @@ mod tests {
         assert!(analyzer.count_tokens("hello world") >= 2);
     }
 
+    #[test]
+    fn test_encoding_for_model() {
+        assert_eq!(
+            Encoding::for_model("gpt-4o-mini"),
+            Some(Encoding::O200kBase)
+        );
+        assert_eq!(
+            Encoding::for_model("GPT-4-turbo"),
+            Some(Encoding::Cl100kBase)
+        );
+        assert_eq!(Encoding::for_model("claude-sonnet"), None);
+
+        let analyzer = TokenAnalyzer::with_encoding(Encoding::Cl100kBase);
+        if cfg!(feature = "tiktoken") {
+            assert!(analyzer.is_exact());
+            assert_eq!(analyzer.count_tokens("hello world"), 2);
+            assert!(analyzer.count_tokens("<|endoftext|>") > 1);
+        } else {
+            // Without the feature, counts fall back to the estimate
+            assert!(!analyzer.is_exact());
+            let source = "fn main() { println!(\"hi\"); }";
+            assert_eq!(
+                analyzer.count_tokens(source),
+                TokenAnalyzer::new().count_tokens(source)
+            );
+        }
+    }
+
+    #[test]
+    fn test_for_lang_go_keywords() {
+        let source = "package main\n\nfunc run() {\n\tdefer close(done)\n\tselect {\n\tcase <-done:\n\t\tfallthrough\n\t}\n}";
+
+        let generic = TokenAnalyzer::new();
+        let go = TokenAnalyzer::for_lang(Lang::Go);
+
+        // Go keywords are single tokens once the language is known
+        assert_eq!(go.count_tokens("defer"), 1);
+        assert_eq!(go.count_tokens("fallthrough"), 1);
+        assert!(go.count_tokens(source) < generic.count_tokens(source));
+
+        // Keywords from other languages are not added
+        assert_eq!(
+            go.count_tokens("nonlocal"),
+            generic.count_tokens("nonlocal")
+        );
+    }
+
     #[test]
     fn test_json_structure() {
         let analyzer = TokenAnalyzer::new();
@@ -7,18 +7,22 @@
 //! - Field headers emitted once per array
 //! - Stable field ordering enforced
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
 use rtoon::encode_default;
+use serde::Serialize;
 use serde_json::{json, Map, Value};
 
-use crate::analysis::{calculate_cognitive_complexity, max_nesting_depth};
+use crate::analysis::{
+    calculate_cognitive_complexity, calculate_symbol_cognitive_complexity, max_nesting_depth,
+};
+use crate::error::{McpDiffError, Result};
+use crate::extract::FallbackConfig;
 use crate::schema::{
     FrameworkEntryPoint, ModuleGroup, RepoOverview, RepoStats, RiskLevel, SemanticSummary,
-    SymbolKind,
+    SymbolInfo, SymbolKind,
 };
 use crate::shard::extract_module_name;
-use crate::utils::truncate_to_char_boundary;
 
 // ============================================================================
 // Noisy call filtering - these are implementation details, not architecture
@@ -329,6 +333,108 @@ pub fn generate_repo_overview_with_modules(
     overview
 }
 
+/// Update an overview for changed and removed files without a full rescan
+///
+/// `previous` must be the [`generate_repo_overview`] result (or an earlier
+/// update) for `previous_files`, which the caller already holds; the
+/// overview keeps no copy of them. Stats, data flow, and the module groups
+/// of affected modules are adjusted in place; the repo-wide detections
+/// (framework, patterns, entry points) are re-run over the updated file
+/// list. Files are grouped with `extract_module_name`, as
+/// [`generate_repo_overview`] does.
+pub fn update_overview(
+    previous: &RepoOverview,
+    previous_files: &[SemanticSummary],
+    changed: &[SemanticSummary],
+    removed: &[String],
+) -> RepoOverview {
+    let mut overview = previous.clone();
+    let mut touched_modules: HashSet<String> = HashSet::new();
+
+    // Retract removed and changed files, keeping analysis order
+    let removed: HashSet<&str> = removed.iter().map(String::as_str).collect();
+    let changed_by_file: HashMap<&str, &SemanticSummary> =
+        changed.iter().map(|s| (s.file.as_str(), s)).collect();
+    let mut files: Vec<&SemanticSummary> = Vec::with_capacity(previous_files.len());
+    for old in previous_files {
+        let replacement = changed_by_file.get(old.file.as_str());
+        if removed.contains(old.file.as_str()) || replacement.is_some() {
+            merge_stats(&mut overview.stats, &file_stats(old), false);
+            overview.data_flow.remove(&old.file);
+            touched_modules.insert(extract_module_name(&old.file));
+        }
+        if !removed.contains(old.file.as_str()) {
+            files.push(replacement.copied().unwrap_or(old));
+        }
+    }
+
+    // Add changed files, appending new ones
+    let known: HashSet<&str> = previous_files.iter().map(|f| f.file.as_str()).collect();
+    for summary in changed {
+        if !known.contains(summary.file.as_str()) {
+            files.push(summary);
+        }
+        merge_stats(&mut overview.stats, &file_stats(summary), true);
+        if !summary.local_imports.is_empty() {
+            overview
+                .data_flow
+                .insert(summary.file.clone(), summary.local_imports.clone());
+        }
+        touched_modules.insert(extract_module_name(&summary.file));
+    }
+
+    // Regroup only the modules whose membership or files changed
+    overview
+        .modules
+        .retain(|m| !touched_modules.contains(&m.name));
+    let mut regrouped: BTreeMap<String, Vec<&SemanticSummary>> = BTreeMap::new();
+    for &file in &files {
+        let module = extract_module_name(&file.file);
+        if touched_modules.contains(&module) {
+            regrouped.entry(module).or_default().push(file);
+        }
+    }
+    overview.modules.extend(
+        regrouped
+            .into_iter()
+            .map(|(name, files)| module_group(name, &files)),
+    );
+    overview.modules.sort_by(|a, b| a.name.cmp(&b.name));
+
+    // The detections only read the overview-relevant slice of each summary
+    let files: Vec<SemanticSummary> = files.into_iter().map(overview_summary).collect();
+    overview.framework = detect_framework(&files);
+    overview.database = detect_database(&files);
+    overview.package_manager = detect_package_manager(&files);
+    overview.entry_points = identify_entry_points(&files);
+    overview.patterns = detect_patterns(&files);
+
+    overview
+}
+
+/// Copy of a summary with only the fields the overview reads
+pub(crate) fn overview_summary(summary: &SemanticSummary) -> SemanticSummary {
+    SemanticSummary {
+        file: summary.file.clone(),
+        language: summary.language.clone(),
+        symbol: summary.symbol.clone(),
+        symbol_kind: summary.symbol_kind,
+        behavioral_risk: summary.behavioral_risk,
+        added_dependencies: summary.added_dependencies.clone(),
+        insertions: summary.insertions.clone(),
+        local_imports: summary.local_imports.clone(),
+        framework_entry_point: summary.framework_entry_point,
+        ..Default::default()
+    }
+}
+
+/// Module a file belongs to, preferring the shard writer's mapping
+fn module_name_for(file: &str, file_to_module: Option<&HashMap<String, String>>) -> String {
+    file_to_module
+        .and_then(|mapping| mapping.get(file).cloned())
+        .unwrap_or_else(|| extract_module_name(file))
+}
+
 fn detect_framework(summaries: &[SemanticSummary]) -> Option<String> {
     let mut frameworks = Vec::new();
 
@@ -463,7 +569,7 @@ fn detect_package_manager(summaries: &[SemanticSummary]) -> Option<String> {
 }
 
 /// Get a human-readable purpose for a module group
-fn get_module_purpose(name: &str) -> String {
+pub(crate) fn get_module_purpose(name: &str) -> String {
     match name {
         "tests" => "Test files and fixtures".to_string(),
         "docs" => "Documentation".to_string(),
@@ -496,60 +602,59 @@ fn build_module_groups_with_map(
     _dir_path: &str,
     file_to_module: Option<&HashMap<String, String>>,
 ) -> Vec<ModuleGroup> {
-    let mut groups: HashMap<String, Vec<&SemanticSummary>> = HashMap::new();
+    // Ordered by name so the encoded overview is stable across runs
+    let mut groups: BTreeMap<String, Vec<&SemanticSummary>> = BTreeMap::new();
 
     for s in summaries {
         // Use provided mapping if available, otherwise fall back to extract_module_name
-        let module = if let Some(mapping) = file_to_module {
-            mapping
-                .get(&s.file)
-                .cloned()
-                .unwrap_or_else(|| extract_module_name(&s.file))
-        } else {
-            extract_module_name(&s.file)
-        };
-        groups.entry(module).or_default().push(s);
+        groups
+            .entry(module_name_for(&s.file, file_to_module))
+            .or_default()
+            .push(s);
     }
 
     groups
         .into_iter()
-        .map(|(name, files)| {
-            let purpose = get_module_purpose(&name);
+        .map(|(name, files)| module_group(name, &files))
+        .collect()
+}
 
-            // Calculate aggregate risk
-            let high_count = files
-                .iter()
-                .filter(|f| f.behavioral_risk == RiskLevel::High)
-                .count();
-            let med_count = files
-                .iter()
-                .filter(|f| f.behavioral_risk == RiskLevel::Medium)
-                .count();
-            let risk = if high_count > 0 {
-                RiskLevel::High
-            } else if med_count > 0 {
-                RiskLevel::Medium
-            } else {
-                RiskLevel::Low
-            };
+/// Summarize one module's files into a group
+fn module_group(name: String, files: &[&SemanticSummary]) -> ModuleGroup {
+    let purpose = get_module_purpose(&name);
 
-            // Get key files (high risk or with symbols)
-            let key_files: Vec<String> = files
-                .iter()
-                .filter(|f| f.behavioral_risk == RiskLevel::High || f.symbol.is_some())
-                .take(3)
-                .map(|f| f.file.rsplit('/').next().unwrap_or(&f.file).to_string())
-                .collect();
-
-            ModuleGroup {
-                name,
-                purpose,
-                file_count: files.len(),
-                risk,
-                key_files,
-            }
-        })
-        .collect()
+    // Calculate aggregate risk
+    let high_count = files
+        .iter()
+        .filter(|f| f.behavioral_risk == RiskLevel::High)
+        .count();
+    let med_count = files
+        .iter()
+        .filter(|f| f.behavioral_risk == RiskLevel::Medium)
+        .count();
+    let risk = if high_count > 0 {
+        RiskLevel::High
+    } else if med_count > 0 {
+        RiskLevel::Medium
+    } else {
+        RiskLevel::Low
+    };
+
+    // Get key files (high risk or with symbols)
+    let key_files: Vec<String> = files
+        .iter()
+        .filter(|f| f.behavioral_risk == RiskLevel::High || f.symbol.is_some())
+        .take(3)
+        .map(|f| f.file.rsplit('/').next().unwrap_or(&f.file).to_string())
+        .collect();
+
+    ModuleGroup {
+        name,
+        purpose,
+        file_count: files.len(),
+        risk,
+        key_files,
+    }
 }
 
 fn identify_entry_points(summaries: &[SemanticSummary]) -> Vec<String> {
@@ -613,41 +718,78 @@ fn build_data_flow(summaries: &[SemanticSummary]) -> HashMap<String, Vec<String>
 
 fn build_stats(summaries: &[SemanticSummary]) -> RepoStats {
     let mut stats = RepoStats::default();
+    for s in summaries {
+        merge_stats(&mut stats, &file_stats(s), true);
+    }
+    stats
+}
 
-    stats.total_files = summaries.len();
+/// Leave files without behavioral content out of the overview's stats
+///
+/// Files for which [`SemanticSummary::is_empty_of_logic`] holds no longer count
+/// toward the file, risk, and component totals, so barrels and type-only
+/// modules don't dilute them; the number left out is kept in
+/// `logic_free_files`. Modules and data flow still include them.
+pub fn exclude_logic_free_stats(overview: &mut RepoOverview, summaries: &[SemanticSummary]) {
+    exclude_flagged_stats(
+        overview,
+        summaries.iter().map(|s| (s, s.is_empty_of_logic())),
+    );
+}
 
-    for s in summaries {
-        // Risk counts
-        match s.behavioral_risk {
-            RiskLevel::High => stats.high_risk += 1,
-            RiskLevel::Medium => stats.medium_risk += 1,
-            RiskLevel::Low => stats.low_risk += 1,
+/// [`exclude_logic_free_stats`] with each file's logic-free flag worked out
+/// by the caller, e.g. before the summary was cut down to [`overview_summary`]
+pub(crate) fn exclude_flagged_stats<'a>(
+    overview: &mut RepoOverview,
+    files: impl IntoIterator<Item = (&'a SemanticSummary, bool)>,
+) {
+    let mut stats = RepoStats::default();
+    for (s, logic_free) in files {
+        if logic_free {
+            stats.logic_free_files += 1;
+        } else {
+            merge_stats(&mut stats, &file_stats(s), true);
         }
+    }
+    stats.total_lines = overview.stats.total_lines;
+    overview.stats = stats;
+}
 
-        // Language counts
-        *stats.by_language.entry(s.language.clone()).or_insert(0) += 1;
+/// One file's contribution to the repository stats
+fn file_stats(s: &SemanticSummary) -> RepoStats {
+    let mut stats = RepoStats {
+        total_files: 1,
+        ..Default::default()
+    };
 
-        // Component counts
-        if s.symbol_kind == Some(SymbolKind::Component) {
-            stats.components += 1;
-        }
+    // Risk counts
+    match s.behavioral_risk {
+        RiskLevel::High => stats.high_risk += 1,
+        RiskLevel::Medium => stats.medium_risk += 1,
+        RiskLevel::Low => stats.low_risk += 1,
+    }
 
-        // API endpoint counts
-        if s.insertions.iter().any(|i| i.contains("API route")) {
-            stats.api_endpoints += 1;
-        }
+    // Language counts
+    stats.by_language.insert(s.language.clone(), 1);
 
-        // Database table counts
-        if s.insertions.iter().any(|i| i.contains("table definition")) {
-            // Extract count from "database schema (N table definitions)"
-            for insertion in &s.insertions {
-                if insertion.contains("table definition") {
-                    if let Some(count_str) = insertion.split('(').nth(1) {
-                        if let Some(num) = count_str.split_whitespace().next() {
-                            if let Ok(n) = num.parse::<usize>() {
-                                stats.database_tables += n;
-                            }
-                        }
+    // Component counts
+    if s.symbol_kind == Some(SymbolKind::Component) {
+        stats.components += 1;
+    }
+
+    // API endpoint counts
+    if s.insertions.iter().any(|i| i.contains("API route")) {
+        stats.api_endpoints += 1;
+    }
+
+    // Database table counts
+    // Extract count from "database schema (N table definitions)"
+    for insertion in &s.insertions {
+        if insertion.contains("table definition") {
+            if let Some(count_str) = insertion.split('(').nth(1) {
+                if let Some(num) = count_str.split_whitespace().next() {
+                    if let Ok(n) = num.parse::<usize>() {
+                        stats.database_tables += n;
                     }
                 }
             }
@@ -657,6 +799,34 @@ fn build_stats(summaries: &[SemanticSummary]) -> RepoStats {
     stats
 }
 
+/// Add `part` into `total`, or retract it when `add` is false
+fn merge_stats(total: &mut RepoStats, part: &RepoStats, add: bool) {
+    let apply = |total: &mut usize, value: usize| {
+        *total = if add {
+            *total + value
+        } else {
+            total.saturating_sub(value)
+        };
+    };
+
+    apply(&mut total.total_files, part.total_files);
+    apply(&mut total.total_lines, part.total_lines);
+    apply(&mut total.high_risk, part.high_risk);
+    apply(&mut total.medium_risk, part.medium_risk);
+    apply(&mut total.low_risk, part.low_risk);
+    apply(&mut total.api_endpoints, part.api_endpoints);
+    apply(&mut total.database_tables, part.database_tables);
+    apply(&mut total.components, part.components);
+
+    for (language, &count) in &part.by_language {
+        let entry = total.by_language.entry(language.clone()).or_insert(0);
+        apply(entry, count);
+        if *entry == 0 {
+            total.by_language.remove(language);
+        }
+    }
+}
+
 fn detect_patterns(summaries: &[SemanticSummary]) -> Vec<String> {
     let mut patterns = Vec::new();
 
@@ -765,6 +935,32 @@ fn detect_patterns(summaries: &[SemanticSummary]) -> Vec<String> {
     patterns
 }
 
+// ============================================================================
+// Value Encoding
+// ============================================================================
+
+/// Encode any serializable value as TOON, surfacing encoder failures
+///
+/// Prefer this over the infallible encoders when the output is consumed as
+/// data, so a failure can't be mistaken for a valid document.
+pub fn try_encode_toon<T: Serialize + ?Sized>(value: &T) -> Result<String> {
+    let value = serde_json::to_value(value)
+        .map_err(|e| McpDiffError::Serialization(format!("TOON encoding failed: {}", e)))?;
+    encode_default(&value)
+        .map_err(|e| McpDiffError::Serialization(format!("TOON encoding failed: {}", e)))
+}
+
+/// Encode a JSON value as TOON, logging failures and yielding empty output
+///
+/// Backs the infallible encoders below; an empty string is never mistaken
+/// for data, unlike an inline error message.
+fn encode_value_or_warn(value: &Value) -> String {
+    try_encode_toon(value).unwrap_or_else(|e| {
+        tracing::warn!("{}", e);
+        String::new()
+    })
+}
+
 // ============================================================================
 // Directory TOON Encoding (with overview)
 // ============================================================================
@@ -834,6 +1030,12 @@ pub fn encode_toon_directory(overview: &RepoOverview, _summaries: &[SemanticSumm
     if stats.components > 0 {
         obj.insert("components".to_string(), json!(stats.components));
     }
+    if stats.logic_free_files > 0 {
+        obj.insert(
+            "logic_free_files".to_string(),
+            json!(stats.logic_free_files),
+        );
+    }
 
     // Entry points
     if !overview.entry_points.is_empty() {
@@ -841,7 +1043,7 @@ pub fn encode_toon_directory(overview: &RepoOverview, _summaries: &[SemanticSumm
     }
 
     let value = Value::Object(obj);
-    encode_default(&value).unwrap_or_else(|e| format!("TOON encoding error: {}", e))
+    encode_value_or_warn(&value)
 }
 
 /// Encode repository overview as TOON
@@ -904,6 +1106,12 @@ fn encode_repo_overview(overview: &RepoOverview) -> String {
     if stats.components > 0 {
         obj.insert("components".to_string(), json!(stats.components));
     }
+    if stats.logic_free_files > 0 {
+        obj.insert(
+            "logic_free_files".to_string(),
+            json!(stats.logic_free_files),
+        );
+    }
 
     // Entry points
     if !overview.entry_points.is_empty() {
@@ -911,11 +1119,125 @@ fn encode_repo_overview(overview: &RepoOverview) -> String {
     }
 
     let value = Value::Object(obj);
-    encode_default(&value).unwrap_or_else(|e| format!("TOON encoding error: {}", e))
+    encode_value_or_warn(&value)
+}
+
+/// Which keys a clean summary encoding emits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToonMode {
+    /// Leave out fields that are empty or hold their default, to save tokens
+    #[default]
+    Lean,
+    /// Emit every key in [`STABLE_SCHEMA_KEYS`] order, absent values as `_`,
+    /// `0`, `false` or an empty list, for consumers parsing into typed structures
+    StableSchema,
+}
+
+/// What a [`ToonMode::StableSchema`] key holds when the summary has no value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbsentValue {
+    /// `_`
+    Placeholder,
+    /// `false`
+    False,
+    /// `0`
+    Zero,
+    /// `low`
+    LowRisk,
+    /// An empty list
+    Empty,
 }
 
+impl AbsentValue {
+    fn to_value(self) -> Value {
+        match self {
+            AbsentValue::Placeholder => json!("_"),
+            AbsentValue::False => json!(false),
+            AbsentValue::Zero => json!(0),
+            AbsentValue::LowRisk => json!(risk_to_string(RiskLevel::Low)),
+            AbsentValue::Empty => json!([]),
+        }
+    }
+}
+
+/// Every key of a clean summary encoding, in order, with its absent value
+pub const STABLE_SCHEMA_KEYS: &[(&str, AbsentValue)] = &[
+    ("file", AbsentValue::Placeholder),
+    ("language", AbsentValue::Placeholder),
+    ("symbol_id", AbsentValue::Placeholder),
+    ("symbol_namespace", AbsentValue::Placeholder),
+    ("symbol", AbsentValue::Placeholder),
+    ("symbol_kind", AbsentValue::Placeholder),
+    ("lines", AbsentValue::Placeholder),
+    ("return_type", AbsentValue::Placeholder),
+    ("public_surface_changed", AbsentValue::False),
+    ("behavioral_risk", AbsentValue::LowRisk),
+    ("cognitive_complexity", AbsentValue::Zero),
+    ("max_nesting_depth", AbsentValue::Zero),
+    ("insertions", AbsentValue::Empty),
+    ("added_dependencies", AbsentValue::Empty),
+    ("imports_from", AbsentValue::Empty),
+    ("state", AbsentValue::Empty),
+    ("control_flow", AbsentValue::Empty),
+    ("missing_default", AbsentValue::Empty),
+    ("import_side_effects", AbsentValue::Empty),
+    ("a11y_issues", AbsentValue::Empty),
+    ("unawaited_async", AbsentValue::Empty),
+    ("mixed_async", AbsentValue::Empty),
+    ("unchecked_nullable", AbsentValue::Empty),
+    ("commented_code", AbsentValue::Empty),
+    ("repeated_blocks", AbsentValue::Empty),
+    ("long_chains", AbsentValue::Empty),
+    ("platform_branches", AbsentValue::Empty),
+    ("build_constraint", AbsentValue::Placeholder),
+    ("bare_rethrows", AbsentValue::Empty),
+    ("broad_catches", AbsentValue::Empty),
+    ("sensitive_logs", AbsentValue::Empty),
+    ("resource_leaks", AbsentValue::Empty),
+    ("overflow_risks", AbsentValue::Empty),
+    ("loop_string_concat", AbsentValue::Empty),
+    ("inconsistent_returns", AbsentValue::Empty),
+    ("expression_complexity", AbsentValue::Zero),
+    ("lock_hazards", AbsentValue::Empty),
+    ("duplicate_imports", AbsentValue::Empty),
+    ("calls", AbsentValue::Empty),
+    ("note", AbsentValue::Placeholder),
+    ("raw", AbsentValue::Placeholder),
+];
+
 /// Encode a summary with filtered calls and no meaningless fields
 pub fn encode_toon_clean(summary: &SemanticSummary) -> String {
+    encode_toon_clean_with_fallback(summary, &FallbackConfig::default())
+}
+
+/// [`encode_toon_clean`] with the key set chosen by `mode`
+pub fn encode_toon_clean_with_mode(summary: &SemanticSummary, mode: ToonMode) -> String {
+    let mut obj = clean_summary_fields(summary, &FallbackConfig::default());
+    if mode == ToonMode::StableSchema {
+        obj = STABLE_SCHEMA_KEYS
+            .iter()
+            .map(|(key, absent)| {
+                let value = obj.remove(*key).unwrap_or_else(|| absent.to_value());
+                (key.to_string(), value)
+            })
+            .collect();
+    }
+    encode_value_or_warn(&Value::Object(obj))
+}
+
+/// [`encode_toon_clean`], rendering `raw_fallback` with `fallback`'s inline limit
+pub fn encode_toon_clean_with_fallback(
+    summary: &SemanticSummary,
+    fallback: &FallbackConfig,
+) -> String {
+    encode_value_or_warn(&Value::Object(clean_summary_fields(summary, fallback)))
+}
+
+/// The fields [`encode_toon_clean`] emits, leaving out empty and default ones
+fn clean_summary_fields(
+    summary: &SemanticSummary,
+    fallback: &FallbackConfig,
+) -> Map<String, Value> {
     let mut obj = Map::new();
 
     // Simple scalar fields
@@ -1012,6 +1334,266 @@ pub fn encode_toon_clean(summary: &SemanticSummary) -> String {
         obj.insert("control_flow".to_string(), json!(kinds));
     }
 
+    // Switch/match constructs without a default arm
+    if !summary.missing_default_branches.is_empty() {
+        let lines: Vec<usize> = summary
+            .missing_default_branches
+            .iter()
+            .map(|l| l.line)
+            .collect();
+        obj.insert("missing_default".to_string(), json!(lines));
+    }
+
+    // Code that runs on import
+    if !summary.import_side_effects.is_empty() {
+        let lines: Vec<usize> = summary.import_side_effects.iter().map(|l| l.line).collect();
+        obj.insert("import_side_effects".to_string(), json!(lines));
+    }
+
+    // Accessibility hints on JSX/HTML elements
+    if !summary.a11y_issues.is_empty() {
+        let issue_objs: Vec<Value> = summary
+            .a11y_issues
+            .iter()
+            .map(|i| {
+                json!({
+                    "kind": i.kind.as_str(),
+                    "element": i.element,
+                    "line": i.location.line
+                })
+            })
+            .collect();
+        obj.insert("a11y_issues".to_string(), Value::Array(issue_objs));
+    }
+
+    // Async calls whose promise/future is dropped
+    if !summary.unawaited_async_calls.is_empty() {
+        let call_objs: Vec<Value> = summary
+            .unawaited_async_calls
+            .iter()
+            .map(|c| json!({ "name": c.name, "line": c.location.line }))
+            .collect();
+        obj.insert("unawaited_async".to_string(), Value::Array(call_objs));
+    }
+
+    // Functions mixing await with .then() chains; pick one style
+    if !summary.mixed_async_styles.is_empty() {
+        let mixed_objs: Vec<Value> = summary
+            .mixed_async_styles
+            .iter()
+            .map(|m| {
+                json!({
+                    "function": m.function,
+                    "line": m.location.line,
+                    "then_line": m.then_line
+                })
+            })
+            .collect();
+        obj.insert("mixed_async".to_string(), Value::Array(mixed_objs));
+    }
+
+    // Property access on lookups that may have found nothing
+    if !summary.unchecked_nullable_accesses.is_empty() {
+        let access_objs: Vec<Value> = summary
+            .unchecked_nullable_accesses
+            .iter()
+            .map(|a| {
+                json!({
+                    "variable": a.variable,
+                    "call": a.call,
+                    "line": a.location.line,
+                    "assigned_line": a.assigned_line
+                })
+            })
+            .collect();
+        obj.insert("unchecked_nullable".to_string(), Value::Array(access_objs));
+    }
+
+    // Comment blocks that look like disabled code
+    if !summary.commented_code.is_empty() {
+        let ranges: Vec<String> = summary
+            .commented_code
+            .iter()
+            .map(|b| format!("{}-{}", b.start_line, b.end_line))
+            .collect();
+        obj.insert("commented_code".to_string(), json!(ranges));
+    }
+
+    // Statement runs copied within a function; candidates for a helper
+    if !summary.repeated_blocks.is_empty() {
+        let block_objs: Vec<Value> = summary
+            .repeated_blocks
+            .iter()
+            .map(|b| {
+                json!({
+                    "fn": b.function,
+                    "statements": b.statements,
+                    "first": format!("{}-{}", b.first_start_line, b.first_end_line),
+                    "repeat": format!("{}-{}", b.location.line, b.end_line)
+                })
+            })
+            .collect();
+        obj.insert("repeated_blocks".to_string(), Value::Array(block_objs));
+    }
+
+    // Member-access chains that reach through too many objects
+    if !summary.long_member_chains.is_empty() {
+        let chain_objs: Vec<Value> = summary
+            .long_member_chains
+            .iter()
+            .map(|c| json!({ "depth": c.depth, "line": c.location.line }))
+            .collect();
+        obj.insert("long_chains".to_string(), Value::Array(chain_objs));
+    }
+
+    // Behavior that diverges per operating system
+    if !summary.platform_branches.is_empty() {
+        let branch_objs: Vec<Value> = summary
+            .platform_branches
+            .iter()
+            .map(|b| json!({ "platform": b.platform, "line": b.location.line }))
+            .collect();
+        obj.insert("platform_branches".to_string(), Value::Array(branch_objs));
+    }
+
+    // Files compiled only for some platforms or tags
+    if let Some(ref constraint) = summary.build_constraint {
+        obj.insert("build_constraint".to_string(), json!(constraint));
+    }
+
+    // Errors caught only to be rethrown as-is
+    if !summary.bare_rethrows.is_empty() {
+        let rethrow_objs: Vec<Value> = summary
+            .bare_rethrows
+            .iter()
+            .map(|r| {
+                json!({
+                    "error": r.error.as_deref().unwrap_or("_"),
+                    "line": r.location.line
+                })
+            })
+            .collect();
+        obj.insert("bare_rethrows".to_string(), Value::Array(rethrow_objs));
+    }
+
+    // Handlers that swallow every exception type
+    if !summary.broad_catches.is_empty() {
+        let catch_objs: Vec<Value> = summary
+            .broad_catches
+            .iter()
+            .map(|c| json!({ "caught": c.caught, "line": c.location.line }))
+            .collect();
+        obj.insert("broad_catches".to_string(), Value::Array(catch_objs));
+    }
+
+    // Credentials or personal data passed to a logger
+    if !summary.sensitive_logs.is_empty() {
+        let log_objs: Vec<Value> = summary
+            .sensitive_logs
+            .iter()
+            .map(|s| json!({ "logger": s.logger, "name": s.name, "line": s.location.line }))
+            .collect();
+        obj.insert("sensitive_logs".to_string(), Value::Array(log_objs));
+    }
+
+    // Resources that are never closed
+    if !summary.resource_leaks.is_empty() {
+        let leak_objs: Vec<Value> = summary
+            .resource_leaks
+            .iter()
+            .map(|l| {
+                json!({
+                    "call": l.call,
+                    "var": l.variable.as_deref().unwrap_or("_"),
+                    "line": l.location.line
+                })
+            })
+            .collect();
+        obj.insert("resource_leaks".to_string(), Value::Array(leak_objs));
+    }
+
+    // Size arithmetic that can wrap silently
+    if !summary.overflow_risks.is_empty() {
+        let risk_objs: Vec<Value> = summary
+            .overflow_risks
+            .iter()
+            .map(|r| json!({ "expr": r.expression, "line": r.location.line }))
+            .collect();
+        obj.insert("overflow_risks".to_string(), Value::Array(risk_objs));
+    }
+
+    // Strings rebuilt on every loop iteration
+    if !summary.loop_string_concats.is_empty() {
+        let concat_objs: Vec<Value> = summary
+            .loop_string_concats
+            .iter()
+            .map(|c| json!({ "var": c.variable, "line": c.location.line }))
+            .collect();
+        obj.insert("loop_string_concat".to_string(), Value::Array(concat_objs));
+    }
+
+    // Functions that sometimes return a value and sometimes nothing
+    if !summary.inconsistent_returns.is_empty() {
+        let return_objs: Vec<Value> = summary
+            .inconsistent_returns
+            .iter()
+            .map(|r| {
+                json!({
+                    "fn": r.function,
+                    "shapes": r.shapes.join("|"),
+                    "line": r.location.line
+                })
+            })
+            .collect();
+        obj.insert(
+            "inconsistent_returns".to_string(),
+            Value::Array(return_objs),
+        );
+    }
+
+    // Dense ternaries and optional chains
+    if summary.expression_complexity > 0 {
+        obj.insert(
+            "expression_complexity".to_string(),
+            json!(summary.expression_complexity),
+        );
+    }
+
+    // Lock usage that hints at deadlocks
+    if !summary.lock_hazards.is_empty() {
+        let hazard_objs: Vec<Value> = summary
+            .lock_hazards
+            .iter()
+            .map(|h| {
+                json!({
+                    "kind": h.kind.as_str(),
+                    "function": h.function,
+                    "locks": h.locks.join(","),
+                    "line": h.location.line
+                })
+            })
+            .collect();
+        obj.insert("lock_hazards".to_string(), Value::Array(hazard_objs));
+    }
+
+    // Imports repeated within the file
+    if !summary.duplicate_imports.is_empty() {
+        let import_objs: Vec<Value> = summary
+            .duplicate_imports
+            .iter()
+            .map(|d| {
+                json!({
+                    "kind": d.kind.as_str(),
+                    "name": d.name,
+                    "from": d.source,
+                    "line": d.location.line,
+                    "fix": d.kind.suggestion()
+                })
+            })
+            .collect();
+        obj.insert("duplicate_imports".to_string(), Value::Array(import_objs));
+    }
+
     // Filtered calls (meaningful only)
     let meaningful_calls = filter_meaningful_calls(&summary.calls);
     if !meaningful_calls.is_empty() {
@@ -1031,30 +1613,21 @@ pub fn encode_toon_clean(summary: &SemanticSummary) -> String {
                 obj.insert("note".to_string(), json!("(empty file)"));
             } else {
                 // Compact representation: single line or line count
-                let lines: Vec<&str> = raw.lines().collect();
-                if lines.len() <= 3 {
-                    let content = raw.split_whitespace().collect::<Vec<_>>().join(" ");
-                    if content.len() <= 100 {
-                        obj.insert("raw".to_string(), json!(content));
-                    } else {
-                        obj.insert(
-                            "raw".to_string(),
-                            json!(format!("{}...", truncate_to_char_boundary(&content, 97))),
-                        );
-                    }
-                } else {
-                    obj.insert("raw".to_string(), json!(format!("({} lines)", lines.len())));
-                }
+                obj.insert("raw".to_string(), json!(fallback.compact(raw, 100)));
             }
         }
     }
 
-    let value = Value::Object(obj);
-    encode_default(&value).unwrap_or_else(|e| format!("TOON encoding error: {}", e))
+    obj
 }
 
 /// Encode a semantic summary as TOON
 pub fn encode_toon(summary: &SemanticSummary) -> String {
+    encode_toon_with_fallback(summary, &FallbackConfig::default())
+}
+
+/// [`encode_toon`], rendering `raw_fallback` with `fallback`'s inline limit
+pub fn encode_toon_with_fallback(summary: &SemanticSummary, fallback: &FallbackConfig) -> String {
     // Build a JSON value that will encode nicely to TOON
     let mut obj = Map::new();
 
@@ -1148,7 +1721,9 @@ pub fn encode_toon(summary: &SemanticSummary) -> String {
                 json!({
                     "name": a.name,
                     "type": a.arg_type.as_deref().unwrap_or("_"),
-                    "default": a.default_value.as_deref().unwrap_or("_")
+                    "default": a.default_value.as_deref().unwrap_or("_"),
+                    "optional": a.is_optional,
+                    "doc": a.description.as_deref().unwrap_or("_")
                 })
             })
             .collect();
@@ -1182,6 +1757,266 @@ pub fn encode_toon(summary: &SemanticSummary) -> String {
         obj.insert("control_flow".to_string(), json!(kinds));
     }
 
+    // Switch/match constructs without a default arm
+    if !summary.missing_default_branches.is_empty() {
+        let lines: Vec<usize> = summary
+            .missing_default_branches
+            .iter()
+            .map(|l| l.line)
+            .collect();
+        obj.insert("missing_default".to_string(), json!(lines));
+    }
+
+    // Code that runs on import
+    if !summary.import_side_effects.is_empty() {
+        let lines: Vec<usize> = summary.import_side_effects.iter().map(|l| l.line).collect();
+        obj.insert("import_side_effects".to_string(), json!(lines));
+    }
+
+    // Accessibility hints on JSX/HTML elements
+    if !summary.a11y_issues.is_empty() {
+        let issue_objs: Vec<Value> = summary
+            .a11y_issues
+            .iter()
+            .map(|i| {
+                json!({
+                    "kind": i.kind.as_str(),
+                    "element": i.element,
+                    "line": i.location.line
+                })
+            })
+            .collect();
+        obj.insert("a11y_issues".to_string(), Value::Array(issue_objs));
+    }
+
+    // Async calls whose promise/future is dropped
+    if !summary.unawaited_async_calls.is_empty() {
+        let call_objs: Vec<Value> = summary
+            .unawaited_async_calls
+            .iter()
+            .map(|c| json!({ "name": c.name, "line": c.location.line }))
+            .collect();
+        obj.insert("unawaited_async".to_string(), Value::Array(call_objs));
+    }
+
+    // Functions mixing await with .then() chains; pick one style
+    if !summary.mixed_async_styles.is_empty() {
+        let mixed_objs: Vec<Value> = summary
+            .mixed_async_styles
+            .iter()
+            .map(|m| {
+                json!({
+                    "function": m.function,
+                    "line": m.location.line,
+                    "then_line": m.then_line
+                })
+            })
+            .collect();
+        obj.insert("mixed_async".to_string(), Value::Array(mixed_objs));
+    }
+
+    // Property access on lookups that may have found nothing
+    if !summary.unchecked_nullable_accesses.is_empty() {
+        let access_objs: Vec<Value> = summary
+            .unchecked_nullable_accesses
+            .iter()
+            .map(|a| {
+                json!({
+                    "variable": a.variable,
+                    "call": a.call,
+                    "line": a.location.line,
+                    "assigned_line": a.assigned_line
+                })
+            })
+            .collect();
+        obj.insert("unchecked_nullable".to_string(), Value::Array(access_objs));
+    }
+
+    // Comment blocks that look like disabled code
+    if !summary.commented_code.is_empty() {
+        let ranges: Vec<String> = summary
+            .commented_code
+            .iter()
+            .map(|b| format!("{}-{}", b.start_line, b.end_line))
+            .collect();
+        obj.insert("commented_code".to_string(), json!(ranges));
+    }
+
+    // Statement runs copied within a function; candidates for a helper
+    if !summary.repeated_blocks.is_empty() {
+        let block_objs: Vec<Value> = summary
+            .repeated_blocks
+            .iter()
+            .map(|b| {
+                json!({
+                    "fn": b.function,
+                    "statements": b.statements,
+                    "first": format!("{}-{}", b.first_start_line, b.first_end_line),
+                    "repeat": format!("{}-{}", b.location.line, b.end_line)
+                })
+            })
+            .collect();
+        obj.insert("repeated_blocks".to_string(), Value::Array(block_objs));
+    }
+
+    // Member-access chains that reach through too many objects
+    if !summary.long_member_chains.is_empty() {
+        let chain_objs: Vec<Value> = summary
+            .long_member_chains
+            .iter()
+            .map(|c| json!({ "depth": c.depth, "line": c.location.line }))
+            .collect();
+        obj.insert("long_chains".to_string(), Value::Array(chain_objs));
+    }
+
+    // Behavior that diverges per operating system
+    if !summary.platform_branches.is_empty() {
+        let branch_objs: Vec<Value> = summary
+            .platform_branches
+            .iter()
+            .map(|b| json!({ "platform": b.platform, "line": b.location.line }))
+            .collect();
+        obj.insert("platform_branches".to_string(), Value::Array(branch_objs));
+    }
+
+    // Files compiled only for some platforms or tags
+    if let Some(ref constraint) = summary.build_constraint {
+        obj.insert("build_constraint".to_string(), json!(constraint));
+    }
+
+    // Errors caught only to be rethrown as-is
+    if !summary.bare_rethrows.is_empty() {
+        let rethrow_objs: Vec<Value> = summary
+            .bare_rethrows
+            .iter()
+            .map(|r| {
+                json!({
+                    "error": r.error.as_deref().unwrap_or("_"),
+                    "line": r.location.line
+                })
+            })
+            .collect();
+        obj.insert("bare_rethrows".to_string(), Value::Array(rethrow_objs));
+    }
+
+    // Handlers that swallow every exception type
+    if !summary.broad_catches.is_empty() {
+        let catch_objs: Vec<Value> = summary
+            .broad_catches
+            .iter()
+            .map(|c| json!({ "caught": c.caught, "line": c.location.line }))
+            .collect();
+        obj.insert("broad_catches".to_string(), Value::Array(catch_objs));
+    }
+
+    // Credentials or personal data passed to a logger
+    if !summary.sensitive_logs.is_empty() {
+        let log_objs: Vec<Value> = summary
+            .sensitive_logs
+            .iter()
+            .map(|s| json!({ "logger": s.logger, "name": s.name, "line": s.location.line }))
+            .collect();
+        obj.insert("sensitive_logs".to_string(), Value::Array(log_objs));
+    }
+
+    // Resources that are never closed
+    if !summary.resource_leaks.is_empty() {
+        let leak_objs: Vec<Value> = summary
+            .resource_leaks
+            .iter()
+            .map(|l| {
+                json!({
+                    "call": l.call,
+                    "var": l.variable.as_deref().unwrap_or("_"),
+                    "line": l.location.line
+                })
+            })
+            .collect();
+        obj.insert("resource_leaks".to_string(), Value::Array(leak_objs));
+    }
+
+    // Size arithmetic that can wrap silently
+    if !summary.overflow_risks.is_empty() {
+        let risk_objs: Vec<Value> = summary
+            .overflow_risks
+            .iter()
+            .map(|r| json!({ "expr": r.expression, "line": r.location.line }))
+            .collect();
+        obj.insert("overflow_risks".to_string(), Value::Array(risk_objs));
+    }
+
+    // Strings rebuilt on every loop iteration
+    if !summary.loop_string_concats.is_empty() {
+        let concat_objs: Vec<Value> = summary
+            .loop_string_concats
+            .iter()
+            .map(|c| json!({ "var": c.variable, "line": c.location.line }))
+            .collect();
+        obj.insert("loop_string_concat".to_string(), Value::Array(concat_objs));
+    }
+
+    // Functions that sometimes return a value and sometimes nothing
+    if !summary.inconsistent_returns.is_empty() {
+        let return_objs: Vec<Value> = summary
+            .inconsistent_returns
+            .iter()
+            .map(|r| {
+                json!({
+                    "fn": r.function,
+                    "shapes": r.shapes.join("|"),
+                    "line": r.location.line
+                })
+            })
+            .collect();
+        obj.insert(
+            "inconsistent_returns".to_string(),
+            Value::Array(return_objs),
+        );
+    }
+
+    // Dense ternaries and optional chains
+    if summary.expression_complexity > 0 {
+        obj.insert(
+            "expression_complexity".to_string(),
+            json!(summary.expression_complexity),
+        );
+    }
+
+    // Lock usage that hints at deadlocks
+    if !summary.lock_hazards.is_empty() {
+        let hazard_objs: Vec<Value> = summary
+            .lock_hazards
+            .iter()
+            .map(|h| {
+                json!({
+                    "kind": h.kind.as_str(),
+                    "function": h.function,
+                    "locks": h.locks.join(","),
+                    "line": h.location.line
+                })
+            })
+            .collect();
+        obj.insert("lock_hazards".to_string(), Value::Array(hazard_objs));
+    }
+
+    // Imports repeated within the file
+    if !summary.duplicate_imports.is_empty() {
+        let import_objs: Vec<Value> = summary
+            .duplicate_imports
+            .iter()
+            .map(|d| {
+                json!({
+                    "kind": d.kind.as_str(),
+                    "name": d.name,
+                    "from": d.source,
+                    "line": d.location.line,
+                    "fix": d.kind.suggestion()
+                })
+            })
+            .collect();
+        obj.insert("duplicate_imports".to_string(), Value::Array(import_objs));
+    }
+
     // Function calls with context (deduplicated, counted)
     if !summary.calls.is_empty() {
         let call_objs = build_deduplicated_calls(&summary.calls);
@@ -1198,33 +2033,225 @@ pub fn encode_toon(summary: &SemanticSummary) -> String {
             && summary.insertions.is_empty()
         {
             // For non-code files, provide a compact single-line summary
-            let lines: Vec<&str> = raw.lines().collect();
-            let line_count = lines.len();
-
-            if line_count <= 3 {
-                // Very short files: include as single line
-                let content = raw.split_whitespace().collect::<Vec<_>>().join(" ");
-                if content.len() <= 200 {
-                    obj.insert("raw_source".to_string(), json!(content));
-                } else {
-                    obj.insert(
-                        "raw_source".to_string(),
-                        json!(format!("{}...", truncate_to_char_boundary(&content, 197))),
-                    );
-                }
-            } else {
-                // Longer files: just report structure
-                obj.insert(
-                    "raw_source".to_string(),
-                    json!(format!("({} lines)", line_count)),
-                );
-            }
+            obj.insert("raw_source".to_string(), json!(fallback.compact(raw, 200)));
         }
     }
 
     // Encode to TOON using rtoon
     let value = Value::Object(obj);
-    encode_default(&value).unwrap_or_else(|e| format!("TOON encoding error: {}", e))
+    encode_value_or_warn(&value)
+}
+
+/// A file's outline: each symbol's kind, signature and risk
+///
+/// Calls, control flow, state and insertions are left out. This is the
+/// smallest summary still useful for navigating a codebase.
+pub fn summary_outline(summary: &SemanticSummary) -> Value {
+    let mut obj = Map::new();
+    obj.insert("file".to_string(), json!(summary.file));
+    obj.insert(
+        "behavioral_risk".to_string(),
+        json!(risk_to_string(summary.behavioral_risk)),
+    );
+
+    if !summary.symbols.is_empty() {
+        let symbols: Vec<Value> = summary
+            .symbols
+            .iter()
+            .map(|s| {
+                json!({
+                    "name": s.name,
+                    "kind": s.kind.as_str(),
+                    "signature": symbol_signature(s),
+                    "risk": risk_to_string(s.behavioral_risk)
+                })
+            })
+            .collect();
+        obj.insert("symbols".to_string(), Value::Array(symbols));
+    }
+
+    Value::Object(obj)
+}
+
+/// Encode only a file's outline (see [`summary_outline`])
+pub fn encode_toon_summary_only(summary: &SemanticSummary) -> String {
+    encode_value_or_warn(&summary_outline(summary))
+}
+
+/// A file's symbol table: each symbol's name, kind, line range and whether
+/// it is exported
+///
+/// Uniform rows, so TOON renders them as one `symbols[N]{...}` table.
+pub fn symbol_table(summary: &SemanticSummary) -> Value {
+    let symbols: Vec<Value> = summary
+        .symbols
+        .iter()
+        .map(|s| {
+            json!({
+                "name": s.name,
+                "kind": s.kind.as_str(),
+                "lines": format!("{}-{}", s.start_line, s.end_line),
+                "exported": s.is_exported
+            })
+        })
+        .collect();
+    json!({ "file": summary.file, "symbols": symbols })
+}
+
+/// Encode only a file's symbol table (see [`symbol_table`])
+pub fn encode_toon_symbols_only(summary: &SemanticSummary) -> String {
+    encode_value_or_warn(&symbol_table(summary))
+}
+
+/// Encode a single symbol as TOON, without the file-level envelope
+///
+/// Used when serving an individual symbol (e.g. `get_symbol`), where `file`,
+/// `language` and other summary fields are already known to the caller.
+///
+/// With `relative_lines`, control flow and each call site carry their line
+/// as an offset from the symbol's `start_line` (`+12`), so the snippet stays
+/// the same when the symbol moves within its file.
+pub fn encode_symbol_toon(symbol: &SymbolInfo, relative_lines: bool) -> String {
+    let offset = |line: usize| format!("+{}", line.saturating_sub(symbol.start_line));
+    let mut obj = Map::new();
+
+    obj.insert("symbol".to_string(), json!(symbol.name));
+    obj.insert("kind".to_string(), json!(symbol.kind.as_str()));
+    obj.insert(
+        "lines".to_string(),
+        json!(format!("{}-{}", symbol.start_line, symbol.end_line)),
+    );
+    obj.insert("signature".to_string(), json!(symbol_signature(symbol)));
+    if symbol.is_stub {
+        obj.insert("stub".to_string(), json!(true));
+    }
+    if !symbol.injected_deps.is_empty() {
+        obj.insert("injected_deps".to_string(), json!(symbol.injected_deps));
+    }
+    if let Some(ref violation) = symbol.naming_violation {
+        obj.insert("naming_violation".to_string(), json!(violation));
+    }
+    if let Some(ref envied) = symbol.feature_envy {
+        obj.insert("feature_envy".to_string(), json!(envied));
+    }
+    if !symbol.patterns.is_empty() {
+        obj.insert("patterns".to_string(), json!(symbol.patterns));
+    }
+    if symbol.is_stub_impl {
+        obj.insert("stub_impl".to_string(), json!(true));
+    }
+
+    if symbol.behavioral_risk != RiskLevel::Low {
+        obj.insert(
+            "behavioral_risk".to_string(),
+            json!(risk_to_string(symbol.behavioral_risk)),
+        );
+    }
+
+    let cc = calculate_symbol_cognitive_complexity(symbol);
+    let nest = max_nesting_depth(&symbol.control_flow);
+    if cc > 0 {
+        obj.insert("cognitive_complexity".to_string(), json!(cc));
+    }
+    if nest > 1 {
+        obj.insert("max_nesting_depth".to_string(), json!(nest));
+    }
+
+    if !symbol.control_flow.is_empty() && relative_lines {
+        let flow: Vec<Value> = symbol
+            .control_flow
+            .iter()
+            .map(|c| json!({ "kind": c.kind.as_str(), "at": offset(c.location.line) }))
+            .collect();
+        obj.insert("control_flow".to_string(), Value::Array(flow));
+    } else if !symbol.control_flow.is_empty() {
+        let kinds: Vec<&str> = symbol
+            .control_flow
+            .iter()
+            .map(|c| c.kind.as_str())
+            .collect();
+        obj.insert("control_flow".to_string(), json!(kinds));
+    }
+
+    let meaningful_calls = filter_meaningful_calls(&symbol.calls);
+    if !meaningful_calls.is_empty() {
+        let call_objs = build_deduplicated_calls(&meaningful_calls);
+        obj.insert("calls".to_string(), Value::Array(call_objs));
+    }
+
+    // One row per call, in source order; calls without a location are left out
+    if relative_lines {
+        let sites: Vec<Value> = meaningful_calls
+            .iter()
+            .filter(|c| c.location.line > 0)
+            .map(|c| {
+                let call = match &c.object {
+                    Some(object) => format!("{}.{}", object, c.name),
+                    None => c.name.clone(),
+                };
+                json!({ "call": call, "at": offset(c.location.line) })
+            })
+            .collect();
+        if !sites.is_empty() {
+            obj.insert("call_sites".to_string(), Value::Array(sites));
+        }
+    }
+
+    // Types this symbol creates (instantiation graph edges)
+    let instantiates: BTreeSet<&str> = symbol
+        .calls
+        .iter()
+        .filter_map(|c| c.instantiated_type.as_deref())
+        .collect();
+    if !instantiates.is_empty() {
+        obj.insert("instantiates".to_string(), json!(instantiates));
+    }
+
+    let value = Value::Object(obj);
+    encode_value_or_warn(&value)
+}
+
+/// Render a compact signature, e.g. `async fetchUser<T>(id: string) -> User`
+pub fn symbol_signature(symbol: &SymbolInfo) -> String {
+    let args: Vec<String> = symbol
+        .arguments
+        .iter()
+        .map(|arg| {
+            // `x?` when it can be left out and there is no default to show
+            let mut rendered = arg.name.clone();
+            if arg.is_optional && arg.default_value.is_none() {
+                rendered.push('?');
+            }
+            if let Some(ref ty) = arg.arg_type {
+                rendered.push_str(": ");
+                rendered.push_str(ty);
+            }
+            if let Some(ref default) = arg.default_value {
+                rendered.push_str(" = ");
+                rendered.push_str(default);
+            }
+            rendered
+        })
+        .collect();
+
+    let mut signature = String::new();
+    if symbol.is_async {
+        signature.push_str("async ");
+    }
+    signature.push_str(&symbol.name);
+    if !symbol.type_params.is_empty() {
+        signature.push('<');
+        signature.push_str(&symbol.type_params.join(", "));
+        signature.push('>');
+    }
+    signature.push('(');
+    signature.push_str(&args.join(", "));
+    signature.push(')');
+    if let Some(ref ret) = symbol.return_type {
+        signature.push_str(" -> ");
+        signature.push_str(ret);
+    }
+    signature
 }
 
 /// Build deduplicated and counted call objects
@@ -1268,7 +2295,9 @@ fn risk_to_string(risk: RiskLevel) -> &'static str {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::schema::{ControlFlowChange, ControlFlowKind, Location, StateChange, SymbolKind};
+    use crate::schema::{
+        Argument, Call, ControlFlowChange, ControlFlowKind, Location, StateChange, SymbolKind,
+    };
 
     #[test]
     fn test_basic_toon_output() {
@@ -1301,6 +2330,91 @@ mod tests {
         assert!(toon.contains("medium"));
     }
 
+    #[test]
+    fn test_summary_only_omits_detail() {
+        use crate::lang::Lang;
+        use crate::parsing::parse_and_extract;
+        use std::path::Path;
+
+        let source = r#"
+export async function fetchUser(id: string): Promise<User> {
+    if (!id) {
+        throw new Error("missing id");
+    }
+    const [user] = useState(null);
+    return await api.get(`/users/${id}`);
+}
+"#;
+        let summary =
+            parse_and_extract(Path::new("src/api/users.ts"), source, Lang::TypeScript).unwrap();
+
+        let toon = encode_toon_summary_only(&summary);
+        assert!(toon.contains("src/api/users.ts"), "{}", toon);
+        assert!(toon.contains("fetchUser(id: string)"), "{}", toon);
+        assert!(toon.contains("behavioral_risk:"), "{}", toon);
+        assert!(!toon.contains("calls"), "{}", toon);
+        assert!(!toon.contains("control_flow"), "{}", toon);
+        assert!(!toon.contains("insertions"), "{}", toon);
+    }
+
+    #[test]
+    fn test_symbols_only_is_a_table() {
+        use crate::lang::Lang;
+        use crate::parsing::parse_and_extract;
+        use std::path::Path;
+
+        let source = r#"
+export function fetchUser(id: string) {
+    return api.get(id);
+}
+
+function cacheKey(id: string) {
+    return `user:${id}`;
+}
+"#;
+        let summary =
+            parse_and_extract(Path::new("src/api/users.ts"), source, Lang::TypeScript).unwrap();
+
+        let toon = encode_toon_symbols_only(&summary);
+        assert!(
+            toon.contains("symbols[2]{name,kind,lines,exported}:"),
+            "{}",
+            toon
+        );
+        assert!(toon.contains("fetchUser,function,2-4,true"), "{}", toon);
+        assert!(toon.contains("cacheKey,function,6-8,false"), "{}", toon);
+        assert!(!toon.contains("calls"), "{}", toon);
+    }
+
+    #[test]
+    fn test_stable_schema_emits_empty_fields() {
+        let summary = SemanticSummary {
+            file: "src/empty.ts".to_string(),
+            language: "typescript".to_string(),
+            ..Default::default()
+        };
+
+        let lean = encode_toon_clean(&summary);
+        assert!(!lean.contains("calls"), "{}", lean);
+        assert!(!lean.contains("control_flow"), "{}", lean);
+
+        let stable = encode_toon_clean_with_mode(&summary, ToonMode::StableSchema);
+        for key in [
+            "calls",
+            "control_flow",
+            "insertions",
+            "behavioral_risk",
+            "symbol",
+        ] {
+            assert!(stable.contains(key), "missing {}: {}", key, stable);
+        }
+        assert_eq!(
+            encode_toon_clean_with_mode(&summary, ToonMode::Lean),
+            lean,
+            "lean mode is the plain clean encoding"
+        );
+    }
+
     #[test]
     fn test_insertions_format() {
         let summary = SemanticSummary {
@@ -1384,6 +2498,78 @@ mod tests {
         assert!(toon.contains("for"));
     }
 
+    #[test]
+    fn test_encode_symbol_toon_omits_envelope() {
+        let symbol = SymbolInfo {
+            name: "fetchUser".to_string(),
+            kind: SymbolKind::Function,
+            start_line: 10,
+            end_line: 20,
+            is_async: true,
+            arguments: vec![Argument {
+                name: "id".to_string(),
+                arg_type: Some("string".to_string()),
+                default_value: None,
+                is_optional: false,
+                description: None,
+            }],
+            return_type: Some("User".to_string()),
+            calls: vec![Call {
+                name: "query".to_string(),
+                object: Some("db".to_string()),
+                is_awaited: true,
+                ..Default::default()
+            }],
+            control_flow: vec![ControlFlowChange {
+                kind: ControlFlowKind::If,
+                location: Location::default(),
+                nesting_depth: 0,
+            }],
+            ..Default::default()
+        };
+
+        let toon = encode_symbol_toon(&symbol, false);
+
+        assert!(toon.contains("async fetchUser(id: string) -> User"));
+        assert!(toon.contains("query"));
+        assert!(toon.contains("cognitive_complexity: 1"));
+        assert!(!toon.contains("file:"));
+        assert!(!toon.contains("language:"));
+    }
+
+    #[test]
+    fn test_encode_symbol_toon_relative_lines() {
+        let symbol = SymbolInfo {
+            name: "syncOrders".to_string(),
+            kind: SymbolKind::Function,
+            start_line: 40,
+            end_line: 60,
+            calls: vec![Call {
+                name: "save".to_string(),
+                object: Some("repo".to_string()),
+                location: Location::new(50, 8),
+                ..Default::default()
+            }],
+            control_flow: vec![ControlFlowChange {
+                kind: ControlFlowKind::For,
+                location: Location::new(44, 4),
+                nesting_depth: 0,
+            }],
+            ..Default::default()
+        };
+
+        let relative = encode_symbol_toon(&symbol, true);
+        let row = |a: &str, b: &str| relative.lines().any(|l| l.contains(a) && l.contains(b));
+        assert!(relative.contains("call_sites[1]{call,at}"), "{}", relative);
+        assert!(row("repo.save", "+10"), "{}", relative);
+        assert!(row("for", "+4"), "{}", relative);
+        assert!(!relative.contains("50"), "{}", relative);
+
+        let absolute = encode_symbol_toon(&symbol, false);
+        assert!(!absolute.contains("call_sites"));
+        assert!(!absolute.contains("+10"));
+    }
+
     #[test]
     fn test_raw_fallback() {
         let summary = SemanticSummary {
@@ -1798,4 +2984,131 @@ mod tests {
         );
         assert!(fw.contains("+"), "Should combine frameworks: {}", fw);
     }
+
+    #[test]
+    fn test_try_encode_toon_surfaces_errors() {
+        // JSON object keys must be strings, so tuple keys fail to encode
+        let mut bad: HashMap<(u32, u32), &str> = HashMap::new();
+        bad.insert((1, 2), "edge");
+
+        let err = try_encode_toon(&bad).unwrap_err();
+        assert!(matches!(err, McpDiffError::Serialization(_)));
+        assert!(err.to_string().contains("TOON encoding failed"));
+
+        let ok = try_encode_toon(&json!({ "symbol": "parse" })).unwrap();
+        assert!(ok.contains("parse"));
+    }
+
+    #[test]
+    fn test_logic_free_files_excluded_from_stats() {
+        use crate::lang::Lang;
+        use crate::parsing::parse_and_extract;
+        use std::path::Path;
+
+        let barrel = parse_and_extract(
+            Path::new("src/auth/index.ts"),
+            "export { login, logout } from './session';\nexport * from './tokens';\n",
+            Lang::TypeScript,
+        )
+        .unwrap();
+        let session = parse_and_extract(
+            Path::new("src/auth/session.ts"),
+            "export function login(user: string) {\n  if (!user) {\n    \
+             throw new Error('no user');\n  }\n  return startSession(user);\n}\n",
+            Lang::TypeScript,
+        )
+        .unwrap();
+        assert!(barrel.is_empty_of_logic());
+        assert!(!session.is_empty_of_logic());
+
+        let summaries = vec![barrel, session];
+        let mut overview = generate_repo_overview(&summaries, ".");
+        assert_eq!(overview.stats.total_files, 2);
+
+        exclude_logic_free_stats(&mut overview, &summaries);
+        assert_eq!(overview.stats.total_files, 1);
+        assert_eq!(overview.stats.logic_free_files, 1);
+        assert_eq!(
+            overview.modules.iter().map(|m| m.file_count).sum::<usize>(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_update_overview_matches_full_regeneration() {
+        fn file(path: &str, risk: RiskLevel, deps: &[&str]) -> SemanticSummary {
+            let language = if path.ends_with(".rs") {
+                "rust"
+            } else {
+                "typescript"
+            };
+            SemanticSummary {
+                file: path.to_string(),
+                language: language.to_string(),
+                symbol: Some("main".to_string()),
+                behavioral_risk: risk,
+                added_dependencies: deps.iter().map(|d| d.to_string()).collect(),
+                ..Default::default()
+            }
+        }
+        fn normalized(overview: &RepoOverview) -> Value {
+            let mut overview = overview.clone();
+            overview.modules.sort_by(|a, b| a.name.cmp(&b.name));
+            serde_json::to_value(&overview).unwrap()
+        }
+
+        let original = vec![
+            file("src/main.rs", RiskLevel::Low, &["clap"]),
+            file("src/api/routes.ts", RiskLevel::Medium, &[]),
+            file("src/api/db.ts", RiskLevel::Low, &["pg"]),
+        ];
+        let previous = generate_repo_overview(&original, ".");
+
+        let modified = file("src/api/routes.ts", RiskLevel::High, &["tokio"]);
+        let updated = update_overview(
+            &previous,
+            &original,
+            std::slice::from_ref(&modified),
+            &["src/api/db.ts".to_string()],
+        );
+
+        let current = vec![original[0].clone(), modified];
+        let regenerated = generate_repo_overview(&current, ".");
+        assert_eq!(normalized(&updated), normalized(&regenerated));
+        assert_eq!(updated.stats.total_files, 2);
+        assert_eq!(updated.stats.high_risk, 1);
+        assert_eq!(updated.stats.medium_risk, 0);
+        assert!(updated.patterns.contains(&"Async/concurrent".to_string()));
+        assert!(updated.database.is_none());
+    }
+
+    #[test]
+    fn test_directory_encoding_is_deterministic() {
+        let summaries: Vec<SemanticSummary> = ["src/api", "src/db", "src/ui", "lib", "cmd"]
+            .iter()
+            .enumerate()
+            .map(|(i, dir)| SemanticSummary {
+                file: format!("{}/mod{}.rs", dir, i),
+                language: if i % 2 == 0 { "rust" } else { "go" }.to_string(),
+                local_imports: vec![format!("./dep{}", i)],
+                ..Default::default()
+            })
+            .collect();
+
+        // Each overview gets freshly seeded hash maps
+        let first = generate_repo_overview(&summaries, ".");
+        let second = generate_repo_overview(&summaries, ".");
+        assert_eq!(
+            encode_toon_directory(&first, &summaries),
+            encode_toon_directory(&second, &summaries)
+        );
+        assert_eq!(
+            try_encode_toon(&first).unwrap(),
+            try_encode_toon(&second).unwrap()
+        );
+        let names: Vec<&str> = first.modules.iter().map(|m| m.name.as_str()).collect();
+        let mut sorted = names.clone();
+        sorted.sort();
+        assert_eq!(names, sorted);
+    }
 }
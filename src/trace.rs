@@ -6,7 +6,7 @@ use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 
 use crate::cache::{CacheDir, SymbolIndexEntry};
-use crate::commands::toon_parser::read_cached_file;
+use crate::commands::toon_parser::parse_cached_content;
 use crate::schema::{CallGraphEdge, RefKind};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -325,11 +325,10 @@ fn resolve_trace_roots(
 }
 
 fn resolve_from_symbol_shard(cache: &CacheDir, hash: &str) -> Option<SymbolIndexEntry> {
-    let path = cache.symbol_path(hash);
-    if !path.exists() {
+    if !cache.symbol_path(hash).exists() {
         return None;
     }
-    let cached = read_cached_file(&path).ok()?;
+    let cached = parse_cached_content(cache.read_symbol_shard(hash).ok()?).ok()?;
     let json = cached.json;
 
     symbol_from_json(&json, "")
@@ -489,6 +488,7 @@ fn symbol_from_json(sym: &serde_json::Value, module_name: &str) -> Option<Symbol
             .and_then(|v| v.as_str())
             .unwrap_or("")
             .to_string(),
+        logic_free: false,
         base_classes: sym
             .get("base_classes")
             .or_else(|| sym.get("bc"))
@@ -81,6 +81,28 @@ export function greet(name: string): string {
     assert_symbol_exists(&json, "greet");
 }
 
+#[test]
+fn test_analyze_file_summary_only_json() {
+    let repo = TestRepo::new();
+    repo.add_file(
+        "src/main.ts",
+        r#"
+export function greet(name: string): string {
+    return format(name);
+}
+"#,
+    );
+
+    let output = repo.run_cli_success(&["analyze", "src/main.ts", "--summary-only", "-f", "json"]);
+    let json = assert_valid_json(&output, "analyze file summary only");
+
+    let signature = json["symbols"][0]["signature"].as_str().unwrap_or_default();
+    assert!(signature.starts_with("greet(name: string)"), "{}", output);
+    assert!(json["symbols"][0]["risk"].is_string());
+    assert!(json.get("calls").is_none());
+    assert!(json.get("control_flow").is_none());
+}
+
 #[test]
 fn test_analyze_file_text_format() {
     let repo = TestRepo::new();
@@ -143,6 +165,74 @@ fn test_analyze_file_empty() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn test_analyze_file_no_fallback() {
+    let repo = TestRepo::new();
+    repo.add_file(
+        "src/notes.ts",
+        "// TODO: port the parser from the old service\n",
+    );
+
+    let output = repo.run_cli_success(&["analyze", "src/notes.ts", "-f", "toon"]);
+    assert!(
+        output.lines().any(|line| line.starts_with("raw:")),
+        "Incomplete extraction should fall back to raw source: {}",
+        output
+    );
+
+    let output = repo.run_cli_success(&["analyze", "src/notes.ts", "-f", "toon", "--no-fallback"]);
+    assert!(
+        !output.lines().any(|line| line.starts_with("raw:")),
+        "--no-fallback should drop the raw field: {}",
+        output
+    );
+}
+
+#[test]
+fn test_analyze_compare_reports_signature_change() {
+    let repo = TestRepo::new();
+    repo.add_file(
+        "old/api.ts",
+        "export function fetchUser(id: string): string {\n    return load(id);\n}\n",
+    );
+    repo.add_file(
+        "new/api.ts",
+        "export function fetchUser(id: string, retries: number): string {\n    log(id);\n    return load(id);\n}\n",
+    );
+
+    let output = repo.run_cli_success(&[
+        "analyze",
+        "--compare",
+        "old/api.ts",
+        "new/api.ts",
+        "-f",
+        "json",
+    ]);
+    let json = assert_valid_json(&output, "analyze --compare");
+    assert_eq!(json["_type"], "file_comparison");
+
+    let symbols = json["symbols"].as_array().expect("symbols array");
+    let fetch_user = symbols
+        .iter()
+        .find(|s| s["name"] == "fetchUser")
+        .expect("fetchUser should be reported as changed");
+    assert_eq!(fetch_user["change"], "modified");
+    assert!(
+        fetch_user["signature_after"]
+            .as_str()
+            .is_some_and(|sig| sig.contains("retries")),
+        "Added parameter should show in the new signature: {}",
+        fetch_user
+    );
+    assert!(
+        fetch_user["calls_added"]
+            .as_array()
+            .is_some_and(|calls| calls.iter().any(|c| c == "log")),
+        "New call should be reported: {}",
+        fetch_user
+    );
+}
+
 // ============================================================================
 // ANALYZE DIRECTORY TESTS
 // Note: Directory analysis outputs TOON/overview format regardless of -f json
@@ -0,0 +1,127 @@
+//! Tests for the `baseline` CLI command
+//!
+//! - `baseline save` - Record per-symbol complexity and risk
+//! - `baseline check` - Fail only when a symbol regressed against the baseline
+
+use crate::common::{assert_contains, TestRepo};
+
+const SIMPLE_HANDLER: &str = r#"
+export function handle(value: number): number {
+    return value + 1;
+}
+"#;
+
+const NESTED_HANDLER: &str = r#"
+export function handle(value: number): number {
+    if (value > 0) {
+        for (let i = 0; i < value; i++) {
+            if (i % 2 === 0) {
+                value += i;
+            }
+        }
+    }
+    return value + 1;
+}
+"#;
+
+#[test]
+fn test_baseline_check_passes_when_unchanged() {
+    let repo = TestRepo::new();
+    repo.add_file("src/handler.ts", SIMPLE_HANDLER);
+
+    repo.run_cli_success(&["baseline", "save"]);
+    assert!(repo.path().join(".semfora-baseline.json").exists());
+
+    let output = repo.run_cli_success(&["baseline", "check", "-f", "text"]);
+    assert_contains(&output, "No regressions", false, "baseline check");
+}
+
+#[test]
+fn test_baseline_check_fails_on_complexity_increase() {
+    let repo = TestRepo::new();
+    repo.add_file("src/handler.ts", SIMPLE_HANDLER);
+
+    repo.run_cli_success(&["baseline", "save"]);
+    repo.add_file("src/handler.ts", NESTED_HANDLER);
+
+    let (_, stderr) = repo.run_cli_failure(&["baseline", "check"]);
+    assert_contains(
+        &stderr,
+        "src/handler.ts::handle",
+        true,
+        "baseline regression",
+    );
+
+    // A generous tolerance absorbs the increase
+    repo.run_cli_success(&["baseline", "check", "--tolerance", "20"]);
+}
+
+#[test]
+fn test_baseline_ignores_new_symbols() {
+    let repo = TestRepo::new();
+    repo.add_file("src/handler.ts", SIMPLE_HANDLER);
+
+    repo.run_cli_success(&["baseline", "save"]);
+    repo.add_file("src/extra.ts", NESTED_HANDLER);
+
+    repo.run_cli_success(&["baseline", "check"]);
+}
+
+#[test]
+fn test_findings_incremental_output_reports_delta() {
+    let repo = TestRepo::new();
+    repo.add_file(
+        "src/names.ts",
+        r#"
+export function Bad_Name(): number {
+    return 1;
+}
+
+export function Other_Name(): number {
+    return 2;
+}
+"#,
+    );
+    repo.run_cli_success(&["baseline", "findings"]);
+    assert!(repo.path().join(".semfora-findings.jsonl").exists());
+
+    // Fix one violation, introduce another
+    repo.add_file(
+        "src/names.ts",
+        r#"
+export function goodName(): number {
+    return 1;
+}
+
+export function Other_Name(): number {
+    return 2;
+}
+
+export function Third_Name(): number {
+    return 3;
+}
+"#,
+    );
+    let output =
+        repo.run_cli_success(&["baseline", "findings", "--incremental-output", "-f", "json"]);
+
+    let mut changes: Vec<(String, String)> = output
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let value: serde_json::Value = serde_json::from_str(line).expect("JSONL line");
+            (
+                value["change"].as_str().unwrap_or_default().to_string(),
+                value["symbol"].as_str().unwrap_or_default().to_string(),
+            )
+        })
+        .collect();
+    changes.sort();
+    assert_eq!(
+        changes,
+        vec![
+            ("new".to_string(), "Third_Name".to_string()),
+            ("resolved".to_string(), "Bad_Name".to_string()),
+        ]
+    );
+}
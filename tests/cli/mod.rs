@@ -5,6 +5,7 @@
 //! and output formats (text, toon, json).
 
 pub mod analyze_tests;
+pub mod baseline_tests;
 pub mod cache_tests;
 pub mod commit_tests;
 pub mod index_tests;
@@ -519,6 +519,28 @@ fn test_query_languages_text_format() {
     assert!(!output.is_empty(), "Should have language list");
 }
 
+#[test]
+fn test_languages_command_lists_extensions() {
+    let repo = TestRepo::new();
+
+    let json = repo.run_cli_success(&["languages", "-f", "json"]);
+    let parsed: serde_json::Value = serde_json::from_str(&json).expect("valid json");
+
+    let languages = parsed["languages"].as_array().expect("languages array");
+    assert_eq!(parsed["count"].as_u64(), Some(languages.len() as u64));
+
+    let rust = languages
+        .iter()
+        .find(|l| l["name"] == "rust")
+        .expect("rust listed");
+    assert_eq!(rust["family"], "rust");
+    assert!(rust["extensions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|e| e == "rs"));
+}
+
 // ============================================================================
 // FORMAT CONSISTENCY TESTS
 // ============================================================================
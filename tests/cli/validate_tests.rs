@@ -782,3 +782,42 @@ export function process(x: number) { return x * 2; }
     let result = repo.run_cli(&["validate", "--duplicates", "--kind", "fn", "-f", "json"]);
     assert!(result.is_ok(), "Kind filter should work");
 }
+
+// ============================================================================
+// SARIF TESTS
+// ============================================================================
+
+#[test]
+fn test_validate_sarif_repository_wide() {
+    let repo = TestRepo::new();
+    repo.add_ts_module("src/service.ts", "User");
+
+    repo.generate_index().unwrap();
+
+    let output = repo.run_cli_success(&["validate", "-f", "sarif"]);
+    let json = assert_valid_json(&output, "validate sarif");
+    assert_eq!(json["version"], "2.1.0");
+    assert!(json["runs"].is_array());
+}
+
+#[test]
+fn test_sarif_rejected_outside_repository_wide_validate() {
+    let repo = TestRepo::new();
+    repo.add_ts_module("src/service.ts", "User");
+
+    repo.generate_index().unwrap();
+
+    let (_, stderr) = repo.run_cli_failure(&["validate", "src/service.ts", "-f", "sarif"]);
+    assert!(
+        stderr.contains("--format sarif is not supported"),
+        "{}",
+        stderr
+    );
+
+    let (_, stderr) = repo.run_cli_failure(&["query", "overview", "-f", "sarif"]);
+    assert!(
+        stderr.contains("--format sarif is not supported"),
+        "{}",
+        stderr
+    );
+}
@@ -0,0 +1,86 @@
+//! Integration tests for the `semfora-lsp` binary
+//!
+//! Run with `cargo test --features lsp --test lsp_tests`.
+
+#![cfg(feature = "lsp")]
+
+use std::io::BufReader;
+use std::process::{Command, Stdio};
+
+use semfora_engine::lsp::{read_message, write_message};
+use serde_json::{json, Value};
+
+const SOURCE: &str = r#"export function add(a: number, b: number): number {
+    return a + b;
+}
+
+export class Calculator {
+    total = 0;
+}
+"#;
+
+#[test]
+fn test_initialize_and_document_symbols() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("math.ts");
+    std::fs::write(&file, SOURCE).unwrap();
+    let uri = format!("file://{}", file.display());
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_semfora-lsp"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("failed to start semfora-lsp");
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+
+    let mut request = |id: u64, method: &str, params: Value| -> Value {
+        let message = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+        write_message(&mut stdin, &message).unwrap();
+        let response = read_message(&mut stdout).unwrap().expect("no response");
+        assert_eq!(response["id"], id);
+        response
+    };
+
+    let init = request(1, "initialize", json!({ "capabilities": {} }));
+    assert_eq!(
+        init["result"]["capabilities"]["documentSymbolProvider"],
+        true
+    );
+    assert_eq!(init["result"]["capabilities"]["hoverProvider"], true);
+
+    let symbols = request(
+        2,
+        "textDocument/documentSymbol",
+        json!({ "textDocument": { "uri": uri } }),
+    );
+    let names: Vec<&str> = symbols["result"]
+        .as_array()
+        .expect("documentSymbol result should be an array")
+        .iter()
+        .filter_map(|s| s["name"].as_str())
+        .collect();
+    assert!(names.contains(&"add"), "symbols: {:?}", names);
+    assert!(names.contains(&"Calculator"), "symbols: {:?}", names);
+
+    let hover = request(
+        3,
+        "textDocument/hover",
+        json!({ "textDocument": { "uri": uri }, "position": { "line": 1, "character": 4 } }),
+    );
+    let contents = hover["result"]["contents"]["value"].as_str().unwrap();
+    assert!(contents.contains("add("), "hover: {}", contents);
+    assert!(
+        contents.contains("cognitive complexity"),
+        "hover: {}",
+        contents
+    );
+
+    let shutdown = request(4, "shutdown", Value::Null);
+    assert!(shutdown["result"].is_null());
+    write_message(&mut stdin, &json!({ "jsonrpc": "2.0", "method": "exit" })).unwrap();
+    drop(stdin);
+
+    assert!(child.wait().unwrap().success());
+}
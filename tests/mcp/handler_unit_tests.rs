@@ -5,7 +5,7 @@
 //! internal handler access.
 //!
 //! These tests cover:
-//! - All 18 MCP tool handlers via their CLI equivalents
+//! - All 19 MCP tool handlers via their CLI equivalents
 //! - Basic functionality, edge cases, and error conditions
 //! - Multi-language support
 //! - Error handling
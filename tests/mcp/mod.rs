@@ -1,6 +1,6 @@
 //! MCP server tests module
 //!
-//! Tests for all 18 MCP tool handlers:
+//! Tests for all 19 MCP tool handlers:
 //! - Direct unit tests (handler_unit_tests.rs)
 //! - JSON-RPC protocol tests (protocol_tests.rs)
 //! - E2E workflow tests (workflow_tests.rs)
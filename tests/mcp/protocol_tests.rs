@@ -251,6 +251,31 @@ export function helper() { return 1; }
     assert!(json.is_object() || json.is_array());
 }
 
+/// Test module summary (get_module) lists the module's symbols with signatures
+#[test]
+fn test_mcp_get_module_summary() {
+    let repo = TestRepo::new();
+    repo.add_ts_function("src/api/users.ts", "getUsers", "return [];")
+        .add_ts_function("src/api/posts.ts", "getPosts", "return [];");
+    repo.generate_index().unwrap();
+
+    // Unknown modules are rejected with the list of available ones
+    let (_, stderr) =
+        repo.run_cli_failure(&["query", "module", "nonexistent_module_xyz", "--summary"]);
+    let module = stderr
+        .split("Available modules: ")
+        .nth(1)
+        .and_then(|list| list.lines().next())
+        .and_then(|list| list.split(", ").find(|m| m.contains("api")))
+        .unwrap_or_else(|| panic!("Error should list the api module: {}", stderr))
+        .to_string();
+
+    let output = repo.run_cli_success(&["query", "module", &module, "--summary", "-f", "toon"]);
+    assert!(output.contains("module_summary"), "Output: {}", output);
+    assert!(output.contains("getUsers("), "Output: {}", output);
+    assert!(output.contains("getPosts("), "Output: {}", output);
+}
+
 // ============================================================================
 // BATCH REQUEST TESTS
 // ============================================================================